@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::{
+    PATHS_ERROR_MESSAGE, VERSION,
+    ignore::{IgnoreOptions, ignore_options},
+    validate_paths,
+};
+
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options, version(VERSION))]
+pub struct FormatCommand {
+    #[bpaf(external(format_mode), fallback(FormatMode::Stdout))]
+    pub mode: FormatMode,
+
+    /// Formatter configuration file
+    ///  * only `.json` extension is supported
+    ///  * option names are the same as Prettier's, e.g. `{ "semi": false }`
+    ///
+    /// If not provided, Oxfmt will look for `.oxfmtrc.json` in the current working directory.
+    #[bpaf(long, short, argument("./.oxfmtrc.json"))]
+    pub config: Option<PathBuf>,
+
+    /// Read the source text from stdin and print the formatted code to stdout.
+    /// The path is used to determine the source type, e.g. `--stdin-filepath foo.ts`.
+    #[bpaf(argument("PATH"))]
+    pub stdin_filepath: Option<PathBuf>,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    /// Number of threads to use. Set to 1 for using only 1 CPU core
+    #[bpaf(argument("INT"), hide_usage)]
+    pub threads: Option<usize>,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
+    pub paths: Vec<PathBuf>,
+}
+
+impl FormatCommand {
+    pub fn handle_threads(&self) {
+        Self::set_rayon_threads(self.threads);
+    }
+
+    fn set_rayon_threads(threads: Option<usize>) {
+        if let Some(threads) = threads {
+            rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().unwrap();
+        }
+    }
+}
+
+/// Output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Bpaf)]
+pub enum FormatMode {
+    /// Check if the given files are formatted, exit with a non-zero code otherwise.
+    /// Prints the paths of the files which are not formatted.
+    #[bpaf(long)]
+    Check,
+    /// Format the given files in place.
+    #[bpaf(long)]
+    Write,
+    /// Print the formatted code to stdout (default).
+    #[bpaf(skip)]
+    Stdout,
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{FormatMode, format_command};
+
+    #[test]
+    fn default() {
+        let options = format_command().run_inner(&["."]).unwrap();
+        assert_eq!(options.mode, FormatMode::Stdout);
+        assert_eq!(options.paths, vec![PathBuf::from(".")]);
+        assert!(options.config.is_none());
+    }
+
+    #[test]
+    fn check() {
+        let options = format_command().run_inner(&["--check", "src"]).unwrap();
+        assert_eq!(options.mode, FormatMode::Check);
+    }
+
+    #[test]
+    fn write() {
+        let options = format_command().run_inner(&["--write", "-c", "fmt.json"]).unwrap();
+        assert_eq!(options.mode, FormatMode::Write);
+        assert_eq!(options.config, Some(PathBuf::from("fmt.json")));
+    }
+
+    #[test]
+    fn check_and_write() {
+        assert!(format_command().run_inner(&["--check", "--write"]).is_err());
+    }
+
+    #[test]
+    fn stdin_filepath() {
+        let options = format_command().run_inner(&["--stdin-filepath", "foo.ts"]).unwrap();
+        assert_eq!(options.stdin_filepath, Some(PathBuf::from("foo.ts")));
+    }
+}
@@ -0,0 +1,45 @@
+use std::ffi::OsString;
+
+use bpaf::{Bpaf, doc::Style};
+
+use oxc_walk::WalkOptions;
+
+pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
+    ("Disables excluding of files from .prettierignore files, ", Style::Text),
+    ("--ignore-path", Style::Literal),
+    (" flags and ", Style::Text),
+    ("--ignore-pattern", Style::Literal),
+    (" flags", Style::Text),
+];
+
+/// Ignore Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct IgnoreOptions {
+    /// Specify the file to use as your .prettierignore
+    #[bpaf(argument("PATH"), fallback(".prettierignore".into()), hide_usage)]
+    pub ignore_path: OsString,
+
+    /// Specify patterns of files to ignore (in addition to those in .prettierignore)
+    ///
+    /// The supported syntax is the same as for .prettierignore and .gitignore files
+    /// You should quote your patterns in order to avoid shell interpretation of glob patterns
+    #[bpaf(argument("PAT"), many, hide_usage)]
+    pub ignore_pattern: Vec<String>,
+
+    #[bpaf(switch, hide_usage, help(NO_IGNORE_HELP))]
+    pub no_ignore: bool,
+
+    /// Follow symbolic links. Oxfmt ignores symbolic links by default.
+    #[bpaf(switch, hide_usage)]
+    pub symlinks: bool,
+}
+
+impl IgnoreOptions {
+    pub fn walk_options(&self) -> WalkOptions {
+        WalkOptions {
+            ignore_path: self.ignore_path.clone(),
+            no_ignore: self.no_ignore,
+            symlinks: self.symlinks,
+        }
+    }
+}
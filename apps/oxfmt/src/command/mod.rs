@@ -0,0 +1,25 @@
+mod format;
+mod ignore;
+
+use std::path::PathBuf;
+
+pub use self::{
+    format::{FormatCommand, FormatMode, format_command},
+    ignore::IgnoreOptions,
+};
+
+const VERSION: &str = match option_env!("OXC_VERSION") {
+    Some(v) => v,
+    None => "dev",
+};
+
+#[expect(clippy::ptr_arg)]
+fn validate_paths(paths: &Vec<PathBuf>) -> bool {
+    if paths.is_empty() {
+        true
+    } else {
+        paths.iter().all(|p| p.components().all(|c| c != std::path::Component::ParentDir))
+    }
+}
+
+const PATHS_ERROR_MESSAGE: &str = "PATH must not contain \"..\"";
@@ -0,0 +1,408 @@
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf, absolute},
+};
+
+use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
+use rayon::prelude::*;
+
+use oxc_allocator::Allocator;
+use oxc_formatter::{FORMAT_CONFIG_FILE, FormatConfig, FormatOptions, Formatter};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_walk::{Extensions, Walk};
+
+use crate::cli::{CliRunResult, FormatCommand, FormatMode, Runner};
+
+/// Extensions of the files which can be formatted.
+const FORMATTABLE_EXTENSIONS: &[&str] = &["js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx"];
+
+pub struct FormatRunner {
+    options: FormatCommand,
+    cwd: PathBuf,
+    stdin: Option<String>,
+    /// Errors are written here, so that they do not end up in formatted code written to stdout.
+    stderr: Box<dyn Write>,
+}
+
+/// Result of formatting a single file.
+enum FileResult {
+    Formatted { code: String, changed: bool },
+    Error(String),
+}
+
+impl Runner for FormatRunner {
+    type Options = FormatCommand;
+
+    fn new(options: Self::Options) -> Self {
+        Self {
+            options,
+            cwd: env::current_dir().expect("Failed to get current working directory"),
+            stdin: None,
+            stderr: Box::new(io::stderr()),
+        }
+    }
+
+    fn run(mut self, stdout: &mut dyn Write) -> CliRunResult {
+        let stderr = &mut *self.stderr;
+        let format_options = match Self::find_format_config(&self.cwd, self.options.config.as_ref())
+        {
+            Ok(options) => options,
+            Err(err) => {
+                print_and_flush(stderr, &format!("Failed to parse configuration file.\n{err}\n"));
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+
+        if let Some(stdin_filepath) = &self.options.stdin_filepath {
+            let source_text = if let Some(stdin) = self.stdin {
+                stdin
+            } else {
+                let mut source_text = String::new();
+                if let Err(err) = std::io::stdin().read_to_string(&mut source_text) {
+                    print_and_flush(stderr, &format!("Failed to read stdin: {err}\n"));
+                    return CliRunResult::FormatFailed;
+                }
+                source_text
+            };
+            return match format_source(stdin_filepath, &source_text, &format_options) {
+                Ok(code) => {
+                    print_and_flush(stdout, &code);
+                    CliRunResult::FormatSucceeded
+                }
+                Err(err) => {
+                    print_and_flush(stderr, &format!("{err}\n"));
+                    CliRunResult::FormatFailed
+                }
+            };
+        }
+
+        let FormatCommand { mode, ignore_options, mut paths, .. } = self.options;
+        let provided_path_count = paths.len();
+        let mut override_builder = None;
+
+        if !ignore_options.no_ignore {
+            let mut builder = OverrideBuilder::new(&self.cwd);
+
+            for pattern in &ignore_options.ignore_pattern {
+                // Meaning of ignore pattern is reversed
+                // <https://docs.rs/ignore/latest/ignore/overrides/struct.OverrideBuilder.html#method.add>
+                let pattern = format!("!{pattern}");
+                builder.add(&pattern).unwrap();
+            }
+
+            let builder = builder.build().unwrap();
+
+            // The ignore crate whitelists explicit paths, but priority
+            // should be given to the ignore file.
+            if !paths.is_empty() {
+                let (ignore, _err) = Gitignore::new(self.cwd.join(&ignore_options.ignore_path));
+
+                paths.retain_mut(|p| {
+                    // Try to prepend cwd to all paths
+                    let Ok(mut path) = absolute(self.cwd.join(&p)) else {
+                        return false;
+                    };
+
+                    std::mem::swap(p, &mut path);
+
+                    if path.is_dir() {
+                        true
+                    } else {
+                        !(builder.matched(p, false).is_ignore()
+                            || ignore.matched(path, false).is_ignore())
+                    }
+                });
+            }
+
+            override_builder = Some(builder);
+        }
+
+        if paths.is_empty() {
+            if provided_path_count > 0 {
+                return CliRunResult::FormatNoFilesFound;
+            }
+            paths.push(self.cwd.clone());
+        }
+
+        let mut paths = Walk::new(
+            &paths,
+            &ignore_options.walk_options(),
+            override_builder,
+            Extensions(FORMATTABLE_EXTENSIONS.to_vec()),
+        )
+        .paths()
+        .into_iter()
+        .map(|path| PathBuf::from(&*path))
+        .collect::<Vec<_>>();
+        if paths.is_empty() {
+            return CliRunResult::FormatNoFilesFound;
+        }
+        // Print the results in a stable order.
+        paths.sort_unstable();
+
+        let results = paths
+            .par_iter()
+            .map(|path| {
+                let source_text = match fs::read_to_string(path) {
+                    Ok(source_text) => source_text,
+                    Err(err) => return FileResult::Error(format!("Failed to read file: {err}")),
+                };
+                match format_source(path, &source_text, &format_options) {
+                    Ok(code) => {
+                        let changed = code != source_text;
+                        if changed && mode == FormatMode::Write {
+                            if let Err(err) = fs::write(path, &code) {
+                                return FileResult::Error(format!("Failed to write file: {err}"));
+                            }
+                        }
+                        FileResult::Formatted { code, changed }
+                    }
+                    Err(err) => FileResult::Error(err),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut number_of_changed_files = 0;
+        let mut has_errors = false;
+        for (path, result) in paths.iter().zip(results) {
+            let display_path = path.strip_prefix(&self.cwd).unwrap_or(path).to_string_lossy();
+            match result {
+                FileResult::Formatted { code, changed } => {
+                    if changed {
+                        number_of_changed_files += 1;
+                    }
+                    match mode {
+                        FormatMode::Check if changed => {
+                            print_and_flush(stdout, &format!("{display_path}\n"));
+                        }
+                        FormatMode::Check | FormatMode::Write => {}
+                        FormatMode::Stdout => print_and_flush(stdout, &code),
+                    }
+                }
+                FileResult::Error(err) => {
+                    has_errors = true;
+                    print_and_flush(stderr, &format!("{display_path}: {err}\n"));
+                }
+            }
+        }
+
+        if has_errors {
+            CliRunResult::FormatFailed
+        } else if number_of_changed_files > 0 && mode == FormatMode::Check {
+            print_and_flush(
+                stdout,
+                &format!("Found {number_of_changed_files} unformatted files.\n"),
+            );
+            CliRunResult::FormatCheckFailed
+        } else {
+            CliRunResult::FormatSucceeded
+        }
+    }
+}
+
+impl FormatRunner {
+    #[must_use]
+    pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
+        self.cwd = cwd;
+        self
+    }
+
+    /// Use `stdin` as the content of stdin, instead of reading from the process' stdin.
+    #[must_use]
+    pub fn with_stdin(mut self, stdin: String) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+
+    /// Write errors to `stderr`, instead of the process' stderr.
+    #[must_use]
+    pub fn with_stderr(mut self, stderr: Box<dyn Write>) -> Self {
+        self.stderr = stderr;
+        self
+    }
+
+    /// Read the format options from `config`,
+    /// or from a [FORMAT_CONFIG_FILE] in `cwd` if `config` is not provided.
+    fn find_format_config(cwd: &Path, config: Option<&PathBuf>) -> Result<FormatOptions, String> {
+        let path = if let Some(config) = config {
+            cwd.join(config)
+        } else {
+            let path = cwd.join(FORMAT_CONFIG_FILE);
+            if !path.is_file() {
+                return Ok(FormatOptions::default());
+            }
+            path
+        };
+        let content = fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+        let config = serde_json::from_str::<FormatConfig>(&content)
+            .map_err(|err| format!("Failed to parse {}: {err}", path.display()))?;
+        config.into_format_options()
+    }
+}
+
+fn format_source(
+    path: &Path,
+    source_text: &str,
+    options: &FormatOptions,
+) -> Result<String, String> {
+    let source_type = SourceType::from_path(path).map_err(|err| err.to_string())?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if let Some(error) = ret.errors.first() {
+        return Err(format!("Failed to parse: {error}"));
+    }
+    Ok(Formatter::new(&allocator, options.clone()).build(&ret.program))
+}
+
+fn print_and_flush(writer: &mut dyn Write, message: &str) {
+    writer.write_all(message.as_bytes()).or_else(check_for_writer_error).unwrap();
+    writer.flush().unwrap();
+}
+
+fn check_for_writer_error(error: std::io::Error) -> Result<(), std::io::Error> {
+    // Do not panic when the process is killed (e.g. piping into `less`).
+    if matches!(error.kind(), std::io::ErrorKind::Interrupted | std::io::ErrorKind::BrokenPipe) {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        cell::RefCell,
+        fs,
+        io::{self, Write},
+        rc::Rc,
+    };
+
+    use crate::cli::{CliRunResult, FormatRunner, Runner, format_command};
+
+    /// A writer whose output can be read after it is moved into a [FormatRunner].
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+    }
+
+    fn run(cwd: &std::path::Path, args: &[&str]) -> (CliRunResult, String) {
+        let (result, stdout, _) = run_with_stderr(cwd, args);
+        (result, stdout)
+    }
+
+    fn run_with_stderr(cwd: &std::path::Path, args: &[&str]) -> (CliRunResult, String, String) {
+        let options = format_command().run_inner(args).unwrap();
+        let mut output = Vec::new();
+        let stderr = SharedBuffer::default();
+        let result = FormatRunner::new(options)
+            .with_cwd(cwd.to_path_buf())
+            .with_stderr(Box::new(stderr.clone()))
+            .run(&mut output);
+        (result, String::from_utf8(output).unwrap(), stderr.contents())
+    }
+
+    #[test]
+    fn check() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("formatted.js"), "let a = 1;\n").unwrap();
+        fs::write(dir.path().join("unformatted.js"), "let  a=1").unwrap();
+        let (result, output) = run(dir.path(), &["--check"]);
+        assert_eq!(result, CliRunResult::FormatCheckFailed);
+        assert_eq!(output, "unformatted.js\nFound 1 unformatted files.\n");
+    }
+
+    #[test]
+    fn write() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("unformatted.ts");
+        fs::write(&path, "let  a : number=1").unwrap();
+        let (result, output) = run(dir.path(), &["--write", "unformatted.ts"]);
+        assert_eq!(result, CliRunResult::FormatSucceeded);
+        assert_eq!(output, "");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "let a: number = 1;\n");
+        assert_eq!(run(dir.path(), &["--check"]).0, CliRunResult::FormatSucceeded);
+    }
+
+    #[test]
+    fn ignore_pattern() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("unformatted.js"), "let  a=1").unwrap();
+        let (result, _) = run(dir.path(), &["--check", "--ignore-pattern", "*.js"]);
+        assert_eq!(result, CliRunResult::FormatNoFilesFound);
+    }
+
+    #[test]
+    fn syntax_error() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("invalid.js"), "let a = ;").unwrap();
+        let (result, stdout, stderr) = run_with_stderr(dir.path(), &["--check"]);
+        assert_eq!(result, CliRunResult::FormatFailed);
+        assert_eq!(stdout, "");
+        assert!(stderr.starts_with("invalid.js: Failed to parse"));
+    }
+
+    #[test]
+    fn stdin_syntax_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = format_command().run_inner(&["--stdin-filepath", "foo.js"]).unwrap();
+        let mut output = Vec::new();
+        let stderr = SharedBuffer::default();
+        let result = FormatRunner::new(options)
+            .with_cwd(dir.path().to_path_buf())
+            .with_stdin("let a = ;".to_string())
+            .with_stderr(Box::new(stderr.clone()))
+            .run(&mut output);
+        assert_eq!(result, CliRunResult::FormatFailed);
+        assert!(output.is_empty());
+        assert!(stderr.contents().starts_with("Failed to parse"));
+    }
+
+    #[test]
+    fn config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".oxfmtrc.json"), r#"{ "semi": false, "singleQuote": true }"#)
+            .unwrap();
+        fs::write(dir.path().join("index.js"), "let a = \"b\";").unwrap();
+        let (result, output) = run(dir.path(), &["index.js"]);
+        assert_eq!(result, CliRunResult::FormatSucceeded);
+        assert_eq!(output, "let a = 'b'\n");
+    }
+
+    #[test]
+    fn invalid_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("oxfmt.json"), r#"{ "trailingComma": "some" }"#).unwrap();
+        let (result, _) = run(dir.path(), &["-c", "oxfmt.json"]);
+        assert_eq!(result, CliRunResult::InvalidOptionConfig);
+    }
+
+    #[test]
+    fn stdin() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = format_command().run_inner(&["--stdin-filepath", "foo.tsx"]).unwrap();
+        let mut output = Vec::new();
+        let result = FormatRunner::new(options)
+            .with_cwd(dir.path().to_path_buf())
+            .with_stdin("const  a = <div/>".to_string())
+            .run(&mut output);
+        assert_eq!(result, CliRunResult::FormatSucceeded);
+        assert_eq!(String::from_utf8(output).unwrap(), "const a = <div />;\n");
+    }
+}
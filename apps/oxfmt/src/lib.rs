@@ -0,0 +1,8 @@
+mod command;
+mod format;
+mod result;
+mod runner;
+
+pub mod cli {
+    pub use crate::{command::*, format::FormatRunner, result::CliRunResult, runner::Runner};
+}
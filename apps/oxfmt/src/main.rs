@@ -0,0 +1,12 @@
+use oxfmt::cli::{CliRunResult, FormatRunner, Runner};
+use std::io::BufWriter;
+
+fn main() -> CliRunResult {
+    let command = oxfmt::cli::format_command().run();
+    command.handle_threads();
+    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
+    // See `https://github.com/rust-lang/rust/issues/60673`.
+    let mut stdout = BufWriter::new(std::io::stdout());
+
+    FormatRunner::new(command).run(&mut stdout)
+}
@@ -0,0 +1,24 @@
+use std::process::{ExitCode, Termination};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CliRunResult {
+    None,
+    InvalidOptionConfig,
+    FormatSucceeded,
+    FormatNoFilesFound,
+    /// `--check` found files which are not formatted.
+    FormatCheckFailed,
+    /// Some files could not be read, parsed or written.
+    FormatFailed,
+}
+
+impl Termination for CliRunResult {
+    fn report(self) -> ExitCode {
+        match self {
+            Self::None | Self::FormatSucceeded | Self::FormatNoFilesFound => ExitCode::SUCCESS,
+            Self::InvalidOptionConfig | Self::FormatCheckFailed | Self::FormatFailed => {
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+// moved down
+debugger;
+debugger;
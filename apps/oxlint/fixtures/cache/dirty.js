@@ -0,0 +1,3 @@
+try {
+  foo();
+} catch {}
@@ -0,0 +1,3 @@
+debugger;
+const a = 1;
+debugger;
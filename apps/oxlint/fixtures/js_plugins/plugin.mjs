@@ -0,0 +1,34 @@
+export default {
+  meta: { name: 'custom' },
+  rules: {
+    'no-foo': {
+      meta: { messages: { noFoo: 'Unexpected identifier `{{ name }}`.' } },
+      create(context) {
+        return {
+          Identifier(node) {
+            if (node.name === 'foo') {
+              context.report({ node, messageId: 'noFoo', data: { name: node.name } });
+            }
+          },
+        };
+      },
+    },
+    'no-var': {
+      meta: { fixable: 'code' },
+      create(context) {
+        const replacement = context.options[0]?.replacement ?? 'let';
+        return {
+          'VariableDeclaration:exit'(node) {
+            if (node.kind === 'var') {
+              context.report({
+                node,
+                message: `Use \`${replacement}\` instead of \`var\`.`,
+                fix: (fixer) => fixer.replaceTextRange([node.start, node.start + 3], replacement),
+              });
+            }
+          },
+        };
+      },
+    },
+  },
+};
@@ -0,0 +1,5 @@
+// Ünïcödé
+var a = foo;
+
+// eslint-disable-next-line custom/no-foo
+foo();
@@ -0,0 +1 @@
+var b = foo as number;
@@ -0,0 +1,71 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use oxc_diagnostics::{Baseline, BaselineEntry};
+
+/// Baseline file, e.g.
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "files": {
+///     "src/index.js": {
+///       "eslint(no-debugger)": {
+///         "a5b1ce5a4b7ba6b5": 2
+///       }
+///     }
+///   }
+/// }
+/// ```
+///
+/// Diagnostics are grouped by file and rule, and counted by fingerprint.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct BaselineFile {
+    version: u32,
+    files: BTreeMap<String, BTreeMap<String, BTreeMap<String, usize>>>,
+}
+
+impl BaselineFile {
+    const VERSION: u32 = 1;
+}
+
+/// Read the baseline file at `path`.
+///
+/// # Errors
+///
+/// * The file could not be read, or is not a valid baseline file.
+pub fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    let text = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let file: BaselineFile = serde_json::from_str(&text).map_err(|err| err.to_string())?;
+    if file.version != BaselineFile::VERSION {
+        return Err(format!("Unsupported baseline version {}", file.version));
+    }
+    let entries = file.files.into_iter().flat_map(|(file, rules)| {
+        rules.into_iter().flat_map(move |(rule, fingerprints)| {
+            let file = file.clone();
+            fingerprints.into_iter().map(move |(fingerprint, count)| {
+                (BaselineEntry { file: file.clone(), rule: rule.clone(), fingerprint }, count)
+            })
+        })
+    });
+    Ok(Baseline::new(entries))
+}
+
+/// Write the diagnostics suppressed by `baseline` to `path`.
+///
+/// # Errors
+///
+/// * The file could not be written.
+pub fn write_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let mut file = BaselineFile { version: BaselineFile::VERSION, files: BTreeMap::new() };
+    for (entry, count) in baseline.suppressed_entries() {
+        file.files
+            .entry(entry.file.clone())
+            .or_default()
+            .entry(entry.rule.clone())
+            .or_default()
+            .insert(entry.fingerprint.clone(), count);
+    }
+    let mut json = serde_json::to_string_pretty(&file).expect("Failed to serialize");
+    json.push('\n');
+    fs::write(path, json)
+}
@@ -2,6 +2,8 @@ use std::ffi::OsString;
 
 use bpaf::{Bpaf, doc::Style};
 
+use oxc_walk::WalkOptions;
+
 pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
     ("Disables excluding of files from .eslintignore files, ", Style::Text),
     ("--ignore-path", Style::Literal),
@@ -32,6 +34,16 @@ pub struct IgnoreOptions {
     pub symlinks: bool,
 }
 
+impl IgnoreOptions {
+    pub fn walk_options(&self) -> WalkOptions {
+        WalkOptions {
+            ignore_path: self.ignore_path.clone(),
+            no_ignore: self.no_ignore,
+            symlinks: self.symlinks,
+        }
+    }
+}
+
 #[cfg(test)]
 mod ignore_options {
     use std::{ffi::OsString, path::PathBuf};
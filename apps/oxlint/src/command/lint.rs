@@ -49,6 +49,18 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub inline_config_options: InlineConfigOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
+    #[bpaf(external)]
+    pub diff_options: DiffOptions,
+
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
@@ -194,7 +206,7 @@ pub struct WarningOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `sarif`, `stylish`, `unix`
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
 }
@@ -379,6 +391,62 @@ pub struct InlineConfigOptions {
     pub report_unused_directives: ReportUnusedDirectives,
 }
 
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files which changed since the last run.
+    /// Files without diagnostics are remembered in a cache file,
+    /// and skipped as long as their contents and configuration do not change.
+    /// With the import plugin, files are also linted again when a module they import changes.
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path of the cache file, or of a directory to put `.oxlintcache` in.
+    /// Defaults to `.oxlintcache` in the current working directory.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Do not report diagnostics which are in this baseline file, so that only new diagnostics are reported.
+    /// Diagnostics in the baseline which no longer occur are reported as stale.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Write all diagnostics to the baseline file instead of reporting them.
+    /// Writes to `oxlint-baseline.json` if `--baseline` is not given.
+    #[bpaf(switch, hide_usage)]
+    pub generate_baseline: bool,
+}
+
+/// Changed Code
+#[derive(Debug, Clone, Bpaf)]
+pub struct DiffOptions {
+    /// Only lint the files in this unified diff, e.g. the output of `git diff`,
+    /// and only report diagnostics on the lines it adds or changes.
+    /// Use `-` to read the diff from stdin. Paths in the diff are relative to the current working directory.
+    /// Imported modules are still loaded, so rules like `import/no-cycle` see unchanged files.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub diff: Option<PathBuf>,
+}
+
+/// Stdin
+#[derive(Debug, Clone, Bpaf)]
+pub struct StdinOptions {
+    /// Lint source code read from stdin instead of files.
+    /// With `--fix`, the fixed source code is written to stdout and diagnostics to stderr.
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// Filename of the source code read from stdin, used to find its configuration,
+    /// apply ignore patterns and overrides, and detect its file type.
+    /// Defaults to `stdin.js` in the current working directory.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+}
+
 #[cfg(test)]
 mod plugins {
     use oxc_linter::LintPlugins;
@@ -604,3 +672,109 @@ mod inline_config_options {
         );
     }
 }
+
+#[cfg(test)]
+mod cache_options {
+    use std::path::PathBuf;
+
+    use super::{LintCommand, lint_command};
+
+    fn get_lint_options(arg: &str) -> LintCommand {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let options = get_lint_options(".");
+        assert!(!options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, None);
+    }
+
+    #[test]
+    fn cache_location() {
+        let options = get_lint_options("--cache --cache-location node_modules/.cache/oxlint .");
+        assert!(options.cache_options.cache);
+        assert_eq!(
+            options.cache_options.cache_location,
+            Some(PathBuf::from("node_modules/.cache/oxlint"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod baseline_options {
+    use std::path::PathBuf;
+
+    use super::{LintCommand, lint_command};
+
+    fn get_lint_options(arg: &str) -> LintCommand {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let options = get_lint_options(".");
+        assert_eq!(options.baseline_options.baseline, None);
+        assert!(!options.baseline_options.generate_baseline);
+    }
+
+    #[test]
+    fn generate_baseline() {
+        let options = get_lint_options("--generate-baseline --baseline baseline.json .");
+        assert_eq!(options.baseline_options.baseline, Some(PathBuf::from("baseline.json")));
+        assert!(options.baseline_options.generate_baseline);
+    }
+}
+
+#[cfg(test)]
+mod diff_options {
+    use std::path::PathBuf;
+
+    use super::{LintCommand, lint_command};
+
+    fn get_lint_options(arg: &str) -> LintCommand {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let options = get_lint_options(".");
+        assert_eq!(options.diff_options.diff, None);
+    }
+
+    #[test]
+    fn stdin() {
+        let options = get_lint_options("--diff - .");
+        assert_eq!(options.diff_options.diff, Some(PathBuf::from("-")));
+    }
+}
+
+#[cfg(test)]
+mod stdin_options {
+    use std::path::PathBuf;
+
+    use super::{LintCommand, lint_command};
+
+    fn get_lint_options(arg: &str) -> LintCommand {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let options = get_lint_options(".");
+        assert!(!options.stdin_options.stdin);
+        assert_eq!(options.stdin_options.stdin_filename, None);
+    }
+
+    #[test]
+    fn stdin_filename() {
+        let options = get_lint_options("--stdin --stdin-filename src/foo.ts");
+        assert!(options.stdin_options.stdin);
+        assert_eq!(options.stdin_options.stdin_filename, Some(PathBuf::from("src/foo.ts")));
+        assert!(options.paths.is_empty());
+    }
+}
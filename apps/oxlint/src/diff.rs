@@ -0,0 +1,150 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use cow_utils::CowUtils;
+use oxc_diagnostics::ChangedLines;
+
+/// Read the unified diff at `path`, or from stdin if `path` is `-`.
+///
+/// # Errors
+///
+/// * The diff could not be read.
+pub fn read_diff(path: &Path) -> io::Result<ChangedLines> {
+    let text = if path == Path::new("-") {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        fs::read_to_string(path)?
+    };
+    Ok(parse_diff(&text))
+}
+
+/// Collect the lines added in each file of a unified diff, e.g. the output of `git diff`.
+///
+/// File paths are taken from the `+++` lines, without the `b/` prefix of git. Deleted files are
+/// skipped, and files with only removed lines are changed, but without any changed lines.
+pub fn parse_diff(text: &str) -> ChangedLines {
+    let mut changed = ChangedLines::default();
+    let mut file: Option<String> = None;
+    // Next line in the new file, and the number of lines of the current hunk in the new file left.
+    let mut line = 0;
+    let mut remaining = 0;
+    // First line of the added lines right before `line`, which are inserted as one range.
+    let mut added_start: Option<usize> = None;
+
+    for text in text.lines() {
+        if remaining > 0 {
+            if text.starts_with('+') {
+                added_start.get_or_insert(line);
+                line += 1;
+                remaining -= 1;
+            } else if text.starts_with(' ') || text.is_empty() {
+                insert_added(&mut changed, file.as_deref(), added_start.take(), line);
+                line += 1;
+                remaining -= 1;
+            }
+            // Removed lines and `\ No newline at end of file` are not in the new file.
+            if remaining == 0 {
+                insert_added(&mut changed, file.as_deref(), added_start.take(), line);
+            }
+            continue;
+        }
+
+        if let Some(path) = text.strip_prefix("+++ ") {
+            // Some tools append a timestamp after a tab.
+            let path = path.split('\t').next().unwrap_or(path).trim_end();
+            file = (path != "/dev/null").then(|| {
+                path.strip_prefix("b/").unwrap_or(path).cow_replace('\\', "/").into_owned()
+            });
+            if let Some(file) = &file {
+                changed.insert_file(file.clone());
+            }
+        } else if let Some(header) = text.strip_prefix("@@ ") {
+            if let Some((start, count)) = parse_hunk_header(header) {
+                line = start;
+                remaining = count;
+            }
+        }
+    }
+
+    // The diff ended in the middle of a hunk.
+    insert_added(&mut changed, file.as_deref(), added_start, line);
+    changed
+}
+
+/// Mark the lines of `file` from `start` up to, but not including, `end` as changed.
+fn insert_added(changed: &mut ChangedLines, file: Option<&str>, start: Option<usize>, end: usize) {
+    if let (Some(file), Some(start)) = (file, start) {
+        let last = end - 1;
+        changed.insert(file.to_string(), start..=last);
+    }
+}
+
+/// Parse the start line and line count in the new file of `-a,b +c,d @@`.
+fn parse_hunk_header(header: &str) -> Option<(usize, usize)> {
+    let range = header.split(' ').find_map(|part| part.strip_prefix('+'))?;
+    let (start, count) = match range.split_once(',') {
+        Some((start, count)) => (start.parse().ok()?, count.parse().ok()?),
+        None => (range.parse().ok()?, 1),
+    };
+    Some((start, count))
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_diff;
+
+    #[test]
+    fn parse() {
+        let diff = "\
+diff --git a/src/a.js b/src/a.js
+index 1234567..89abcde 100644
+--- a/src/a.js
++++ b/src/a.js
+@@ -1,3 +1,4 @@
+ const a = 1;
+-const b = 2;
++const b = 3;
++const c = 4;
+ export { a };
+@@ -10 +11 @@ function foo() {
+-  debugger;
++  debugger
+@@ -20,3 +21,4 @@
++x;
+ y;
++z;
+-w;
++v;
+diff --git a/src/removed.js b/src/removed.js
+deleted file mode 100644
+--- a/src/removed.js
++++ /dev/null
+@@ -1 +0,0 @@
+-debugger;
+--- a/src/b.js
++++ b/src/b.js
+@@ -2,2 +1,0 @@
+-a;
+-b;
+";
+        let changed = parse_diff(diff);
+        assert_eq!(changed.files().collect::<Vec<_>>(), vec!["src/a.js", "src/b.js"]);
+        assert!(!changed.contains_line("src/a.js", 1));
+        assert!(changed.contains_line("src/a.js", 2));
+        assert!(changed.contains_line("src/a.js", 3));
+        assert!(!changed.contains_line("src/a.js", 4));
+        assert!(!changed.contains_line("src/a.js", 10));
+        assert!(changed.contains_line("src/a.js", 11));
+        assert!(changed.contains_line("src/a.js", 21));
+        assert!(!changed.contains_line("src/a.js", 22));
+        assert!(changed.contains_line("src/a.js", 23));
+        assert!(changed.contains_line("src/a.js", 24));
+        assert!(!changed.contains_line("src/a.js", 25));
+        assert!(!changed.contains_line("src/b.js", 1));
+    }
+}
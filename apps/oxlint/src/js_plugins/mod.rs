@@ -0,0 +1,189 @@
+//! Runs the rules of JavaScript plugins.
+//!
+//! Rules currently run in a Node.js process started with `node --eval`, which requires `node` on
+//! the `PATH`. This is an interim solution until they run in the Node.js process of the `oxlint`
+//! npm package through NAPI, so it is only built with the `js_plugins` feature. Without it,
+//! `jsPlugins` of configuration files are an error.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::{Mutex, PoisonError},
+};
+
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::json;
+
+use oxc_linter::{
+    ExternalDiagnostic, ExternalFix, ExternalLintFile, ExternalLinter, ExternalPlugin,
+};
+use oxc_span::Span;
+
+/// Script run by Node.js, see the comments in the file for the protocol.
+const PLUGIN_HOST: &str = include_str!("plugin_host.mjs");
+
+/// An [`ExternalLinter`] which runs plugins in a Node.js process.
+///
+/// The process is started on first use, and shared by all threads. Requests are handled one at
+/// a time, so rules of JavaScript plugins do not run in parallel.
+#[derive(Debug, Default)]
+pub struct NodeExternalLinter {
+    process: Mutex<Option<NodeProcess>>,
+}
+
+impl NodeExternalLinter {
+    fn request<T: DeserializeOwned>(&self, request: &str) -> Result<T, String> {
+        let mut process = self.process.lock().unwrap_or_else(PoisonError::into_inner);
+        let node = match process.as_mut() {
+            Some(node) => node,
+            None => process.insert(NodeProcess::spawn()?),
+        };
+        match node.request(request) {
+            Ok(Response::Ok(value)) => Ok(value),
+            Ok(Response::Error(message)) => Err(message),
+            Err(err) => {
+                // Start a new process for the next request.
+                *process = None;
+                Err(err)
+            }
+        }
+    }
+}
+
+impl ExternalLinter for NodeExternalLinter {
+    fn load_plugin(&self, path: &Path) -> Result<ExternalPlugin, String> {
+        #[derive(Deserialize)]
+        struct Plugin {
+            name: String,
+            rules: Vec<String>,
+        }
+
+        let request = json!({ "type": "loadPlugin", "path": path }).to_string();
+        let Plugin { name, rules } = self.request(&request)?;
+        Ok(ExternalPlugin { name, rules })
+    }
+
+    fn lint_file(&self, file: &ExternalLintFile<'_>) -> Result<Vec<ExternalDiagnostic>, String> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Diagnostic {
+            rule_index: usize,
+            message: String,
+            start: u32,
+            end: u32,
+            fix: Option<Fix>,
+        }
+
+        #[derive(Deserialize)]
+        struct Fix {
+            start: u32,
+            end: u32,
+            text: String,
+        }
+
+        let rules = file
+            .rules
+            .iter()
+            .map(|rule| {
+                json!({
+                    "pluginPath": &*rule.plugin_path,
+                    "pluginName": rule.plugin_name,
+                    "ruleName": rule.rule_name,
+                    "options": rule.options,
+                })
+            })
+            .collect::<Vec<_>>();
+        let comments = file
+            .comments
+            .iter()
+            .map(|(span, is_line)| {
+                json!({
+                    "type": if *is_line { "Line" } else { "Block" },
+                    "start": span.start,
+                    "end": span.end,
+                })
+            })
+            .collect::<Vec<_>>();
+        let request = json!({
+            "type": "lintFile",
+            "path": file.path,
+            "sourceText": file.source_text,
+            "comments": comments,
+            "rules": rules,
+        })
+        .to_string();
+        // Splice in the program, which is already serialized to JSON. Requests are terminated by
+        // line breaks, which can only be whitespace in JSON, as strings escape them.
+        let mut request = request.strip_suffix('}').unwrap_or(&request).to_string();
+        request.push_str(",\"program\":");
+        request.extend(file.program_json.split('\n'));
+        request.push('}');
+
+        let diagnostics: Vec<Diagnostic> = self.request(&request)?;
+        Ok(diagnostics
+            .into_iter()
+            .map(|diagnostic| ExternalDiagnostic {
+                rule_index: diagnostic.rule_index,
+                message: diagnostic.message,
+                span: Span::new(diagnostic.start, diagnostic.end),
+                fix: diagnostic
+                    .fix
+                    .map(|fix| ExternalFix { span: Span::new(fix.start, fix.end), text: fix.text }),
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Response<T> {
+    Ok(T),
+    Error(String),
+}
+
+#[derive(Debug)]
+struct NodeProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl NodeProcess {
+    fn spawn() -> Result<Self, String> {
+        let mut child = Command::new("node")
+            .args(["--input-type=module", "--eval", PLUGIN_HOST])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|err| {
+                format!("failed to start Node.js, which is required for JS plugins: {err}")
+            })?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err("failed to connect to Node.js".to_string());
+        };
+        Ok(Self { child, stdin, stdout: BufReader::new(stdout) })
+    }
+
+    fn request<T: DeserializeOwned>(&mut self, request: &str) -> Result<Response<T>, String> {
+        let connection_lost = |err: std::io::Error| format!("lost connection to Node.js: {err}");
+        self.stdin.write_all(request.as_bytes()).map_err(connection_lost)?;
+        self.stdin.write_all(b"\n").map_err(connection_lost)?;
+        self.stdin.flush().map_err(connection_lost)?;
+
+        let mut response = String::new();
+        if self.stdout.read_line(&mut response).map_err(connection_lost)? == 0 {
+            return Err("Node.js exited unexpectedly".to_string());
+        }
+        serde_json::from_str(&response)
+            .map_err(|err| format!("invalid response from Node.js: {err}"))
+    }
+}
+
+impl Drop for NodeProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,379 @@
+// Host for the JavaScript plugins of oxlint, run with Node.js by `NodeExternalLinter`.
+//
+// Reads requests from stdin, one JSON object per line, and writes one JSON response per line to
+// stdout, either `{ "ok": result }` or `{ "error": message }`. Requests are:
+//
+// * `{ "type": "loadPlugin", "path": string }`, with result `{ name: string, rules: string[] }`.
+// * `{ "type": "lintFile", "path", "sourceText", "comments", "rules", "program" }`, with result
+//   `{ ruleIndex, message, start, end, fix: { start, end, text } | null }[]`.
+//
+// Oxlint works with UTF-8 byte offsets, which are converted from and to the UTF-16 offsets
+// that rules expect.
+//
+// Rules use ESLint's API: `create(context)` returns a visitor whose keys are node types,
+// optionally with an `:exit` suffix, or `*`. Other selectors and the scope manager are not
+// supported.
+
+import { readFileSync } from 'node:fs';
+import { basename, dirname, extname, join, sep } from 'node:path';
+import { createInterface } from 'node:readline';
+import { pathToFileURL } from 'node:url';
+
+// Stdout is reserved for responses, so redirect output of plugins to stderr.
+const writeResponse = process.stdout.write.bind(process.stdout);
+process.stdout.write = process.stderr.write.bind(process.stderr);
+console.log = console.info = console.debug = console.error;
+
+/** Loaded plugins, keyed by path. */
+const plugins = new Map();
+
+async function loadPlugin(path) {
+  let plugin = plugins.get(path);
+  if (!plugin) {
+    const module = await import(pathToFileURL(path).href);
+    plugin = module.default ?? module;
+    if (plugin === null || typeof plugin !== 'object' || typeof plugin.rules !== 'object') {
+      throw new Error(`${path} is not an ESLint plugin, it does not export \`rules\``);
+    }
+    plugins.set(path, plugin);
+  }
+  return { name: pluginName(plugin, path), rules: Object.keys(plugin.rules) };
+}
+
+/**
+ * The prefix of the rules of a plugin: `meta.name`, or the name of its package without
+ * `eslint-plugin`, or the file name for plugins outside of `node_modules`.
+ */
+function pluginName(plugin, path) {
+  let name = plugin.meta?.name;
+  if (typeof name !== 'string') {
+    name = path.includes(`${sep}node_modules${sep}`)
+      ? packageName(path)
+      : basename(path, extname(path));
+  }
+  // `eslint-plugin-foo` -> `foo`, `@scope/eslint-plugin` -> `@scope`,
+  // `@scope/eslint-plugin-foo` -> `@scope/foo`
+  const match = /^(@[^/]+)?\/?eslint-plugin(?:-(.+))?$/.exec(name);
+  if (match) {
+    const [, scope, rest] = match;
+    if (scope) return rest ? `${scope}/${rest}` : scope;
+    if (rest) return rest;
+  }
+  return name;
+}
+
+function packageName(path) {
+  for (let dir = dirname(path); dir !== dirname(dir); dir = dirname(dir)) {
+    try {
+      const { name } = JSON.parse(readFileSync(join(dir, 'package.json'), 'utf8'));
+      if (typeof name === 'string') return name;
+    } catch {
+      // No `package.json` in this directory.
+    }
+  }
+  return basename(path, extname(path));
+}
+
+/** Converts between UTF-8 offsets used by oxlint and UTF-16 offsets used by JavaScript. */
+class Offsets {
+  constructor(text) {
+    // oxlint-disable-next-line no-control-regex
+    this.isAscii = /^[\x00-\x7f]*$/.test(text);
+    if (this.isAscii) return;
+
+    this.utf16ToUtf8 = new Uint32Array(text.length + 1);
+    const utf8ToUtf16 = [];
+    let utf8 = 0;
+    for (let i = 0; i < text.length; i++) {
+      this.utf16ToUtf8[i] = utf8;
+      const code = text.charCodeAt(i);
+      let bytes;
+      if (code < 0x80) bytes = 1;
+      else if (code < 0x800) bytes = 2;
+      else if (code >= 0xd800 && code < 0xdc00 && i + 1 < text.length) {
+        // Surrogate pair, both code units map to the start of the 4-byte character.
+        bytes = 4;
+        this.utf16ToUtf8[i + 1] = utf8;
+      } else bytes = 3;
+      for (let b = 0; b < bytes; b++) utf8ToUtf16.push(i);
+      utf8 += bytes;
+      if (bytes === 4) i++;
+    }
+    this.utf16ToUtf8[text.length] = utf8;
+    utf8ToUtf16.push(text.length);
+    this.utf8ToUtf16 = utf8ToUtf16;
+  }
+
+  toUtf16(offset) {
+    return this.isAscii ? offset : this.utf8ToUtf16[offset];
+  }
+
+  toUtf8(offset) {
+    return this.isAscii ? offset : this.utf16ToUtf8[offset];
+  }
+}
+
+class SourceCode {
+  constructor(text, ast, comments) {
+    this.text = text;
+    this.ast = ast;
+    this.comments = comments;
+    this.lines = text.split(/\r\n|[\r\n\u2028\u2029]/);
+    this.lineStarts = [0];
+    for (const match of text.matchAll(/\r\n|[\r\n\u2028\u2029]/g)) {
+      this.lineStarts.push(match.index + match[0].length);
+    }
+  }
+
+  getText(node, beforeCount = 0, afterCount = 0) {
+    if (!node) return this.text;
+    const [start, end] = rangeOf(node);
+    return this.text.slice(Math.max(start - beforeCount, 0), end + afterCount);
+  }
+
+  getLines() {
+    return this.lines;
+  }
+
+  getAllComments() {
+    return this.comments;
+  }
+
+  getCommentsInside(node) {
+    const [start, end] = rangeOf(node);
+    return this.comments.filter((comment) => comment.start >= start && comment.end <= end);
+  }
+
+  getAncestors(node) {
+    const ancestors = [];
+    for (let parent = node.parent; parent; parent = parent.parent) ancestors.unshift(parent);
+    return ancestors;
+  }
+
+  /** `{ line, column }` of `index`, with 1-based lines and 0-based columns. */
+  getLocFromIndex(index) {
+    let low = 0;
+    let high = this.lineStarts.length - 1;
+    while (low < high) {
+      const mid = (low + high + 1) >> 1;
+      if (this.lineStarts[mid] <= index) low = mid;
+      else high = mid - 1;
+    }
+    return { line: low + 1, column: index - this.lineStarts[low] };
+  }
+
+  getIndexFromLoc({ line, column }) {
+    const lineStart = this.lineStarts[line - 1];
+    if (lineStart === undefined) throw new RangeError(`Line ${line} does not exist`);
+    return lineStart + column;
+  }
+}
+
+function rangeOf(nodeOrToken) {
+  return nodeOrToken.range ?? [nodeOrToken.start, nodeOrToken.end];
+}
+
+const fixer = {
+  insertTextAfter: (nodeOrToken, text) => fixer.insertTextAfterRange(rangeOf(nodeOrToken), text),
+  insertTextAfterRange: (range, text) => ({ range: [range[1], range[1]], text }),
+  insertTextBefore: (nodeOrToken, text) => fixer.insertTextBeforeRange(rangeOf(nodeOrToken), text),
+  insertTextBeforeRange: (range, text) => ({ range: [range[0], range[0]], text }),
+  remove: (nodeOrToken) => fixer.removeRange(rangeOf(nodeOrToken)),
+  removeRange: (range) => ({ range: [range[0], range[1]], text: '' }),
+  replaceText: (nodeOrToken, text) => fixer.replaceTextRange(rangeOf(nodeOrToken), text),
+  replaceTextRange: (range, text) => ({ range: [range[0], range[1]], text }),
+};
+
+/** Merge the fixes returned by a `fix` function into one fix, as ESLint does. */
+function mergeFixes(fixes, text) {
+  if (!fixes) return null;
+  const list = (typeof fixes[Symbol.iterator] === 'function' ? [...fixes] : [fixes])
+    .filter(Boolean)
+    .sort((a, b) => a.range[0] - b.range[0] || a.range[1] - b.range[1]);
+  if (list.length === 0) return null;
+  let merged = '';
+  let end = list[0].range[0];
+  for (const fix of list) {
+    if (fix.range[0] < end) throw new Error('Fix objects must not be overlapped in a report.');
+    merged += text.slice(end, fix.range[0]) + fix.text;
+    end = fix.range[1];
+  }
+  return { start: list[0].range[0], end, text: merged };
+}
+
+function interpolate(message, data) {
+  if (!data) return message;
+  return message.replace(/\{\{\s*([^{}]+?)\s*\}\}/g, (match, key) =>
+    key in data ? String(data[key]) : match,
+  );
+}
+
+/** Set the `value` of `BigInt` and `RegExp` literals, which can not be represented in JSON. */
+function applyFix(program, fixPath) {
+  let node = program;
+  for (const key of fixPath) node = node[key];
+  if (node.bigint) {
+    node.value = BigInt(node.bigint);
+  } else {
+    try {
+      node.value = RegExp(node.regex.pattern, node.regex.flags);
+    } catch {
+      // Invalid regexp, or syntax not supported by this version of Node.js.
+    }
+  }
+}
+
+function isNode(value) {
+  return value !== null && typeof value === 'object' && typeof value.type === 'string';
+}
+
+function lintFile({ path, sourceText, comments, rules, program }) {
+  const { node: ast, fixes } = program;
+  for (const fixPath of fixes) applyFix(ast, fixPath);
+
+  const offsets = new Offsets(sourceText);
+  const convert = (node) => {
+    if (!offsets.isAscii) {
+      node.start = offsets.toUtf16(node.start);
+      node.end = offsets.toUtf16(node.end);
+    }
+    node.range = [node.start, node.end];
+  };
+  const allComments = comments.map(({ type, start, end }) => {
+    const comment = { type, start, end };
+    convert(comment);
+    const text = sourceText.slice(comment.start, comment.end);
+    comment.value = type === 'Line' ? text.slice(2) : text.slice(2, -2);
+    return comment;
+  });
+  const sourceCode = new SourceCode(sourceText, ast, allComments);
+
+  const diagnostics = [];
+  /** Visitor functions by node type, and by node type with `:exit` suffix. */
+  const visitors = new Map();
+
+  rules.forEach(({ pluginPath, pluginName, ruleName, options }, ruleIndex) => {
+    const id = `${pluginName}/${ruleName}`;
+    const rule = plugins.get(pluginPath).rules[ruleName];
+    const create = typeof rule === 'function' ? rule : rule.create;
+    const meta = rule.meta ?? {};
+
+    const report = (descriptor, legacyMessage, legacyData) => {
+      if (legacyMessage !== undefined) {
+        descriptor = { node: descriptor, message: legacyMessage, data: legacyData };
+      }
+      let message = descriptor.message;
+      if (descriptor.messageId !== undefined) {
+        message = meta.messages?.[descriptor.messageId];
+        if (message === undefined) {
+          throw new Error(`\`${id}\` has no message with id \`${descriptor.messageId}\``);
+        }
+      }
+      message = interpolate(message, descriptor.data);
+
+      let start;
+      let end;
+      if (descriptor.loc) {
+        const loc = descriptor.loc.start ? descriptor.loc : { start: descriptor.loc };
+        start = sourceCode.getIndexFromLoc(loc.start);
+        end = loc.end ? sourceCode.getIndexFromLoc(loc.end) : start;
+      } else {
+        [start, end] = rangeOf(descriptor.node);
+      }
+
+      let fix = null;
+      if (typeof descriptor.fix === 'function') {
+        fix = mergeFixes(descriptor.fix(fixer), sourceText);
+        if (fix && !meta.fixable) {
+          throw new Error('Fixable rules must set the `meta.fixable` property to "code" or "whitespace".');
+        }
+      }
+      if (fix) {
+        fix = { start: offsets.toUtf8(fix.start), end: offsets.toUtf8(fix.end), text: fix.text };
+      }
+
+      diagnostics.push({
+        ruleIndex,
+        message,
+        start: offsets.toUtf8(start),
+        end: offsets.toUtf8(end),
+        fix,
+      });
+    };
+
+    const context = {
+      id,
+      options: options ?? [],
+      settings: {},
+      filename: path,
+      physicalFilename: path,
+      cwd: process.cwd(),
+      sourceCode,
+      getFilename: () => path,
+      getPhysicalFilename: () => path,
+      getCwd: () => process.cwd(),
+      getSourceCode: () => sourceCode,
+      getAncestors: () => {
+        throw new Error('`context.getAncestors()` is not supported, use `sourceCode.getAncestors(node)`.');
+      },
+      report,
+    };
+
+    const visitor = withRuleName(id, () => create(context)) ?? {};
+    for (const [selector, visit] of Object.entries(visitor)) {
+      if (typeof visit !== 'function') continue;
+      for (const key of selector.split(',')) {
+        const type = key.trim();
+        if (!visitors.has(type)) visitors.set(type, []);
+        visitors.get(type).push({ id, visit });
+      }
+    }
+  });
+
+  const callVisitors = (key, node) => {
+    const list = visitors.get(key);
+    if (list) for (const { id, visit } of list) withRuleName(id, () => visit(node));
+  };
+
+  const traverse = (node, parent) => {
+    convert(node);
+    node.parent = parent;
+    callVisitors('*', node);
+    callVisitors(node.type, node);
+    for (const key in node) {
+      if (key === 'parent') continue;
+      const value = node[key];
+      if (Array.isArray(value)) {
+        for (const child of value) if (isNode(child)) traverse(child, node);
+      } else if (isNode(value)) {
+        traverse(value, node);
+      }
+    }
+    callVisitors(`${node.type}:exit`, node);
+    callVisitors('*:exit', node);
+  };
+  traverse(ast, null);
+
+  return diagnostics;
+}
+
+function withRuleName(id, run) {
+  try {
+    return run();
+  } catch (err) {
+    throw new Error(`Error in rule \`${id}\`: ${err instanceof Error ? err.message : err}`);
+  }
+}
+
+for await (const line of createInterface({ input: process.stdin, crlfDelay: Infinity })) {
+  let response;
+  try {
+    const request = JSON.parse(line);
+    const result =
+      request.type === 'loadPlugin' ? await loadPlugin(request.path) : lintFile(request);
+    response = { ok: result };
+  } catch (err) {
+    response = { error: err instanceof Error ? err.message : String(err) };
+  }
+  writeResponse(`${JSON.stringify(response)}\n`);
+}
@@ -1,10 +1,14 @@
+mod baseline;
 mod command;
+mod diff;
+#[cfg(feature = "js_plugins")]
+mod js_plugins;
 mod lint;
 mod output_formatter;
 mod result;
 mod runner;
+mod stdin;
 mod tester;
-mod walk;
 
 pub mod cli {
 
@@ -2,7 +2,8 @@ use std::{
     env,
     ffi::OsStr,
     fs,
-    io::{ErrorKind, Write},
+    hash::{Hash, Hasher},
+    io::{self, ErrorKind, Read, Write},
     path::{Path, PathBuf, absolute},
     sync::Arc,
     time::Instant,
@@ -11,31 +12,44 @@ use std::{
 use cow_utils::CowUtils;
 use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
 use oxc_allocator::AllocatorPool;
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
+use oxc_diagnostics::{Baseline, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
-    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, InvalidFilterKind, LintFilter,
-    LintOptions, LintService, LintServiceOptions, Linter, Oxlintrc,
+    AllowWarnDeny, Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ExternalLinter,
+    FixKind, InvalidFilterKind, LINTABLE_EXTENSIONS, LintCache, LintFilter, LintOptions,
+    LintService, LintServiceOptions, Linter, Oxlintrc,
 };
-use rustc_hash::{FxHashMap, FxHashSet};
+use oxc_walk::{Extensions, Walk};
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use serde_json::Value;
 
 use crate::{
+    baseline::{read_baseline, write_baseline},
     cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, Runner, WarningOptions},
-    output_formatter::{LintCommandInfo, OutputFormatter},
-    walk::Walk,
+    diff::read_diff,
+    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
+    stdin::StdinFileSystem,
 };
 
+#[cfg(feature = "js_plugins")]
+use crate::js_plugins::NodeExternalLinter;
+
 #[derive(Debug)]
 pub struct LintRunner {
     options: LintCommand,
     cwd: PathBuf,
+    /// Source text to lint with `--stdin`, instead of reading it from stdin.
+    stdin: Option<String>,
 }
 
 impl Runner for LintRunner {
     type Options = LintCommand;
 
     fn new(options: Self::Options) -> Self {
-        Self { options, cwd: env::current_dir().expect("Failed to get current working directory") }
+        Self {
+            options,
+            cwd: env::current_dir().expect("Failed to get current working directory"),
+            stdin: None,
+        }
     }
 
     fn run(self, stdout: &mut dyn Write) -> CliRunResult {
@@ -60,6 +74,10 @@ impl Runner for LintRunner {
             misc_options,
             disable_nested_config,
             inline_config_options,
+            cache_options,
+            baseline_options,
+            diff_options,
+            stdin_options,
             ..
         } = self.options;
 
@@ -69,6 +87,42 @@ impl Runner for LintRunner {
             basic_options.config.is_none();
 
         let mut paths = paths;
+
+        let stdin = if stdin_options.stdin {
+            if !paths.is_empty() {
+                print_and_flush_stdout(
+                    stdout,
+                    "Paths cannot be linted with `--stdin`. Use `--stdin-filename` to name the source code.\n",
+                );
+                return CliRunResult::InvalidOptionStdin;
+            }
+            let source_text = if let Some(source_text) = self.stdin {
+                source_text
+            } else {
+                let mut source_text = String::new();
+                if let Err(err) = io::stdin().read_to_string(&mut source_text) {
+                    print_and_flush_stdout(stdout, &format!("Failed to read from stdin: {err}\n"));
+                    return CliRunResult::InvalidOptionStdin;
+                }
+                source_text
+            };
+            let path = self.cwd.join(
+                stdin_options
+                    .stdin_filename
+                    .as_deref()
+                    .unwrap_or_else(|| Path::new(Self::DEFAULT_STDIN_FILENAME)),
+            );
+            let path = absolute(&path).unwrap_or(path);
+            // The file does not need to exist, it is only used to find the configuration and
+            // ignore patterns, and to detect the file type.
+            paths.push(path.clone());
+            Some((path, source_text))
+        } else {
+            None
+        };
+        // With `--stdin --fix`, stdout is reserved for the fixed source code.
+        let fix_stdin = stdin.is_some() && fix_options.is_enabled();
+
         let provided_path_count = paths.len();
         let now = Instant::now();
 
@@ -151,7 +205,9 @@ impl Runner for LintRunner {
             // If explicit paths were provided, but all have been
             // filtered, return early.
             if provided_path_count > 0 {
-                if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
+                if let Some((_, source_text)) = stdin.filter(|_| fix_stdin) {
+                    print_and_flush_stdout(stdout, &source_text);
+                } else if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
                     number_of_files: 0,
                     number_of_rules: None,
                     threads_count: rayon::current_num_threads(),
@@ -166,14 +222,66 @@ impl Runner for LintRunner {
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
-        let paths = walker.paths();
+        let changed_lines = if let Some(diff_path) = &diff_options.diff {
+            let diff_path = if diff_path == Path::new("-") {
+                diff_path.clone()
+            } else {
+                self.cwd.join(diff_path)
+            };
+            match read_diff(&diff_path) {
+                Ok(changed_lines) => Some(changed_lines),
+                Err(err) => {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!(
+                            "Failed to read the diff file {:?}: {err}\n",
+                            diff_path.to_string_lossy().cow_replace('\\', "/")
+                        ),
+                    );
+                    return CliRunResult::InvalidOptionDiff;
+                }
+            }
+        } else {
+            None
+        };
+
+        let paths = if stdin.is_some() {
+            paths.iter().map(|path| path.as_os_str().into()).collect()
+        } else {
+            let mut walker = Walk::new(
+                &paths,
+                &ignore_options.walk_options(),
+                override_builder,
+                Extensions(LINTABLE_EXTENSIONS.to_vec()),
+            );
+            if let Some(changed_lines) = &changed_lines {
+                // Only lint changed files. Unchanged modules they import are still loaded by the
+                // runtime for cross-module rules, but not linted.
+                walker = walker.with_changed_files(
+                    changed_lines.files().map(|file| self.cwd.join(file)).collect(),
+                );
+            }
+            walker.paths()
+        };
         let number_of_files = paths.len();
 
         let handler = GraphicalReportHandler::new();
 
+        // Without the `js_plugins` feature, `jsPlugins` of configuration files are an error.
+        #[cfg(feature = "js_plugins")]
+        let external_linter: Option<Arc<dyn ExternalLinter>> =
+            Some(Arc::new(NodeExternalLinter::default()));
+        #[cfg(not(feature = "js_plugins"))]
+        let external_linter: Option<Arc<dyn ExternalLinter>> = None;
+
         let nested_configs = if search_for_nested_configs {
-            match Self::get_nested_configs(stdout, &handler, &filters, &paths) {
+            match Self::get_nested_configs(
+                stdout,
+                &handler,
+                &filters,
+                &paths,
+                external_linter.as_deref(),
+            ) {
                 Ok(v) => v,
                 Err(v) => return v,
             }
@@ -192,7 +300,9 @@ impl Runner for LintRunner {
         } else {
             None
         };
-        let config_builder = match ConfigStoreBuilder::from_oxlintrc(false, oxlintrc) {
+        let config_builder = match ConfigStoreBuilder::from_oxlintrc(false, oxlintrc)
+            .and_then(|builder| Self::load_js_plugins(builder, external_linter.as_deref()))
+        {
             Ok(builder) => builder,
             Err(e) => {
                 print_and_flush_stdout(
@@ -243,6 +353,44 @@ impl Runner for LintRunner {
             }
         }
 
+        let baseline_path = self.cwd.join(
+            baseline_options
+                .baseline
+                .as_deref()
+                .unwrap_or_else(|| Path::new(Self::DEFAULT_BASELINE_FILE)),
+        );
+        let baseline = if baseline_options.generate_baseline {
+            Some(Baseline::generate())
+        } else if baseline_options.baseline.is_some() {
+            match read_baseline(&baseline_path) {
+                Ok(mut baseline) => {
+                    // Diagnostics of files which are not linted in this run are not stale.
+                    let files = paths
+                        .iter()
+                        .map(|path| {
+                            let path = Path::new(path);
+                            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+                            path.to_string_lossy().cow_replace('\\', "/").into_owned()
+                        })
+                        .collect::<FxHashSet<_>>();
+                    baseline.retain(|entry| files.contains(&entry.file));
+                    Some(baseline)
+                }
+                Err(err) => {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!(
+                            "Failed to read the baseline file {:?}: {err}\n",
+                            baseline_path.to_string_lossy().cow_replace('\\', "/")
+                        ),
+                    );
+                    return CliRunResult::InvalidOptionBaseline;
+                }
+            }
+        } else {
+            None
+        };
+
         // TODO(refactor): pull this into a shared function, so that the language server can use
         // the same functionality.
         let use_cross_module = if nested_configs.is_empty() {
@@ -250,8 +398,11 @@ impl Runner for LintRunner {
         } else {
             nested_configs.values().any(|config| config.plugins().has_import())
         };
-        let mut options =
-            LintServiceOptions::new(self.cwd, paths).with_cross_module(use_cross_module);
+        // SARIF output includes the fixes of diagnostics, which are only reported if they are not applied.
+        let report_fixes = format_str == OutputFormat::Sarif && !fix_options.is_enabled();
+        let mut options = LintServiceOptions::new(self.cwd, paths)
+            .with_cross_module(use_cross_module)
+            .with_report_fixes(report_fixes);
 
         let lint_config = config_builder.build();
 
@@ -263,8 +414,13 @@ impl Runner for LintRunner {
 
         let linter =
             Linter::new(LintOptions::default(), ConfigStore::new(lint_config, nested_configs))
-                .with_fix(fix_options.fix_kind())
+                .with_fix(if report_fixes { FixKind::All } else { fix_options.fix_kind() })
                 .with_report_unused_directives(report_unused_directives);
+        #[cfg(feature = "js_plugins")]
+        let linter = match external_linter {
+            Some(external_linter) => linter.with_external_linter(external_linter),
+            None => linter,
+        };
 
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
@@ -285,21 +441,76 @@ impl Runner for LintRunner {
             }
         }
 
+        let cache = cache_options.cache.then(|| {
+            let key = Self::get_cache_key(
+                report_unused_directives,
+                use_cross_module,
+                tsconfig.as_deref(),
+            );
+            let location = Self::get_cache_location(options.cwd(), cache_options.cache_location);
+            Arc::new(LintCache::load(location, key, options.cwd().to_path_buf()))
+        });
+        if let Some(cache) = &cache {
+            options = options.with_cache(Arc::clone(cache));
+        }
+
         let mut diagnostic_service =
-            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
+            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options)
+                .with_baseline(baseline)
+                .with_changed_lines(changed_lines);
         let tx_error = diagnostic_service.sender().clone();
 
         let number_of_rules = linter.number_of_rules();
 
         let allocator_pool = AllocatorPool::new(rayon::current_num_threads());
 
+        let stdin_source_text =
+            stdin.as_ref().filter(|_| fix_stdin).map(|(_, source_text)| source_text.clone());
+        let stdin_file_system =
+            stdin.map(|(path, source_text)| StdinFileSystem::new(path, source_text));
+        let stdin_output = stdin_file_system.as_ref().map(StdinFileSystem::output);
+
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn(move || {
             let mut lint_service = LintService::new(&linter, allocator_pool, options);
+            if let Some(file_system) = stdin_file_system {
+                lint_service = lint_service.with_file_system(Box::new(file_system));
+            }
             lint_service.run(&tx_error);
         });
 
-        let diagnostic_result = diagnostic_service.run(stdout);
+        let mut stderr = io::stderr();
+        let report: &mut dyn Write = if fix_stdin { &mut stderr } else { &mut *stdout };
+
+        let diagnostic_result = diagnostic_service.run(report);
+
+        if let Some(baseline) = diagnostic_service.baseline() {
+            if baseline.is_generating() {
+                let count = baseline.suppressed_entries().map(|(_, count)| count).sum::<usize>();
+                let result = write_baseline(&baseline_path, baseline);
+                let baseline_path = baseline_path.to_string_lossy();
+                let baseline_path = baseline_path.cow_replace('\\', "/");
+                let message = match result {
+                    Ok(()) => format!("Wrote {count} diagnostics to {baseline_path:?}.\n"),
+                    Err(err) => {
+                        format!("Failed to write the baseline file {baseline_path:?}: {err}\n")
+                    }
+                };
+                print_and_flush_stdout(report, &message);
+            }
+        }
+
+        if let Some(cache) = cache {
+            if let Err(err) = cache.save() {
+                print_and_flush_stdout(
+                    report,
+                    &format!(
+                        "Failed to write the cache file {:?}: {err}\n",
+                        cache.location().to_string_lossy().cow_replace('\\', "/")
+                    ),
+                );
+            }
+        }
 
         if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
             number_of_files,
@@ -307,7 +518,12 @@ impl Runner for LintRunner {
             threads_count: rayon::current_num_threads(),
             start_time: now.elapsed(),
         }) {
-            print_and_flush_stdout(stdout, &end);
+            print_and_flush_stdout(report, &end);
+        }
+
+        if let Some(source_text) = stdin_source_text {
+            let output = stdin_output.and_then(|output| output.lock().unwrap().take());
+            print_and_flush_stdout(stdout, &output.unwrap_or(source_text));
         }
 
         if diagnostic_result.errors_count() > 0 {
@@ -324,6 +540,9 @@ impl Runner for LintRunner {
 
 impl LintRunner {
     const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
+    const DEFAULT_CACHE_FILE: &'static str = ".oxlintcache";
+    const DEFAULT_BASELINE_FILE: &'static str = "oxlint-baseline.json";
+    const DEFAULT_STDIN_FILENAME: &'static str = "stdin.js";
 
     #[must_use]
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -331,6 +550,42 @@ impl LintRunner {
         self
     }
 
+    /// Lint `source_text` with `--stdin`, instead of reading it from stdin.
+    #[must_use]
+    pub fn with_stdin(mut self, source_text: String) -> Self {
+        self.stdin = Some(source_text);
+        self
+    }
+
+    /// Hash of the options which affect diagnostics, but are not part of the config of each file.
+    ///
+    /// Changes to the source of JS plugins are not detected.
+    fn get_cache_key(
+        report_unused_directives: Option<AllowWarnDeny>,
+        use_cross_module: bool,
+        tsconfig: Option<&Path>,
+    ) -> u64 {
+        let mut hasher = FxHasher::default();
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        format!("{report_unused_directives:?}").hash(&mut hasher);
+        use_cross_module.hash(&mut hasher);
+        if let Some(tsconfig) = tsconfig {
+            tsconfig.hash(&mut hasher);
+            fs::read(tsconfig).unwrap_or_default().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    fn get_cache_location(cwd: &Path, cache_location: Option<PathBuf>) -> PathBuf {
+        match cache_location {
+            Some(path) => {
+                let path = cwd.join(path);
+                if path.is_dir() { path.join(Self::DEFAULT_CACHE_FILE) } else { path }
+            }
+            None => cwd.join(Self::DEFAULT_CACHE_FILE),
+        }
+    }
+
     fn get_diagnostic_service(
         reporter: &OutputFormatter,
         warning_options: &WarningOptions,
@@ -382,11 +637,30 @@ impl LintRunner {
         Ok(filters)
     }
 
+    /// Load the JavaScript plugins of `builder` with `external_linter`.
+    ///
+    /// Without an external linter, JavaScript plugins can not be loaded, so they are an error
+    /// instead of being ignored.
+    fn load_js_plugins(
+        builder: ConfigStoreBuilder,
+        external_linter: Option<&dyn ExternalLinter>,
+    ) -> Result<ConfigStoreBuilder, ConfigBuilderError> {
+        match (external_linter, builder.js_plugins().first()) {
+            (Some(external_linter), _) => builder.with_external_linter(external_linter),
+            (None, Some(plugin)) => Err(ConfigBuilderError::PluginLoadFailed {
+                plugin: plugin.clone(),
+                reason: "oxlint was built without the `js_plugins` feature".to_string(),
+            }),
+            (None, None) => Ok(builder),
+        }
+    }
+
     fn get_nested_configs(
         stdout: &mut dyn Write,
         handler: &GraphicalReportHandler,
         filters: &Vec<LintFilter>,
         paths: &Vec<Arc<OsStr>>,
+        external_linter: Option<&dyn ExternalLinter>,
     ) -> Result<FxHashMap<PathBuf, Config>, CliRunResult> {
         // TODO(perf): benchmark whether or not it is worth it to store the configurations on a
         // per-file or per-directory basis, to avoid calling `.parent()` on every path.
@@ -417,7 +691,9 @@ impl LintRunner {
         // iterate over each config and build the ConfigStore
         for (dir, oxlintrc) in nested_oxlintrc {
             // TODO(refactor): clean up all of the error handling in this function
-            let builder = match ConfigStoreBuilder::from_oxlintrc(false, oxlintrc) {
+            let builder = match ConfigStoreBuilder::from_oxlintrc(false, oxlintrc)
+                .and_then(|builder| Self::load_js_plugins(builder, external_linter))
+            {
                 Ok(builder) => builder,
                 Err(e) => {
                     print_and_flush_stdout(
@@ -897,6 +1173,20 @@ mod test {
         Tester::new().test_and_snapshot_multiple(&[args_1, args_2, args_3]);
     }
 
+    #[test]
+    #[cfg(feature = "js_plugins")]
+    fn test_js_plugins() {
+        let args = &["-A", "all", "-c", "config.json", "test.js", "test.ts"];
+        Tester::new().with_cwd("fixtures/js_plugins".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    #[cfg(not(feature = "js_plugins"))]
+    fn test_js_plugins_without_feature() {
+        let args = &["-c", "config.json", "test.js"];
+        Tester::new().with_cwd("fixtures/js_plugins".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_overrides_directories() {
         let args = &["-c", "fixtures/overrides/directories-config.json", "fixtures/overrides"];
@@ -1003,6 +1293,75 @@ mod test {
         Tester::new().with_cwd("fixtures/report_unused_directives".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_baseline() {
+        let args = &["--baseline", "baseline.json", "test.js"];
+
+        Tester::new().with_cwd("fixtures/baseline".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_diff() {
+        let args = &["--diff", "changes.diff"];
+
+        Tester::new().with_cwd("fixtures/diff".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_cache() {
+        let cwd = PathBuf::from("fixtures/cache");
+        let cache_location = cwd.join(".oxlintcache");
+        let _ = fs::remove_file(&cache_location);
+
+        // `clean.js` has no diagnostics, so it is cached.
+        let args =
+            &["--cache", "--cache-location", ".oxlintcache", "-c", ".oxlintrc.json", "clean.js"];
+        Tester::new().with_cwd(cwd.clone()).test(args);
+
+        // `dirty.js` has the same contents and config hash as `clean.js`, but an override reports
+        // it. Moving the cache entry of `clean.js` to `dirty.js` shows whether it is skipped.
+        let mut bytes = fs::read(&cache_location).unwrap();
+        let index = bytes.windows(8).position(|window| window == b"clean.js").unwrap();
+        bytes[index..index + 8].copy_from_slice(b"dirty.js");
+        fs::write(&cache_location, bytes).unwrap();
+
+        let args_1 =
+            &["--cache", "--cache-location", ".oxlintcache", "-c", ".oxlintrc.json", "dirty.js"];
+        // Only the options of `no-empty` are changed.
+        let args_2 = &[
+            "--cache",
+            "--cache-location",
+            ".oxlintcache",
+            "-c",
+            "changed_options.json",
+            "dirty.js",
+        ];
+        Tester::new().with_cwd(cwd).test_and_snapshot_multiple(&[args_1, args_2]);
+
+        fs::remove_file(cache_location).unwrap();
+    }
+
+    #[test]
+    fn test_stdin() {
+        let args1 = &["--stdin", "--stdin-filename", "src/test.ts"];
+        let args2 = &["--stdin", "--stdin-filename", "ignored.js"];
+
+        Tester::new()
+            .with_cwd("fixtures/stdin".into())
+            .with_stdin("debugger;\nconst a: number = 1;\n")
+            .test_and_snapshot_multiple(&[args1, args2]);
+    }
+
+    #[test]
+    fn test_stdin_fix() {
+        let args = &["--stdin", "--fix"];
+
+        Tester::new()
+            .with_cwd("fixtures/stdin".into())
+            .with_stdin("debugger;\nfoo();\n")
+            .test_and_snapshot(args);
+    }
+
     #[test]
     fn test_adjust_ignore_patterns() {
         let base = PathBuf::from("/project/root");
@@ -4,6 +4,7 @@ mod github;
 mod gitlab;
 mod json;
 mod junit;
+mod sarif;
 mod stylish;
 mod unix;
 mod xml_utils;
@@ -15,6 +16,7 @@ use checkstyle::CheckStyleOutputFormatter;
 use github::GithubOutputFormatter;
 use gitlab::GitlabOutputFormatter;
 use junit::JUnitOutputFormatter;
+use sarif::SarifOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
@@ -34,6 +36,9 @@ pub enum OutputFormat {
     Checkstyle,
     Stylish,
     JUnit,
+    /// SARIF 2.1.0, e.g. for GitHub code scanning
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
 }
 
 impl FromStr for OutputFormat {
@@ -49,6 +54,7 @@ impl FromStr for OutputFormat {
             "gitlab" => Ok(Self::Gitlab),
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
+            "sarif" => Ok(Self::Sarif),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -105,6 +111,7 @@ impl OutputFormatter {
             OutputFormat::Default => Box::new(DefaultOutputFormatter),
             OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
+            OutputFormat::Sarif => Box::<SarifOutputFormatter>::default(),
         }
     }
 
@@ -177,4 +184,14 @@ mod test {
 
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
+
+    /// disabled for windows
+    /// sarif will output the byte offsets of fixes, which will be different for windows
+    #[cfg(all(test, not(target_os = "windows")))]
+    #[test]
+    fn test_output_formatter_diagnostic_sarif() {
+        let args = &["--format=sarif", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
 }
@@ -0,0 +1,369 @@
+use oxc_diagnostics::{
+    Error, Fingerprint, OxcDiagnostic, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+use oxc_linter::{RuleCategory, plugin_name_to_prefix, rules::RULES};
+use rustc_hash::FxHashMap;
+
+use crate::{command::VERSION, output_formatter::InternalFormatter};
+
+#[derive(Debug, Default)]
+pub struct SarifOutputFormatter;
+
+impl InternalFormatter for SarifOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(SarifReporter::default())
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun; 1],
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifRuleProperties>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifRuleProperties {
+    category: RuleCategory,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    partial_fingerprints: SarifFingerprints,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifFingerprints {
+    #[serde(rename = "oxlint/v1")]
+    oxlint: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<SarifMessage>,
+    artifact_changes: [SarifArtifactChange; 1],
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: [SarifReplacement; 1],
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifByteRegion,
+    inserted_content: SarifInsertedContent,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifByteRegion {
+    byte_offset: usize,
+    byte_length: usize,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SarifInsertedContent {
+    text: String,
+}
+
+/// Renders reports as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log,
+/// e.g. for uploading to GitHub code scanning.
+///
+/// Each result has a `oxlint/v1` partial fingerprint, which is derived from the rule, file, message
+/// and the code the diagnostic points at, but not from its line and column. It stays the same when
+/// unrelated code above it changes.
+///
+/// Note that, due to syntactic restrictions of JSON, this reporter waits until all
+/// diagnostics have been reported before writing them to the output stream.
+#[derive(Default)]
+struct SarifReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_sarif(&mut self.diagnostics))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+fn format_sarif(diagnostics: &mut Vec<Error>) -> String {
+    let rule_categories = RULES
+        .iter()
+        .map(|rule| {
+            let id = format!("{}({})", plugin_name_to_prefix(rule.plugin_name()), rule.name());
+            (id, rule.category())
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    let mut rules = Vec::<SarifRule>::new();
+    let mut rule_indices = FxHashMap::<String, usize>::default();
+    // Number of results with the same fingerprint so far, to tell identical results apart.
+    let mut fingerprint_counts = FxHashMap::<u64, usize>::default();
+
+    let results = diagnostics
+        .drain(..)
+        .map(|error| {
+            let Info { start, end, filename, message, severity, rule_id } = Info::new(&error);
+
+            let rule_index = rule_id.as_ref().map(|rule_id| {
+                *rule_indices.entry(rule_id.clone()).or_insert_with(|| {
+                    rules.push(SarifRule {
+                        id: rule_id.clone(),
+                        help_uri: error.url().map(|url| url.to_string()),
+                        properties: rule_categories
+                            .get(rule_id)
+                            .map(|&category| SarifRuleProperties { category }),
+                    });
+                    rules.len() - 1
+                })
+            });
+
+            let fingerprint = {
+                let mut fingerprint = Fingerprint::default();
+                fingerprint.write_str(rule_id.as_deref().unwrap_or_default());
+                fingerprint.write_str(&filename);
+                fingerprint.write_error(&error);
+                let count = fingerprint_counts.entry(fingerprint.finish()).or_default();
+                *count += 1;
+                fingerprint.write(&(*count as u64).to_le_bytes());
+                fingerprint.to_string()
+            };
+
+            let artifact_location = SarifArtifactLocation { uri: filename };
+            let fixes = OxcDiagnostic::from_error(&error)
+                .map(|diagnostic| {
+                    diagnostic
+                        .fixes
+                        .iter()
+                        .map(|fix| SarifFix {
+                            description: fix
+                                .message
+                                .as_ref()
+                                .map(|message| SarifMessage { text: message.to_string() }),
+                            artifact_changes: [SarifArtifactChange {
+                                artifact_location: artifact_location.clone(),
+                                replacements: [SarifReplacement {
+                                    deleted_region: SarifByteRegion {
+                                        byte_offset: fix.span.offset(),
+                                        byte_length: fix.span.len(),
+                                    },
+                                    inserted_content: SarifInsertedContent {
+                                        text: fix.content.to_string(),
+                                    },
+                                }],
+                            }],
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            SarifResult {
+                rule_id,
+                rule_index,
+                level: match severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Advice => "note",
+                },
+                message: SarifMessage { text: message },
+                locations: vec![SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location,
+                        region: SarifRegion {
+                            start_line: start.line,
+                            start_column: start.column,
+                            end_line: end.line,
+                            end_column: end.column,
+                        },
+                    },
+                }],
+                partial_fingerprints: SarifFingerprints { oxlint: fingerprint },
+                fixes,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let log = SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "oxlint",
+                    version: VERSION,
+                    information_uri: "https://oxc.rs",
+                    rules,
+                },
+            },
+            results,
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).expect("Failed to serialize")
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        DiagnosticFix, NamedSource, OxcDiagnostic, SourceSpan,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::SarifReporter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = SarifReporter::default();
+
+        let diagnostic = || {
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger")
+                .with_url("https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html")
+                .with_label(Span::new(0, 9))
+        };
+        let fix = DiagnosticFix {
+            content: "".into(),
+            message: Some("Remove the debugger statement".into()),
+            span: SourceSpan::new(0.into(), 9),
+        };
+        let source = NamedSource::new("test.js", "debugger;\ndebugger;".to_string());
+        assert!(
+            reporter
+                .render_error(diagnostic().with_fixes([fix]).with_source_code(source.clone()))
+                .is_none()
+        );
+        assert!(
+            reporter
+                .render_error(diagnostic().with_label(Span::new(10, 19)).with_source_code(source))
+                .is_none()
+        );
+
+        let result = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(json["version"], "2.1.0");
+        let run = &json["runs"][0];
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0]["id"], "eslint(no-debugger)");
+        assert_eq!(
+            rules[0]["helpUri"],
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+        );
+        assert_eq!(rules[0]["properties"]["category"], "correctness");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        let first = &results[0];
+        assert_eq!(first["ruleId"], "eslint(no-debugger)");
+        assert_eq!(first["ruleIndex"], 0);
+        assert_eq!(first["level"], "warning");
+        assert_eq!(first["message"]["text"], "`debugger` statement is not allowed");
+        let location = &first["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "test.js");
+        assert_eq!(location["region"]["startLine"], 1);
+        assert_eq!(location["region"]["startColumn"], 1);
+        assert_eq!(location["region"]["endColumn"], 10);
+        let fix = &first["fixes"][0];
+        assert_eq!(fix["description"]["text"], "Remove the debugger statement");
+        let replacement = &fix["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["byteOffset"], 0);
+        assert_eq!(replacement["deletedRegion"]["byteLength"], 9);
+        assert_eq!(replacement["insertedContent"]["text"], "");
+
+        let second = &results[1];
+        assert_eq!(second["locations"][0]["physicalLocation"]["region"]["startLine"], 2);
+        assert!(second.get("fixes").is_none());
+        // Same rule, file, message and code, but still a different result
+        assert_ne!(
+            first["partialFingerprints"]["oxlint/v1"],
+            second["partialFingerprints"]["oxlint/v1"]
+        );
+    }
+}
@@ -5,6 +5,9 @@ pub enum CliRunResult {
     None,
     InvalidOptionConfig,
     InvalidOptionTsConfig,
+    InvalidOptionBaseline,
+    InvalidOptionDiff,
+    InvalidOptionStdin,
     InvalidOptionSeverityWithoutFilter,
     InvalidOptionSeverityWithoutPluginName,
     InvalidOptionSeverityWithoutRuleName,
@@ -33,6 +36,9 @@ impl Termination for CliRunResult {
             | Self::LintMaxWarningsExceeded
             | Self::InvalidOptionConfig
             | Self::InvalidOptionTsConfig
+            | Self::InvalidOptionBaseline
+            | Self::InvalidOptionDiff
+            | Self::InvalidOptionStdin
             | Self::InvalidOptionSeverityWithoutFilter
             | Self::InvalidOptionSeverityWithoutPluginName
             | Self::InvalidOptionSeverityWithoutRuleName => ExitCode::FAILURE,
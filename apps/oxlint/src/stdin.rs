@@ -0,0 +1,48 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{RuntimeFileSystem, read_to_arena_str};
+
+/// Source text read from stdin, linted as if it was the file at `path`.
+///
+/// Other files, e.g. modules imported by the source text, are read from disk. Fixes are not
+/// written to disk, but kept in [`StdinFileSystem::output`].
+pub struct StdinFileSystem {
+    path: PathBuf,
+    source_text: String,
+    output: Arc<Mutex<Option<String>>>,
+}
+
+impl StdinFileSystem {
+    pub fn new(path: PathBuf, source_text: String) -> Self {
+        Self { path, source_text, output: Arc::default() }
+    }
+
+    /// The fixed source text, which is set once linting is done if any fixes were applied.
+    pub fn output(&self) -> Arc<Mutex<Option<String>>> {
+        Arc::clone(&self.output)
+    }
+}
+
+impl RuntimeFileSystem for StdinFileSystem {
+    fn read_to_arena_str<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, std::io::Error> {
+        if path == self.path {
+            return Ok(allocator.alloc_str(&self.source_text));
+        }
+
+        read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: String) -> Result<(), std::io::Error> {
+        debug_assert_eq!(path, self.path, "only the source text from stdin is linted");
+        *self.output.lock().unwrap() = Some(content);
+        Ok(())
+    }
+}
@@ -11,6 +11,7 @@ use std::{env, path::PathBuf};
 #[cfg(test)]
 pub struct Tester {
     cwd: PathBuf,
+    stdin: Option<String>,
 }
 
 #[cfg(test)]
@@ -23,7 +24,7 @@ impl Tester {
         // do not unwrap because we can set it only one time.
         let _ = rayon::ThreadPoolBuilder::new().num_threads(1).build_global();
 
-        Self { cwd }
+        Self { cwd, stdin: None }
     }
 
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -31,13 +32,27 @@ impl Tester {
         self
     }
 
+    /// Source text to lint with `--stdin`.
+    pub fn with_stdin(mut self, source_text: &str) -> Self {
+        self.stdin = Some(source_text.to_string());
+        self
+    }
+
+    fn runner(&self, options: crate::cli::LintCommand) -> LintRunner {
+        let runner = LintRunner::new(options).with_cwd(self.cwd.clone());
+        match &self.stdin {
+            Some(source_text) => runner.with_stdin(source_text.clone()),
+            None => runner,
+        }
+    }
+
     pub fn test(&self, args: &[&str]) {
         let mut new_args = vec!["--silent"];
         new_args.extend(args);
 
         let options = lint_command().run_inner(new_args.as_slice()).unwrap();
         let mut output = Vec::new();
-        let _ = LintRunner::new(options).with_cwd(self.cwd.clone()).run(&mut output);
+        let _ = self.runner(options).run(&mut output);
     }
 
     pub fn test_and_snapshot(&self, args: &[&str]) {
@@ -59,7 +74,7 @@ impl Tester {
                 format!("working directory: {}\n", relative_dir.to_str().unwrap()).as_bytes(),
             );
             output.extend_from_slice(b"----------\n");
-            let result = LintRunner::new(options).with_cwd(self.cwd.clone()).run(&mut output);
+            let result = self.runner(options).run(&mut output);
 
             output.extend_from_slice(b"----------\n");
             output.extend_from_slice(format!("CLI result: {result:?}\n").as_bytes());
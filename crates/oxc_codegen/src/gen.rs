@@ -1580,7 +1580,10 @@ impl Gen for ObjectProperty<'_> {
             if key.name == "__proto__" {
                 shorthand = self.shorthand;
             } else if let Expression::Identifier(ident) = self.value.without_parentheses() {
-                if key.name == p.get_identifier_reference_name(ident) {
+                // Only print as shorthand if it was originally, so that `{ a: a }` output by
+                // ES2015 shorthand properties transform is not printed back as `{ a }`.
+                // Mangler can rename `a`, so name still needs to be checked.
+                if self.shorthand && key.name == p.get_identifier_reference_name(ident) {
                     shorthand = true;
                 }
             }
@@ -0,0 +1,188 @@
+use std::fmt::Write;
+
+use oxc_sourcemap::{SourceMap, Token};
+use oxc_syntax::identifier::{LS, PS};
+
+/// A section of an [`IndexSourceMap`].
+#[derive(Debug, Clone)]
+pub struct IndexSourceMapSection {
+    /// Line (0-based) in the concatenated code where this section starts.
+    pub line: u32,
+    /// Column (0-based, in UTF-16 code units) in the concatenated code where this section starts.
+    pub column: u32,
+    /// Source map of the code in this section, relative to the start of the section.
+    pub map: SourceMap,
+}
+
+/// An index (sectioned) source map.
+///
+/// See <https://tc39.es/ecma426/#sec-index-source-map>.
+#[derive(Debug, Clone, Default)]
+pub struct IndexSourceMap {
+    /// The `file` field.
+    pub file: Option<String>,
+    /// Sections, in the order of their offsets.
+    pub sections: Vec<IndexSourceMapSection>,
+}
+
+impl IndexSourceMap {
+    /// Serialize to an index source map JSON string.
+    pub fn to_json_string(&self) -> String {
+        let mut json = String::from("{\"version\":3,");
+        if let Some(file) = &self.file {
+            json.push_str("\"file\":");
+            write_json_string(&mut json, file);
+            json.push(',');
+        }
+        json.push_str("\"sections\":[");
+        for (i, section) in self.sections.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            write!(
+                json,
+                "{{\"offset\":{{\"line\":{},\"column\":{}}},\"map\":{}}}",
+                section.line,
+                section.column,
+                section.map.to_json_string()
+            )
+            .unwrap();
+        }
+        json.push_str("]}");
+        json
+    }
+
+    /// Flatten into a single source map.
+    ///
+    /// Useful for consumers which do not support index source maps.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn to_flat_sourcemap(&self) -> SourceMap {
+        let mut names = vec![];
+        let mut sources = vec![];
+        let mut source_contents = vec![];
+        let mut tokens = vec![];
+
+        for section in &self.sections {
+            let map = &section.map;
+            let name_offset = names.len() as u32;
+            let source_offset = sources.len() as u32;
+            names.extend(map.get_names().map(Into::into));
+            sources.extend(map.get_sources().map(Into::into));
+            source_contents.extend(map.get_source_contents().map(|c| c.map(Into::into)));
+            // Keep the contents aligned with the sources of later sections, if this map has
+            // fewer contents than sources.
+            source_contents.resize(sources.len(), None);
+            tokens.extend(map.get_tokens().map(|token| {
+                let dst_line = token.get_dst_line();
+                // The column offset only applies to the first line of a section.
+                let dst_col = if dst_line == 0 {
+                    token.get_dst_col() + section.column
+                } else {
+                    token.get_dst_col()
+                };
+                Token::new(
+                    dst_line + section.line,
+                    dst_col,
+                    token.get_src_line(),
+                    token.get_src_col(),
+                    token.get_source_id().map(|id| id + source_offset),
+                    token.get_name_id().map(|id| id + name_offset),
+                )
+            }));
+        }
+
+        SourceMap::new(
+            self.file.as_deref().map(Into::into),
+            names,
+            None,
+            sources,
+            source_contents,
+            tokens,
+            None,
+        )
+    }
+}
+
+/// Build an [`IndexSourceMap`] while concatenating code.
+///
+/// ## Example
+/// ```rust
+/// use oxc_codegen::IndexSourceMapBuilder;
+///
+/// let mut builder = IndexSourceMapBuilder::default();
+/// let mut code = String::new();
+/// for (chunk, map) in [("a();\n", None), ("b();\n", None)] {
+///     builder.add(chunk, map);
+///     code.push_str(chunk);
+/// }
+/// let index_map = builder.build();
+/// assert!(index_map.sections.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct IndexSourceMapBuilder {
+    file: Option<String>,
+    line: u32,
+    column: u32,
+    sections: Vec<IndexSourceMapSection>,
+}
+
+impl IndexSourceMapBuilder {
+    /// Set the `file` field of the index source map.
+    pub fn set_file(&mut self, file: &str) {
+        self.file = Some(file.to_string());
+    }
+
+    /// Record that `code` was appended to the concatenated output.
+    ///
+    /// `map` is the source map of `code`, e.g. [`crate::CodegenReturn::map`].
+    /// Code without a source map (e.g. separators or banners) only shifts later sections.
+    pub fn add(&mut self, code: &str, map: Option<SourceMap>) {
+        if let Some(map) = map {
+            self.sections.push(IndexSourceMapSection { line: self.line, column: self.column, map });
+        }
+        self.advance(code);
+    }
+
+    /// Finish building.
+    pub fn build(self) -> IndexSourceMap {
+        IndexSourceMap { file: self.file, sections: self.sections }
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn advance(&mut self, code: &str) {
+        let mut chars = code.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\r' => {
+                    if chars.peek() == Some(&'\n') {
+                        chars.next();
+                    }
+                    self.new_line();
+                }
+                '\n' | LS | PS => self.new_line(),
+                _ => self.column += ch.len_utf16() as u32,
+            }
+        }
+    }
+
+    fn new_line(&mut self) {
+        self.line += 1;
+        self.column = 0;
+    }
+}
+
+fn write_json_string(json: &mut String, s: &str) {
+    json.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
@@ -8,9 +8,11 @@ mod binary_expr_visitor;
 mod comment;
 mod context;
 mod r#gen;
+mod index_sourcemap;
 mod operator;
 mod options;
 mod sourcemap_builder;
+mod sourcemap_chain;
 mod str;
 
 use std::borrow::Cow;
@@ -32,7 +34,9 @@ use crate::{
 pub use crate::{
     context::Context,
     r#gen::{Gen, GenExpr},
+    index_sourcemap::{IndexSourceMap, IndexSourceMapBuilder, IndexSourceMapSection},
     options::{CodegenOptions, LegalComment},
+    sourcemap_chain::compose_sourcemaps,
 };
 
 /// Output from [`Codegen::build`]
@@ -44,6 +48,8 @@ pub struct CodegenReturn {
     /// The source map from the input source code to the generated source code.
     ///
     /// You must set [`CodegenOptions::source_map_path`] for this to be [`Some`].
+    ///
+    /// If [`CodegenOptions::input_source_map`] is set, this maps to the original sources instead.
     pub map: Option<oxc_sourcemap::SourceMap>,
 
     /// All the legal comments returned from [LegalComment::Linked] or [LegalComment::External].
@@ -198,7 +204,13 @@ impl<'a> Codegen<'a> {
         program.print(&mut self, Context::default());
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
         let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self.sourcemap_builder.map(|builder| {
+            let map = builder.into_sourcemap();
+            match &self.options.input_source_map {
+                Some(input_source_map) => compose_sourcemaps(&map, input_source_map),
+                None => map,
+            }
+        });
         CodegenReturn { code, map, legal_comments }
     }
 
@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use oxc_sourcemap::SourceMap;
+
 /// Codegen Options.
 #[derive(Debug, Clone)]
 pub struct CodegenOptions {
@@ -47,6 +49,17 @@ pub struct CodegenOptions {
     ///
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
+
+    /// Source map of the input source code, e.g. from a previous transform step.
+    ///
+    /// When set together with [CodegenOptions::source_map_path], the returned sourcemap is
+    /// composed with this map so that it points to the original sources instead of the input.
+    ///
+    /// A `//# sourceMappingURL` comment in the input source code is not read. Load the map it
+    /// refers to (e.g. with [`SourceMap::from_json_string`]) and pass it here.
+    ///
+    /// Default is `None`.
+    pub input_source_map: Option<SourceMap>,
 }
 
 impl Default for CodegenOptions {
@@ -58,6 +71,7 @@ impl Default for CodegenOptions {
             annotation_comments: true,
             legal_comments: LegalComment::Inline,
             source_map_path: None,
+            input_source_map: None,
         }
     }
 }
@@ -72,6 +86,7 @@ impl CodegenOptions {
             annotation_comments: false,
             legal_comments: LegalComment::None,
             source_map_path: None,
+            input_source_map: None,
         }
     }
 
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+
+use oxc_sourcemap::{SourceMap, Token};
+
+/// Compose `map` with the `input` source map it was generated from.
+///
+/// `map` maps the generated code to the input code, `input` maps the input code to the
+/// original sources. The returned source map maps the generated code to the original sources.
+///
+/// Tokens which cannot be traced back to an original source are dropped.
+/// Names from `input` take precedence over names from `map`, since they are the names
+/// in the original sources.
+#[expect(clippy::cast_possible_truncation)]
+pub fn compose_sourcemaps(map: &SourceMap, input: &SourceMap) -> SourceMap {
+    let lookup_table = input.generate_lookup_table();
+
+    let mut names = vec![];
+    let mut name_ids = FxHashMap::<&str, u32>::default();
+    let mut sources = vec![];
+    let mut source_contents = vec![];
+    let mut source_ids = FxHashMap::<u32, u32>::default();
+    let mut tokens = vec![];
+
+    for token in map.get_tokens() {
+        if token.get_source_id().is_none() {
+            continue;
+        }
+        let Some(original) =
+            input.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+        else {
+            continue;
+        };
+        let Some(original_source_id) = original.get_source_id() else {
+            continue;
+        };
+
+        let source_id = *source_ids.entry(original_source_id).or_insert_with(|| {
+            let id = sources.len() as u32;
+            sources.push(Arc::from(input.get_source(original_source_id).unwrap_or_default()));
+            source_contents.push(input.get_source_content(original_source_id).map(Arc::from));
+            id
+        });
+
+        let name = original
+            .get_name_id()
+            .and_then(|id| input.get_name(id))
+            .or_else(|| token.get_name_id().and_then(|id| map.get_name(id)));
+        let name_id = name.map(|name| {
+            *name_ids.entry(name).or_insert_with(|| {
+                let id = names.len() as u32;
+                names.push(Arc::from(name));
+                id
+            })
+        });
+
+        tokens.push(Token::new(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original.get_src_line(),
+            original.get_src_col(),
+            Some(source_id),
+            name_id,
+        ));
+    }
+
+    SourceMap::new(
+        map.get_file().map(Arc::from),
+        names,
+        input.get_source_root().map(ToString::to_string),
+        sources,
+        source_contents,
+        tokens,
+        None,
+    )
+}
@@ -2,6 +2,7 @@
 pub mod comments;
 pub mod esbuild;
 pub mod js;
+pub mod sourcemap;
 pub mod tester;
 pub mod ts;
 
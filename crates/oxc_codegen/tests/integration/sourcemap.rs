@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn, IndexSourceMapBuilder};
+use oxc_parser::Parser;
+use oxc_sourcemap::{SourceMap, Token};
+use oxc_span::SourceType;
+
+fn codegen_with_map(
+    source_text: &str,
+    source_type: SourceType,
+    path: &str,
+    input_source_map: Option<SourceMap>,
+) -> CodegenReturn {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let options = CodegenOptions {
+        source_map_path: Some(PathBuf::from(path)),
+        input_source_map,
+        ..CodegenOptions::default()
+    };
+    Codegen::new().with_options(options).build(&ret.program)
+}
+
+#[test]
+fn input_source_map() {
+    let original = "const  a = 1;\n\n\nfoo( a );\n";
+    let first = codegen_with_map(original, SourceType::mjs(), "input.js", None);
+    assert_eq!(first.code, "const a = 1;\nfoo(a);\n");
+    let first_map = first.map.unwrap();
+
+    let second = codegen_with_map(&first.code, SourceType::mjs(), "output.js", Some(first_map));
+    let map = second.map.unwrap();
+
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), vec!["input.js"]);
+    assert_eq!(map.get_source_contents().collect::<Vec<_>>(), vec![Some(original)]);
+
+    // `foo` on the second generated line maps to the fourth line of the original source.
+    let lookup_table = map.generate_lookup_table();
+    let token = map.lookup_token(&lookup_table, 1, 0).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (3, 0));
+    // `a` in `foo(a)` maps to column 5 of the original source.
+    let token = map.lookup_token(&lookup_table, 1, 4).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (3, 5));
+    // The binding `a` maps to column 7 of the original source.
+    let token = map.lookup_token(&lookup_table, 0, 6).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (0, 7));
+}
+
+#[test]
+fn index_source_map() {
+    let a = codegen_with_map("foo();\n", SourceType::mjs(), "a.js", None);
+    let b = codegen_with_map("bar();\nbaz();\n", SourceType::mjs(), "b.js", None);
+
+    let mut builder = IndexSourceMapBuilder::default();
+    builder.set_file("bundle.js");
+    let mut code = String::new();
+    for (chunk, map) in
+        [("/* banner */ ", None), (a.code.as_str(), a.map), (b.code.as_str(), b.map)]
+    {
+        builder.add(chunk, map);
+        code.push_str(chunk);
+    }
+    let index_map = builder.build();
+    assert_eq!(code, "/* banner */ foo();\nbar();\nbaz();\n");
+
+    let offsets =
+        index_map.sections.iter().map(|section| (section.line, section.column)).collect::<Vec<_>>();
+    assert_eq!(offsets, vec![(0, 13), (1, 0)]);
+
+    let json = index_map.to_json_string();
+    assert!(json.starts_with(
+        r#"{"version":3,"file":"bundle.js","sections":[{"offset":{"line":0,"column":13},"map":{"#
+    ));
+    assert!(json.contains(r#"{"offset":{"line":1,"column":0},"map":{"#));
+
+    let map = index_map.to_flat_sourcemap();
+    assert_eq!(map.get_file(), Some("bundle.js"));
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), vec!["a.js", "b.js"]);
+    let lookup_table = map.generate_lookup_table();
+    let token = map.lookup_token(&lookup_table, 0, 13).unwrap();
+    assert_eq!(token.get_source_id().and_then(|id| map.get_source(id)), Some("a.js"));
+    assert_eq!((token.get_src_line(), token.get_src_col()), (0, 0));
+    let token = map.lookup_token(&lookup_table, 2, 0).unwrap();
+    assert_eq!(token.get_source_id().and_then(|id| map.get_source(id)), Some("b.js"));
+    assert_eq!((token.get_src_line(), token.get_src_col()), (1, 0));
+}
+
+#[test]
+fn index_source_map_without_source_contents() {
+    // A map which has sources, but no source contents.
+    let a = SourceMap::new(
+        None,
+        vec![],
+        None,
+        vec!["a.js".into()],
+        vec![],
+        vec![Token::new(0, 0, 0, 0, Some(0), None)],
+        None,
+    );
+    let b = codegen_with_map("bar();\n", SourceType::mjs(), "b.js", None);
+
+    let mut builder = IndexSourceMapBuilder::default();
+    builder.add("foo();\n", Some(a));
+    builder.add(&b.code, b.map);
+    let map = builder.build().to_flat_sourcemap();
+
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), vec!["a.js", "b.js"]);
+    assert_eq!(map.get_source_contents().collect::<Vec<_>>(), vec![None, Some("bar();\n")]);
+}
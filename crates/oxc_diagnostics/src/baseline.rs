@@ -0,0 +1,113 @@
+//! [`Baseline`] of known diagnostics, which are not reported.
+
+use std::collections::BTreeMap;
+
+use crate::{Error, Fingerprint, OxcDiagnostic};
+
+/// A diagnostic in a [`Baseline`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BaselineEntry {
+    /// Path of the file, as sent to the [`DiagnosticService`](crate::DiagnosticService), with `/`
+    /// as separator.
+    pub file: String,
+    /// Error code of the diagnostic, e.g. `eslint(no-debugger)`.
+    pub rule: String,
+    /// See [`Baseline::fingerprint`].
+    pub fingerprint: String,
+}
+
+/// Known diagnostics, which are suppressed by the [`DiagnosticService`](crate::DiagnosticService)
+/// so that only new diagnostics are reported.
+///
+/// Diagnostics are matched by file, error code and [fingerprint](Baseline::fingerprint), which
+/// does not change when code is moved to other lines. If the same diagnostic occurs multiple times
+/// in a file, only as many occurrences as are in the baseline are suppressed.
+///
+/// Only diagnostics with an error code (e.g. lint rule violations) can be suppressed, so syntax
+/// errors are always reported.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    /// Number of occurrences of each entry.
+    entries: BTreeMap<BaselineEntry, usize>,
+    /// Number of occurrences of each entry which were suppressed in this run.
+    suppressed: BTreeMap<BaselineEntry, usize>,
+    /// Suppress all diagnostics, to record them in a new baseline.
+    generate: bool,
+}
+
+impl Baseline {
+    /// Create a baseline which suppresses the given number of occurrences of each entry.
+    pub fn new<T: IntoIterator<Item = (BaselineEntry, usize)>>(entries: T) -> Self {
+        let mut baseline = Self::default();
+        for (entry, count) in entries {
+            *baseline.entries.entry(entry).or_default() += count;
+        }
+        baseline
+    }
+
+    /// Create an empty baseline which suppresses all diagnostics, to record them in
+    /// [`Baseline::suppressed_entries`].
+    pub fn generate() -> Self {
+        Self { generate: true, ..Self::default() }
+    }
+
+    pub fn is_generating(&self) -> bool {
+        self.generate
+    }
+
+    /// Only keep the entries for which `f` returns `true`, e.g. those of the files being checked.
+    pub fn retain<F: FnMut(&BaselineEntry) -> bool>(&mut self, mut f: F) {
+        self.entries.retain(|entry, _| f(entry));
+    }
+
+    /// Entries which were suppressed so far, with the number of suppressed occurrences.
+    pub fn suppressed_entries(&self) -> impl Iterator<Item = (&BaselineEntry, usize)> {
+        self.suppressed.iter().map(|(entry, count)| (entry, *count))
+    }
+
+    /// Entries which were not suppressed so far, with the number of unused occurrences.
+    pub fn stale_entries(&self) -> impl Iterator<Item = (&BaselineEntry, usize)> {
+        self.entries.iter().filter_map(|(entry, count)| {
+            let suppressed = self.suppressed.get(entry).copied().unwrap_or_default();
+            (*count > suppressed).then_some((entry, count - suppressed))
+        })
+    }
+
+    /// Returns `true` if `error` in `file` is in the baseline, and should not be reported.
+    pub(crate) fn suppress(&mut self, file: &str, error: &Error) -> bool {
+        let Some(rule) = error.code() else { return false };
+        let entry = BaselineEntry {
+            file: file.to_string(),
+            rule: rule.to_string(),
+            fingerprint: Self::fingerprint(error),
+        };
+        let suppressed = self.suppressed.get(&entry).copied().unwrap_or_default();
+        if !self.generate && self.entries.get(&entry).copied().unwrap_or_default() <= suppressed {
+            return false;
+        }
+        *self.suppressed.entry(entry).or_default() += 1;
+        true
+    }
+
+    /// [`Fingerprint`] of the message of `error` and the source code it points at.
+    pub fn fingerprint(error: &Error) -> String {
+        let mut fingerprint = Fingerprint::default();
+        fingerprint.write_error(error);
+        fingerprint.to_string()
+    }
+
+    /// Advice diagnostic for an entry which was not suppressed.
+    pub(crate) fn stale_diagnostic(entry: &BaselineEntry, count: usize) -> Error {
+        let occurrences = if count == 1 { "occurrence" } else { "occurrences" };
+        Error::new(
+            OxcDiagnostic::warn(format!(
+                "Baseline has {count} stale {occurrences} of `{}` in {}",
+                entry.rule, entry.file
+            ))
+            .with_severity(crate::Severity::Advice)
+            .with_help(
+                "These diagnostics are no longer reported. Regenerate the baseline to remove them.",
+            ),
+        )
+    }
+}
@@ -0,0 +1,84 @@
+//! [`ChangedLines`] of files, outside of which diagnostics are not reported.
+
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use crate::Error;
+
+/// Lines which were added or changed in each file, e.g. according to a diff.
+///
+/// The [`DiagnosticService`](crate::DiagnosticService) only reports diagnostics of files in this
+/// set, and only if one of their labels overlaps a changed line. Diagnostics without labels are
+/// always reported for changed files.
+#[derive(Debug, Default, Clone)]
+pub struct ChangedLines {
+    /// Sorted, 1-based line ranges keyed by path, with `/` as separator. Ranges neither overlap
+    /// nor touch each other.
+    files: BTreeMap<String, Vec<RangeInclusive<usize>>>,
+}
+
+impl ChangedLines {
+    /// Mark `file` as changed, without any changed lines yet.
+    pub fn insert_file(&mut self, file: String) {
+        self.files.entry(file).or_default();
+    }
+
+    /// Mark `lines` (1-based) of `file` as changed.
+    ///
+    /// Inserting ranges in order of their lines, as in a diff, takes logarithmic time.
+    pub fn insert(&mut self, file: String, lines: RangeInclusive<usize>) {
+        let ranges = self.files.entry(file).or_default();
+        if lines.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = lines.into_inner();
+        // Ranges which overlap or touch `lines` are merged with it.
+        let first = ranges.partition_point(|range| range.end().saturating_add(1) < start);
+        let last = ranges.partition_point(|range| *range.start() <= end.saturating_add(1));
+        if first < last {
+            start = start.min(*ranges[first].start());
+            end = end.max(*ranges[last - 1].end());
+        }
+        ranges.splice(first..last, [start..=end]);
+    }
+
+    /// Paths of the changed files, with `/` as separator.
+    pub fn files(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Returns `true` if `line` (1-based) of `file` was changed.
+    pub fn contains_line(&self, file: &str, line: usize) -> bool {
+        self.overlaps(file, line..=line)
+    }
+
+    /// Returns `true` if any line of `lines` (1-based) of `file` was changed.
+    fn overlaps(&self, file: &str, lines: RangeInclusive<usize>) -> bool {
+        self.files.get(file).is_some_and(|ranges| {
+            // The first range which does not end before `lines`.
+            let index = ranges.partition_point(|range| range.end() < lines.start());
+            ranges.get(index).is_some_and(|range| range.start() <= lines.end())
+        })
+    }
+
+    /// Returns `true` if `error` in `file` should be reported.
+    pub(crate) fn contains(&self, file: &str, error: &Error) -> bool {
+        if !self.files.contains_key(file) {
+            return false;
+        }
+        let Some(labels) = error.labels() else { return true };
+        let Some(source) = error.source_code() else { return true };
+        let mut labels = labels.peekable();
+        if labels.peek().is_none() {
+            return true;
+        }
+        labels.any(|label| {
+            let Ok(span) = source.read_span(label.inner(), 0, 0) else { return true };
+            let start = span.line() + 1;
+            self.overlaps(file, start..=start + span.line_count().saturating_sub(1))
+        })
+    }
+}
@@ -0,0 +1,61 @@
+//! [`Fingerprint`] of diagnostics, to recognize them across runs.
+
+use std::fmt::{self, Display};
+
+use crate::Error;
+
+/// 64 bit FNV-1a hash of diagnostics.
+///
+/// Unlike [`std::hash::Hash`], the hash function is fixed, so fingerprints are the same across
+/// versions and platforms. Displayed as 16 hexadecimal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fingerprint(u64);
+
+impl Default for Fingerprint {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fingerprint {
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    /// Write `s` followed by a separator, which does not occur in UTF-8.
+    pub fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes());
+        self.write(&[0xff]);
+    }
+
+    /// Write the message of `error` and the source code covered by its first label, but not the
+    /// position of that code.
+    pub fn write_error(&mut self, error: &Error) {
+        self.write(error.to_string().as_bytes());
+        if let Some(snippet) = snippet(error) {
+            self.write(&[0xff]);
+            self.write(snippet);
+        }
+    }
+
+    pub fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// The source code covered by the first label of `error`.
+fn snippet(error: &Error) -> Option<&[u8]> {
+    let label = error.labels()?.next()?;
+    let source = error.source_code()?;
+    let span = source.read_span(label.inner(), 0, 0).ok()?;
+    Some(span.data())
+}
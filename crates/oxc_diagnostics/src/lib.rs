@@ -48,6 +48,9 @@
 //! service.run();
 //! ```
 
+mod baseline;
+mod changed_lines;
+mod fingerprint;
 mod service;
 
 use std::{
@@ -58,7 +61,12 @@ use std::{
 
 pub mod reporter;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::{
+    baseline::{Baseline, BaselineEntry},
+    changed_lines::ChangedLines,
+    fingerprint::Fingerprint,
+    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
+};
 
 pub type Error = miette::Error;
 pub type Severity = miette::Severity;
@@ -66,7 +74,7 @@ pub type Severity = miette::Severity;
 pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
 
 use miette::{Diagnostic, SourceCode};
-pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource};
+pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource, SourceSpan};
 
 /// Describes an error or warning that occurred.
 ///
@@ -124,6 +132,18 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    /// Edits of the source code which resolve this diagnostic.
+    pub fixes: Vec<DiagnosticFix>,
+}
+
+/// An edit of the source code which resolves a diagnostic.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiagnosticFix {
+    /// Text to replace the source code covered by `span` with.
+    pub content: Cow<'static, str>,
+    /// A brief message describing the fix.
+    pub message: Option<Cow<'static, str>>,
+    pub span: SourceSpan,
 }
 
 impl Display for OxcDiagnostic {
@@ -180,6 +200,7 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                fixes: Vec::new(),
             }),
         }
     }
@@ -194,6 +215,7 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                fixes: Vec::new(),
             }),
         }
     }
@@ -334,10 +356,78 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Set the edits of the source code which resolve this diagnostic.
+    ///
+    /// Existing fixes will be removed.
+    pub fn with_fixes<T: IntoIterator<Item = DiagnosticFix>>(mut self, fixes: T) -> Self {
+        self.inner.fixes = fixes.into_iter().collect();
+        self
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::new(OxcDiagnosticWithSource { diagnostic: self, source_code: Box::new(code) })
+    }
+
+    /// Get the [`OxcDiagnostic`] which `error` was created from, if any.
+    ///
+    /// This gives access to data which is not part of miette's [`Diagnostic`] trait, like
+    /// [`OxcDiagnosticInner::fixes`].
+    pub fn from_error(error: &Error) -> Option<&Self> {
+        error
+            .downcast_ref::<OxcDiagnosticWithSource>()
+            .map(|error| &error.diagnostic)
+            .or_else(|| error.downcast_ref::<Self>())
+    }
+}
+
+/// An [`OxcDiagnostic`] with the source code its labels point into.
+///
+/// Unlike miette's [`Report::with_source_code`](miette::Report::with_source_code), this allows
+/// getting the [`OxcDiagnostic`] back with [`OxcDiagnostic::from_error`].
+struct OxcDiagnosticWithSource {
+    diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode + Send + Sync>,
+}
+
+impl fmt::Debug for OxcDiagnosticWithSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.diagnostic, f)
+    }
+}
+
+impl Display for OxcDiagnosticWithSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for OxcDiagnosticWithSource {}
+
+impl Diagnostic for OxcDiagnosticWithSource {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
     }
 }
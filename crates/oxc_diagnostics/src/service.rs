@@ -5,10 +5,12 @@ use std::{
 };
 
 use cow_utils::CowUtils;
-use miette::LabeledSpan;
+use miette::{LabeledSpan, SourceSpan};
 
 use crate::{
     Error, NamedSource, OxcDiagnostic, Severity,
+    baseline::Baseline,
+    changed_lines::ChangedLines,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
 
@@ -61,6 +63,12 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Known diagnostics which are not reported
+    baseline: Option<Baseline>,
+
+    /// Only report diagnostics on these lines
+    changed_lines: Option<ChangedLines>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -70,7 +78,16 @@ impl DiagnosticService {
     /// provided [`DiagnosticReporter`].
     pub fn new(reporter: Box<dyn DiagnosticReporter>) -> Self {
         let (sender, receiver) = mpsc::channel();
-        Self { reporter, quiet: false, silent: false, max_warnings: None, sender, receiver }
+        Self {
+            reporter,
+            quiet: false,
+            silent: false,
+            max_warnings: None,
+            baseline: None,
+            changed_lines: None,
+            sender,
+            receiver,
+        }
     }
 
     /// Set to `true` to only report errors and ignore warnings.
@@ -109,6 +126,36 @@ impl DiagnosticService {
         self
     }
 
+    /// Do not report diagnostics which are in the [`Baseline`], and report the entries of the
+    /// baseline which no longer occur as advice.
+    ///
+    /// Suppressed diagnostics do not count towards the number of warnings and errors.
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Only report diagnostics which point at one of the [`ChangedLines`], e.g. to check only the
+    /// code changed in a pull request.
+    ///
+    /// Other diagnostics do not count towards the number of warnings and errors.
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_changed_lines(mut self, changed_lines: Option<ChangedLines>) -> Self {
+        self.changed_lines = changed_lines;
+        self
+    }
+
+    /// The [`Baseline`] set with [`with_baseline`](DiagnosticService::with_baseline), with the
+    /// diagnostics it suppressed.
+    pub fn baseline(&self) -> Option<&Baseline> {
+        self.baseline.as_ref()
+    }
+
     /// Channel for sending [diagnostic messages] to the service.
     ///
     /// The service will only start processing diagnostics after [`run`](DiagnosticService::run)
@@ -143,11 +190,18 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path_display, source_text.to_owned()));
         let diagnostics = diagnostics
             .into_iter()
-            .map(|diagnostic| {
+            .map(|mut diagnostic| {
                 if source_start == 0 {
                     return diagnostic.with_source_code(Arc::clone(&source));
                 }
 
+                for fix in &mut diagnostic.fixes {
+                    fix.span = SourceSpan::new(
+                        (fix.span.offset() + source_start as usize).into(),
+                        fix.span.len(),
+                    );
+                }
+
                 match &diagnostic.labels {
                     None => diagnostic.with_source_code(Arc::clone(&source)),
                     Some(labels) => {
@@ -184,7 +238,21 @@ impl DiagnosticService {
         let mut errors_count: usize = 0;
 
         while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
+            let path_display = path.to_string_lossy();
+            let path_display = path_display.cow_replace('\\', "/");
             for diagnostic in diagnostics {
+                if let Some(baseline) = &mut self.baseline {
+                    if baseline.suppress(&path_display, &diagnostic) {
+                        continue;
+                    }
+                }
+
+                if let Some(changed_lines) = &self.changed_lines {
+                    if !changed_lines.contains(&path_display, &diagnostic) {
+                        continue;
+                    }
+                }
+
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
@@ -233,6 +301,21 @@ impl DiagnosticService {
             }
         }
 
+        if let Some(baseline) = &self.baseline {
+            if !baseline.is_generating() && !self.silent {
+                for (entry, count) in baseline.stale_entries() {
+                    if let Some(err_str) =
+                        self.reporter.render_error(Baseline::stale_diagnostic(entry, count))
+                    {
+                        writer
+                            .write_all(err_str.as_bytes())
+                            .or_else(Self::check_for_writer_error)
+                            .unwrap();
+                    }
+                }
+            }
+        }
+
         let result = DiagnosticResult::new(
             warnings_count,
             errors_count,
@@ -0,0 +1,138 @@
+use std::str::FromStr;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ArrowParentheses, BracketSameLine, BracketSpacing, EmbeddedLanguageFormatting, Expand,
+    FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, OperatorPosition,
+    QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
+};
+
+/// Name of the formatter configuration file.
+pub const FORMAT_CONFIG_FILE: &str = ".oxfmtrc.json";
+
+/// Formatter configuration, e.g. from a [FORMAT_CONFIG_FILE] or editor settings.
+///
+/// Option names and values follow Prettier, so that an existing `.prettierrc.json`
+/// can be used as is. Options which are not set keep their [FormatOptions] default.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
+pub struct FormatConfig {
+    /// Indent with tabs instead of spaces.
+    pub use_tabs: Option<bool>,
+    /// Number of spaces per indentation level.
+    pub tab_width: Option<u8>,
+    /// `"lf"`, `"crlf"` or `"cr"`.
+    pub end_of_line: Option<String>,
+    /// The line length that the printer will wrap on.
+    pub print_width: Option<u16>,
+    /// Use single quotes instead of double quotes.
+    pub single_quote: Option<bool>,
+    /// Use single quotes instead of double quotes in JSX.
+    pub jsx_single_quote: Option<bool>,
+    /// `"as-needed"` or `"preserve"`.
+    pub quote_props: Option<String>,
+    /// `"all"`, `"es5"` or `"none"`.
+    pub trailing_comma: Option<String>,
+    /// Print semicolons at the ends of statements.
+    pub semi: Option<bool>,
+    /// `"always"` or `"avoid"`.
+    pub arrow_parens: Option<String>,
+    /// Print spaces between brackets in object literals.
+    pub bracket_spacing: Option<bool>,
+    /// Put the `>` of a multi-line JSX element at the end of the last line.
+    pub bracket_same_line: Option<bool>,
+    /// `"preserve"` or `"collapse"`.
+    pub object_wrap: Option<String>,
+    /// `"start"` or `"end"`.
+    pub experimental_operator_position: Option<String>,
+    /// `"auto"` or `"off"`.
+    pub embedded_language_formatting: Option<String>,
+}
+
+impl FormatConfig {
+    /// Map the configuration onto [FormatOptions].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message for the first option with an unsupported value.
+    pub fn into_format_options(self) -> Result<FormatOptions, String> {
+        let mut options = FormatOptions::default();
+        if let Some(use_tabs) = self.use_tabs {
+            options.indent_style = if use_tabs { IndentStyle::Tab } else { IndentStyle::Space };
+        }
+        if let Some(tab_width) = self.tab_width {
+            options.indent_width = IndentWidth::try_from(tab_width)
+                .map_err(|_| invalid_value("tabWidth", &tab_width.to_string()))?;
+        }
+        if let Some(end_of_line) = self.end_of_line {
+            options.line_ending = parse("endOfLine", &end_of_line)?;
+        }
+        if let Some(print_width) = self.print_width {
+            options.line_width = LineWidth::try_from(print_width)
+                .map_err(|_| invalid_value("printWidth", &print_width.to_string()))?;
+        }
+        if let Some(single_quote) = self.single_quote {
+            options.quote_style = quote_style(single_quote);
+        }
+        if let Some(jsx_single_quote) = self.jsx_single_quote {
+            options.jsx_quote_style = quote_style(jsx_single_quote);
+        }
+        if let Some(quote_props) = self.quote_props {
+            options.quote_properties = parse::<QuoteProperties>("quoteProps", &quote_props)?;
+        }
+        if let Some(trailing_comma) = self.trailing_comma {
+            options.trailing_commas = parse::<TrailingCommas>("trailingComma", &trailing_comma)?;
+        }
+        if let Some(semi) = self.semi {
+            options.semicolons = if semi { Semicolons::Always } else { Semicolons::AsNeeded };
+        }
+        if let Some(arrow_parens) = self.arrow_parens {
+            options.arrow_parentheses = match arrow_parens.as_str() {
+                "avoid" => ArrowParentheses::AsNeeded,
+                value => parse("arrowParens", value)?,
+            };
+        }
+        if let Some(bracket_spacing) = self.bracket_spacing {
+            options.bracket_spacing = BracketSpacing::from(bracket_spacing);
+        }
+        if let Some(bracket_same_line) = self.bracket_same_line {
+            options.bracket_same_line = BracketSameLine::from(bracket_same_line);
+        }
+        if let Some(object_wrap) = self.object_wrap {
+            options.expand = match object_wrap.as_str() {
+                "preserve" => Expand::Auto,
+                "collapse" => Expand::Never,
+                value => return Err(invalid_value("objectWrap", value)),
+            };
+        }
+        if let Some(operator_position) = self.experimental_operator_position {
+            options.experimental_operator_position =
+                parse::<OperatorPosition>("experimentalOperatorPosition", &operator_position)?;
+        }
+        if let Some(embedded_language_formatting) = self.embedded_language_formatting {
+            options.embedded_language_formatting = parse::<EmbeddedLanguageFormatting>(
+                "embeddedLanguageFormatting",
+                &embedded_language_formatting,
+            )?;
+        }
+        Ok(options)
+    }
+}
+
+fn quote_style(single_quote: bool) -> QuoteStyle {
+    if single_quote { QuoteStyle::Single } else { QuoteStyle::Double }
+}
+
+fn parse<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    T::from_str(value).map_err(|_| invalid_value(name, value))
+}
+
+fn invalid_value(name: &str, value: &str) -> String {
+    format!("Unsupported value `{value}` for option `{name}`")
+}
@@ -0,0 +1,308 @@
+//! A lightweight CSS formatter for styled-components style templates.
+//!
+//! Rules, at-rules and declarations are put on their own lines and indented, and whitespace
+//! within them is normalized. Values are otherwise printed as is.
+
+use crate::options::FormatOptions;
+
+use super::{PLACEHOLDER_PREFIX, indent_unit};
+
+/// Format CSS, e.g. the content of `` css`...` `` or `` styled.div`...` ``.
+///
+/// Nested rules (`&:hover { ... }`) and `//` comments are supported, as in styled-components.
+/// Returns `None` if the braces, parentheses, strings or comments are not balanced.
+pub fn format_css(source: &str, options: &FormatOptions) -> Option<String> {
+    let mut printer = Printer { lines: vec![], indent_unit: indent_unit(options), depth: 0 };
+    let mut statement = String::new();
+    let mut newlines = 0;
+    let mut paren_depth = 0usize;
+    let mut chars = source.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let at_statement_start = statement.trim().is_empty();
+        match c {
+            '"' | '\'' => {
+                let end = string_end(source, i)?;
+                statement.push_str(&source[i..end]);
+                while chars.next_if(|(j, _)| *j < end).is_some() {}
+            }
+            '/' if source[i..].starts_with("/*") => {
+                let end = source[i + 2..].find("*/")? + i + 4;
+                if at_statement_start {
+                    printer.line(&source[i..end], newlines);
+                    newlines = 0;
+                } else {
+                    statement.push_str(&source[i..end]);
+                }
+                while chars.next_if(|(j, _)| *j < end).is_some() {}
+            }
+            '/' if at_statement_start && source[i..].starts_with("//") => {
+                let end = source[i..].find('\n').map_or(source.len(), |end| end + i);
+                printer.line(source[i..end].trim_end(), newlines);
+                newlines = 0;
+                while chars.next_if(|(j, _)| *j < end).is_some() {}
+            }
+            '(' => {
+                paren_depth += 1;
+                statement.push(c);
+            }
+            ')' => {
+                paren_depth = paren_depth.checked_sub(1)?;
+                statement.push(c);
+            }
+            ';' if paren_depth == 0 => {
+                if !at_statement_start {
+                    printer.declaration(&statement, true, newlines);
+                }
+                statement.clear();
+                newlines = 0;
+            }
+            '{' if paren_depth == 0 => {
+                printer.open(&statement, newlines);
+                statement.clear();
+                newlines = 0;
+            }
+            '}' if paren_depth == 0 => {
+                if !at_statement_start {
+                    printer.declaration(&statement, false, newlines);
+                }
+                printer.close()?;
+                statement.clear();
+                newlines = 0;
+            }
+            '\n' if is_placeholder(statement.trim()) => {
+                // A mixin without semicolon, e.g. `${mixin}` on its own line.
+                printer.declaration(&statement, false, newlines);
+                statement.clear();
+                newlines = 1;
+            }
+            c if c.is_whitespace() && at_statement_start => {
+                if c == '\n' {
+                    newlines += 1;
+                }
+            }
+            c => statement.push(c),
+        }
+    }
+
+    if paren_depth != 0 || printer.depth != 0 {
+        return None;
+    }
+    if !statement.trim().is_empty() {
+        printer.declaration(&statement, false, newlines);
+    }
+    Some(printer.lines.join("\n"))
+}
+
+struct Printer {
+    lines: Vec<String>,
+    indent_unit: String,
+    depth: usize,
+}
+
+impl Printer {
+    /// Print `text` on its own line, preceded by a blank line if there is one in the source.
+    fn line(&mut self, text: &str, newlines_before: usize) {
+        let at_block_start = self.lines.last().is_none_or(|line| line.ends_with('{'));
+        if newlines_before > 1 && !at_block_start {
+            self.lines.push(String::new());
+        }
+        self.lines.push(format!("{}{text}", self.indent_unit.repeat(self.depth)));
+    }
+
+    fn open(&mut self, prelude: &str, newlines_before: usize) {
+        let prelude = prelude.trim();
+        if prelude.starts_with('@') && !prelude.starts_with(PLACEHOLDER_PREFIX) {
+            self.line(&format!("{} {{", format_at_rule(prelude)), newlines_before);
+        } else {
+            let selectors = split_top_level(prelude, ',');
+            let last = selectors.len() - 1;
+            for (index, selector) in selectors.iter().enumerate() {
+                let selector = format_selector(selector);
+                let separator = if index == last { " {" } else { "," };
+                self.line(
+                    &format!("{selector}{separator}"),
+                    if index == 0 { newlines_before } else { 0 },
+                );
+            }
+        }
+        self.depth += 1;
+    }
+
+    fn close(&mut self) -> Option<()> {
+        self.depth = self.depth.checked_sub(1)?;
+        self.line("}", 0);
+        Some(())
+    }
+
+    fn declaration(&mut self, declaration: &str, semicolon: bool, newlines_before: usize) {
+        let declaration = declaration.trim();
+        let is_placeholder = declaration.starts_with(PLACEHOLDER_PREFIX);
+        let text = if declaration.starts_with('@') && !is_placeholder {
+            format!("{};", format_at_rule(declaration))
+        } else if let Some(colon) = find_top_level(declaration, ':') {
+            let property = declaration[..colon].trim();
+            let value = collapse_whitespace(&declaration[colon + 1..]);
+            format!("{property}: {value};")
+        } else {
+            // E.g. a mixin: `${mixin};`
+            let text = collapse_whitespace(declaration);
+            if semicolon { format!("{text};") } else { text }
+        };
+        self.line(&text, newlines_before);
+    }
+}
+
+fn is_placeholder(text: &str) -> bool {
+    text.strip_prefix(PLACEHOLDER_PREFIX)
+        .is_some_and(|index| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Byte offset after the closing quote of the string starting at `start`.
+fn string_end(source: &str, start: usize) -> Option<usize> {
+    let quote = source[start..].chars().next()?;
+    let mut escaped = false;
+    for (i, c) in source[start + 1..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '\n' => return None,
+            c if c == quote => return Some(start + 1 + i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Calls `f` with each char of `text` and whether it is outside of strings, parentheses and
+/// brackets.
+fn for_each_top_level(text: &str, mut f: impl FnMut(usize, char, bool)) {
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            f(i, c, false);
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        f(i, c, depth == 0 && quote.is_none() && !matches!(c, '(' | '[' | '"' | '\''));
+    }
+}
+
+fn find_top_level(text: &str, target: char) -> Option<usize> {
+    let mut found = None;
+    for_each_top_level(text, |i, c, top_level| {
+        if found.is_none() && top_level && c == target {
+            found = Some(i);
+        }
+    });
+    found
+}
+
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut start = 0;
+    for_each_top_level(text, |i, c, top_level| {
+        if top_level && c == separator {
+            parts.push(&text[start..i]);
+            start = i + c.len_utf8();
+        }
+    });
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Collapse whitespace outside of strings to a single space,
+/// and put a single space after commas.
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut quote = None;
+    let mut escaped = false;
+    let mut pending_space = false;
+    for c in text.trim().chars() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            result.push(c);
+            continue;
+        }
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if c == ',' || c == ')' {
+            pending_space = false;
+        } else if pending_space && !result.ends_with('(') {
+            result.push(' ');
+        }
+        pending_space = c == ',';
+        if matches!(c, '"' | '\'') {
+            quote = Some(c);
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Format a selector, e.g. `a>b` to `a > b`.
+fn format_selector(selector: &str) -> String {
+    let selector = collapse_whitespace(selector);
+    let mut result = String::with_capacity(selector.len());
+    let mut skip_space = false;
+    let mut top_level_chars = vec![];
+    for_each_top_level(&selector, |_, _, top_level| top_level_chars.push(top_level));
+    for (c, top_level) in selector.chars().zip(top_level_chars) {
+        if top_level && matches!(c, '>' | '+' | '~') {
+            let trimmed_len = result.trim_end().len();
+            result.truncate(trimmed_len);
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            result.push(c);
+            result.push(' ');
+            skip_space = true;
+        } else if !(skip_space && c == ' ') {
+            skip_space = false;
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Format the prelude of an at-rule, e.g. `@media (max-width:100px)` to `@media (max-width: 100px)`.
+fn format_at_rule(prelude: &str) -> String {
+    let prelude = collapse_whitespace(prelude);
+    let mut result = String::with_capacity(prelude.len());
+    let mut depth = 0usize;
+    let mut chars = prelude.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        result.push(c);
+        if c == ':' && depth > 0 && chars.peek().is_some_and(|c| *c != ' ') {
+            result.push(' ');
+        }
+    }
+    result
+}
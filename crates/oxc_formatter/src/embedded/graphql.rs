@@ -0,0 +1,313 @@
+//! A lightweight GraphQL formatter for `` gql`...` `` and `` graphql`...` `` templates.
+//!
+//! Selection sets and type definitions are put on their own lines and indented, arguments,
+//! lists and objects are printed on one line separated by `, `.
+
+use crate::options::FormatOptions;
+
+use super::{PLACEHOLDER_PREFIX, indent_unit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Names, numbers, variables after `$` and template placeholders.
+    Name,
+    String,
+    Comment,
+    Punctuator,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'s> {
+    kind: Kind,
+    text: &'s str,
+    /// Number of line breaks between the previous token and this one.
+    newlines_before: usize,
+}
+
+impl Token<'_> {
+    fn is(&self, text: &str) -> bool {
+        self.kind == Kind::Punctuator && self.text == text
+    }
+
+    /// Whether the token can end a value, field or type.
+    fn ends_value(&self) -> bool {
+        match self.kind {
+            Kind::Name | Kind::String => true,
+            Kind::Comment => false,
+            Kind::Punctuator => matches!(self.text, ")" | "]" | "}" | "!"),
+        }
+    }
+
+    /// Whether the token can start a value, argument, field or definition.
+    fn starts_value(&self) -> bool {
+        match self.kind {
+            Kind::Name | Kind::String => true,
+            Kind::Comment => false,
+            Kind::Punctuator => matches!(self.text, "$" | "[" | "{" | "..."),
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Option<Vec<Token<'_>>> {
+    let mut tokens = vec![];
+    let mut newlines_before = 0;
+    let mut rest = source;
+    while let Some(c) = rest.chars().next() {
+        let (kind, len) = match c {
+            '\n' => {
+                newlines_before += 1;
+                rest = &rest[1..];
+                continue;
+            }
+            ',' => {
+                // Commas are insignificant, they are re-inserted when printing.
+                rest = &rest[1..];
+                continue;
+            }
+            c if c.is_whitespace() => {
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+            '#' => (Kind::Comment, rest.find(['\n', '\r']).unwrap_or(rest.len())),
+            '"' if rest.starts_with("\"\"\"") => {
+                let mut end = 3;
+                loop {
+                    end += rest[end..].find("\"\"\"")?;
+                    if !rest[..end].ends_with('\\') {
+                        break;
+                    }
+                    end += 3;
+                }
+                (Kind::String, end + 3)
+            }
+            '"' => {
+                let mut escaped = false;
+                let end = rest[1..].char_indices().find_map(|(i, c)| match c {
+                    _ if escaped => {
+                        escaped = false;
+                        None
+                    }
+                    '\\' => {
+                        escaped = true;
+                        None
+                    }
+                    '"' => Some(Some(i + 2)),
+                    '\n' => Some(None),
+                    _ => None,
+                })??;
+                (Kind::String, end)
+            }
+            '@' if rest.starts_with(PLACEHOLDER_PREFIX) => {
+                let digits =
+                    rest[PLACEHOLDER_PREFIX.len()..].bytes().take_while(u8::is_ascii_digit).count();
+                (Kind::Name, PLACEHOLDER_PREFIX.len() + digits)
+            }
+            '.' if rest.starts_with("...") => (Kind::Punctuator, 3),
+            '!' | '$' | '&' | '(' | ')' | ':' | '=' | '@' | '[' | ']' | '{' | '|' | '}' => {
+                (Kind::Punctuator, 1)
+            }
+            c if c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+') => {
+                let len = rest
+                    .find(|c: char| {
+                        !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+'))
+                    })
+                    .unwrap_or(rest.len());
+                (Kind::Name, len)
+            }
+            _ => return None,
+        };
+        tokens.push(Token { kind, text: &rest[..len], newlines_before });
+        newlines_before = 0;
+        rest = &rest[len..];
+    }
+    Some(tokens)
+}
+
+/// Format GraphQL, e.g. the content of `` gql`...` ``.
+///
+/// Returns `None` if the source can not be tokenized, or the brackets are not balanced.
+pub fn format_graphql(source: &str, options: &FormatOptions) -> Option<String> {
+    let tokens = tokenize(source)?;
+    let indent_unit = indent_unit(options);
+    let mut lines: Vec<String> = vec![];
+    let mut line = String::new();
+    // Nesting of selection sets and type definitions, which are printed on multiple lines.
+    let mut depth = 0usize;
+    // Nesting of arguments, lists and objects, which are printed on one line.
+    let mut inline_stack: Vec<&str> = vec![];
+    let mut prev: Option<Token<'_>> = None;
+
+    let mut new_line = |lines: &mut Vec<String>, line: &mut String, depth: usize, blank: bool| {
+        if !line.trim().is_empty() {
+            lines.push(std::mem::take(line));
+        }
+        line.clear();
+        let at_block_start = lines.last().is_none_or(|line| line.ends_with('{'));
+        if blank && !at_block_start {
+            lines.push(String::new());
+        }
+        line.push_str(&indent_unit.repeat(depth));
+    };
+    let line_is_empty = |line: &str| line.trim().is_empty();
+
+    for token in tokens {
+        let inline = !inline_stack.is_empty();
+        let blank = token.newlines_before > 1;
+        match token.kind {
+            Kind::Comment => {
+                if token.newlines_before > 0 || line_is_empty(&line) {
+                    new_line(&mut lines, &mut line, depth, blank);
+                } else {
+                    line.push(' ');
+                }
+                line.push_str(token.text);
+                new_line(&mut lines, &mut line, depth, false);
+                prev = None;
+                continue;
+            }
+            Kind::Punctuator => match token.text {
+                "{" if inline || prev.is_some_and(|prev| prev.is(":") || prev.is("=")) => {
+                    separate(&mut line, prev, &token, true);
+                    line.push_str("{ ");
+                    inline_stack.push("}");
+                }
+                "{" => {
+                    if !line_is_empty(&line) {
+                        line.push(' ');
+                    }
+                    line.push('{');
+                    depth += 1;
+                    new_line(&mut lines, &mut line, depth, false);
+                }
+                "}" if inline => {
+                    if inline_stack.pop()? != "}" {
+                        return None;
+                    }
+                    if line.ends_with("{ ") {
+                        line.pop();
+                        line.push('}');
+                    } else {
+                        line.push_str(" }");
+                    }
+                }
+                "}" => {
+                    depth = depth.checked_sub(1)?;
+                    new_line(&mut lines, &mut line, depth, false);
+                    line.push('}');
+                }
+                "(" | "[" => {
+                    separate(&mut line, prev, &token, inline);
+                    line.push_str(token.text);
+                    inline_stack.push(if token.text == "(" { ")" } else { "]" });
+                }
+                ")" | "]" => {
+                    if inline_stack.pop()? != token.text {
+                        return None;
+                    }
+                    line.push_str(token.text);
+                }
+                ":" | "!" => line.push_str(token.text),
+                "=" | "|" | "&" => {
+                    if !line_is_empty(&line) {
+                        line.push(' ');
+                    }
+                    line.push_str(token.text);
+                }
+                _ => {
+                    // `$`, `@` and `...`
+                    start_item(&mut lines, &mut line, depth, prev, &token, inline, &mut new_line);
+                    line.push_str(token.text);
+                }
+            },
+            Kind::Name | Kind::String => {
+                start_item(&mut lines, &mut line, depth, prev, &token, inline, &mut new_line);
+                line.push_str(token.text);
+            }
+        }
+        prev = Some(token);
+    }
+
+    if depth != 0 || !inline_stack.is_empty() {
+        return None;
+    }
+    if !line_is_empty(&line) {
+        lines.push(line);
+    }
+    Some(lines.join("\n"))
+}
+
+/// Start a new line for `token` if it starts a new field or definition,
+/// otherwise separate it from the previous token.
+fn start_item(
+    lines: &mut Vec<String>,
+    line: &mut String,
+    depth: usize,
+    prev: Option<Token<'_>>,
+    token: &Token<'_>,
+    inline: bool,
+    new_line: &mut impl FnMut(&mut Vec<String>, &mut String, usize, bool),
+) {
+    let starts_item = !inline
+        && !line.trim().is_empty()
+        && prev.is_some_and(|prev| prev.ends_value())
+        && token.starts_value()
+        && (depth > 0 || prev.is_some_and(|prev| prev.is("}")) || token.newlines_before > 0)
+        && !prev.is_some_and(|prev| prev.kind == Kind::Name && is_definition_keyword(prev.text));
+    if starts_item {
+        new_line(lines, line, depth, token.newlines_before > 1);
+    } else {
+        separate(line, prev, token, inline);
+    }
+}
+
+/// Whether the name is followed by another name in a definition header,
+/// e.g. `query Name`, `fragment Name on Type` or `type Name implements Interface`.
+fn is_definition_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "query"
+            | "mutation"
+            | "subscription"
+            | "fragment"
+            | "on"
+            | "type"
+            | "interface"
+            | "union"
+            | "enum"
+            | "input"
+            | "scalar"
+            | "schema"
+            | "directive"
+            | "extend"
+            | "implements"
+            | "repeatable"
+    )
+}
+
+/// Separate `token` from the previous token on the same line.
+fn separate(line: &mut String, prev: Option<Token<'_>>, token: &Token<'_>, inline: bool) {
+    let Some(prev) = prev else { return };
+    if line.trim().is_empty() {
+        return;
+    }
+    if prev.is("(") || prev.is("[") || prev.is("$") || prev.is("@") {
+        return;
+    }
+    if prev.is("...") {
+        // `...Fragment`, but `... on Type` and `... @include(if: $foo)`
+        if token.kind == Kind::Name && token.text != "on" {
+            return;
+        }
+    } else if prev.is("{") {
+        // The space is already printed after `{` of an inline object.
+        return;
+    } else if token.is("(") && prev.kind == Kind::Name {
+        // `field(arg: 1)`, `@include(if: $foo)`
+        return;
+    }
+    if inline && prev.ends_value() && token.starts_value() {
+        line.push_str(", ");
+    } else if !line.ends_with(' ') {
+        line.push(' ');
+    }
+}
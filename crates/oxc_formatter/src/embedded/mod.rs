@@ -0,0 +1,233 @@
+//! Formatting of tagged templates in embedded languages, e.g. CSS in `` css`...` ``
+//! or GraphQL in `` gql`...` ``.
+
+mod css;
+mod graphql;
+
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+
+use crate::{
+    IndentStyle,
+    formatter::{FormatResult, Formatter, prelude::*},
+    generated::ast_nodes::AstNode,
+    options::FormatOptions,
+    write,
+};
+
+pub use self::{css::format_css, graphql::format_graphql};
+
+/// Prefix of the placeholders which replace the `${}` expressions of an embedded template.
+pub const PLACEHOLDER_PREFIX: &str = "@prettier-placeholder-";
+
+/// Formats the content of a tagged template in an embedded language.
+///
+/// Implemented for closures, so hosts can plug in a formatter with
+/// `formatters.register("html", |source: &str, options: &FormatOptions| ...)`.
+pub trait EmbeddedFormatter: Send + Sync {
+    /// Format `source`, the raw text of a template in which the `n`-th `${}` expression is
+    /// replaced by the placeholder `@prettier-placeholder-n`.
+    ///
+    /// The placeholders must be kept in the result, in their original order.
+    /// Leading and trailing whitespace of the result is ignored, and lines are indented
+    /// relative to the template.
+    ///
+    /// Returns `None` if `source` can not be formatted, e.g. because of a syntax error,
+    /// in which case the template is printed as is.
+    fn format(&self, source: &str, options: &FormatOptions) -> Option<String>;
+}
+
+impl<F> EmbeddedFormatter for F
+where
+    F: Fn(&str, &FormatOptions) -> Option<String> + Send + Sync,
+{
+    fn format(&self, source: &str, options: &FormatOptions) -> Option<String> {
+        self(source, options)
+    }
+}
+
+/// [EmbeddedFormatter]s keyed by tag name.
+///
+/// The tag name of `` css`...` ``, `` styled.div`...` ``, `` styled(Button)`...` `` and
+/// `` styled.div.attrs(...)`...` `` is `css` and `styled` respectively,
+/// i.e. the name of the leftmost identifier of the tag.
+///
+/// [EmbeddedFormatters::default] contains the built-in formatters:
+/// [format_css] for `css`, `keyframes`, `injectGlobal`, `createGlobalStyle` and `styled`,
+/// and [format_graphql] for `graphql` and `gql`.
+#[derive(Clone)]
+pub struct EmbeddedFormatters {
+    formatters: FxHashMap<String, Arc<dyn EmbeddedFormatter>>,
+}
+
+impl Default for EmbeddedFormatters {
+    fn default() -> Self {
+        let css: Arc<dyn EmbeddedFormatter> = Arc::new(format_css);
+        let graphql: Arc<dyn EmbeddedFormatter> = Arc::new(format_graphql);
+        let mut formatters = Self::empty();
+        for tag in ["css", "keyframes", "injectGlobal", "createGlobalStyle", "styled"] {
+            formatters.formatters.insert(tag.to_string(), Arc::clone(&css));
+        }
+        for tag in ["graphql", "gql"] {
+            formatters.formatters.insert(tag.to_string(), Arc::clone(&graphql));
+        }
+        formatters
+    }
+}
+
+impl std::fmt::Debug for EmbeddedFormatters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut tags = self.formatters.keys().collect::<Vec<_>>();
+        tags.sort_unstable();
+        f.debug_struct("EmbeddedFormatters").field("tags", &tags).finish()
+    }
+}
+
+impl EmbeddedFormatters {
+    /// Without any formatters, not even the built-in ones.
+    pub fn empty() -> Self {
+        Self { formatters: FxHashMap::default() }
+    }
+
+    /// Use `formatter` for templates tagged with `tag`, replacing the previous formatter if any.
+    #[must_use]
+    pub fn register(
+        mut self,
+        tag: impl Into<String>,
+        formatter: impl EmbeddedFormatter + 'static,
+    ) -> Self {
+        self.formatters.insert(tag.into(), Arc::new(formatter));
+        self
+    }
+
+    /// Stop formatting templates tagged with `tag`.
+    #[must_use]
+    pub fn unregister(mut self, tag: &str) -> Self {
+        self.formatters.remove(tag);
+        self
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&dyn EmbeddedFormatter> {
+        self.formatters.get(tag).map(AsRef::as_ref)
+    }
+}
+
+/// The name of the leftmost identifier of `tag`, see [EmbeddedFormatters].
+fn tag_name<'a>(tag: &'a Expression<'a>) -> Option<&'a str> {
+    match tag {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        Expression::StaticMemberExpression(member) => tag_name(&member.object),
+        Expression::CallExpression(call) => tag_name(&call.callee),
+        _ => None,
+    }
+}
+
+/// A line of formatted embedded code: text and the indices of the expressions in between.
+type Line<'a> = Vec<(&'a str, Option<usize>)>;
+
+/// Split `code` into lines, and the lines at the placeholders.
+///
+/// Returns `None` unless every placeholder of the `expressions_count` expressions
+/// appears exactly once, in order.
+fn split_lines(code: &str, expressions_count: usize) -> Option<Vec<(Line<'_>, bool)>> {
+    let mut lines = Vec::new();
+    let mut next_expression = 0;
+    let mut blank_line_before = false;
+    for line in code.trim().lines() {
+        let mut line = line.trim_end();
+        if line.is_empty() {
+            blank_line_before = true;
+            continue;
+        }
+        let mut parts = Vec::new();
+        while let Some(start) = line.find(PLACEHOLDER_PREFIX) {
+            let rest = &line[start + PLACEHOLDER_PREFIX.len()..];
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            let index = rest[..digits].parse::<usize>().ok()?;
+            if index != next_expression {
+                return None;
+            }
+            next_expression += 1;
+            parts.push((&line[..start], Some(index)));
+            line = &rest[digits..];
+        }
+        parts.push((line, None));
+        lines.push((parts, blank_line_before));
+        blank_line_before = false;
+    }
+    (next_expression == expressions_count).then_some(lines)
+}
+
+/// Write the quasi of `tagged` formatted by the [EmbeddedFormatter] for its tag.
+///
+/// Returns `false` without writing anything if there is no formatter for the tag,
+/// or if the formatter fails.
+pub fn write_embedded_template<'a>(
+    tagged: &AstNode<'a, TaggedTemplateExpression<'a>>,
+    f: &mut Formatter<'_, 'a>,
+) -> FormatResult<bool> {
+    if f.options().embedded_language_formatting.is_off() {
+        return Ok(false);
+    }
+    let Some(formatter) =
+        tag_name(tagged.tag().as_ref()).and_then(|tag| f.context().embedded_formatters().get(tag))
+    else {
+        return Ok(false);
+    };
+
+    let quasi = tagged.quasi();
+    let mut source = String::new();
+    for (index, element) in quasi.quasis().iter().enumerate() {
+        if index > 0 {
+            source.push_str(PLACEHOLDER_PREFIX);
+            source.push_str(&(index - 1).to_string());
+        }
+        source.push_str(element.value().raw.as_str());
+    }
+    let Some(code) = formatter.format(&source, f.options()) else {
+        return Ok(false);
+    };
+    let code = f.context().allocator().alloc_str(&code);
+    let expressions = quasi.expressions().iter().collect::<Vec<_>>();
+    let Some(lines) = split_lines(code, expressions.len()) else {
+        return Ok(false);
+    };
+
+    if lines.is_empty() {
+        write!(f, "``")?;
+        return Ok(true);
+    }
+    let content = format_with(|f| {
+        for (index, (parts, blank_line_before)) in lines.iter().enumerate() {
+            if index > 0 {
+                if *blank_line_before {
+                    write!(f, empty_line())?;
+                } else {
+                    write!(f, hard_line_break())?;
+                }
+            }
+            for (text, expression) in parts {
+                if !text.is_empty() {
+                    write!(f, dynamic_text(text))?;
+                }
+                if let Some(expression) = expression {
+                    write!(f, ["${", expressions[*expression], "}"])?;
+                }
+            }
+        }
+        Ok(())
+    });
+    write!(f, ["`", block_indent(&content), "`"])?;
+    Ok(true)
+}
+
+/// The string of one indentation level.
+fn indent_unit(options: &FormatOptions) -> String {
+    match options.indent_style {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(options.indent_width.value() as usize),
+    }
+}
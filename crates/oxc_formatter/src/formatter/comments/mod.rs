@@ -21,12 +21,12 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Comments<'a> {
     source_text: &'a str,
-    comments: &'a Vec<'a, Comment>,
+    comments: &'a [Comment],
     printed_count: usize,
 }
 
 impl<'a> Comments<'a> {
-    pub fn new(source_text: &'a str, comments: &'a Vec<'a, Comment>) -> Self {
+    pub fn new(source_text: &'a str, comments: &'a [Comment]) -> Self {
         Comments { source_text, comments, printed_count: 0 }
     }
 
@@ -191,6 +191,25 @@ impl<'a> Comments<'a> {
     pub fn increment_printed_count(&mut self) {
         self.printed_count += 1;
     }
+
+    /// Mark the unprinted comments ending before `position` as printed, without printing them.
+    pub fn skip_comments_before(&mut self, position: u32) {
+        self.printed_count +=
+            self.unprinted_comments().partition_point(|comment| comment.span.end <= position);
+    }
+
+    /// Hide the comments starting after `position`, until they are restored with
+    /// [Comments::restore_comments] and the returned comments.
+    pub fn hide_comments_after(&mut self, position: u32) -> &'a [Comment] {
+        let comments = self.comments;
+        let end = comments.partition_point(|comment| comment.span.start < position);
+        self.comments = &comments[..end];
+        comments
+    }
+
+    pub fn restore_comments(&mut self, comments: &'a [Comment]) {
+        self.comments = comments;
+    }
 }
 
 #[inline]
@@ -7,7 +7,10 @@ use oxc_ast::{
 };
 use oxc_span::{GetSpan, SourceType, Span};
 
-use crate::{formatter::FormatElement, generated::ast_nodes::AstNode, options::FormatOptions};
+use crate::{
+    embedded::EmbeddedFormatters, formatter::FormatElement, generated::ast_nodes::AstNode,
+    options::FormatOptions, range::FormatRange,
+};
 
 use super::Comments;
 
@@ -25,6 +28,11 @@ pub struct FormatContext<'ast> {
     cached_function_body: Option<(Span, FormatElement<'ast>)>,
 
     allocator: &'ast Allocator,
+
+    embedded_formatters: EmbeddedFormatters,
+
+    /// The statements to format with [crate::Formatter::format_range].
+    range: Option<FormatRange>,
 }
 
 impl std::fmt::Debug for FormatContext<'_> {
@@ -44,6 +52,7 @@ impl<'ast> FormatContext<'ast> {
         program: &'ast Program<'ast>,
         allocator: &'ast Allocator,
         options: FormatOptions,
+        embedded_formatters: EmbeddedFormatters,
     ) -> Self {
         Self {
             options,
@@ -52,9 +61,18 @@ impl<'ast> FormatContext<'ast> {
             comments: Comments::new(program.source_text, &program.comments),
             cached_function_body: None,
             allocator,
+            embedded_formatters,
+            range: None,
         }
     }
 
+    /// Only format the statements in `range`. See [crate::Formatter::format_range].
+    #[must_use]
+    pub(crate) fn with_range(mut self, range: FormatRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+
     /// Returns the formatting options
     pub fn options(&self) -> &FormatOptions {
         &self.options
@@ -103,6 +121,23 @@ impl<'ast> FormatContext<'ast> {
         self.comments.increment_printed_count();
     }
 
+    pub(crate) fn comments_mut(&mut self) -> &mut Comments<'ast> {
+        &mut self.comments
+    }
+
+    pub(crate) fn range(&self) -> Option<FormatRange> {
+        self.range
+    }
+
+    pub(crate) fn set_range(&mut self, range: Option<FormatRange>) {
+        self.range = range;
+    }
+
+    /// Returns the formatters for tagged templates in embedded languages.
+    pub fn embedded_formatters(&self) -> &EmbeddedFormatters {
+        &self.embedded_formatters
+    }
+
     pub fn allocator(&self) -> &'ast Allocator {
         self.allocator
     }
@@ -288,6 +288,9 @@ pub enum VerbatimKind {
         /// the length of the formatted node
         length: TextSize,
     },
+    /// The statements formatted by [crate::Formatter::format_range], marked to find them in the
+    /// printed code.
+    Range,
 }
 
 impl VerbatimKind {
@@ -239,31 +239,27 @@ impl<'a> Printer<'a> {
                 self.state.line_suffixes.extend(args, queue.iter_content(TagKind::LineSuffix));
             }
 
-            FormatElement::Tag(StartVerbatim(kind)) => {
-                todo!()
-                // if let VerbatimKind::Verbatim { length } = kind {
-                // self.state.verbatim_markers.push(TextRange::at(
-                // TextSize::from(self.state.buffer.len() as u32),
-                // *length,
-                // ));
-                // }
-
-                // stack.push(TagKind::Verbatim, args);
+            FormatElement::Tag(StartVerbatim(_)) => {
+                self.state.verbatim_starts.push(self.state.buffer.len());
+                stack.push(TagKind::Verbatim, args);
             }
 
             FormatElement::Tag(tag @ (StartLabelled(_) | StartEntry)) => {
                 stack.push(tag.kind(), args);
             }
             FormatElement::Tag(
-                tag @ (EndLabelled
-                | EndEntry
-                | EndGroup
-                | EndConditionalContent
-                | EndVerbatim
-                | EndFill),
+                tag @ (EndLabelled | EndEntry | EndGroup | EndConditionalContent | EndFill),
             ) => {
                 stack.pop(tag.kind())?;
             }
+            FormatElement::Tag(tag @ EndVerbatim) => {
+                if let Some(start) = self.state.verbatim_starts.pop() {
+                    #[expect(clippy::cast_possible_truncation)]
+                    let range = TextRange::new(start as u32, self.state.buffer.len() as u32);
+                    self.state.verbatim_markers.push(range);
+                }
+                stack.pop(tag.kind())?;
+            }
             FormatElement::Tag(tag @ EndIndentIfGroupBreaks(group_id)) => {
                 if self.state.group_modes.unwrap_print_mode(*group_id, element)
                     == PrintMode::Expanded
@@ -694,6 +690,8 @@ struct PrinterState<'a> {
     has_empty_line: bool,
     line_suffixes: LineSuffixes<'a>,
     verbatim_markers: Vec<TextRange>,
+    /// Start offsets in `buffer` of the verbatim elements being printed.
+    verbatim_starts: Vec<usize>,
     group_modes: GroupModes,
     // Re-used queue to measure if a group fits. Optimisation to avoid re-allocating a new
     // vec everytime a group gets measured
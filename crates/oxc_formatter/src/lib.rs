@@ -14,9 +14,12 @@ mod generated {
     pub mod format;
     pub mod format_write;
 }
+mod config;
+mod embedded;
 mod formatter;
 mod options;
 mod parentheses;
+mod range;
 mod utils;
 mod write;
 
@@ -30,24 +33,49 @@ use std::{
 
 use oxc_allocator::{Address, Allocator, GetAddress};
 use oxc_ast::{AstKind, ast::*};
+use oxc_span::Span;
 use rustc_hash::{FxHashMap, FxHashSet};
 use write::FormatWrite;
 
-pub use crate::options::*;
+pub use crate::{
+    config::{FORMAT_CONFIG_FILE, FormatConfig},
+    embedded::{
+        EmbeddedFormatter, EmbeddedFormatters, PLACEHOLDER_PREFIX, format_css, format_graphql,
+    },
+    options::*,
+    range::{FormatRangeResult, minimal_edit},
+};
 use crate::{
     formatter::FormatContext,
     generated::ast_nodes::{AstNode, AstNodes},
+    range::{StatementLists, line_start},
 };
 
 pub struct Formatter<'a> {
     allocator: &'a Allocator,
     source_text: &'a str,
     options: FormatOptions,
+    embedded_formatters: EmbeddedFormatters,
 }
 
 impl<'a> Formatter<'a> {
     pub fn new(allocator: &'a Allocator, options: FormatOptions) -> Self {
-        Self { allocator, source_text: "", options }
+        Self {
+            allocator,
+            source_text: "",
+            options,
+            embedded_formatters: EmbeddedFormatters::default(),
+        }
+    }
+
+    /// Use `embedded_formatters` instead of the built-in ones for tagged templates,
+    /// e.g. to add a formatter for `` html`...` ``.
+    ///
+    /// They are only used if [FormatOptions::embedded_language_formatting] is `Auto`.
+    #[must_use]
+    pub fn with_embedded_formatters(mut self, embedded_formatters: EmbeddedFormatters) -> Self {
+        self.embedded_formatters = embedded_formatters;
+        self
     }
 
     pub fn build(mut self, program: &Program<'a>) -> String {
@@ -56,7 +84,8 @@ impl<'a> Formatter<'a> {
 
         let source_text = program.source_text;
         self.source_text = source_text;
-        let context = FormatContext::new(program, self.allocator, self.options);
+        let context =
+            FormatContext::new(program, self.allocator, self.options, self.embedded_formatters);
         let formatted = formatter::format(
             program,
             context,
@@ -65,4 +94,46 @@ impl<'a> Formatter<'a> {
         .unwrap();
         formatted.print().unwrap().into_code()
     }
+
+    /// Format the statements intersecting `range`, e.g. for "format selection" or "format on paste".
+    ///
+    /// Only the innermost statement list enclosing `range` is considered, and within it only the
+    /// statements intersecting `range` are formatted, together with the statements enclosing them
+    /// to get their indentation right. The returned edit is minimal: unchanged text at the start
+    /// and end of the statements is not part of it.
+    ///
+    /// Returns `None` if `range` does not intersect any statement.
+    pub fn format_range(self, program: &Program<'a>, range: Span) -> Option<FormatRangeResult> {
+        let source_text = program.source_text;
+        let range = StatementLists::new(program).find(range)?;
+
+        let parent = self.allocator.alloc(AstNodes::Dummy());
+        let program_node = AstNode::new(program, parent, self.allocator);
+        let context =
+            FormatContext::new(program, self.allocator, self.options, self.embedded_formatters)
+                .with_range(range);
+        let formatted = formatter::format(
+            program,
+            context,
+            formatter::Arguments::new(&[formatter::Argument::new(&program_node)]),
+        )
+        .ok()?;
+        let printed = formatted.print().ok()?;
+        let code = printed.verbatim_ranges().first()?.source_text(printed.as_code());
+
+        // The printed code starts with its indentation, which replaces the original one if the
+        // statements are at the start of their line.
+        let (span, code) = match line_start(source_text, range.span.start) {
+            Some(start) => (Span::new(start, range.span.end), code),
+            None => (range.span, code.trim_start()),
+        };
+        Some(minimal_edit(source_text, span, code))
+    }
+
+    /// Format the statement ending at `offset`, e.g. after typing `;` or `}`.
+    ///
+    /// See [Formatter::format_range].
+    pub fn format_on_type(self, program: &Program<'a>, offset: u32) -> Option<FormatRangeResult> {
+        self.format_range(program, Span::new(offset.saturating_sub(1), offset))
+    }
 }
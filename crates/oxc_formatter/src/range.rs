@@ -0,0 +1,97 @@
+use oxc_allocator::{Address, Vec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_span::{GetSpan, Span};
+
+/// Result of [crate::Formatter::format_range].
+///
+/// Replacing `span` in the source text with `code` yields the formatted source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatRangeResult {
+    /// Span of the source text to replace.
+    pub span: Span,
+    /// Formatted code to replace `span` with.
+    pub code: String,
+}
+
+/// The statements formatted by [crate::Formatter::format_range].
+#[derive(Debug, Clone, Copy)]
+pub struct FormatRange {
+    /// Address of the statement list containing the statements.
+    pub list: Address,
+    /// Span from the start of the first to the end of the last statement.
+    pub span: Span,
+}
+
+/// The spans of the statements of every statement list in a program.
+#[derive(Default)]
+pub struct StatementLists {
+    lists: std::vec::Vec<(Address, std::vec::Vec<Span>)>,
+}
+
+impl StatementLists {
+    pub fn new(program: &Program<'_>) -> Self {
+        let mut lists = Self::default();
+        lists.visit_program(program);
+        lists
+    }
+
+    /// Find the statements intersecting `range` in the innermost statement list enclosing it.
+    pub fn find(&self, range: Span) -> Option<FormatRange> {
+        self.lists
+            .iter()
+            .filter_map(|(list, spans)| {
+                let first = spans.first()?;
+                let last = spans.last()?;
+                if range.start < first.start || range.end > last.end {
+                    return None;
+                }
+                let start = spans.iter().find(|span| intersects(**span, range))?;
+                let end = spans.iter().rfind(|span| intersects(**span, range))?;
+                let span = Span::new(start.start, end.end);
+                Some((FormatRange { list: *list, span }, last.end - first.start))
+            })
+            .min_by_key(|(_, size)| *size)
+            .map(|(range, _)| range)
+    }
+}
+
+impl<'a> Visit<'a> for StatementLists {
+    fn visit_statements(&mut self, it: &Vec<'a, Statement<'a>>) {
+        self.lists.push((Address::from_ptr(it), it.iter().map(GetSpan::span).collect()));
+        walk::walk_statements(self, it);
+    }
+}
+
+/// Whether `span` intersects `range`. An empty `range` intersects the span containing it.
+fn intersects(span: Span, range: Span) -> bool {
+    if range.is_empty() {
+        span.start <= range.start && range.end < span.end
+    } else {
+        span.start < range.end && range.start < span.end
+    }
+}
+
+/// Start of the line of `position`, if it is only preceded by indentation on its line.
+pub fn line_start(source_text: &str, position: u32) -> Option<u32> {
+    let before = &source_text[..position as usize];
+    let line_start = before.rfind(['\n', '\r']).map_or(0, |i| i + 1);
+    #[expect(clippy::cast_possible_truncation)]
+    before[line_start..].chars().all(|c| c == ' ' || c == '\t').then_some(line_start as u32)
+}
+
+/// Shrink the replacement of `span` in `source_text` by `code` to a minimal edit,
+/// by removing the common prefix and suffix.
+pub fn minimal_edit(source_text: &str, span: Span, code: &str) -> FormatRangeResult {
+    let original = span.source_text(source_text);
+    let prefix = common_prefix_len(original.chars(), code.chars());
+    let suffix = common_prefix_len(original[prefix..].chars().rev(), code[prefix..].chars().rev());
+    #[expect(clippy::cast_possible_truncation)]
+    let span = Span::new(span.start + prefix as u32, span.end - suffix as u32);
+    FormatRangeResult { span, code: code[prefix..code.len() - suffix].to_string() }
+}
+
+/// Length in bytes of the common prefix of `a` and `b`.
+fn common_prefix_len(a: impl Iterator<Item = char>, b: impl Iterator<Item = char>) -> usize {
+    a.zip(b).take_while(|(a, b)| a == b).map(|(c, _)| c.len_utf8()).sum()
+}
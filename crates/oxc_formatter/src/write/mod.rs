@@ -26,9 +26,11 @@ use oxc_span::{GetSpan, SPAN};
 use oxc_syntax::identifier::{ZWNBSP, is_identifier_name, is_line_terminator};
 
 use crate::{
+    embedded::write_embedded_template,
     format_args,
     formatter::{
         Buffer, Format, FormatResult, Formatter,
+        format_element::tag::VerbatimKind,
         prelude::*,
         separated::FormatSeparatedIter,
         token::number::{NumberFormatOptions, format_number_token},
@@ -276,7 +278,11 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TemplateLiteral<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, TaggedTemplateExpression<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, [self.tag(), self.type_arguments(), self.quasi()])
+        write!(f, [self.tag(), self.type_arguments()])?;
+        if write_embedded_template(self, f)? {
+            return Ok(());
+        }
+        write!(f, self.quasi())
     }
 }
 
@@ -567,11 +573,38 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ParenthesizedExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, Statement<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         let source_text = f.context().source_text();
+        let Some(range) = f.context().range() else {
+            let mut join = f.join_nodes_with_hardline();
+            for stmt in self {
+                join.entry(stmt.span(), source_text, stmt);
+            }
+            return join.finish();
+        };
+
+        if Address::from_ptr(&**self) != range.list {
+            // Only format the statement on the way to the range, its output is discarded.
+            let mut join = f.join_nodes_with_hardline();
+            for stmt in self.iter().filter(|stmt| stmt.span().contains_inclusive(range.span)) {
+                join.entry(stmt.span(), source_text, stmt);
+            }
+            return join.finish();
+        }
+
+        // Format the statements in the range, without the comments outside of it, and mark them
+        // to find them in the printed code.
+        f.context_mut().set_range(None);
+        f.context_mut().comments_mut().skip_comments_before(range.span.start);
+        let comments = f.context_mut().comments_mut().hide_comments_after(range.span.end);
+        f.write_element(FormatElement::Tag(Tag::StartVerbatim(VerbatimKind::Range)))?;
         let mut join = f.join_nodes_with_hardline();
-        for stmt in self {
+        for stmt in self.iter().filter(|stmt| range.span.contains_inclusive(stmt.span())) {
             join.entry(stmt.span(), source_text, stmt);
         }
-        join.finish()
+        join.finish()?;
+        f.write_element(FormatElement::Tag(Tag::EndVerbatim))?;
+        f.context_mut().comments_mut().restore_comments(comments);
+        f.context_mut().set_range(Some(range));
+        Ok(())
     }
 }
 
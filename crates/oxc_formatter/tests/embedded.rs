@@ -0,0 +1,97 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{
+    EmbeddedFormatters, EmbeddedLanguageFormatting, FormatOptions, Formatter, PLACEHOLDER_PREFIX,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn format(source_text: &str, options: FormatOptions, formatters: EmbeddedFormatters) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    Formatter::new(&allocator, options).with_embedded_formatters(formatters).build(&ret.program)
+}
+
+fn auto() -> FormatOptions {
+    FormatOptions {
+        embedded_language_formatting: EmbeddedLanguageFormatting::Auto,
+        ..FormatOptions::default()
+    }
+}
+
+#[track_caller]
+fn test(source_text: &str, expected: &str) {
+    let formatted = format(source_text, auto(), EmbeddedFormatters::default());
+    assert_eq!(formatted, expected);
+}
+
+#[test]
+fn css() {
+    test(
+        "const Button = styled.a`\ndisplay:inline-block;color : ${(props) => props.color};\n\n  &:hover,&>span{ color:red }\n  @media (max-width:100px) { margin:0 }\n`;\n",
+        "const Button = styled.a`\n  display: inline-block;\n  color: ${(props) => props.color};\n\n  &:hover,\n  & > span {\n    color: red;\n  }\n  @media (max-width: 100px) {\n    margin: 0;\n  }\n`;\n",
+    );
+    test(
+        "const a = css`\n  ${mixin}\n  box-shadow: 0 0 0 1px rgba(0,0,0,.1);\n  /* comment */\n  content: \"a  b\"\n`;\n",
+        "const a = css`\n  ${mixin}\n  box-shadow: 0 0 0 1px rgba(0, 0, 0, .1);\n  /* comment */\n  content: \"a  b\";\n`;\n",
+    );
+    test(
+        "const Link = styled(Button).attrs({})`color:red;`;\n",
+        "const Link = styled(Button).attrs({})`\n  color: red;\n`;\n",
+    );
+    test("const a = css``;\n", "const a = css``;\n");
+    // Unbalanced braces are printed as is.
+    test("const a = css`a { color: red;`;\n", "const a = css`a { color: red;`;\n");
+}
+
+#[test]
+fn graphql() {
+    test(
+        "const query = gql`\n  query User($id: ID!,$first:Int = 10) { user(id: $id) { name, ...UserFields friends(first: $first) { edges { node { id } } } } }\n\n  ${fragment}\n`;\n",
+        "const query = gql`\n  query User($id: ID!, $first: Int = 10) {\n    user(id: $id) {\n      name\n      ...UserFields\n      friends(first: $first) {\n        edges {\n          node {\n            id\n          }\n        }\n      }\n    }\n  }\n\n  ${fragment}\n`;\n",
+    );
+    test(
+        "const typeDefs = graphql`\ntype Query { posts(filter: {tags: [\"a\" \"b\"]}): [Post!]! @deprecated(reason: \"no\") }\n# comment\nunion Result = A | B\n`;\n",
+        "const typeDefs = graphql`\n  type Query {\n    posts(filter: { tags: [\"a\", \"b\"] }): [Post!]! @deprecated(reason: \"no\")\n  }\n  # comment\n  union Result = A | B\n`;\n",
+    );
+    test(
+        "const fragment = gql`fragment F on User { ... on Admin { role } }`;\n",
+        "const fragment = gql`\n  fragment F on User {\n    ... on Admin {\n      role\n    }\n  }\n`;\n",
+    );
+}
+
+#[test]
+fn off() {
+    let source_text = "const a = css`color:red;`;\n";
+    // Embedded languages are not formatted by default.
+    assert_eq!(
+        format(source_text, FormatOptions::default(), EmbeddedFormatters::default()),
+        source_text
+    );
+    let formatters = EmbeddedFormatters::default().unregister("css");
+    assert_eq!(format(source_text, auto(), formatters), source_text);
+    assert_eq!(format(source_text, auto(), EmbeddedFormatters::empty()), source_text);
+}
+
+#[test]
+fn custom_formatter() {
+    let formatters =
+        EmbeddedFormatters::empty().register("html", |source: &str, _: &FormatOptions| {
+            Some(source.split_whitespace().collect::<Vec<_>>().join("\n"))
+        });
+    let formatted = format("const a = html`<div>  ${b}  </div>`;\n", auto(), formatters.clone());
+    assert_eq!(formatted, "const a = html`\n  <div>\n  ${b}\n  </div>\n`;\n");
+
+    // Templates are printed as is if the formatter drops placeholders.
+    let formatters = formatters.register("html", |_: &str, _: &FormatOptions| Some(String::new()));
+    let source_text = "const a = html`<div>${b}</div>`;\n";
+    assert_eq!(format(source_text, auto(), formatters), source_text);
+
+    // The formatter sees `${}` expressions as placeholders.
+    let formatters =
+        EmbeddedFormatters::empty().register("html", |source: &str, _: &FormatOptions| {
+            assert_eq!(source, format!("<a>{PLACEHOLDER_PREFIX}0</a>{PLACEHOLDER_PREFIX}1"));
+            None
+        });
+    format("html`<a>${b}</a>${c}`;\n", auto(), formatters);
+}
@@ -0,0 +1,70 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+/// Format the range marked by `<|` and `|>` and apply the edit.
+#[track_caller]
+fn test(source_text: &str, expected: &str) {
+    let start = source_text.find("<|").unwrap();
+    let end = source_text.find("|>").unwrap() - 2;
+    let source_text = format!(
+        "{}{}{}",
+        &source_text[..start],
+        &source_text[start + 2..end + 2],
+        &source_text[end + 4..]
+    );
+    #[expect(clippy::cast_possible_truncation)]
+    let range = Span::new(start as u32, end as u32);
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, SourceType::mjs()).parse();
+    let result = Formatter::new(&allocator, FormatOptions::default())
+        .format_range(&ret.program, range)
+        .unwrap();
+    let mut formatted = source_text.clone();
+    formatted.replace_range(result.span.start as usize..result.span.end as usize, &result.code);
+    assert_eq!(formatted, expected);
+}
+
+#[test]
+fn format_range() {
+    test("let  a=1;\nlet  b=<|2|>;\nlet  c=3;\n", "let  a=1;\nlet b = 2;\nlet  c=3;\n");
+    test("let  a=<|1;\nlet  b=2|>;\nlet  c=3;\n", "let a = 1;\nlet b = 2;\nlet  c=3;\n");
+    test(
+        "let  a=1;\nfunction f(){\nfoo( <|1|> );\nbar( 2 );\n}\n",
+        "let  a=1;\nfunction f(){\n  foo(1);\nbar( 2 );\n}\n",
+    );
+    // Empty statements are dropped by the formatter, but do not prevent matching statements.
+    test("let  a=1;;\nlet  b=<|2|>;\n;let  c=3;\n", "let  a=1;;\nlet b = 2;\n;let  c=3;\n");
+    test("function f(){;\nfoo( <|1|> );\n}\n;\n", "function f(){;\n  foo(1);\n}\n;\n");
+    // The indentation is the one of the formatted program.
+    test("const o = {\nm() {\nfoo( <|1|> );\n}\n};\n", "const o = {\nm() {\n    foo(1);\n}\n};\n");
+    // Comments outside of the statements are kept as they are.
+    test("// a\nfoo( <|1|> ); // b\nbar( 2 );\n", "// a\nfoo(1); // b\nbar( 2 );\n");
+    test("foo( <|1|> /* a */ );\n", "foo(1 /* a */);\n");
+}
+
+#[test]
+fn format_range_minimal_edit() {
+    let source_text = "foo( 1 );\n";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let result = Formatter::new(&allocator, FormatOptions::default())
+        .format_range(&ret.program, Span::new(5, 6))
+        .unwrap();
+    assert_eq!(result.span, Span::new(4, 7));
+    assert_eq!(result.code, "1");
+}
+
+#[test]
+fn format_on_type() {
+    let source_text = "let  a=1;\nlet  b=2;\n";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let result = Formatter::new(&allocator, FormatOptions::default())
+        .format_on_type(&ret.program, 9)
+        .unwrap();
+    assert_eq!(result.span, Span::new(4, 7));
+    assert_eq!(result.code, "a = ");
+}
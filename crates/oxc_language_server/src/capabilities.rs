@@ -92,6 +92,8 @@ impl From<Capabilities> for ServerCapabilities {
             } else {
                 None
             },
+            document_formatting_provider: Some(OneOf::Left(true)),
+            document_range_formatting_provider: Some(OneOf::Left(true)),
             ..ServerCapabilities::default()
         }
     }
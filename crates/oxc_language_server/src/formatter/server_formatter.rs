@@ -0,0 +1,199 @@
+use std::{fs, path::Path};
+
+use log::{debug, warn};
+use tower_lsp_server::{
+    UriExt,
+    lsp_types::{Position, Range, TextEdit, Uri},
+};
+
+use oxc_allocator::Allocator;
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_formatter::{
+    FORMAT_CONFIG_FILE, FormatConfig, FormatOptions, FormatRangeResult, Formatter, minimal_edit,
+};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+use crate::Options;
+
+pub struct ServerFormatter {
+    options: FormatOptions,
+}
+
+impl ServerFormatter {
+    /// Reads the format options from the [FORMAT_CONFIG_FILE] in the workspace root,
+    /// falling back to the `format` workspace configuration.
+    pub fn new(root_uri: &Uri, options: &Options) -> Self {
+        let config = root_uri
+            .to_file_path()
+            .and_then(|root_path| Self::read_config_file(&root_path.join(FORMAT_CONFIG_FILE)))
+            .or_else(|| options.format_config.clone())
+            .unwrap_or_default();
+
+        let options = config.into_format_options().unwrap_or_else(|err| {
+            warn!("Invalid format options, fallback to default options: {err}");
+            FormatOptions::default()
+        });
+
+        Self { options }
+    }
+
+    fn read_config_file(path: &Path) -> Option<FormatConfig> {
+        let content = fs::read_to_string(path).ok()?;
+        match serde_json::from_str::<FormatConfig>(&content) {
+            Ok(config) => Some(config),
+            Err(err) => {
+                warn!("Failed to parse format config {}: {err}", path.to_string_lossy());
+                None
+            }
+        }
+    }
+
+    /// Formats the whole document.
+    pub fn run_format(&self, uri: &Uri, content: Option<String>) -> Option<Vec<TextEdit>> {
+        self.run(uri, content, None)
+    }
+
+    /// Formats the statements intersecting `range`.
+    pub fn run_range_format(
+        &self,
+        uri: &Uri,
+        content: Option<String>,
+        range: Range,
+    ) -> Option<Vec<TextEdit>> {
+        self.run(uri, content, Some(range))
+    }
+
+    fn run(
+        &self,
+        uri: &Uri,
+        content: Option<String>,
+        range: Option<Range>,
+    ) -> Option<Vec<TextEdit>> {
+        let path = uri.to_file_path()?;
+        let source_type = SourceType::from_path(&path).ok()?;
+        let source_text = match content {
+            Some(content) => content,
+            None => fs::read_to_string(&path).ok()?,
+        };
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if ret.panicked || !ret.errors.is_empty() {
+            debug!("Skip formatting {}, it has syntax errors", path.to_string_lossy());
+            return None;
+        }
+
+        let rope = Rope::from_str(&source_text);
+        let formatter = Formatter::new(&allocator, self.options.clone());
+        let FormatRangeResult { span, code: new_text } = if let Some(range) = range {
+            let span = Span::new(
+                position_to_offset(&rope, &source_text, range.start),
+                position_to_offset(&rope, &source_text, range.end),
+            );
+            let Some(result) = formatter.format_range(&ret.program, span) else {
+                return Some(vec![]);
+            };
+            result
+        } else {
+            #[expect(clippy::cast_possible_truncation)]
+            let span = Span::new(0, source_text.len() as u32);
+            minimal_edit(&source_text, span, &formatter.build(&ret.program))
+        };
+
+        if span.source_text(&source_text) == new_text {
+            return Some(vec![]);
+        }
+
+        Some(vec![TextEdit {
+            range: Range {
+                start: offset_to_position(&rope, &source_text, span.start),
+                end: offset_to_position(&rope, &source_text, span.end),
+            },
+            new_text,
+        }])
+    }
+}
+
+fn offset_to_position(rope: &Rope, source_text: &str, offset: u32) -> Position {
+    let (line, character) = get_line_column(rope, offset, source_text);
+    Position { line, character }
+}
+
+/// Convert a LSP position (UTF-16 column) to a byte offset in `source_text`.
+#[expect(clippy::cast_possible_truncation)]
+fn position_to_offset(rope: &Rope, source_text: &str, position: Position) -> u32 {
+    let line = position.line as usize;
+    if line >= rope.len_lines() {
+        return source_text.len() as u32;
+    }
+    let line_start = rope.line_to_byte(line);
+    let line_text = rope.line(line);
+    let mut offset = line_start;
+    let mut column = 0;
+    for ch in line_text.chars() {
+        if column >= position.character as usize || ch == '\n' || ch == '\r' {
+            break;
+        }
+        column += ch.len_utf16();
+        offset += ch.len_utf8();
+    }
+    offset as u32
+}
+
+#[cfg(test)]
+mod test {
+    use tower_lsp_server::lsp_types::{Position, Range};
+
+    use crate::{Options, tester::get_file_uri};
+
+    use super::ServerFormatter;
+
+    #[test]
+    fn test_format() {
+        let uri = get_file_uri("fixtures/formatter/basic/index.js");
+        let formatter =
+            ServerFormatter::new(&get_file_uri("fixtures/formatter/basic"), &Options::default());
+
+        let edits = formatter.run_format(&uri, Some("let  a=1\n".to_string())).unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range, Range::new(Position::new(0, 4), Position::new(0, 8)));
+        assert_eq!(edits[0].new_text, "a = 1;");
+
+        let edits = formatter.run_format(&uri, Some("let a = 1;\n".to_string())).unwrap();
+        assert!(edits.is_empty());
+
+        // syntax errors
+        assert!(formatter.run_format(&uri, Some("let a = ;\n".to_string())).is_none());
+    }
+
+    #[test]
+    fn test_range_format() {
+        let uri = get_file_uri("fixtures/formatter/basic/index.js");
+        let formatter =
+            ServerFormatter::new(&get_file_uri("fixtures/formatter/basic"), &Options::default());
+
+        let edits = formatter
+            .run_range_format(
+                &uri,
+                Some("let  a=1;\nlet  b=2;\n".to_string()),
+                Range::new(Position::new(1, 0), Position::new(1, 1)),
+            )
+            .unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range, Range::new(Position::new(1, 4), Position::new(1, 7)));
+        assert_eq!(edits[0].new_text, "b = ");
+    }
+
+    #[test]
+    fn test_format_config_file() {
+        let uri = get_file_uri("fixtures/formatter/config_file/index.js");
+        let formatter = ServerFormatter::new(
+            &get_file_uri("fixtures/formatter/config_file"),
+            &Options::default(),
+        );
+
+        let edits = formatter.run_format(&uri, Some("let a = \"a\";\n".to_string())).unwrap();
+        assert_eq!(edits[0].new_text, "'a'");
+    }
+}
@@ -13,8 +13,9 @@ use tower_lsp_server::{
         DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
         DidChangeWatchedFilesRegistrationOptions, DidChangeWorkspaceFoldersParams,
         DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
-        ExecuteCommandParams, InitializeParams, InitializeResult, InitializedParams, Registration,
-        ServerInfo, Unregistration, Uri, WorkspaceEdit,
+        DocumentFormattingParams, DocumentRangeFormattingParams, ExecuteCommandParams,
+        InitializeParams, InitializeResult, InitializedParams, Registration, ServerInfo, TextEdit,
+        Unregistration, Uri, WorkspaceEdit,
     },
 };
 // #
@@ -26,6 +27,7 @@ use worker::WorkspaceWorker;
 mod capabilities;
 mod code_actions;
 mod commands;
+mod formatter;
 mod linter;
 mod options;
 #[cfg(test)]
@@ -38,7 +40,7 @@ const OXC_CONFIG_FILE: &str = ".oxlintrc.json";
 
 struct Backend {
     client: Client,
-    // Each Workspace has it own worker with Linter (and in the future the formatter).
+    // Each Workspace has it own worker with Linter and Formatter.
     // We must respect each program inside with its own root folder
     // and can not use shared programmes across multiple workspaces.
     // Each Workspace can have its own server configuration and program root configuration.
@@ -462,10 +464,13 @@ impl LanguageServer for Backend {
         let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
             return;
         };
+        let content = params.content_changes.first().map(|c| c.text.clone());
+        if let Some(content) = &content {
+            worker.update_document(uri, content.clone());
+        }
         if !worker.should_lint_on_run_type(Run::OnType).await {
             return;
         }
-        let content = params.content_changes.first().map(|c| c.text.clone());
         if let Some(diagnostics) = worker.lint_file(uri, content).await {
             self.client
                 .publish_diagnostics(
@@ -485,6 +490,7 @@ impl LanguageServer for Backend {
         };
 
         let content = params.text_document.text;
+        worker.update_document(uri, content.clone());
         if let Some(diagnostics) = worker.lint_file(uri, Some(content)).await {
             self.client
                 .publish_diagnostics(
@@ -503,6 +509,7 @@ impl LanguageServer for Backend {
             return;
         };
         worker.remove_diagnostics(&params.text_document.uri);
+        worker.remove_document(&params.text_document.uri);
     }
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
@@ -527,6 +534,27 @@ impl LanguageServer for Backend {
         Ok(Some(code_actions))
     }
 
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+        Ok(worker.format_file(uri, None).await)
+    }
+
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+        Ok(worker.format_file(uri, Some(params.range)).await)
+    }
+
     async fn execute_command(
         &self,
         params: ExecuteCommandParams,
@@ -1,4 +1,5 @@
 use log::info;
+use oxc_formatter::FormatConfig;
 use oxc_linter::FixKind;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use serde::{Deserialize, Deserializer, Serialize, de::Error};
@@ -29,6 +30,7 @@ pub struct Options {
     pub config_path: Option<String>,
     pub unused_disable_directives: UnusedDisableDirectives,
     pub flags: FxHashMap<String, String>,
+    pub format_config: Option<FormatConfig>,
 }
 
 impl Options {
@@ -100,6 +102,9 @@ impl TryFrom<Value> for Options {
                 .get("configPath")
                 .and_then(|config_path| serde_json::from_value::<String>(config_path.clone()).ok()),
             flags,
+            format_config: object
+                .get("format")
+                .and_then(|format| serde_json::from_value::<FormatConfig>(format.clone()).ok()),
         })
     }
 }
@@ -127,6 +132,9 @@ mod test {
             "flags": {
                 "disable_nested_config": "true",
                 "fix_kind": "dangerous_fix"
+            },
+            "format": {
+                "semi": false
             }
         });
 
@@ -136,6 +144,7 @@ mod test {
         assert_eq!(options.unused_disable_directives, UnusedDisableDirectives::Warn);
         assert_eq!(options.flags.get("disable_nested_config"), Some(&"true".to_string()));
         assert_eq!(options.flags.get("fix_kind"), Some(&"dangerous_fix".to_string()));
+        assert_eq!(options.format_config.and_then(|config| config.semi), Some(false));
     }
 
     #[test]
@@ -147,6 +156,7 @@ mod test {
         assert_eq!(options.config_path, None);
         assert_eq!(options.unused_disable_directives, UnusedDisableDirectives::Allow);
         assert!(options.flags.is_empty());
+        assert!(options.format_config.is_none());
     }
 
     #[test]
@@ -1,6 +1,7 @@
 use std::{str::FromStr, sync::Arc, vec};
 
 use log::debug;
+use oxc_formatter::FORMAT_CONFIG_FILE;
 use rustc_hash::FxBuildHasher;
 use tokio::sync::{Mutex, RwLock};
 use tower_lsp_server::{
@@ -17,6 +18,7 @@ use crate::{
         apply_all_fix_code_action, apply_fix_code_actions, ignore_this_line_code_action,
         ignore_this_rule_code_action,
     },
+    formatter::server_formatter::ServerFormatter,
     linter::{
         error_with_position::{DiagnosticReport, PossibleFixContent},
         server_linter::{ServerLinter, normalize_path},
@@ -26,7 +28,10 @@ use crate::{
 pub struct WorkspaceWorker {
     root_uri: Uri,
     server_linter: RwLock<Option<ServerLinter>>,
+    server_formatter: RwLock<Option<ServerFormatter>>,
     diagnostics_report_map: Arc<ConcurrentHashMap<String, Vec<DiagnosticReport>>>,
+    // Content of the open documents, which may not be written to disk yet.
+    documents: ConcurrentHashMap<String, String>,
     options: Mutex<Options>,
 }
 
@@ -35,7 +40,9 @@ impl WorkspaceWorker {
         Self {
             root_uri,
             server_linter: RwLock::new(None),
+            server_formatter: RwLock::new(None),
             diagnostics_report_map: Arc::new(ConcurrentHashMap::default()),
+            documents: ConcurrentHashMap::default(),
             options: Mutex::new(Options::default()),
         }
     }
@@ -54,6 +61,7 @@ impl WorkspaceWorker {
     pub async fn init_linter(&self, options: &Options) {
         *self.options.lock().await = options.clone();
         *self.server_linter.write().await = Some(ServerLinter::new(&self.root_uri, options));
+        *self.server_formatter.write().await = Some(ServerFormatter::new(&self.root_uri, options));
     }
 
     // WARNING: start all programs (linter, formatter) before calling this function
@@ -78,6 +86,15 @@ impl WorkspaceWorker {
             kind: Some(WatchKind::all()), // created, deleted, changed
         });
 
+        // the format config file in the workspace root
+        watchers.push(FileSystemWatcher {
+            glob_pattern: GlobPattern::Relative(RelativePattern {
+                base_uri: OneOf::Right(self.root_uri.clone()),
+                pattern: FORMAT_CONFIG_FILE.to_string(),
+            }),
+            kind: Some(WatchKind::all()), // created, deleted, changed
+        });
+
         let Some(root_path) = &self.root_uri.to_file_path() else {
             return watchers;
         };
@@ -116,6 +133,14 @@ impl WorkspaceWorker {
         self.diagnostics_report_map.pin().remove(&uri.to_string());
     }
 
+    pub fn update_document(&self, uri: &Uri, content: String) {
+        self.documents.pin().insert(uri.to_string(), content);
+    }
+
+    pub fn remove_document(&self, uri: &Uri) {
+        self.documents.pin().remove(&uri.to_string());
+    }
+
     async fn refresh_server_linter(&self) {
         let options = self.options.lock().await;
         let server_linter = ServerLinter::new(&self.root_uri, &options);
@@ -123,6 +148,13 @@ impl WorkspaceWorker {
         *self.server_linter.write().await = Some(server_linter);
     }
 
+    async fn refresh_server_formatter(&self) {
+        let options = self.options.lock().await;
+        let server_formatter = ServerFormatter::new(&self.root_uri, &options);
+
+        *self.server_formatter.write().await = Some(server_formatter);
+    }
+
     fn needs_linter_restart(old_options: &Options, new_options: &Options) -> bool {
         old_options.config_path != new_options.config_path
             || old_options.use_nested_configs() != new_options.use_nested_configs()
@@ -162,6 +194,19 @@ impl WorkspaceWorker {
         server_linter.run_single(uri, content)
     }
 
+    /// Format the whole document, or only `range` of it.
+    pub async fn format_file(&self, uri: &Uri, range: Option<Range>) -> Option<Vec<TextEdit>> {
+        let Some(server_formatter) = &*self.server_formatter.read().await else {
+            return None;
+        };
+
+        let content = self.documents.pin().get(&uri.to_string()).cloned();
+        match range {
+            Some(range) => server_formatter.run_range_format(uri, content, range),
+            None => server_formatter.run_format(uri, content),
+        }
+    }
+
     fn update_diagnostics(&self, uri: &Uri, diagnostics: &[DiagnosticReport]) {
         self.diagnostics_report_map.pin().insert(uri.to_string(), diagnostics.to_owned());
     }
@@ -296,8 +341,12 @@ impl WorkspaceWorker {
 
     pub async fn did_change_watched_files(
         &self,
-        _file_event: &FileEvent,
+        file_event: &FileEvent,
     ) -> Option<ConcurrentHashMap<String, Vec<DiagnosticReport>>> {
+        if file_event.uri.path().as_str().ends_with(FORMAT_CONFIG_FILE) {
+            self.refresh_server_formatter().await;
+            return None;
+        }
         self.refresh_server_linter().await;
         Some(self.revalidate_diagnostics().await)
     }
@@ -319,6 +368,10 @@ impl WorkspaceWorker {
 
         *self.options.lock().await = changed_options.clone();
 
+        if current_option.format_config != changed_options.format_config {
+            self.refresh_server_formatter().await;
+        }
+
         if Self::needs_linter_restart(current_option, changed_options) {
             self.refresh_server_linter().await;
 
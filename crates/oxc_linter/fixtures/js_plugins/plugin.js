@@ -0,0 +1,14 @@
+export default {
+  meta: { name: 'my-plugin' },
+  rules: {
+    'no-foo': {
+      create(context) {
+        return {
+          Identifier(node) {
+            if (node.name === 'foo') context.report({ node, message: 'Unexpected foo.' });
+          },
+        };
+      },
+    },
+  },
+};
@@ -1,17 +1,21 @@
 use std::{
     cell::{Ref, RefCell},
     fmt::{self, Debug, Display},
-    path::PathBuf,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use itertools::Itertools;
-use rustc_hash::FxHashMap;
+use oxc_resolver::{ResolveOptions, Resolver};
+use rustc_hash::{FxHashMap, FxHasher};
 
 use oxc_span::{CompactStr, format_compact_str};
 
 use crate::{
     AllowWarnDeny, LintConfig, LintFilter, LintFilterKind, Oxlintrc, RuleCategory, RuleEnum,
     config::{ESLintRule, LintPlugins, OxlintOverrides, OxlintRules, overrides::OxlintOverride},
+    external_linter::{ExternalLinter, ExternalRule, LoadedExternalPlugin, resolve_external_rule},
     rules::RULES,
 };
 
@@ -25,6 +29,17 @@ pub struct ConfigStoreBuilder {
     overrides: OxlintOverrides,
     cache: RulesCache,
 
+    /// Specifiers of the JavaScript plugins in `jsPlugins`, loaded by
+    /// [`ConfigStoreBuilder::with_external_linter`].
+    js_plugins: Vec<String>,
+    /// Configured rules of plugins which are not built in, which may belong to JavaScript plugins.
+    external_rule_configs: Vec<ESLintRule>,
+    external_plugins: Vec<LoadedExternalPlugin>,
+    external_rules: Vec<(ExternalRule, AllowWarnDeny)>,
+    /// Hash of the configured rules with their options. Rule options are not kept after the
+    /// rules are configured, so this is used to detect changes to them.
+    rule_options_hash: u64,
+
     // Collect all `extends` file paths for the language server.
     // The server will tell the clients to watch for the extends files.
     pub extended_paths: Vec<PathBuf>,
@@ -49,7 +64,19 @@ impl ConfigStoreBuilder {
         let cache = RulesCache::new(config.plugins);
         let extended_paths = Vec::new();
 
-        Self { rules, config, categories, overrides, cache, extended_paths }
+        Self {
+            rules,
+            config,
+            categories,
+            overrides,
+            cache,
+            js_plugins: vec![],
+            external_rule_configs: vec![],
+            external_plugins: vec![],
+            external_rules: vec![],
+            rule_options_hash: 0,
+            extended_paths,
+        }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -63,7 +90,19 @@ impl ConfigStoreBuilder {
         let cache = RulesCache::new(config.plugins);
         let rules = RULES.iter().map(|rule| (rule.clone(), AllowWarnDeny::Warn)).collect();
         let extended_paths = Vec::new();
-        Self { rules, config, categories, overrides, cache, extended_paths }
+        Self {
+            rules,
+            config,
+            categories,
+            overrides,
+            cache,
+            js_plugins: vec![],
+            external_rule_configs: vec![],
+            external_plugins: vec![],
+            external_rules: vec![],
+            rule_options_hash: 0,
+            extended_paths,
+        }
     }
 
     /// Create a [`ConfigStoreBuilder`] from a loaded or manually built [`Oxlintrc`].
@@ -156,12 +195,32 @@ impl ConfigStoreBuilder {
         };
         let cache = RulesCache::new(config.plugins);
 
+        let external_rule_configs = oxlintrc
+            .rules
+            .rules
+            .iter()
+            .filter(|rule| {
+                rule.plugin_name != "eslint"
+                    && LintPlugins::from(rule.plugin_name.as_str()).is_empty()
+            })
+            .cloned()
+            .collect();
+
+        let mut hasher = FxHasher::default();
+        serde_json::to_string(&oxlintrc.rules).unwrap_or_default().hash(&mut hasher);
+        let rule_options_hash = hasher.finish();
+
         let mut builder = Self {
             rules,
             config,
             categories,
             overrides: oxlintrc.overrides,
             cache,
+            js_plugins: oxlintrc.js_plugins,
+            external_rule_configs,
+            external_plugins: vec![],
+            external_rules: vec![],
+            rule_options_hash,
             extended_paths,
         };
 
@@ -219,6 +278,67 @@ impl ConfigStoreBuilder {
         self.config.plugins
     }
 
+    /// Specifiers of the JavaScript plugins in `jsPlugins`.
+    #[inline]
+    pub fn js_plugins(&self) -> &[String] {
+        &self.js_plugins
+    }
+
+    /// Load the JavaScript plugins listed in `jsPlugins` with `external_linter`, and enable the
+    /// configured rules of those plugins.
+    ///
+    /// Plugins are resolved from the directory of the configuration file. Rules of plugins which
+    /// are not loaded are ignored, like rules of unknown built-in plugins.
+    ///
+    /// # Errors
+    ///
+    /// * [`ConfigBuilderError::PluginLoadFailed`] if a plugin can not be resolved or loaded.
+    /// * [`ConfigBuilderError::UnknownRules`] if a configured rule does not exist in its plugin.
+    pub fn with_external_linter(
+        mut self,
+        external_linter: &dyn ExternalLinter,
+    ) -> Result<Self, ConfigBuilderError> {
+        if self.js_plugins.is_empty() {
+            return Ok(self);
+        }
+
+        let resolver = Resolver::new(ResolveOptions {
+            extensions: vec![".js".into(), ".mjs".into(), ".cjs".into()],
+            condition_names: vec!["node".into(), "import".into(), "require".into()],
+            ..ResolveOptions::default()
+        });
+        let config_dir =
+            self.config.path.as_deref().and_then(Path::parent).unwrap_or(Path::new(""));
+        let config_dir = std::path::absolute(config_dir).unwrap_or_else(|_| config_dir.into());
+
+        for specifier in &self.js_plugins {
+            let plugin_load_failed = |reason: String| ConfigBuilderError::PluginLoadFailed {
+                plugin: specifier.clone(),
+                reason,
+            };
+            let path = resolver
+                .resolve(&config_dir, specifier)
+                .map_err(|err| plugin_load_failed(err.to_string()))?
+                .into_path_buf();
+            let plugin = external_linter.load_plugin(&path).map_err(plugin_load_failed)?;
+            self.external_plugins.push((Arc::from(path), plugin));
+        }
+
+        let mut unknown_rules = vec![];
+        for rule in &self.external_rule_configs {
+            match resolve_external_rule(&self.external_plugins, rule) {
+                Some(Ok(external_rule)) => self.external_rules.push(external_rule),
+                Some(Err(())) => unknown_rules.push(rule.clone()),
+                None => {}
+            }
+        }
+        if !unknown_rules.is_empty() {
+            return Err(ConfigBuilderError::UnknownRules { rules: unknown_rules });
+        }
+
+        Ok(self)
+    }
+
     #[cfg(test)]
     pub(crate) fn with_rule(mut self, rule: RuleEnum, severity: AllowWarnDeny) -> Self {
         self.rules.insert(rule, severity);
@@ -306,6 +426,8 @@ impl ConfigStoreBuilder {
         };
         rules.sort_unstable_by_key(|(r, _)| r.id());
         Config::new(rules, self.categories, self.config, self.overrides)
+            .with_external_rules(self.external_plugins, self.external_rules)
+            .with_rule_options_hash(self.rule_options_hash)
     }
 
     /// Warn for all correctness rules in the given set of plugins.
@@ -346,6 +468,7 @@ impl ConfigStoreBuilder {
                     .get(&get_name(r.plugin_name(), r.name()))
                     .and_then(|r| r.config.clone()),
             })
+            .chain(self.external_rule_configs.iter().cloned())
             .collect();
 
         oxlintrc.rules = OxlintRules::new(new_rules);
@@ -386,6 +509,8 @@ pub enum ConfigBuilderError {
     UnknownRules { rules: Vec<ESLintRule> },
     /// A configuration file was referenced which was not valid for some reason.
     InvalidConfigFile { file: String, reason: String },
+    /// A JavaScript plugin could not be resolved or loaded.
+    PluginLoadFailed { plugin: String, reason: String },
 }
 
 impl Display for ConfigBuilderError {
@@ -405,6 +530,9 @@ impl Display for ConfigBuilderError {
             ConfigBuilderError::InvalidConfigFile { file, reason } => {
                 write!(f, "invalid config file {file}: {reason}")
             }
+            ConfigBuilderError::PluginLoadFailed { plugin, reason } => {
+                write!(f, "failed to load JS plugin {plugin}: {reason}")
+            }
         }
     }
 }
@@ -504,6 +632,7 @@ mod test {
     use std::path::PathBuf;
 
     use super::*;
+    use crate::{ExternalDiagnostic, ExternalLintFile, ExternalPlugin};
 
     #[test]
     fn test_builder_default() {
@@ -1021,6 +1150,79 @@ mod test {
         assert!(config.rules().is_empty());
     }
 
+    #[derive(Debug)]
+    struct MockExternalLinter;
+
+    impl ExternalLinter for MockExternalLinter {
+        fn load_plugin(&self, path: &Path) -> Result<ExternalPlugin, String> {
+            assert!(path.is_absolute());
+            assert!(path.ends_with("fixtures/js_plugins/plugin.js"));
+            Ok(ExternalPlugin { name: "my-plugin".to_string(), rules: vec!["no-foo".to_string()] })
+        }
+
+        fn lint_file(&self, _: &ExternalLintFile<'_>) -> Result<Vec<ExternalDiagnostic>, String> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_js_plugins() {
+        let oxlintrc =
+            Oxlintrc::from_file(Path::new("fixtures/js_plugins/.oxlintrc.json")).unwrap();
+        let config = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc)
+            .unwrap()
+            .with_external_linter(&MockExternalLinter)
+            .unwrap()
+            .build();
+
+        let external_rules = &config.base.external_rules;
+        assert_eq!(external_rules.len(), 1);
+        let (rule, severity) = &external_rules[0];
+        assert_eq!((rule.plugin_name, rule.rule_name), ("my-plugin", "no-foo"));
+        assert_eq!(*severity, AllowWarnDeny::Deny);
+
+        // Disabled by an override.
+        let resolved = config.apply_overrides(Path::new("fixtures/js_plugins/foo.test.js"));
+        assert!(resolved.external_rules.is_empty());
+        let resolved = config.apply_overrides(Path::new("fixtures/js_plugins/foo.js"));
+        assert_eq!(resolved.external_rules.len(), 1);
+
+        // Without an external linter, rules of JS plugins are ignored.
+        let oxlintrc =
+            Oxlintrc::from_file(Path::new("fixtures/js_plugins/.oxlintrc.json")).unwrap();
+        let config = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc).unwrap().build();
+        assert!(config.base.external_rules.is_empty());
+        assert!(config.rules().is_empty());
+    }
+
+    #[test]
+    fn test_js_plugins_errors() {
+        let mut oxlintrc =
+            Oxlintrc::from_file(Path::new("fixtures/js_plugins/.oxlintrc.json")).unwrap();
+        oxlintrc.rules = OxlintRules::new(vec![ESLintRule {
+            plugin_name: "my-plugin".to_string(),
+            rule_name: "no-bar".to_string(),
+            severity: AllowWarnDeny::Warn,
+            config: None,
+        }]);
+        let err = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc)
+            .unwrap()
+            .with_external_linter(&MockExternalLinter)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "unknown rules: my-plugin/no-bar");
+
+        let mut oxlintrc =
+            Oxlintrc::from_file(Path::new("fixtures/js_plugins/.oxlintrc.json")).unwrap();
+        oxlintrc.js_plugins = vec!["./missing.js".to_string()];
+        let err = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc)
+            .unwrap()
+            .with_external_linter(&MockExternalLinter)
+            .unwrap_err();
+        assert!(
+            matches!(err, ConfigBuilderError::PluginLoadFailed { ref plugin, .. } if plugin == "./missing.js")
+        );
+    }
+
     fn config_store_from_path(path: &str) -> Config {
         ConfigStoreBuilder::from_oxlintrc(true, Oxlintrc::from_file(&PathBuf::from(path)).unwrap())
             .unwrap()
@@ -1,13 +1,16 @@
 use std::{
+    hash::{Hash, Hasher},
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, OnceLock},
 };
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::Serialize;
 
 use super::{LintConfig, LintPlugins, categories::OxlintCategories, overrides::OxlintOverrides};
 use crate::{
     AllowWarnDeny,
+    external_linter::{ExternalRule, LoadedExternalPlugin, resolve_external_rule},
     rules::{RULES, RuleEnum},
 };
 
@@ -17,11 +20,17 @@ pub struct ResolvedLinterState {
     // TODO: Arc + Vec -> SyncVec? It would save a pointer dereference.
     pub rules: Arc<[(RuleEnum, AllowWarnDeny)]>,
     pub config: Arc<LintConfig>,
+    /// Enabled rules of JavaScript plugins.
+    pub external_rules: Arc<[(ExternalRule, AllowWarnDeny)]>,
 }
 
 impl Clone for ResolvedLinterState {
     fn clone(&self) -> Self {
-        Self { rules: Arc::clone(&self.rules), config: Arc::clone(&self.config) }
+        Self {
+            rules: Arc::clone(&self.rules),
+            config: Arc::clone(&self.config),
+            external_rules: Arc::clone(&self.external_rules),
+        }
     }
 }
 
@@ -42,6 +51,15 @@ pub struct Config {
 
     /// An optional set of overrides to apply to the base state depending on the file being linted.
     pub(crate) overrides: OxlintOverrides,
+
+    /// JavaScript plugins, used to resolve the rules of overrides.
+    pub(crate) external_plugins: Arc<[LoadedExternalPlugin]>,
+
+    /// Like `base_rules`, for rules of JavaScript plugins.
+    pub(crate) base_external_rules: Vec<(ExternalRule, AllowWarnDeny)>,
+
+    /// Hash of the options of configured rules, which are not kept by [`RuleEnum`].
+    pub(crate) rule_options_hash: u64,
 }
 
 impl Config {
@@ -62,13 +80,35 @@ impl Config {
                         .into_boxed_slice(),
                 ),
                 config: Arc::new(config),
+                external_rules: Arc::from([]),
             },
             base_rules: rules,
             categories,
             overrides,
+            external_plugins: Arc::from([]),
+            base_external_rules: vec![],
+            rule_options_hash: 0,
         }
     }
 
+    /// Set the loaded JavaScript plugins, and the configured rules of those plugins.
+    pub(crate) fn with_external_rules(
+        mut self,
+        plugins: Vec<LoadedExternalPlugin>,
+        rules: Vec<(ExternalRule, AllowWarnDeny)>,
+    ) -> Self {
+        self.base.external_rules =
+            rules.iter().filter(|(_, severity)| severity.is_warn_deny()).cloned().collect();
+        self.external_plugins = Arc::from(plugins);
+        self.base_external_rules = rules;
+        self
+    }
+
+    pub(crate) fn with_rule_options_hash(mut self, rule_options_hash: u64) -> Self {
+        self.rule_options_hash = rule_options_hash;
+        self
+    }
+
     pub fn plugins(&self) -> LintPlugins {
         self.base.config.plugins
     }
@@ -78,7 +118,7 @@ impl Config {
     }
 
     pub fn number_of_rules(&self) -> usize {
-        self.base.rules.len()
+        self.base.rules.len() + self.base.external_rules.len()
     }
 
     pub fn apply_overrides(&self, path: &Path) -> ResolvedLinterState {
@@ -105,6 +145,7 @@ impl Config {
             return self.base.clone();
         }
 
+        let mut external_rules = self.base_external_rules.clone();
         let mut env = self.base.config.env.clone();
         let mut globals = self.base.config.globals.clone();
         let mut plugins = self.base.config.plugins;
@@ -145,6 +186,21 @@ impl Config {
                 override_config.rules.override_rules(&mut rules, &all_rules);
             }
 
+            if !self.external_plugins.is_empty() {
+                for rule in &override_config.rules.rules {
+                    let Some(Ok((rule, severity))) =
+                        resolve_external_rule(&self.external_plugins, rule)
+                    else {
+                        continue;
+                    };
+                    external_rules.retain(|(existing, _)| {
+                        existing.plugin_name != rule.plugin_name
+                            || existing.rule_name != rule.rule_name
+                    });
+                    external_rules.push((rule, severity));
+                }
+            }
+
             if let Some(override_env) = &override_config.env {
                 override_env.override_envs(&mut env);
             }
@@ -170,7 +226,9 @@ impl Config {
 
         let rules =
             rules.into_iter().filter(|(_, severity)| severity.is_warn_deny()).collect::<Vec<_>>();
-        ResolvedLinterState { rules: Arc::from(rules.into_boxed_slice()), config }
+        let external_rules =
+            external_rules.into_iter().filter(|(_, severity)| severity.is_warn_deny()).collect();
+        ResolvedLinterState { rules: Arc::from(rules.into_boxed_slice()), config, external_rules }
     }
 }
 
@@ -183,15 +241,17 @@ impl Config {
 pub struct ConfigStore {
     base: Config,
     nested_configs: FxHashMap<PathBuf, Config>,
+    /// Hashes of `base` and `nested_configs`, computed on first call to [`ConfigStore::config_hash`].
+    config_hashes: OnceLock<(u64, FxHashMap<PathBuf, u64>)>,
 }
 
 impl ConfigStore {
     pub fn new(base_config: Config, nested_configs: FxHashMap<PathBuf, Config>) -> Self {
-        Self { base: base_config, nested_configs }
+        Self { base: base_config, nested_configs, config_hashes: OnceLock::new() }
     }
 
     pub fn number_of_rules(&self) -> Option<usize> {
-        self.nested_configs.is_empty().then_some(self.base.base.rules.len())
+        self.nested_configs.is_empty().then(|| self.base.number_of_rules())
     }
 
     pub fn rules(&self) -> &Arc<[(RuleEnum, AllowWarnDeny)]> {
@@ -214,13 +274,36 @@ impl ConfigStore {
         Config::apply_overrides(resolved_config, path)
     }
 
+    /// Hash of the config which [`ConfigStore::resolve`] uses for `path`, including its overrides.
+    ///
+    /// Files with the same contents and config hash produce the same diagnostics,
+    /// unless they depend on other files (e.g. rules of the import plugin).
+    pub(crate) fn config_hash(&self, path: &Path) -> u64 {
+        let (base_hash, nested_hashes) = self.config_hashes.get_or_init(|| {
+            let nested_hashes = self
+                .nested_configs
+                .iter()
+                .map(|(dir, config)| (dir.clone(), hash_config(config)))
+                .collect();
+            (hash_config(&self.base), nested_hashes)
+        });
+        match self.get_nearest_config_dir(path) {
+            Some(dir) => nested_hashes[dir],
+            None => *base_hash,
+        }
+    }
+
     fn get_nearest_config(&self, path: &Path) -> Option<&Config> {
+        self.get_nearest_config_dir(path).map(|dir| &self.nested_configs[dir])
+    }
+
+    fn get_nearest_config_dir(&self, path: &Path) -> Option<&Path> {
         // TODO(perf): should we cache the computed nearest config for every directory,
         // so we don't have to recompute it for every file?
         let mut current = path.parent();
         while let Some(dir) = current {
-            if let Some(config) = self.nested_configs.get(dir) {
-                return Some(config);
+            if let Some((dir, _)) = self.nested_configs.get_key_value(dir) {
+                return Some(dir);
             }
             current = dir.parent();
         }
@@ -228,6 +311,36 @@ impl ConfigStore {
     }
 }
 
+/// Hashes everything in `config` which affects diagnostics: its rules with their severities and
+/// options, plugins, settings, env, globals, categories and overrides.
+fn hash_config(config: &Config) -> u64 {
+    fn hash_json<T: Serialize>(value: &T, hasher: &mut FxHasher) {
+        serde_json::to_string(value).unwrap_or_default().hash(hasher);
+    }
+
+    let mut hasher = FxHasher::default();
+    for (rule, severity) in &config.base_rules {
+        rule.id().hash(&mut hasher);
+        severity.hash(&mut hasher);
+    }
+    config.rule_options_hash.hash(&mut hasher);
+    for (rule, severity) in &config.base_external_rules {
+        rule.plugin_path.hash(&mut hasher);
+        rule.rule_name.hash(&mut hasher);
+        hash_json(&rule.options, &mut hasher);
+        severity.hash(&mut hasher);
+    }
+
+    let lint_config = &config.base.config;
+    lint_config.plugins.hash(&mut hasher);
+    hash_json(&lint_config.settings, &mut hasher);
+    hash_json(&lint_config.env, &mut hasher);
+    hash_json(&lint_config.globals, &mut hasher);
+    hash_json(&config.categories, &mut hasher);
+    hash_json(&config.overrides, &mut hasher);
+    hasher.finish()
+}
+
 #[cfg(test)]
 mod test {
     use rustc_hash::FxHashMap;
@@ -103,6 +103,26 @@ pub struct Oxlintrc {
     /// overriding the previous ones.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub extends: Vec<PathBuf>,
+    /// JavaScript plugins providing custom rules, as paths relative to the configuration file
+    /// or package names. Rules of a plugin are prefixed with the plugin's `meta.name`, or the
+    /// package name without `eslint-plugin`.
+    ///
+    /// Example
+    ///
+    /// ```json
+    /// {
+    ///   "jsPlugins": ["./plugins/my-plugin.js", "eslint-plugin-foo"],
+    ///   "rules": {
+    ///     "my-plugin/no-bar": "error",
+    ///     "foo/no-baz": "warn"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Plugins are run with Node.js, which must be installed. This is experimental, and only
+    /// supported by builds of oxlint with the `js_plugins` feature. Other builds report an error.
+    #[serde(rename = "jsPlugins", skip_serializing_if = "Vec::is_empty")]
+    pub js_plugins: Vec<String>,
 }
 
 impl Oxlintrc {
@@ -193,6 +213,19 @@ impl Oxlintrc {
         let mut overrides = self.overrides.clone();
         overrides.extend(other.overrides);
 
+        // Relative paths of `other` are relative to its own file.
+        let mut js_plugins = self.js_plugins.clone();
+        let other_dir = other.path.parent();
+        for plugin in other.js_plugins {
+            let plugin = match other_dir {
+                Some(dir) if plugin.starts_with('.') => dir.join(plugin).to_string_lossy().into(),
+                _ => plugin,
+            };
+            if !js_plugins.contains(&plugin) {
+                js_plugins.push(plugin);
+            }
+        }
+
         Oxlintrc {
             plugins: self.plugins.map_or_else(
                 || other.plugins,
@@ -207,6 +240,7 @@ impl Oxlintrc {
             path: self.path.clone(),
             ignore_patterns: self.ignore_patterns.clone(),
             extends: self.extends.clone(),
+            js_plugins,
         }
     }
 }
@@ -13,10 +13,14 @@ use crate::{
     module_record::ModuleRecord,
     options::LintOptions,
     rules::RuleEnum,
+    type_provider::{LocalTypeProvider, TypeProvider},
 };
 
 use super::{LintContext, plugin_name_to_prefix};
 
+#[cfg(feature = "js_plugins")]
+use crate::external_linter::ExternalRule;
+
 /// Stores shared information about a file being linted.
 ///
 /// When linting a file, there are a number of shared resources that are
@@ -65,6 +69,8 @@ pub struct ContextHost<'a> {
     pub(super) frameworks: FrameworkFlags,
     /// A list of all available linter plugins.
     pub(super) plugins: LintPlugins,
+    /// Type information for type-aware rules. Defaults to [`LocalTypeProvider`].
+    pub(super) type_provider: Arc<dyn TypeProvider>,
 }
 
 impl<'a> ContextHost<'a> {
@@ -102,6 +108,7 @@ impl<'a> ContextHost<'a> {
             config,
             frameworks: options.framework_hints,
             plugins,
+            type_provider: Arc::new(LocalTypeProvider),
         }
         .sniff_for_frameworks()
     }
@@ -120,6 +127,13 @@ impl<'a> ContextHost<'a> {
         self
     }
 
+    /// Set the [`TypeProvider`] for type-aware rules.
+    #[inline]
+    pub fn with_type_provider(mut self, type_provider: Arc<dyn TypeProvider>) -> Self {
+        self.type_provider = type_provider;
+        self
+    }
+
     /// Shared reference to the [`Semantic`] analysis of the file.
     #[inline]
     pub fn semantic(&self) -> &Semantic<'a> {
@@ -156,7 +170,7 @@ impl<'a> ContextHost<'a> {
     /// Add a diagnostic message to the end of the list of diagnostics. Can be used
     /// by any rule to report issues.
     #[inline]
-    pub(super) fn push_diagnostic(&self, diagnostic: Message<'a>) {
+    pub(crate) fn push_diagnostic(&self, diagnostic: Message<'a>) {
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
@@ -260,6 +274,27 @@ impl<'a> ContextHost<'a> {
             current_plugin_prefix: plugin_name_to_prefix(plugin_name),
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: rule.fix(),
+            current_rule_is_external: false,
+            severity: severity.into(),
+        }
+    }
+
+    /// Creates a new [`LintContext`] for a rule of a JavaScript plugin.
+    #[cfg(feature = "js_plugins")]
+    pub(crate) fn spawn_external(
+        self: Rc<Self>,
+        rule: &ExternalRule,
+        severity: AllowWarnDeny,
+    ) -> LintContext<'a> {
+        LintContext {
+            parent: self,
+            current_rule_name: rule.rule_name,
+            current_plugin_name: rule.plugin_name,
+            current_plugin_prefix: rule.plugin_name,
+            // Whether a rule is fixable is checked by the external linter.
+            #[cfg(debug_assertions)]
+            current_rule_fix_capabilities: crate::rule::RuleFixMeta::Conditional(FixKind::SafeFix),
+            current_rule_is_external: true,
             severity: severity.into(),
         }
     }
@@ -274,6 +309,7 @@ impl<'a> ContextHost<'a> {
             current_plugin_prefix: "eslint",
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: crate::rule::RuleFixMeta::None,
+            current_rule_is_external: false,
             severity: oxc_diagnostics::Severity::Warning,
         }
     }
@@ -4,7 +4,7 @@ use std::{ops::Deref, path::Path, rc::Rc};
 
 use javascript_globals::GLOBALS;
 
-use oxc_ast::ast::IdentifierReference;
+use oxc_ast::ast::{Expression, IdentifierReference};
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
@@ -17,6 +17,7 @@ use crate::{
     config::GlobalValue,
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
+    type_provider::{Type, TypeProvider},
 };
 
 mod host;
@@ -47,6 +48,9 @@ pub struct LintContext<'a> {
     /// - Rule is fully auto-fixable [`RuleFixMeta::Fixable`]
     #[cfg(debug_assertions)]
     current_rule_fix_capabilities: RuleFixMeta,
+    /// Whether the current rule belongs to a JavaScript plugin, and therefore has no
+    /// documentation on the Oxc website.
+    current_rule_is_external: bool,
     /// Current rule severity. Allows for user severity overrides, e.g.
     /// ```json
     /// // .oxlintrc.json
@@ -122,6 +126,17 @@ impl<'a> LintContext<'a> {
         unsafe { self.parent.semantic.cfg().unwrap_unchecked() }
     }
 
+    /// Type information for the file being linted. See [`TypeProvider`].
+    #[inline]
+    pub fn type_provider(&self) -> &dyn TypeProvider {
+        &*self.parent.type_provider
+    }
+
+    /// The type of `expr`, or `None` if the [`TypeProvider`] does not know it.
+    pub fn type_of(&self, expr: &Expression<'a>) -> Option<Type> {
+        self.type_provider().type_of_expression(expr, self.semantic())
+    }
+
     /// List of all disable directives in the file being linted.
     #[inline]
     pub fn disable_directives(&self) -> &DisableDirectives<'a> {
@@ -225,15 +240,16 @@ impl<'a> LintContext<'a> {
         if self.parent.disable_directives.contains(self.current_rule_name, message.span()) {
             return;
         }
-        message.error = message
-            .error
-            .with_error_code(self.current_plugin_prefix, self.current_rule_name)
-            .with_url(format!(
+        message.error =
+            message.error.with_error_code(self.current_plugin_prefix, self.current_rule_name);
+        if !self.current_rule_is_external {
+            message.error = message.error.with_url(format!(
                 "{}/{}/{}.html",
                 Self::WEBSITE_BASE_URL,
                 self.current_plugin_name,
                 self.current_rule_name
             ));
+        }
         if message.error.severity != self.severity {
             message.error = message.error.with_severity(self.severity);
         }
@@ -431,7 +447,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     PLUGIN_PREFIXES.get(plugin_name).copied().unwrap_or(plugin_name)
 }
 
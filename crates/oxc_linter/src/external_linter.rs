@@ -0,0 +1,209 @@
+//! Lint rules implemented outside of Oxc, such as custom rules of JavaScript plugins.
+//!
+//! Plugins are listed in the `jsPlugins` field of a configuration file, and loaded by an
+//! [`ExternalLinter`] when building the configuration with
+//! [`ConfigStoreBuilder::with_external_linter`](crate::ConfigStoreBuilder::with_external_linter).
+//! Their rules are configured like built-in rules, e.g. `"my-plugin/no-foo": "error"`.
+//!
+//! When a file is linted, the [`Linter`](crate::Linter) serializes the AST to ESTree JSON and
+//! hands it to [`ExternalLinter::lint_file`] together with the enabled external rules. The
+//! diagnostics returned are reported like those of built-in rules, so they respect severities,
+//! disable directives and `--fix`.
+
+use std::{
+    fmt,
+    path::Path,
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+};
+
+use rustc_hash::FxHashSet;
+
+use oxc_span::Span;
+
+use crate::{AllowWarnDeny, config::ESLintRule};
+
+#[cfg(feature = "js_plugins")]
+use std::rc::Rc;
+
+#[cfg(feature = "js_plugins")]
+use oxc_ast::AstKind;
+#[cfg(feature = "js_plugins")]
+use oxc_diagnostics::OxcDiagnostic;
+#[cfg(feature = "js_plugins")]
+use oxc_semantic::AstNode;
+
+#[cfg(feature = "js_plugins")]
+use crate::{
+    context::ContextHost,
+    fixer::{Message, PossibleFixes},
+};
+
+/// Loads plugins and runs their rules, e.g. in a JavaScript runtime.
+///
+/// An external linter is shared by all files and threads.
+pub trait ExternalLinter: fmt::Debug + Send + Sync {
+    /// Load the plugin at `path`, an absolute path to a JavaScript module.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing the error if the plugin can not be loaded.
+    fn load_plugin(&self, path: &Path) -> Result<ExternalPlugin, String>;
+
+    /// Run `file.rules` on a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing the error if a rule throws, or the runtime fails.
+    fn lint_file(&self, file: &ExternalLintFile<'_>) -> Result<Vec<ExternalDiagnostic>, String>;
+}
+
+/// A plugin loaded by an [`ExternalLinter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalPlugin {
+    /// Name of the plugin, which prefixes its rules in configuration files, e.g. `my-plugin`
+    /// for `my-plugin/no-foo`.
+    pub name: String,
+    /// Names of the rules of the plugin, e.g. `no-foo`.
+    pub rules: Vec<String>,
+}
+
+/// A configured rule of an [`ExternalPlugin`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalRule {
+    /// Absolute path of the plugin module.
+    pub plugin_path: Arc<Path>,
+    pub plugin_name: &'static str,
+    pub rule_name: &'static str,
+    /// Rule options, i.e. the configuration after the severity.
+    pub options: Option<serde_json::Value>,
+}
+
+/// A file to be linted by an [`ExternalLinter`].
+#[derive(Debug)]
+pub struct ExternalLintFile<'a> {
+    pub path: &'a Path,
+    pub source_text: &'a str,
+    /// The program serialized to ESTree JSON, as `{ "node": Program, "fixes": [...] }`.
+    /// See [`Program::to_estree_ts_json_with_fixes`](oxc_ast::ast::Program::to_estree_ts_json_with_fixes).
+    ///
+    /// Spans are UTF-8 byte offsets into `source_text`.
+    pub program_json: &'a str,
+    /// Spans of the comments of the file, and whether they are line comments.
+    pub comments: Vec<(Span, bool)>,
+    /// Rules to run.
+    pub rules: Vec<&'a ExternalRule>,
+}
+
+/// A violation reported by an [`ExternalRule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalDiagnostic {
+    /// Index of the rule in [`ExternalLintFile::rules`].
+    pub rule_index: usize,
+    pub message: String,
+    /// UTF-8 byte offsets into the source text.
+    pub span: Span,
+    pub fix: Option<ExternalFix>,
+}
+
+/// Replace the text at `span` with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalFix {
+    /// UTF-8 byte offsets into the source text.
+    pub span: Span,
+    pub text: String,
+}
+
+/// A plugin loaded from `path`.
+pub type LoadedExternalPlugin = (Arc<Path>, ExternalPlugin);
+
+/// Find the rule configured by `rule` in `plugins`.
+///
+/// Returns `None` if no plugin is named like the prefix of the rule, and `Some(Err(()))` if the
+/// plugin exists but does not have the rule.
+pub fn resolve_external_rule(
+    plugins: &[LoadedExternalPlugin],
+    rule: &ESLintRule,
+) -> Option<Result<(ExternalRule, AllowWarnDeny), ()>> {
+    // Match on the full name, as plugin names can contain `/`, e.g. `@scope/foo/no-bar`.
+    let full_name = rule.full_name();
+    plugins.iter().find_map(|(path, plugin)| {
+        let rule_name = full_name.strip_prefix(plugin.name.as_str())?.strip_prefix('/')?;
+        if !plugin.rules.iter().any(|name| name == rule_name) {
+            return Some(Err(()));
+        }
+        Some(Ok((
+            ExternalRule {
+                plugin_path: Arc::clone(path),
+                plugin_name: intern(&plugin.name),
+                rule_name: intern(rule_name),
+                options: rule.config.clone(),
+            },
+            rule.severity,
+        )))
+    })
+}
+
+/// Run the `rules` of JavaScript plugins on the file of `ctx_host`, and report their diagnostics
+/// like those of built-in rules.
+#[cfg(feature = "js_plugins")]
+pub fn run_external_rules(
+    external_linter: &dyn ExternalLinter,
+    ctx_host: &Rc<ContextHost<'_>>,
+    rules: &[(ExternalRule, AllowWarnDeny)],
+) {
+    let semantic = ctx_host.semantic();
+    let Some(AstKind::Program(program)) = semantic.nodes().root_node().map(AstNode::kind) else {
+        return;
+    };
+    let program_json = if semantic.source_type().is_typescript() {
+        program.to_estree_ts_json_with_fixes(false)
+    } else {
+        program.to_estree_js_json_with_fixes(false)
+    };
+    let file = ExternalLintFile {
+        path: ctx_host.file_path(),
+        source_text: semantic.source_text(),
+        program_json: &program_json,
+        comments: semantic
+            .comments()
+            .iter()
+            .map(|comment| (comment.span, comment.is_line()))
+            .collect(),
+        rules: rules.iter().map(|(rule, _)| rule).collect(),
+    };
+
+    let diagnostics = match external_linter.lint_file(&file) {
+        Ok(diagnostics) => diagnostics,
+        Err(err) => {
+            let diagnostic = OxcDiagnostic::error(format!("Failed to run JS plugins: {err}"));
+            ctx_host.push_diagnostic(Message::new(diagnostic, PossibleFixes::None));
+            return;
+        }
+    };
+
+    for ExternalDiagnostic { rule_index, message, span, fix } in diagnostics {
+        let Some((rule, severity)) = rules.get(rule_index) else { continue };
+        let ctx = Rc::clone(ctx_host).spawn_external(rule, *severity);
+        let diagnostic = OxcDiagnostic::warn(message).with_label(span);
+        match fix {
+            Some(ExternalFix { span, text }) => {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, text));
+            }
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Rules are identified by `&'static str` names in [`LintContext`](crate::LintContext), so the
+/// names of external rules are leaked. Interning them bounds the leak when configurations are
+/// reloaded, e.g. by the language server.
+fn intern(name: &str) -> &'static str {
+    static NAMES: LazyLock<Mutex<FxHashSet<&'static str>>> = LazyLock::new(Mutex::default);
+    let mut names = NAMES.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(interned) = names.get(name) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.into());
+    names.insert(interned);
+    interned
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{DiagnosticFix, OxcDiagnostic, SourceSpan};
 use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
@@ -295,7 +295,17 @@ impl<'a> Message<'a> {
 impl From<Message<'_>> for OxcDiagnostic {
     #[inline]
     fn from(message: Message) -> Self {
-        message.error
+        let fixes = match message.fixes {
+            PossibleFixes::None => return message.error,
+            PossibleFixes::Single(fix) => vec![fix],
+            PossibleFixes::Multiple(fixes) => fixes,
+        };
+        let fixes = fixes.into_iter().filter(|fix| !fix.content.is_empty() || !fix.span.is_empty());
+        message.error.with_fixes(fixes.map(|fix| DiagnosticFix {
+            content: Cow::Owned(fix.content.into_owned()),
+            message: fix.message.map(|message| Cow::Owned(message.into_owned())),
+            span: SourceSpan::new((fix.span.start as usize).into(), fix.span.size() as usize),
+        }))
     }
 }
 
@@ -8,6 +8,7 @@ mod ast_util;
 mod config;
 mod context;
 mod disable_directives;
+mod external_linter;
 mod fixer;
 mod frameworks;
 mod globals;
@@ -16,6 +17,7 @@ mod module_record;
 mod options;
 mod rule;
 mod service;
+mod type_provider;
 mod utils;
 
 pub mod loader;
@@ -31,7 +33,11 @@ pub use crate::{
         Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintPlugins,
         Oxlintrc,
     },
-    context::LintContext,
+    context::{LintContext, plugin_name_to_prefix},
+    external_linter::{
+        ExternalDiagnostic, ExternalFix, ExternalLintFile, ExternalLinter, ExternalPlugin,
+        ExternalRule,
+    },
     fixer::FixKind,
     frameworks::FrameworkFlags,
     loader::LINTABLE_EXTENSIONS,
@@ -39,7 +45,8 @@ pub use crate::{
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
     rule::{RuleCategory, RuleFixMeta, RuleMeta},
-    service::{LintService, LintServiceOptions, RuntimeFileSystem},
+    service::{LintCache, LintService, LintServiceOptions, RuntimeFileSystem},
+    type_provider::{LocalTypeProvider, Type, TypeProvider},
     utils::read_to_arena_str,
     utils::read_to_string,
 };
@@ -68,11 +75,37 @@ pub struct Linter {
     options: LintOptions,
     // config: Arc<LintConfig>,
     config: ConfigStore,
+    type_provider: Arc<dyn TypeProvider>,
+    /// Runs the rules of JavaScript plugins.
+    #[cfg(feature = "js_plugins")]
+    external: Option<Arc<dyn ExternalLinter>>,
 }
 
 impl Linter {
     pub fn new(options: LintOptions, config: ConfigStore) -> Self {
-        Self { options, config }
+        Self {
+            options,
+            config,
+            type_provider: Arc::new(LocalTypeProvider),
+            #[cfg(feature = "js_plugins")]
+            external: None,
+        }
+    }
+
+    /// Set the [`ExternalLinter`] which runs the rules of JavaScript plugins. Without one, rules
+    /// of JavaScript plugins are not run.
+    #[cfg(feature = "js_plugins")]
+    #[must_use]
+    pub fn with_external_linter(mut self, external_linter: Arc<dyn ExternalLinter>) -> Self {
+        self.external = Some(external_linter);
+        self
+    }
+
+    /// Set the [`TypeProvider`] queried by type-aware rules. Defaults to [`LocalTypeProvider`].
+    #[must_use]
+    pub fn with_type_provider(mut self, type_provider: Arc<dyn TypeProvider>) -> Self {
+        self.type_provider = type_provider;
+        self
     }
 
     /// Set the kind of auto fixes to apply.
@@ -105,10 +138,13 @@ impl Linter {
         semantic: Rc<Semantic<'a>>,
         module_record: Arc<ModuleRecord>,
     ) -> Vec<Message<'a>> {
-        let ResolvedLinterState { rules, config } = self.config.resolve(path);
+        #[cfg_attr(not(feature = "js_plugins"), expect(unused_variables))]
+        let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
 
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, module_record, self.options, config));
+        let ctx_host = Rc::new(
+            ContextHost::new(path, semantic, module_record, self.options, config)
+                .with_type_provider(Arc::clone(&self.type_provider)),
+        );
 
         let rules = rules
             .iter()
@@ -185,6 +221,13 @@ impl Linter {
             }
         }
 
+        #[cfg(feature = "js_plugins")]
+        if let Some(external_linter) = &self.external {
+            if !external_rules.is_empty() {
+                external_linter::run_external_rules(&**external_linter, &ctx_host, &external_rules);
+            }
+        }
+
         if let Some(severity) = self.options.report_unused_directive {
             if severity.is_warn_deny() {
                 ctx_host.report_unused_directives(severity.into());
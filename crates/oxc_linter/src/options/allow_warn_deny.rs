@@ -10,7 +10,7 @@ use serde_json::{Number, Value};
 
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AllowWarnDeny {
     Allow, // Off
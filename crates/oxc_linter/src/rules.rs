@@ -187,6 +187,7 @@ mod eslint {
 mod typescript {
     pub mod adjacent_overload_signatures;
     pub mod array_type;
+    pub mod await_thenable;
     pub mod ban_ts_comment;
     pub mod ban_tslint_comment;
     pub mod ban_types;
@@ -203,6 +204,7 @@ mod typescript {
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
     pub mod no_extraneous_class;
+    pub mod no_floating_promises;
     pub mod no_import_type_side_effects;
     pub mod no_inferrable_types;
     pub mod no_misused_new;
@@ -943,6 +945,7 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_object_as_prop,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
+    typescript::await_thenable,
     typescript::ban_ts_comment,
     typescript::ban_tslint_comment,
     typescript::ban_types,
@@ -960,6 +963,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
     typescript::no_extraneous_class,
+    typescript::no_floating_promises,
     typescript::no_import_type_side_effects,
     typescript::no_misused_new,
     typescript::no_namespace,
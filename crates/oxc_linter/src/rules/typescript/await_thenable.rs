@@ -0,0 +1,120 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn await_thenable_diagnostic(span: Span, ty: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `await` of a non-Promise (non-\"Thenable\") value.")
+        .with_help(format!("The awaited value is of type `{ty}`, remove the unnecessary `await`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AwaitThenable;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow awaiting a value that is not a Thenable.
+    ///
+    /// This rule is type-aware: it uses the types known to the linter's type provider,
+    /// and does not report values of unknown type.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// While it is valid JavaScript to await a non-Promise-like value (it will resolve
+    /// immediately), this pattern is often a programmer error, such as forgetting to add
+    /// parenthesis to call a function that returns a Promise.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// await 'value';
+    ///
+    /// const createValue = () => 'value';
+    /// await createValue();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// await Promise.resolve('value');
+    ///
+    /// const createValue = async () => 'value';
+    /// await createValue();
+    /// ```
+    AwaitThenable,
+    typescript,
+    nursery,
+    suggestion
+);
+
+impl Rule for AwaitThenable {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(await_expr) = node.kind() else { return };
+        let Some(ty) = ctx.type_of(&await_expr.argument) else { return };
+        if ty.is_thenable() != Some(false) {
+            return;
+        }
+        ctx.diagnostic_with_suggestion(
+            await_thenable_diagnostic(await_expr.span, &ty.to_string()),
+            |fixer| {
+                fixer.delete_range(Span::new(
+                    await_expr.span.start,
+                    await_expr.argument.span().start,
+                ))
+            },
+        );
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await Promise.resolve(1); }",
+        "async function f() { await new Promise((resolve) => resolve(1)); }",
+        "async function f(p: Promise<number>) { await p; }",
+        "async function f(p: PromiseLike<number> | number) { await p; }",
+        "async function f(p: any) { await p; }",
+        "async function f(p: unknown) { await p; }",
+        "async function f() { await foo(); }",
+        "async function f() { await import('./foo'); }",
+        "const g = async () => 1; async function f() { await g(); }",
+        "async function f() { await { then() {} }; }",
+        "async function f() { for await (const x of [1]) {} }",
+    ];
+
+    let fail = vec![
+        "async function f() { await 0; }",
+        "async function f() { await 'value'; }",
+        "async function f() { await (1, null); }",
+        "async function f(x: number | string) { await x; }",
+        "async function f(x?: number) { await x; }",
+        "const g = () => 1; async function f() { await g(); }",
+        "function g(): string { return ''; } async function f() { await g(); }",
+        "async function f() { await { a: 1 }; }",
+        "async function f() { await [Promise.resolve(1)]; }",
+        "async function f() { await (await Promise.resolve(1) as number); }",
+    ];
+
+    let fix = vec![
+        ("async function f() { await 0; }", "async function f() { 0; }"),
+        ("async function f(x: number) { await   (x); }", "async function f(x: number) { (x); }"),
+    ];
+
+    Tester::new(AwaitThenable::NAME, AwaitThenable::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,166 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_floating_promises_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Promises must be awaited, end with a call to `.catch`, end with a call to `.then` with a rejection handler or be explicitly marked as ignored with the `void` operator.")
+        .with_help("Await the promise, handle its rejection, or add `void` to ignore it.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFloatingPromises;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require Promise-like statements to be handled appropriately.
+    ///
+    /// This rule is type-aware: it uses the types known to the linter's type provider,
+    /// and does not report values of unknown type.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A "floating" Promise is one that is created without any code set up to handle any
+    /// errors it might throw. Floating Promises can cause several issues, such as improperly
+    /// sequenced operations, ignored Promise rejections, and more.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const promise = new Promise((resolve, reject) => resolve('value'));
+    /// promise;
+    ///
+    /// async function returnsPromise() {
+    ///   return 'value';
+    /// }
+    /// returnsPromise().then(() => {});
+    ///
+    /// Promise.reject('value').catch();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const promise = new Promise((resolve, reject) => resolve('value'));
+    /// await promise;
+    ///
+    /// async function returnsPromise() {
+    ///   return 'value';
+    /// }
+    /// void returnsPromise();
+    /// returnsPromise().then(
+    ///   () => {},
+    ///   () => {},
+    /// );
+    ///
+    /// Promise.reject('value').catch(() => {});
+    /// ```
+    NoFloatingPromises,
+    typescript,
+    nursery,
+    suggestion
+);
+
+impl Rule for NoFloatingPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        let expr = stmt.expression.get_inner_expression();
+        if matches!(expr, Expression::AssignmentExpression(_)) {
+            return;
+        }
+        if is_unhandled_promise(expr, ctx) {
+            ctx.diagnostic_with_suggestion(no_floating_promises_diagnostic(expr.span()), |fixer| {
+                fixer.insert_text_before(&stmt.expression, "void ")
+            });
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+/// Whether `expr` is a Promise whose rejection is not handled.
+fn is_unhandled_promise<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match expr.get_inner_expression() {
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => false,
+        Expression::SequenceExpression(sequence) => {
+            sequence.expressions.iter().any(|expr| is_unhandled_promise(expr, ctx))
+        }
+        Expression::ConditionalExpression(conditional) => {
+            is_unhandled_promise(&conditional.consequent, ctx)
+                || is_unhandled_promise(&conditional.alternate, ctx)
+        }
+        Expression::LogicalExpression(logical) => {
+            is_unhandled_promise(&logical.left, ctx) || is_unhandled_promise(&logical.right, ctx)
+        }
+        Expression::CallExpression(call)
+            if call.callee.as_member_expression().is_some_and(|member| {
+                match member.static_property_name() {
+                    Some("catch") => !call.arguments.is_empty(),
+                    Some("then") => call.arguments.len() >= 2,
+                    Some("finally") => !is_unhandled_promise(member.object(), ctx),
+                    _ => false,
+                }
+            }) =>
+        {
+            false
+        }
+        expr => ctx.type_of(expr).is_some_and(|ty| ty.is_thenable() == Some(true)),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await Promise.resolve(1); }",
+        "Promise.resolve(1).catch(() => {});",
+        "Promise.resolve(1).then(() => {}, () => {});",
+        "Promise.resolve(1).catch(() => {}).finally(() => {});",
+        "void Promise.resolve(1);",
+        "async function g() {} void g();",
+        "async function g() {} let p; p = g();",
+        "declare const p: Promise<number> | undefined; p?.then(() => {});",
+        "foo();",
+        "1;",
+        "function g(): number { return 1; } g();",
+    ];
+
+    let fail = vec![
+        "Promise.resolve(1);",
+        "new Promise(() => {});",
+        "Promise.resolve(1).then(() => {});",
+        "Promise.reject(1).catch();",
+        "Promise.resolve(1).finally(() => {});",
+        "async function g() {} g();",
+        "const g = async () => {}; g();",
+        "function g(): Promise<void> { return Promise.resolve(); } g();",
+        "async function f(p: Promise<number>) { p; }",
+        "declare const a: boolean; async function g() {} a ? g() : null;",
+        "declare const a: boolean; async function g() {} a && g();",
+        "async function g() {} (1, g());",
+        "(async () => {})();",
+    ];
+
+    let fix = vec![
+        ("Promise.resolve(1);", "void Promise.resolve(1);"),
+        ("async function g() {} (g());", "async function g() {} void (g());"),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, NoFloatingPromises::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
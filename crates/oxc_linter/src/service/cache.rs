@@ -0,0 +1,256 @@
+use std::{
+    ffi::OsStr,
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, RwLock},
+};
+
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet, FxHasher};
+
+use crate::ModuleRecord;
+
+/// Persistent cache of files which had no diagnostics when they were last linted.
+///
+/// A file is skipped if its contents and the config used for it are the same as when it was cached.
+/// When the import plugin is enabled, the contents of all modules it imports (directly or
+/// indirectly) must be the same too.
+///
+/// Only files without diagnostics are cached, so that all diagnostics are still reported on each run.
+///
+/// ## Format
+///
+/// All numbers are little endian.
+///
+/// ```text
+/// magic        b"OXLC" + u8 format version
+/// key          u64
+/// entry count  u32
+/// entries      path length (u32), UTF-8 path relative to `cwd`, content hash (u64),
+///              config hash (u64), dependencies hash (u64)
+/// ```
+pub struct LintCache {
+    location: PathBuf,
+    /// Hash of everything besides file contents and configs which affects diagnostics,
+    /// e.g. the linter version.
+    key: u64,
+    /// Directory which paths of entries are relative to.
+    cwd: PathBuf,
+    entries: Mutex<FxHashMap<PathBuf, CacheEntry>>,
+    /// Content hashes of modules read in this run. Only used when the import plugin is enabled.
+    content_hashes: RwLock<FxHashMap<PathBuf, u64>>,
+}
+
+#[expect(clippy::struct_field_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct CacheEntry {
+    pub content_hash: u64,
+    pub config_hash: u64,
+    pub dependencies_hash: u64,
+}
+
+impl LintCache {
+    const MAGIC: &[u8; 4] = b"OXLC";
+    const FORMAT_VERSION: u8 = 1;
+
+    /// Load the cache saved at `location`.
+    ///
+    /// The cache is empty if the file does not exist, is not a valid cache file, or was saved
+    /// with a different `key`.
+    pub fn load(location: PathBuf, key: u64, cwd: PathBuf) -> Self {
+        let entries = fs::read(&location)
+            .ok()
+            .and_then(|bytes| Self::parse(&bytes, key, &cwd))
+            .unwrap_or_default();
+        Self {
+            location,
+            key,
+            cwd,
+            entries: Mutex::new(entries),
+            content_hashes: RwLock::new(FxHashMap::default()),
+        }
+    }
+
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Save the cache to its location.
+    ///
+    /// Entries of files which no longer exist are removed.
+    ///
+    /// # Errors
+    ///
+    /// * The cache file could not be written.
+    ///
+    /// # Panics
+    ///
+    /// * A thread panicked while updating the cache.
+    pub fn save(&self) -> io::Result<()> {
+        let entries = self.entries.lock().unwrap();
+        let mut bytes = Vec::with_capacity(entries.len() * 64);
+        bytes.extend_from_slice(Self::MAGIC);
+        bytes.push(Self::FORMAT_VERSION);
+        bytes.extend_from_slice(&self.key.to_le_bytes());
+
+        let entries = entries
+            .iter()
+            .filter(|(path, _)| path.is_file())
+            .filter_map(|(path, entry)| {
+                let relative_path = path.strip_prefix(&self.cwd).unwrap_or(path).to_str()?;
+                Some((relative_path, entry))
+            })
+            .collect::<Vec<_>>();
+        #[expect(clippy::cast_possible_truncation)]
+        bytes.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+        for (path, entry) in entries {
+            #[expect(clippy::cast_possible_truncation)]
+            bytes.extend_from_slice(&(path.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(path.as_bytes());
+            bytes.extend_from_slice(&entry.content_hash.to_le_bytes());
+            bytes.extend_from_slice(&entry.config_hash.to_le_bytes());
+            bytes.extend_from_slice(&entry.dependencies_hash.to_le_bytes());
+        }
+
+        if let Some(dir) = self.location.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write to a temporary file first, so the cache is never left half written.
+        let temp_location = self.location.with_extension("tmp");
+        fs::write(&temp_location, bytes)?;
+        fs::rename(temp_location, &self.location)
+    }
+
+    fn parse(bytes: &[u8], key: u64, cwd: &Path) -> Option<FxHashMap<PathBuf, CacheEntry>> {
+        let mut reader = Reader(bytes);
+        if reader.take(4)? != Self::MAGIC
+            || reader.take(1)? != [Self::FORMAT_VERSION]
+            || reader.u64()? != key
+        {
+            return None;
+        }
+        let count = reader.u32()? as usize;
+        let mut entries = FxHashMap::with_capacity_and_hasher(count, FxBuildHasher);
+        for _ in 0..count {
+            let path_len = reader.u32()? as usize;
+            let path = std::str::from_utf8(reader.take(path_len)?).ok()?;
+            let entry = CacheEntry {
+                content_hash: reader.u64()?,
+                config_hash: reader.u64()?,
+                dependencies_hash: reader.u64()?,
+            };
+            entries.insert(cwd.join(path), entry);
+        }
+        reader.0.is_empty().then_some(entries)
+    }
+
+    /// Returns `true` if `path` had no diagnostics when it was last linted with the same `entry`.
+    pub(super) fn is_clean(&self, path: &Path, entry: CacheEntry) -> bool {
+        self.entries.lock().unwrap().get(path) == Some(&entry)
+    }
+
+    /// Record the result of linting `path`.
+    pub(super) fn update(&self, path: &Path, entry: CacheEntry, is_clean: bool) {
+        let mut entries = self.entries.lock().unwrap();
+        if is_clean {
+            entries.insert(path.to_path_buf(), entry);
+        } else {
+            entries.remove(path);
+        }
+    }
+
+    /// Record the content hash of a module, for [`LintCache::dependencies_hash`].
+    pub(super) fn insert_content_hash(&self, path: &OsStr, content_hash: u64) {
+        self.content_hashes.write().unwrap().insert(PathBuf::from(path), content_hash);
+    }
+
+    /// Hash of the paths and contents of all modules which `module_records` import, directly or indirectly.
+    ///
+    /// Returns `0` if they import nothing, which is always the case when the import plugin is disabled.
+    pub(super) fn dependencies_hash<'m>(
+        &self,
+        module_records: impl IntoIterator<Item = &'m Arc<ModuleRecord>>,
+    ) -> u64 {
+        let mut stack = module_records.into_iter().map(Arc::clone).collect::<Vec<_>>();
+        let mut visited = FxHashSet::<PathBuf>::default();
+        let mut dependencies_hash = 0u64;
+        let content_hashes = self.content_hashes.read().unwrap();
+        while let Some(module_record) = stack.pop() {
+            for dependency in module_record.loaded_modules.read().unwrap().values() {
+                let path = &dependency.resolved_absolute_path;
+                if !visited.insert(path.clone()) {
+                    continue;
+                }
+                let mut hasher = FxHasher::default();
+                path.hash(&mut hasher);
+                content_hashes.get(path).copied().unwrap_or_default().hash(&mut hasher);
+                // Order independent, because modules are loaded in parallel
+                dependencies_hash = dependencies_hash.wrapping_add(hasher.finish());
+                stack.push(Arc::clone(dependency));
+            }
+        }
+        dependencies_hash
+    }
+}
+
+/// Hash of the contents of a file.
+pub(super) fn content_hash(source_text: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    source_text.hash(&mut hasher);
+    hasher.finish()
+}
+
+struct Reader<'b>(&'b [u8]);
+
+impl<'b> Reader<'b> {
+    fn take(&mut self, len: usize) -> Option<&'b [u8]> {
+        if self.0.len() < len {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)?.try_into().ok().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take(8)?.try_into().ok().map(u64::from_le_bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CacheEntry, LintCache};
+
+    #[test]
+    fn save_and_load() {
+        let cwd = std::env::temp_dir().join(format!("oxc_linter_cache_{}", std::process::id()));
+        std::fs::create_dir_all(&cwd).unwrap();
+        let file = cwd.join("a.js");
+        std::fs::write(&file, "debugger;").unwrap();
+        let location = cwd.join(".cache/oxlint");
+        let entry = CacheEntry { content_hash: 1, config_hash: 2, dependencies_hash: 3 };
+
+        let cache = LintCache::load(location.clone(), 42, cwd.clone());
+        assert!(!cache.is_clean(&file, entry));
+        cache.update(&file, entry, true);
+        // Not saved, because the file does not exist.
+        cache.update(&cwd.join("deleted.js"), entry, true);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(location.clone(), 42, cwd.clone());
+        assert!(cache.is_clean(&file, entry));
+        assert!(!cache.is_clean(&cwd.join("deleted.js"), entry));
+        assert!(!cache.is_clean(&file, CacheEntry { content_hash: 4, ..entry }));
+
+        // Different key
+        let cache = LintCache::load(location, 43, cwd.clone());
+        assert!(!cache.is_clean(&file, entry));
+
+        std::fs::remove_dir_all(cwd).unwrap();
+    }
+}
@@ -4,12 +4,14 @@ use std::{
     sync::Arc,
 };
 
+pub use cache::LintCache;
 use oxc_diagnostics::DiagnosticSender;
 use runtime::Runtime;
 pub use runtime::RuntimeFileSystem;
 
 use crate::Linter;
 
+mod cache;
 mod runtime;
 
 #[cfg(feature = "language_server")]
@@ -26,6 +28,12 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Skip files which had no diagnostics when they were last linted
+    cache: Option<Arc<LintCache>>,
+
+    /// Report fixes along with diagnostics, instead of applying them
+    report_fixes: bool,
 }
 
 impl LintServiceOptions {
@@ -34,7 +42,14 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            paths,
+            tsconfig: None,
+            cross_module: false,
+            cache: None,
+            report_fixes: false,
+        }
     }
 
     #[inline]
@@ -59,6 +74,22 @@ impl LintServiceOptions {
         self
     }
 
+    #[inline]
+    #[must_use]
+    pub fn with_cache(mut self, cache: Arc<LintCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Report the fixes of the [`Linter`](crate::Linter)'s fix kind with each diagnostic, instead
+    /// of applying them to the linted files.
+    #[inline]
+    #[must_use]
+    pub fn with_report_fixes(mut self, report_fixes: bool) -> Self {
+        self.report_fixes = report_fixes;
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
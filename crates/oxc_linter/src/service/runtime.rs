@@ -22,7 +22,10 @@ use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
 
-use super::LintServiceOptions;
+use super::{
+    LintServiceOptions,
+    cache::{CacheEntry, LintCache, content_hash},
+};
 use crate::{
     Fixer, Linter, Message,
     fixer::PossibleFixes,
@@ -44,6 +47,10 @@ pub struct Runtime<'l> {
     pub(super) file_system: Box<dyn RuntimeFileSystem + Sync + Send>,
 
     allocator_pool: AllocatorPool,
+
+    cache: Option<Arc<LintCache>>,
+
+    report_fixes: bool,
 }
 
 /// Output of `Runtime::process_path`
@@ -192,6 +199,8 @@ impl<'l> Runtime<'l> {
             linter,
             resolver,
             file_system: Box::new(OsFileSystem),
+            cache: options.cache,
+            report_fixes: options.report_fixes,
         }
     }
 
@@ -508,6 +517,24 @@ impl<'l> Runtime<'l> {
 
                     let path = Path::new(&module_to_lint.path);
 
+                    let cache_entry = me.cache.as_ref().map(|cache| {
+                        let entry = CacheEntry {
+                            content_hash: content_hash(dep.source_text),
+                            config_hash: me.linter.config.config_hash(path),
+                            dependencies_hash: cache.dependencies_hash(
+                                module_to_lint.section_module_records.iter().flatten(),
+                            ),
+                        };
+                        (cache, entry)
+                    });
+                    // Without the import plugin, clean files are skipped before parsing them.
+                    if let Some((cache, entry)) = cache_entry {
+                        if me.resolver.is_some() && cache.is_clean(path, entry) {
+                            return;
+                        }
+                    }
+                    let mut is_clean = true;
+
                     assert_eq!(
                         module_to_lint.section_module_records.len(),
                         dep.section_contents.len()
@@ -530,7 +557,7 @@ impl<'l> Runtime<'l> {
                         };
 
                         let source_text = section.source.source_text;
-                        if me.linter.options().fix.is_some() {
+                        if me.linter.options().fix.is_some() && !me.report_fixes {
                             let fix_result = Fixer::new(source_text, messages).fix();
                             if fix_result.fixed {
                                 // write to file, replacing only the changed part
@@ -549,6 +576,7 @@ impl<'l> Runtime<'l> {
                         }
 
                         if !messages.is_empty() {
+                            is_clean = false;
                             let errors = messages.into_iter().map(Into::into).collect();
                             let path = path.strip_prefix(&me.cwd).unwrap_or(path);
                             let diagnostics = DiagnosticService::wrap_diagnostics(
@@ -563,8 +591,12 @@ impl<'l> Runtime<'l> {
                     // If the new source text is owned, that means it was modified,
                     // so we write the new source text to the file.
                     if let Cow::Owned(new_source_text) = new_source_text {
+                        is_clean = false;
                         me.file_system.write_file(path, new_source_text).unwrap();
                     }
+                    if let Some((cache, entry)) = cache_entry {
+                        cache.update(path, entry, is_clean);
+                    }
                 });
             });
         });
@@ -821,6 +853,23 @@ impl<'l> Runtime<'l> {
                     }
                 };
 
+                if let Some(cache) = &self.cache {
+                    let content_hash = content_hash(source_text);
+                    if self.resolver.is_some() {
+                        cache.insert_content_hash(path, content_hash);
+                    } else {
+                        let path = Path::new(path);
+                        let entry = CacheEntry {
+                            content_hash,
+                            config_hash: self.linter.config.config_hash(path),
+                            dependencies_hash: 0,
+                        };
+                        if cache.is_clean(path, entry) {
+                            return Err(());
+                        }
+                    }
+                }
+
                 let mut section_contents = SmallVec::new();
                 records = self.process_source(
                     Path::new(path),
@@ -854,6 +903,10 @@ impl<'l> Runtime<'l> {
                 }
             };
 
+            if let Some(cache) = &self.cache {
+                cache.insert_content_hash(path, content_hash(source_text));
+            }
+
             records = self.process_source(
                 Path::new(path),
                 ext,
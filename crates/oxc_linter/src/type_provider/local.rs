@@ -0,0 +1,381 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BinaryOperator, BindingPattern, BindingPatternKind, Expression,
+        Function, IdentifierReference, LogicalOperator, ObjectPropertyKind, PropertyKey,
+        UnaryOperator,
+    },
+};
+use oxc_semantic::Semantic;
+
+use super::{Type, TypeProvider};
+
+/// Infers the types of simple expressions from the file being linted alone.
+///
+/// Like isolated declarations, types are only taken from type annotations, literals and
+/// the initializers of variables which are never reassigned. Anything which would need
+/// a type checker, e.g. imported bindings or generic calls, is unknown.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LocalTypeProvider;
+
+/// Maximum depth of following variables to their initializers, which guards against cycles.
+const MAX_DEPTH: u8 = 16;
+
+impl TypeProvider for LocalTypeProvider {
+    fn type_of_expression<'a>(
+        &self,
+        expr: &Expression<'a>,
+        semantic: &Semantic<'a>,
+    ) -> Option<Type> {
+        Inference { semantic }.infer(expr, 0)
+    }
+}
+
+struct Inference<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+}
+
+impl<'a> Inference<'_, 'a> {
+    fn infer(&self, expr: &Expression<'a>, depth: u8) -> Option<Type> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let ty = match expr {
+            Expression::BooleanLiteral(_) => Type::Boolean,
+            Expression::NullLiteral(_) => Type::Null,
+            Expression::NumericLiteral(_) => Type::Number,
+            Expression::BigIntLiteral(_) => Type::BigInt,
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => Type::String,
+            Expression::RegExpLiteral(_) => Type::Object,
+            Expression::ArrayExpression(array) => {
+                let element = array
+                    .elements
+                    .iter()
+                    .map(|element| self.infer(element.as_expression()?, depth + 1))
+                    .collect::<Option<Vec<_>>>()?;
+                if element.is_empty() {
+                    return None;
+                }
+                Type::Array(Box::new(Type::union(element)))
+            }
+            Expression::ObjectExpression(object) => {
+                // An object with a `then` method is thenable.
+                let maybe_thenable = object.properties.iter().any(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        property.computed
+                            || matches!(&property.key, PropertyKey::StaticIdentifier(key) if key.name == "then")
+                            || property.key.is_specific_string_literal("then")
+                    }
+                    ObjectPropertyKind::SpreadProperty(_) => true,
+                });
+                if maybe_thenable {
+                    return None;
+                }
+                Type::Object
+            }
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_) => Type::Function,
+            Expression::Identifier(ident) => return self.infer_identifier(ident, depth),
+            Expression::ParenthesizedExpression(paren) => {
+                return self.infer(&paren.expression, depth);
+            }
+            Expression::SequenceExpression(sequence) => {
+                return self.infer(sequence.expressions.last()?, depth);
+            }
+            Expression::AssignmentExpression(assignment) if assignment.operator.is_assign() => {
+                return self.infer(&assignment.right, depth);
+            }
+            Expression::AwaitExpression(await_expr) => {
+                return self.infer(&await_expr.argument, depth).map(Type::awaited);
+            }
+            Expression::TSAsExpression(as_expr) => {
+                if as_expr.type_annotation.is_const_type_reference() {
+                    return self.infer(&as_expr.expression, depth);
+                }
+                return Type::from_ts_type(&as_expr.type_annotation);
+            }
+            Expression::TSTypeAssertion(assertion) => {
+                return Type::from_ts_type(&assertion.type_annotation);
+            }
+            Expression::TSSatisfiesExpression(satisfies) => {
+                return self.infer(&satisfies.expression, depth);
+            }
+            Expression::TSNonNullExpression(non_null) => {
+                return self.infer(&non_null.expression, depth).map(Type::non_nullable);
+            }
+            Expression::UnaryExpression(unary) => match unary.operator {
+                UnaryOperator::Typeof => Type::String,
+                UnaryOperator::LogicalNot | UnaryOperator::Delete => Type::Boolean,
+                UnaryOperator::Void => Type::Undefined,
+                UnaryOperator::UnaryPlus => Type::Number,
+                UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
+                    match self.infer(&unary.argument, depth + 1)? {
+                        Type::BigInt => Type::BigInt,
+                        Type::Number => Type::Number,
+                        _ => return None,
+                    }
+                }
+            },
+            Expression::BinaryExpression(binary) => {
+                let operator = binary.operator;
+                if operator.is_equality()
+                    || operator.is_compare()
+                    || matches!(operator, BinaryOperator::In | BinaryOperator::Instanceof)
+                {
+                    return Some(Type::Boolean);
+                }
+                let left = self.infer(&binary.left, depth + 1)?;
+                let right = self.infer(&binary.right, depth + 1)?;
+                match (left, right) {
+                    (Type::String, _) | (_, Type::String)
+                        if operator == BinaryOperator::Addition =>
+                    {
+                        Type::String
+                    }
+                    (Type::Number, Type::Number) => Type::Number,
+                    (Type::BigInt, Type::BigInt)
+                        if operator != BinaryOperator::ShiftRightZeroFill =>
+                    {
+                        Type::BigInt
+                    }
+                    _ => return None,
+                }
+            }
+            Expression::LogicalExpression(logical) => {
+                let left = self.infer(&logical.left, depth + 1)?;
+                let right = self.infer(&logical.right, depth + 1)?;
+                match logical.operator {
+                    // The left side is only returned if it is not nullish.
+                    LogicalOperator::Coalesce => Type::union([left.non_nullable(), right]),
+                    LogicalOperator::Or | LogicalOperator::And => Type::union([left, right]),
+                }
+            }
+            Expression::ConditionalExpression(conditional) => Type::union([
+                self.infer(&conditional.consequent, depth + 1)?,
+                self.infer(&conditional.alternate, depth + 1)?,
+            ]),
+            Expression::NewExpression(new_expr) => {
+                let Expression::Identifier(callee) = &new_expr.callee else { return None };
+                if !self.is_global(callee) {
+                    return None;
+                }
+                match callee.name.as_str() {
+                    "Promise" => Type::Promise(Box::new(Type::Unknown)),
+                    "Object" | "Map" | "Set" | "WeakMap" | "WeakSet" | "Date" | "Error"
+                    | "RegExp" | "URL" => Type::Object,
+                    _ => return None,
+                }
+            }
+            Expression::CallExpression(call) => {
+                if let Some(member) = call.callee.as_member_expression() {
+                    let method = member.static_property_name()?;
+                    let object = member.object().get_inner_expression();
+                    if matches!(object, Expression::Identifier(ident) if ident.name == "Promise" && self.is_global(ident))
+                    {
+                        return matches!(
+                            method,
+                            "resolve" | "reject" | "all" | "allSettled" | "race" | "any"
+                        )
+                        .then(|| Type::Promise(Box::new(Type::Unknown)));
+                    }
+                    if matches!(method, "then" | "catch" | "finally")
+                        && matches!(self.infer(object, depth + 1)?, Type::Promise(_))
+                    {
+                        return Some(Type::Promise(Box::new(Type::Unknown)));
+                    }
+                    return None;
+                }
+                return self.infer_call(call.callee.get_inner_expression(), depth);
+            }
+            _ => return None,
+        };
+        Some(ty)
+    }
+
+    fn is_global(&self, ident: &IdentifierReference<'a>) -> bool {
+        self.semantic.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+    }
+
+    fn infer_identifier(&self, ident: &IdentifierReference<'a>, depth: u8) -> Option<Type> {
+        let scoping = self.semantic.scoping();
+        let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return match ident.name.as_str() {
+                "undefined" => Some(Type::Undefined),
+                "NaN" | "Infinity" => Some(Type::Number),
+                _ => None,
+            };
+        };
+        let declaration = self.semantic.nodes().get_node(scoping.symbol_declaration(symbol_id));
+        match declaration.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                if !matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_)) {
+                    return None;
+                }
+                if let Some(annotation) = &declarator.id.type_annotation {
+                    return Type::from_ts_type(&annotation.type_annotation);
+                }
+                if scoping.symbol_is_mutated(symbol_id) {
+                    return None;
+                }
+                self.infer(declarator.init.as_ref()?, depth + 1)
+            }
+            AstKind::FormalParameter(param) => Self::infer_parameter(&param.pattern),
+            AstKind::Function(_) | AstKind::Class(_) => Some(Type::Function),
+            _ => None,
+        }
+    }
+
+    fn infer_parameter(pattern: &BindingPattern<'a>) -> Option<Type> {
+        let (annotation, optional) = match &pattern.kind {
+            BindingPatternKind::AssignmentPattern(assignment) => (
+                pattern.type_annotation.as_ref().or(assignment.left.type_annotation.as_ref()),
+                false,
+            ),
+            _ => (pattern.type_annotation.as_ref(), pattern.optional),
+        };
+        let ty = Type::from_ts_type(&annotation?.type_annotation)?;
+        Some(if optional { Type::union([ty, Type::Undefined]) } else { ty })
+    }
+
+    /// The return type of calling `callee`.
+    fn infer_call(&self, callee: &Expression<'a>, depth: u8) -> Option<Type> {
+        match callee {
+            Expression::FunctionExpression(func) => self.infer_function_return(func),
+            Expression::ArrowFunctionExpression(arrow) => self.infer_arrow_return(arrow, depth),
+            Expression::Identifier(ident) => {
+                let scoping = self.semantic.scoping();
+                let symbol_id = scoping.get_reference(ident.reference_id()).symbol_id()?;
+                let declaration =
+                    self.semantic.nodes().get_node(scoping.symbol_declaration(symbol_id));
+                match declaration.kind() {
+                    AstKind::Function(func) => self.infer_function_return(func),
+                    AstKind::VariableDeclarator(declarator) => {
+                        if scoping.symbol_is_mutated(symbol_id) {
+                            return None;
+                        }
+                        self.infer_call(declarator.init.as_ref()?.get_inner_expression(), depth + 1)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    #[expect(clippy::unused_self)]
+    fn infer_function_return(&self, func: &Function<'a>) -> Option<Type> {
+        if let Some(return_type) = &func.return_type {
+            return Type::from_ts_type(&return_type.type_annotation);
+        }
+        if func.generator {
+            return None;
+        }
+        func.r#async.then(|| Type::Promise(Box::new(Type::Unknown)))
+    }
+
+    fn infer_arrow_return(&self, arrow: &ArrowFunctionExpression<'a>, depth: u8) -> Option<Type> {
+        if let Some(return_type) = &arrow.return_type {
+            return Type::from_ts_type(&return_type.type_annotation);
+        }
+        let body = arrow.get_expression().and_then(|body| self.infer(body, depth + 1));
+        if arrow.r#async {
+            Some(Type::Promise(Box::new(body.map_or(Type::Unknown, Type::awaited))))
+        } else {
+            body
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::LocalTypeProvider;
+    use crate::type_provider::{Type, TypeProvider};
+
+    /// The type of the expression of the last statement of `source_text`.
+    fn type_of(source_text: &str) -> Option<Type> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.last() else {
+            panic!("expected an expression statement");
+        };
+        let expr: &Expression = &stmt.expression;
+        let ty = LocalTypeProvider.type_of_expression(expr, &semantic);
+        assert_eq!(LocalTypeProvider.type_at_span(expr.span(), &semantic), ty);
+        ty
+    }
+
+    fn promise() -> Type {
+        Type::Promise(Box::new(Type::Unknown))
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(type_of("1"), Some(Type::Number));
+        assert_eq!(type_of("`a${b}`"), Some(Type::String));
+        assert_eq!(
+            type_of("[1, 'a', 2]"),
+            Some(Type::Array(Box::new(Type::union([Type::Number, Type::String]))))
+        );
+        assert_eq!(type_of("({ a: 1 })"), Some(Type::Object));
+        assert_eq!(type_of("({ then() {} })"), None);
+        assert_eq!(type_of("-1n"), Some(Type::BigInt));
+        assert_eq!(type_of("'a' + 1"), Some(Type::String));
+        assert_eq!(type_of("a ? 1 : null"), Some(Type::union([Type::Number, Type::Null])));
+        assert_eq!(type_of("undefined"), Some(Type::Undefined));
+        assert_eq!(type_of("foo"), None);
+    }
+
+    #[test]
+    fn variables() {
+        assert_eq!(type_of("const a = 1; a"), Some(Type::Number));
+        assert_eq!(type_of("let a = 1; a"), Some(Type::Number));
+        assert_eq!(type_of("let a = 1; a = 'b'; a"), None);
+        assert_eq!(
+            type_of("let a: string | null = f(); a"),
+            Some(Type::union([Type::String, Type::Null]))
+        );
+        assert_eq!(type_of("let a: string | null = f(); a!"), Some(Type::String));
+        assert_eq!(type_of("function f(a?: number) { a }; f"), Some(Type::Function));
+        assert_eq!(
+            type_of("const a = b as number[]; a"),
+            Some(Type::Array(Box::new(Type::Number)))
+        );
+        assert_eq!(type_of("const a: Foo = b; a"), None);
+    }
+
+    #[test]
+    fn promises() {
+        assert_eq!(type_of("new Promise(() => {})"), Some(promise()));
+        assert_eq!(type_of("Promise.resolve(1).then(() => {})"), Some(promise()));
+        assert_eq!(type_of("async function f() {}; f()"), Some(promise()));
+        assert_eq!(
+            type_of("function f(): Promise<number> {}; f()"),
+            Some(Type::Promise(Box::new(Type::Number)))
+        );
+        assert_eq!(
+            type_of("const f = async () => 1; f()"),
+            Some(Type::Promise(Box::new(Type::Number)))
+        );
+        assert_eq!(type_of("const f = () => 1; f()"), Some(Type::Number));
+        assert_eq!(type_of("(async () => {})()"), Some(promise()));
+        assert_eq!(
+            type_of("const f = async () => 1; (async () => await f())()"),
+            Some(Type::Promise(Box::new(Type::Number)))
+        );
+        assert_eq!(
+            type_of("function f(): Promise<number> {}; async () => await f()"),
+            Some(Type::Function)
+        );
+        assert_eq!(type_of("class Promise {}; new Promise()"), None);
+    }
+}
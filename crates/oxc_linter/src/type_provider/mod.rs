@@ -0,0 +1,262 @@
+//! Type information for type-aware lint rules.
+//!
+//! Rules query types through [`LintContext::type_of`](crate::LintContext::type_of), which is
+//! backed by the [`TypeProvider`] of the [`Linter`](crate::Linter). The default provider is
+//! [`LocalTypeProvider`], which only infers the types of simple expressions within a file.
+//! A provider backed by a full type checker can be plugged in with
+//! [`Linter::with_type_provider`](crate::Linter::with_type_provider).
+
+mod local;
+
+use std::fmt;
+
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSType, TSTypeName},
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::Semantic;
+use oxc_span::{GetSpan, Span};
+
+pub use local::LocalTypeProvider;
+
+/// The type of an expression, as far as a [`TypeProvider`] knows it.
+///
+/// This is a simplified model of TypeScript types: literal types are widened to their
+/// primitive type, and object types are only distinguished as far as rules need it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    Any,
+    Unknown,
+    Never,
+    Void,
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    BigInt,
+    String,
+    Symbol,
+    /// An object which is known to be not thenable, e.g. an object literal without `then`.
+    Object,
+    /// A function or class.
+    Function,
+    /// `T[]` or `Array<T>`.
+    Array(Box<Type>),
+    /// `Promise<T>`, `PromiseLike<T>` or another thenable resolving to `T`.
+    Promise(Box<Type>),
+    /// A union of at least two distinct types, none of which is a union itself.
+    Union(Vec<Type>),
+}
+
+impl Type {
+    /// The union of `types`, flattened and without duplicates.
+    ///
+    /// As in TypeScript, `any` and `unknown` absorb all other types, and `never` is dropped.
+    pub fn union(types: impl IntoIterator<Item = Type>) -> Self {
+        let mut members: Vec<Type> = vec![];
+        for ty in types {
+            let flattened = match ty {
+                Type::Union(types) => types,
+                ty => vec![ty],
+            };
+            for ty in flattened {
+                if ty != Type::Never && !members.contains(&ty) {
+                    members.push(ty);
+                }
+            }
+        }
+        for absorbing in [Type::Any, Type::Unknown] {
+            if members.contains(&absorbing) {
+                return absorbing;
+            }
+        }
+        if members.len() > 1 { Type::Union(members) } else { members.pop().unwrap_or(Type::Never) }
+    }
+
+    /// Whether values of this type are thenable, i.e. can be awaited.
+    ///
+    /// Returns `None` if some values may be thenable, e.g. for `any` or `unknown`.
+    pub fn is_thenable(&self) -> Option<bool> {
+        match self {
+            Type::Any | Type::Unknown => None,
+            Type::Promise(_) => Some(true),
+            Type::Union(types) => {
+                let thenable = types.iter().map(Type::is_thenable).collect::<Vec<_>>();
+                if thenable.contains(&Some(true)) {
+                    Some(true)
+                } else if thenable.contains(&None) {
+                    None
+                } else {
+                    Some(false)
+                }
+            }
+            _ => Some(false),
+        }
+    }
+
+    /// Whether `null` or `undefined` are values of this type.
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            Type::Any | Type::Unknown | Type::Void | Type::Undefined | Type::Null => true,
+            Type::Union(types) => types.iter().any(Type::is_nullable),
+            _ => false,
+        }
+    }
+
+    /// This type without `null` and `undefined`, as after a non-null assertion.
+    #[must_use]
+    pub fn non_nullable(self) -> Self {
+        match self {
+            Type::Union(types) => Type::union(
+                types.into_iter().filter(|ty| !matches!(ty, Type::Null | Type::Undefined)),
+            ),
+            Type::Null | Type::Undefined => Type::Never,
+            ty => ty,
+        }
+    }
+
+    /// The type of `await`ing a value of this type.
+    #[must_use]
+    pub fn awaited(self) -> Self {
+        match self {
+            Type::Promise(ty) => ty.awaited(),
+            Type::Union(types) => Type::union(types.into_iter().map(Type::awaited)),
+            ty => ty,
+        }
+    }
+
+    /// Convert a type annotation.
+    ///
+    /// Returns `None` for types which can not be represented, e.g. literal types or
+    /// references to type aliases and interfaces.
+    pub fn from_ts_type(ts_type: &TSType) -> Option<Self> {
+        let ty = match ts_type {
+            TSType::TSAnyKeyword(_) => Type::Any,
+            TSType::TSUnknownKeyword(_) => Type::Unknown,
+            TSType::TSNeverKeyword(_) => Type::Never,
+            TSType::TSVoidKeyword(_) => Type::Void,
+            TSType::TSUndefinedKeyword(_) => Type::Undefined,
+            TSType::TSNullKeyword(_) => Type::Null,
+            TSType::TSBooleanKeyword(_) => Type::Boolean,
+            TSType::TSNumberKeyword(_) => Type::Number,
+            TSType::TSBigIntKeyword(_) => Type::BigInt,
+            TSType::TSStringKeyword(_) => Type::String,
+            TSType::TSSymbolKeyword(_) => Type::Symbol,
+            TSType::TSArrayType(array) => {
+                Type::Array(Box::new(Type::from_ts_type(&array.element_type)?))
+            }
+            TSType::TSParenthesizedType(ty) => Type::from_ts_type(&ty.type_annotation)?,
+            TSType::TSUnionType(union) => {
+                Type::union(union.types.iter().map(Type::from_ts_type).collect::<Option<Vec<_>>>()?)
+            }
+            TSType::TSTypeReference(reference) => {
+                let TSTypeName::IdentifierReference(name) = &reference.type_name else {
+                    return None;
+                };
+                let type_argument = || {
+                    reference
+                        .type_arguments
+                        .as_ref()
+                        .and_then(|arguments| arguments.params.first())
+                        .map_or(Some(Type::Unknown), Type::from_ts_type)
+                };
+                match name.name.as_str() {
+                    "Promise" | "PromiseLike" => Type::Promise(Box::new(type_argument()?)),
+                    "Array" | "ReadonlyArray" => Type::Array(Box::new(type_argument()?)),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        Some(ty)
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Any => f.write_str("any"),
+            Type::Unknown => f.write_str("unknown"),
+            Type::Never => f.write_str("never"),
+            Type::Void => f.write_str("void"),
+            Type::Undefined => f.write_str("undefined"),
+            Type::Null => f.write_str("null"),
+            Type::Boolean => f.write_str("boolean"),
+            Type::Number => f.write_str("number"),
+            Type::BigInt => f.write_str("bigint"),
+            Type::String => f.write_str("string"),
+            Type::Symbol => f.write_str("symbol"),
+            Type::Object => f.write_str("object"),
+            Type::Function => f.write_str("Function"),
+            Type::Array(ty) => match **ty {
+                Type::Union(_) => write!(f, "({ty})[]"),
+                _ => write!(f, "{ty}[]"),
+            },
+            Type::Promise(ty) => write!(f, "Promise<{ty}>"),
+            Type::Union(types) => {
+                for (i, ty) in types.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{ty}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Provides type information to type-aware lint rules.
+///
+/// A provider is shared by all files and threads, so the file being linted is passed to each
+/// query. Queries return `None` if the type is not known, in which case rules should not report.
+pub trait TypeProvider: fmt::Debug + Send + Sync {
+    /// The type of `expr`, an expression in the program of `semantic`.
+    fn type_of_expression<'a>(
+        &self,
+        expr: &Expression<'a>,
+        semantic: &Semantic<'a>,
+    ) -> Option<Type>;
+
+    /// The type of the expression at `span` in the program of `semantic`.
+    ///
+    /// If several nested expressions have this span, e.g. `a` and `(a)`, the outermost is used.
+    fn type_at_span(&self, span: Span, semantic: &Semantic<'_>) -> Option<Type> {
+        let AstKind::Program(program) = semantic.nodes().root_node()?.kind() else {
+            return None;
+        };
+        let mut finder =
+            ExpressionFinder { provider: self, semantic, span, found: false, ty: None };
+        finder.visit_program(program);
+        finder.ty
+    }
+
+    /// Whether `expr` is thenable, i.e. can be awaited. See [`Type::is_thenable`].
+    fn is_thenable<'a>(&self, expr: &Expression<'a>, semantic: &Semantic<'a>) -> Option<bool> {
+        self.type_of_expression(expr, semantic)?.is_thenable()
+    }
+}
+
+/// Finds the outermost expression at `span` and queries its type.
+struct ExpressionFinder<'s, 'a, P: TypeProvider + ?Sized> {
+    provider: &'s P,
+    semantic: &'s Semantic<'a>,
+    span: Span,
+    found: bool,
+    ty: Option<Type>,
+}
+
+impl<'a, P: TypeProvider + ?Sized> Visit<'a> for ExpressionFinder<'_, 'a, P> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.found || !expr.span().contains_inclusive(self.span) {
+            return;
+        }
+        if expr.span() == self.span {
+            self.found = true;
+            self.ty = self.provider.type_of_expression(expr, self.semantic);
+            return;
+        }
+        walk::walk_expression(self, expr);
+    }
+}
@@ -15,8 +15,12 @@ use oxc_span::Atom;
 
 pub(crate) mod base54;
 mod keep_names;
+mod name_cache;
+mod properties;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use name_cache::NameCache;
+pub use properties::{ManglePropertiesOptions, PropertyMangler};
 
 #[derive(Default, Debug, Clone, Copy)]
 pub struct MangleOptions {
@@ -181,6 +185,8 @@ pub struct Mangler<'t> {
     /// It can be cleared after mangling is done, to free up memory for subsequent
     /// files or other operations.
     temp_allocator: TempAllocator<'t>,
+    /// Names of top level symbols from a previous run, updated after mangling.
+    name_cache: Option<&'t mut NameCache>,
 }
 
 impl Default for Mangler<'_> {
@@ -188,6 +194,7 @@ impl Default for Mangler<'_> {
         Self {
             options: MangleOptions::default(),
             temp_allocator: TempAllocator::Owned(Allocator::default()),
+            name_cache: None,
         }
     }
 }
@@ -246,6 +253,7 @@ impl<'t> Mangler<'t> {
         Self {
             options: MangleOptions::default(),
             temp_allocator: TempAllocator::Borrowed(temp_allocator),
+            name_cache: None,
         }
     }
 
@@ -255,6 +263,40 @@ impl<'t> Mangler<'t> {
         self
     }
 
+    /// Seeds the mangler with the names of top level symbols from a previous run,
+    /// so that unchanged symbols keep their mangled names across builds.
+    ///
+    /// Only used when [MangleOptions::top_level] is enabled, because other top level symbols
+    /// are not mangled. Otherwise `name_cache` is left untouched. Symbols in nested scopes are
+    /// always assigned from scratch.
+    ///
+    /// After mangling, the names of the top level symbols of this program (original name ->
+    /// mangled name) are added to `name_cache`. Entries of symbols which are not in this program
+    /// are kept, and their mangled names are not used for other symbols, so that one cache can be
+    /// shared by several chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxc_allocator::Allocator;
+    /// use oxc_mangler::{MangleOptions, Mangler, NameCache};
+    /// use oxc_parser::Parser;
+    /// use oxc_span::SourceType;
+    ///
+    /// let allocator = Allocator::default();
+    /// let mut name_cache = NameCache::default();
+    /// let options = MangleOptions { top_level: true, ..MangleOptions::default() };
+    ///
+    /// let parsed = Parser::new(&allocator, "let foo = 1; foo", SourceType::mjs()).parse();
+    /// Mangler::new().with_options(options).with_name_cache(&mut name_cache).build(&parsed.program);
+    /// assert_eq!(name_cache.get("foo"), Some("e"));
+    /// ```
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: &'t mut NameCache) -> Self {
+        self.name_cache = Some(name_cache);
+        self
+    }
+
     /// Mangles the program. The resulting SymbolTable contains the mangled symbols - `program` is not modified.
     /// Pass the symbol table to oxc_codegen to generate the mangled code.
     #[must_use]
@@ -296,9 +338,51 @@ impl<'t> Mangler<'t> {
         } else {
             Default::default()
         };
-        let (keep_name_names, keep_name_symbols) =
+        let (mut keep_name_names, mut keep_name_symbols) =
             Mangler::collect_keep_name_symbols(self.options.keep_names, scoping, ast_nodes);
 
+        // Top level symbols which can be mangled, with their original names.
+        let top_level_symbols = if self.options.top_level && self.name_cache.is_some() {
+            scoping
+                .get_bindings(scoping.root_scope_id())
+                .iter()
+                .filter(|(name, symbol_id)| {
+                    !exported_symbols.contains(*symbol_id)
+                        && !keep_name_symbols.contains(*symbol_id)
+                        && !is_special_name(name)
+                })
+                .map(|(name, symbol_id)| (*symbol_id, (*name).to_string()))
+                .collect::<std::vec::Vec<_>>()
+        } else {
+            vec![]
+        };
+
+        // Symbols which keep their names from the name cache.
+        // They are excluded from slot assignment, like the symbols kept by `keep_names`.
+        let mut cached_symbols = std::vec::Vec::new();
+        if let Some(name_cache) = self.name_cache.as_deref().filter(|_| self.options.top_level) {
+            let root_unresolved_references = scoping.root_unresolved_references();
+            let mut cached_names = FxHashSet::default();
+            for (symbol_id, name) in &top_level_symbols {
+                let Some(cached_name) = name_cache.get(name) else { continue };
+                if !is_keyword(cached_name)
+                    && !is_special_name(cached_name)
+                    && !root_unresolved_references.contains_key(cached_name)
+                    && !exported_names.contains(cached_name)
+                    && !keep_name_names.contains(cached_name)
+                    && cached_names.insert(cached_name)
+                {
+                    cached_symbols.push((*symbol_id, cached_name.to_string()));
+                }
+            }
+            // Names of symbols of other programs in the cache (e.g. other chunks) are not reused.
+            keep_name_names.extend(name_cache.iter().map(|(_, mangled)| mangled));
+        }
+        for (symbol_id, cached_name) in &cached_symbols {
+            keep_name_names.insert(cached_name);
+            keep_name_symbols.insert(*symbol_id);
+        }
+
         let temp_allocator = self.temp_allocator.as_ref();
 
         // All symbols with their assigned slots. Keyed by symbol id.
@@ -460,6 +544,15 @@ impl<'t> Mangler<'t> {
                 }
             }
         }
+
+        if let Some(name_cache) = self.name_cache.filter(|_| self.options.top_level) {
+            for (symbol_id, name) in &cached_symbols {
+                scoping.set_symbol_name(*symbol_id, name);
+            }
+            for (symbol_id, name) in top_level_symbols {
+                name_cache.insert(name, scoping.symbol_name(symbol_id).to_string());
+            }
+        }
     }
 
     fn tally_slot_frequencies<'a>(
@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Mapping from original names to mangled names.
+///
+/// A name cache produced by one mangler run can be fed into the next run,
+/// so that names which are still present keep their previously mangled name.
+///
+/// With the `serialize` feature enabled, the cache (de)serializes as a plain object,
+/// e.g. `{ "_private": "e" }`, so it can be persisted between builds.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize), serde(transparent))]
+pub struct NameCache {
+    names: BTreeMap<String, String>,
+}
+
+impl NameCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the mangled name of `original`.
+    pub fn get(&self, original: &str) -> Option<&str> {
+        self.names.get(original).map(String::as_str)
+    }
+
+    /// Record that `original` is mangled to `mangled`.
+    pub fn insert(&mut self, original: String, mangled: String) {
+        self.names.insert(original, mangled);
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Iterate over `(original, mangled)` pairs, sorted by original name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.names.iter().map(|(original, mangled)| (original.as_str(), mangled.as_str()))
+    }
+}
+
+impl FromIterator<(String, String)> for NameCache {
+    fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+        Self { names: iter.into_iter().collect() }
+    }
+}
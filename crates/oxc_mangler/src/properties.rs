@@ -0,0 +1,300 @@
+use std::mem;
+
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_span::{Atom, SPAN};
+
+use crate::{NameCache, base54::base54, is_keyword};
+
+#[derive(Debug, Clone)]
+pub struct ManglePropertiesOptions {
+    /// Only mangle property names matching this regular expression, e.g. `^_`.
+    pub regex: Regex,
+
+    /// Property names that are never mangled, even when they match `regex`.
+    pub reserved: Vec<String>,
+
+    /// Do not mangle quoted property names (`o["_foo"]`, `{ "_foo": 1 }`, `"_foo" in o`).
+    ///
+    /// A name that is quoted anywhere in the program is not mangled anywhere.
+    ///
+    /// Default `false`
+    pub keep_quoted: bool,
+}
+
+impl ManglePropertiesOptions {
+    pub fn new(regex: Regex) -> Self {
+        Self { regex, reserved: vec![], keep_quoted: false }
+    }
+}
+
+/// # Property Mangler
+///
+/// Renames property names selected by [ManglePropertiesOptions::regex].
+///
+/// Unlike [crate::Mangler], which only renames bindings and leaves `program` untouched,
+/// property mangling rewrites the property names in `program`.
+///
+/// This is an unsafe transformation: properties accessed from outside of the program
+/// (e.g. by other bundles, `JSON`, or reflection) must be excluded from the regex
+/// or listed in [ManglePropertiesOptions::reserved].
+///
+/// Names are assigned by the `base54` generator, in order of frequency so that the most used
+/// properties get the shortest names. Names from the [NameCache] passed to
+/// [PropertyMangler::with_name_cache] are reused, so that the same property gets the same name
+/// across files and builds.
+pub struct PropertyMangler {
+    options: ManglePropertiesOptions,
+    name_cache: NameCache,
+}
+
+impl PropertyMangler {
+    pub fn new(options: ManglePropertiesOptions) -> Self {
+        Self { options, name_cache: NameCache::default() }
+    }
+
+    /// Seed the mangler with names from a previous run.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
+    /// Mangles property names in `program`.
+    ///
+    /// Returns the updated name cache, which contains all entries of the input name cache
+    /// and the properties mangled in this program.
+    pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> NameCache {
+        let Self { options, mut name_cache } = self;
+
+        let mut collector = PropertyNameCollector::default();
+        collector.visit_program(program);
+
+        let is_mangleable = |name: &str| {
+            options.regex.is_match(name)
+                && !options.reserved.iter().any(|reserved| reserved == name)
+                && !(options.keep_quoted && collector.quoted.contains(name))
+        };
+
+        // Sort by frequency, then by the order of first occurrence.
+        let mut candidates = collector
+            .names
+            .iter()
+            .filter(|(name, _)| is_mangleable(name))
+            .map(|(name, occurrence)| (*name, *occurrence))
+            .collect::<Vec<_>>();
+        candidates.sort_unstable_by_key(|(_, occurrence)| {
+            (std::cmp::Reverse(occurrence.count), occurrence.first)
+        });
+
+        // Names that must not be generated.
+        let mut used_names = collector
+            .names
+            .keys()
+            .filter(|name| !is_mangleable(name))
+            .map(Atom::as_str)
+            .collect::<FxHashSet<_>>();
+        used_names.extend(options.reserved.iter().map(String::as_str));
+        let mut cached_names =
+            name_cache.iter().map(|(_, mangled)| mangled.to_string()).collect::<FxHashSet<_>>();
+
+        let ast = AstBuilder::new(allocator);
+        let mut renames = FxHashMap::default();
+        let mut count = 0;
+        for (name, _) in candidates {
+            let mangled = match name_cache.get(&name) {
+                Some(mangled) if !used_names.contains(mangled) => mangled.to_string(),
+                _ => loop {
+                    let mangled = base54(count);
+                    count += 1;
+                    let mangled = mangled.as_str();
+                    if !is_keyword(mangled)
+                        && !used_names.contains(mangled)
+                        && !cached_names.contains(mangled)
+                    {
+                        break mangled.to_string();
+                    }
+                },
+            };
+            cached_names.insert(mangled.clone());
+            name_cache.insert(name.to_string(), mangled.clone());
+            renames.insert(name, ast.atom(&mangled));
+        }
+
+        if !renames.is_empty() {
+            PropertyNameRenamer { ast, renames }.visit_program(program);
+        }
+        name_cache
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Occurrence {
+    count: usize,
+    first: usize,
+}
+
+/// Collects the property names of a program.
+#[derive(Default)]
+struct PropertyNameCollector<'a> {
+    names: FxHashMap<Atom<'a>, Occurrence>,
+    quoted: FxHashSet<Atom<'a>>,
+}
+
+impl<'a> PropertyNameCollector<'a> {
+    fn add(&mut self, name: Atom<'a>, quoted: bool) {
+        let first = self.names.len();
+        self.names.entry(name).or_insert(Occurrence { count: 0, first }).count += 1;
+        if quoted {
+            self.quoted.insert(name);
+        }
+    }
+}
+
+impl<'a> Visit<'a> for PropertyNameCollector<'a> {
+    fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'a>) {
+        self.add(it.property.name, false);
+        walk::walk_static_member_expression(self, it);
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &it.expression {
+            self.add(lit.value, true);
+        }
+        walk::walk_computed_member_expression(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &PropertyKey<'a>) {
+        match it {
+            PropertyKey::StaticIdentifier(ident) => self.add(ident.name, false),
+            PropertyKey::StringLiteral(lit) => self.add(lit.value, true),
+            _ => {}
+        }
+        walk::walk_property_key(self, it);
+    }
+
+    fn visit_binary_expression(&mut self, it: &BinaryExpression<'a>) {
+        if it.operator == BinaryOperator::In {
+            if let Expression::StringLiteral(lit) = &it.left {
+                self.add(lit.value, true);
+            }
+        }
+        walk::walk_binary_expression(self, it);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.add(it.binding.name, false);
+        walk::walk_assignment_target_property_identifier(self, it);
+    }
+}
+
+/// Renames property names collected by [PropertyNameCollector].
+struct PropertyNameRenamer<'a> {
+    ast: AstBuilder<'a>,
+    renames: FxHashMap<Atom<'a>, Atom<'a>>,
+}
+
+impl<'a> VisitMut<'a> for PropertyNameRenamer<'a> {
+    fn visit_static_member_expression(&mut self, it: &mut StaticMemberExpression<'a>) {
+        if let Some(name) = self.renames.get(&it.property.name) {
+            it.property.name = *name;
+        }
+        walk_mut::walk_static_member_expression(self, it);
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &mut ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &mut it.expression {
+            if let Some(name) = self.renames.get(&lit.value) {
+                lit.value = *name;
+                lit.raw = None;
+            }
+        }
+        walk_mut::walk_computed_member_expression(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &mut PropertyKey<'a>) {
+        match it {
+            PropertyKey::StaticIdentifier(ident) => {
+                if let Some(name) = self.renames.get(&ident.name) {
+                    ident.name = *name;
+                }
+            }
+            PropertyKey::StringLiteral(lit) => {
+                if let Some(name) = self.renames.get(&lit.value) {
+                    lit.value = *name;
+                    lit.raw = None;
+                }
+            }
+            _ => {}
+        }
+        walk_mut::walk_property_key(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        if it.shorthand {
+            // `{ _foo }` -> `{ e: _foo }`
+            if let PropertyKey::StaticIdentifier(ident) = &it.key {
+                it.shorthand = !self.renames.contains_key(&ident.name);
+            }
+        }
+        walk_mut::walk_object_property(self, it);
+    }
+
+    fn visit_binding_property(&mut self, it: &mut BindingProperty<'a>) {
+        if it.shorthand {
+            // `const { _foo } = o` -> `const { e: _foo } = o`
+            if let PropertyKey::StaticIdentifier(ident) = &it.key {
+                it.shorthand = !self.renames.contains_key(&ident.name);
+            }
+        }
+        walk_mut::walk_binding_property(self, it);
+    }
+
+    fn visit_binary_expression(&mut self, it: &mut BinaryExpression<'a>) {
+        if it.operator == BinaryOperator::In {
+            if let Expression::StringLiteral(lit) = &mut it.left {
+                if let Some(name) = self.renames.get(&lit.value) {
+                    lit.value = *name;
+                    lit.raw = None;
+                }
+            }
+        }
+        walk_mut::walk_binary_expression(self, it);
+    }
+
+    fn visit_assignment_target_property(&mut self, it: &mut AssignmentTargetProperty<'a>) {
+        // `({ _foo } = o)` -> `({ e: _foo } = o)`
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = it {
+            if let Some(name) = self.renames.get(&prop.binding.name) {
+                let key = self.ast.property_key_static_identifier(prop.binding.span, *name);
+                let span = prop.span;
+                let binding = mem::replace(
+                    &mut prop.binding,
+                    self.ast.identifier_reference(SPAN, Atom::empty()),
+                );
+                let target = AssignmentTarget::from(
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(self.ast.alloc(binding)),
+                );
+                let binding = match prop.init.take() {
+                    Some(init) => {
+                        self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                            span, target, init,
+                        )
+                    }
+                    None => AssignmentTargetMaybeDefault::from(target),
+                };
+                *it = self.ast.assignment_target_property_assignment_target_property_property(
+                    span, key, binding, false,
+                );
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, it);
+    }
+}
@@ -7,6 +7,7 @@ mod ctx;
 mod keep_var;
 mod options;
 mod peephole;
+mod tree_shaker;
 
 #[cfg(test)]
 mod tester;
@@ -18,7 +19,11 @@ use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
 pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
 
-pub use crate::{compressor::Compressor, options::*};
+pub use crate::{
+    compressor::Compressor,
+    options::*,
+    tree_shaker::{TreeShakeModule, TreeShakeReturn, TreeShaker},
+};
 
 #[derive(Debug, Clone)]
 pub struct MinifierOptions {
@@ -0,0 +1,550 @@
+//! Cross-module tree shaking.
+//!
+//! Computes which exports are used across a graph of ES modules and removes unused exports
+//! together with the side-effect free declarations that only they depended on.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, TakeIn};
+use oxc_ast::{AstBuilder, NONE, ast::*};
+use oxc_ecmascript::{
+    BoundNames,
+    is_global_reference::IsGlobalReference,
+    side_effects::{MayHaveSideEffects, MayHaveSideEffectsContext, PropertyReadSideEffects},
+};
+use oxc_semantic::{IsGlobalReference as _, Scoping, SemanticBuilder};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ImportImportName, ModuleRecord,
+};
+
+use crate::TreeShakeOptions;
+
+/// A module participating in cross-module tree shaking.
+///
+/// All programs must be allocated in the same [Allocator] that is passed to [TreeShaker::new].
+pub struct TreeShakeModule<'a, 'm> {
+    /// The parsed program of this module.
+    pub program: &'m mut Program<'a>,
+
+    /// The module record returned by the parser alongside `program`.
+    pub module_record: &'m ModuleRecord<'a>,
+
+    /// Index of the module each module specifier of this module resolves to.
+    ///
+    /// Specifiers that are not present are treated as external modules,
+    /// which are always kept and never shaken.
+    pub resolved_modules: FxHashMap<String, usize>,
+
+    /// Entry modules keep all of their exports.
+    ///
+    /// Modules loaded through `import()` should also be marked as entries,
+    /// because the namespace object of a dynamic import is not statically analyzable.
+    pub is_entry: bool,
+}
+
+pub struct TreeShakeReturn {
+    /// Names of the exports used by other modules, for each module.
+    ///
+    /// `None` means all exports are used, e.g. for entry modules or namespace imports.
+    pub used_exports: Vec<Option<FxHashSet<String>>>,
+
+    /// Whether the module is reachable from an entry and is not empty after tree shaking.
+    ///
+    /// Modules that are not included can be dropped from the output.
+    pub included: Vec<bool>,
+}
+
+/// Removes unused exports across a module graph.
+///
+/// The analysis is iterated until a fixed point is reached:
+///
+/// 1. compute the used exports of each module from its [ModuleRecord] and the imports
+///    still present in the importing programs,
+/// 2. remove unused exports; declarations are kept but lose their `export` keyword,
+/// 3. remove unreferenced top-level declarations and import specifiers without side effects.
+///
+/// Imports and re-exports of modules that become empty are removed as well.
+pub struct TreeShaker<'a> {
+    allocator: &'a Allocator,
+    options: TreeShakeOptions,
+}
+
+impl<'a> TreeShaker<'a> {
+    pub fn new(allocator: &'a Allocator, options: TreeShakeOptions) -> Self {
+        Self { allocator, options }
+    }
+
+    pub fn build(self, modules: &mut [TreeShakeModule<'a, '_>]) -> TreeShakeReturn {
+        loop {
+            let live = modules.iter().map(|module| LiveModuleDeclarations::new(module.program));
+            let live = live.collect::<Vec<_>>();
+            let usage = ExportUsage::new(modules, &live);
+            let empty = modules
+                .iter()
+                .map(|module| !module.is_entry && module.program.body.is_empty())
+                .collect::<Vec<_>>();
+
+            let mut changed = false;
+            for (index, module) in modules.iter_mut().enumerate() {
+                // Modules that are not reachable from an entry are left untouched.
+                if !usage.included[index] || !module.program.source_type.is_module() {
+                    continue;
+                }
+                let used = (!usage.all_used[index]).then(|| &usage.used[index]);
+                let is_empty_module = |specifier: &str| {
+                    module.resolved_modules.get(specifier).is_some_and(|&index| empty[index])
+                };
+                changed |= self.strip_unused_exports(module.program, used, is_empty_module);
+                changed |= self.remove_unused_declarations(module.program);
+            }
+
+            if !changed {
+                let included = usage
+                    .included
+                    .iter()
+                    .zip(modules.iter())
+                    .map(|(included, module)| {
+                        *included && (module.is_entry || !module.program.body.is_empty())
+                    })
+                    .collect();
+                let used_exports = usage
+                    .used
+                    .into_iter()
+                    .zip(usage.all_used)
+                    .map(|(used, all_used)| (!all_used).then_some(used))
+                    .collect();
+                return TreeShakeReturn { used_exports, included };
+            }
+        }
+    }
+
+    /// Removes the `export` of declarations that are not in `used`.
+    ///
+    /// `used` is `None` when all exports of the module are used.
+    fn strip_unused_exports(
+        &self,
+        program: &mut Program<'a>,
+        used: Option<&FxHashSet<String>>,
+        is_empty_module: impl Fn(&str) -> bool,
+    ) -> bool {
+        let ast = AstBuilder::new(self.allocator);
+        let scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
+        let ctx = TreeShakeCtx { scoping: &scoping, options: &self.options };
+        let is_used = |name: &str| used.is_none_or(|used| used.contains(name));
+
+        let mut changed = false;
+        let body = program.body.take_in(self.allocator);
+        let mut new_body = ast.vec_with_capacity(body.len());
+        for stmt in body {
+            let stmt = match stmt {
+                Statement::ImportDeclaration(decl)
+                    if decl.specifiers.as_ref().is_none_or(|specifiers| specifiers.is_empty())
+                        && is_empty_module(&decl.source.value) =>
+                {
+                    None
+                }
+                Statement::ExportAllDeclaration(mut decl) if !decl.export_kind.is_type() => {
+                    let is_unused = decl.exported.as_ref().is_some_and(|e| !is_used(&e.name()));
+                    if is_empty_module(&decl.source.value) && (decl.exported.is_none() || is_unused)
+                    {
+                        None
+                    } else if is_unused {
+                        changed = true;
+                        Some(Self::side_effect_import(ast, decl.span, decl.source.take_in(ast)))
+                    } else {
+                        Some(Statement::ExportAllDeclaration(decl))
+                    }
+                }
+                Statement::ExportNamedDeclaration(mut decl)
+                    if used.is_some() && !decl.is_typescript_syntax() =>
+                {
+                    if let Some(declaration) = &decl.declaration {
+                        let mut has_used_name = false;
+                        declaration.bound_names(&mut |ident| {
+                            has_used_name |= is_used(&ident.name);
+                        });
+                        if has_used_name {
+                            Some(Statement::ExportNamedDeclaration(decl))
+                        } else {
+                            changed = true;
+                            decl.declaration.take().map(Statement::from)
+                        }
+                    } else {
+                        let len = decl.specifiers.len();
+                        decl.specifiers.retain(|specifier| {
+                            specifier.export_kind.is_type() || is_used(&specifier.exported.name())
+                        });
+                        changed |= len != decl.specifiers.len();
+                        if !decl.specifiers.is_empty() {
+                            Some(Statement::ExportNamedDeclaration(decl))
+                        } else if let Some(source) = decl.source.take() {
+                            (!is_empty_module(&source.value))
+                                .then(|| Self::side_effect_import(ast, decl.span, source))
+                        } else {
+                            None
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl)
+                    if !is_used("default") && !decl.is_typescript_syntax() =>
+                {
+                    changed = true;
+                    let decl = decl.unbox();
+                    match decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                            func.id.is_some().then_some(Statement::FunctionDeclaration(func))
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                            if class.id.is_some() {
+                                Some(Statement::ClassDeclaration(class))
+                            } else if class.may_have_side_effects(&ctx) {
+                                class.r#type = ClassType::ClassExpression;
+                                let expr = Expression::ClassExpression(class);
+                                Some(ast.statement_expression(decl.span, expr))
+                            } else {
+                                None
+                            }
+                        }
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => unreachable!(),
+                        kind => {
+                            let expr = kind.into_expression();
+                            expr.may_have_side_effects(&ctx)
+                                .then(|| ast.statement_expression(decl.span, expr))
+                        }
+                    }
+                }
+                stmt => Some(stmt),
+            };
+            match stmt {
+                Some(stmt) => new_body.push(stmt),
+                None => changed = true,
+            }
+        }
+        program.body = new_body;
+        changed
+    }
+
+    fn side_effect_import(
+        ast: AstBuilder<'a>,
+        span: Span,
+        source: StringLiteral<'a>,
+    ) -> Statement<'a> {
+        Statement::from(ast.module_declaration_import_declaration(
+            span,
+            None,
+            source,
+            None,
+            NONE,
+            ImportOrExportKind::Value,
+        ))
+    }
+
+    /// Removes unreferenced top-level declarations and import specifiers without side effects,
+    /// until no more can be removed.
+    fn remove_unused_declarations(&self, program: &mut Program<'a>) -> bool {
+        let mut changed = false;
+        loop {
+            let scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
+            let ctx = TreeShakeCtx { scoping: &scoping, options: &self.options };
+            let is_unused =
+                |ident: &BindingIdentifier<'a>| scoping.symbol_is_unused(ident.symbol_id());
+
+            let mut removed = false;
+            program.body.retain_mut(|stmt| {
+                let keep = match stmt {
+                    Statement::FunctionDeclaration(func) => {
+                        func.declare || func.id.as_ref().is_none_or(|id| !is_unused(id))
+                    }
+                    Statement::ClassDeclaration(class) => {
+                        class.declare
+                            || class.id.as_ref().is_none_or(|id| !is_unused(id))
+                            || class.may_have_side_effects(&ctx)
+                    }
+                    Statement::VariableDeclaration(decl) if !decl.declare => {
+                        let is_using = matches!(
+                            decl.kind,
+                            VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+                        );
+                        if !is_using {
+                            let len = decl.declarations.len();
+                            decl.declarations.retain(|declarator| {
+                                let BindingPatternKind::BindingIdentifier(ident) =
+                                    &declarator.id.kind
+                                else {
+                                    return true;
+                                };
+                                !is_unused(ident)
+                                    || declarator
+                                        .init
+                                        .as_ref()
+                                        .is_some_and(|init| init.may_have_side_effects(&ctx))
+                            });
+                            removed |= len != decl.declarations.len();
+                        }
+                        !decl.declarations.is_empty()
+                    }
+                    Statement::ImportDeclaration(decl) if !decl.import_kind.is_type() => {
+                        if let Some(specifiers) = &mut decl.specifiers {
+                            let len = specifiers.len();
+                            specifiers.retain(|specifier| {
+                                matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(s) if s.import_kind.is_type())
+                                    || !is_unused(specifier.local())
+                            });
+                            if specifiers.len() != len {
+                                removed = true;
+                                if specifiers.is_empty() {
+                                    // Keep the side effects of the imported module.
+                                    decl.specifiers = None;
+                                }
+                            }
+                        }
+                        true
+                    }
+                    _ => true,
+                };
+                removed |= !keep;
+                keep
+            });
+
+            if !removed {
+                return changed;
+            }
+            changed = true;
+        }
+    }
+}
+
+/// Import and export statements still present in a program.
+struct LiveModuleDeclarations {
+    /// Spans of the statements of all module declarations.
+    statements: FxHashSet<Span>,
+    /// Spans of the local bindings of all import specifiers.
+    import_locals: FxHashSet<Span>,
+    /// Module specifiers of all import and re-export statements.
+    sources: Vec<String>,
+}
+
+impl LiveModuleDeclarations {
+    fn new(program: &Program<'_>) -> Self {
+        let mut live = Self {
+            statements: FxHashSet::default(),
+            import_locals: FxHashSet::default(),
+            sources: vec![],
+        };
+        for stmt in &program.body {
+            let Some(decl) = stmt.as_module_declaration() else { continue };
+            live.statements.insert(decl.span());
+            match decl {
+                ModuleDeclaration::ImportDeclaration(decl) => {
+                    if let Some(specifiers) = &decl.specifiers {
+                        live.import_locals.extend(specifiers.iter().map(|s| s.local().span));
+                    }
+                    live.sources.push(decl.source.value.to_string());
+                }
+                ModuleDeclaration::ExportAllDeclaration(decl) => {
+                    live.sources.push(decl.source.value.to_string());
+                }
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    if let Some(source) = &decl.source {
+                        live.sources.push(source.value.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        live
+    }
+}
+
+enum Usage {
+    /// The module is reachable.
+    Include(usize),
+    /// An export of the module is used.
+    Name(usize, String),
+    /// All exports of the module are used.
+    All(usize),
+}
+
+/// Used exports of each module in the graph.
+struct ExportUsage {
+    included: Vec<bool>,
+    all_used: Vec<bool>,
+    used: Vec<FxHashSet<String>>,
+}
+
+impl ExportUsage {
+    fn new(modules: &[TreeShakeModule<'_, '_>], live: &[LiveModuleDeclarations]) -> Self {
+        let len = modules.len();
+        let mut usage = Self {
+            included: vec![false; len],
+            all_used: vec![false; len],
+            used: vec![FxHashSet::default(); len],
+        };
+        let mut worklist = modules
+            .iter()
+            .enumerate()
+            .filter(|(_, module)| module.is_entry)
+            .map(|(index, _)| Usage::Include(index))
+            .collect::<Vec<_>>();
+
+        while let Some(item) = worklist.pop() {
+            match item {
+                Usage::Include(index) => {
+                    if usage.included[index] {
+                        continue;
+                    }
+                    usage.included[index] = true;
+                    let module = &modules[index];
+                    let live = &live[index];
+                    if module.is_entry || !module.module_record.has_module_syntax {
+                        worklist.push(Usage::All(index));
+                    }
+                    let resolve = |specifier: &str| module.resolved_modules.get(specifier).copied();
+                    worklist.extend(
+                        live.sources
+                            .iter()
+                            .filter_map(|source| resolve(source))
+                            .map(Usage::Include),
+                    );
+                    for entry in &module.module_record.import_entries {
+                        if entry.is_type || !live.import_locals.contains(&entry.local_name.span) {
+                            continue;
+                        }
+                        let Some(target) = resolve(&entry.module_request.name) else { continue };
+                        worklist.push(match &entry.import_name {
+                            ImportImportName::Name(name) => {
+                                Usage::Name(target, name.name.to_string())
+                            }
+                            ImportImportName::Default(_) => {
+                                Usage::Name(target, "default".to_string())
+                            }
+                            ImportImportName::NamespaceObject => Usage::All(target),
+                        });
+                    }
+                }
+                Usage::Name(index, name) => {
+                    if usage.all_used[index] || usage.used[index].contains(&name) {
+                        continue;
+                    }
+                    let module = &modules[index];
+                    let record = module.module_record;
+                    let live = &live[index];
+                    let resolve = |specifier: &str| module.resolved_modules.get(specifier).copied();
+
+                    let mut found = record
+                        .local_export_entries
+                        .iter()
+                        .any(|entry| export_name_matches(&entry.export_name, &name));
+                    for entry in &record.indirect_export_entries {
+                        if !export_name_matches(&entry.export_name, &name)
+                            || !live.statements.contains(&entry.statement_span)
+                        {
+                            continue;
+                        }
+                        found = true;
+                        let Some(target) =
+                            entry.module_request.as_ref().and_then(|m| resolve(&m.name))
+                        else {
+                            continue;
+                        };
+                        match &entry.import_name {
+                            ExportImportName::Name(import_name) => {
+                                worklist.push(Usage::Name(target, import_name.name.to_string()));
+                            }
+                            ExportImportName::All | ExportImportName::AllButDefault => {
+                                worklist.push(Usage::All(target));
+                            }
+                            ExportImportName::Null => {}
+                        }
+                    }
+                    // `export *` does not re-export `default`.
+                    if !found && name != "default" {
+                        for entry in &record.star_export_entries {
+                            if !live.statements.contains(&entry.statement_span) {
+                                continue;
+                            }
+                            if let Some(target) =
+                                entry.module_request.as_ref().and_then(|m| resolve(&m.name))
+                            {
+                                worklist.push(Usage::Name(target, name.clone()));
+                            }
+                        }
+                    }
+                    usage.used[index].insert(name);
+                }
+                Usage::All(index) => {
+                    if usage.all_used[index] {
+                        continue;
+                    }
+                    usage.all_used[index] = true;
+                    let module = &modules[index];
+                    let record = module.module_record;
+                    let live = &live[index];
+                    let entries =
+                        record.indirect_export_entries.iter().chain(&record.star_export_entries);
+                    for entry in entries {
+                        if !live.statements.contains(&entry.statement_span) {
+                            continue;
+                        }
+                        let Some(target) = entry
+                            .module_request
+                            .as_ref()
+                            .and_then(|m| module.resolved_modules.get(m.name.as_str()).copied())
+                        else {
+                            continue;
+                        };
+                        worklist.push(match &entry.import_name {
+                            ExportImportName::Name(import_name) => {
+                                Usage::Name(target, import_name.name.to_string())
+                            }
+                            _ => Usage::All(target),
+                        });
+                    }
+                }
+            }
+        }
+
+        usage
+    }
+}
+
+fn export_name_matches(export_name: &ExportExportName<'_>, name: &str) -> bool {
+    match export_name {
+        ExportExportName::Name(export_name) => export_name.name == name,
+        ExportExportName::Default(_) => name == "default",
+        ExportExportName::Null => false,
+    }
+}
+
+struct TreeShakeCtx<'s> {
+    scoping: &'s Scoping,
+    options: &'s TreeShakeOptions,
+}
+
+impl<'a> IsGlobalReference<'a> for TreeShakeCtx<'_> {
+    fn is_global_reference(&self, ident: &IdentifierReference<'a>) -> Option<bool> {
+        Some(ident.is_global_reference(self.scoping))
+    }
+}
+
+impl MayHaveSideEffectsContext<'_> for TreeShakeCtx<'_> {
+    fn annotations(&self) -> bool {
+        self.options.annotations
+    }
+
+    fn manual_pure_functions(&self, callee: &Expression) -> bool {
+        if let Expression::Identifier(ident) = callee {
+            return self.options.manual_pure_functions.iter().any(|name| ident.name == name);
+        }
+        false
+    }
+
+    fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        self.options.property_read_side_effects
+    }
+
+    fn unknown_global_side_effects(&self) -> bool {
+        self.options.unknown_global_side_effects
+    }
+}
@@ -2,8 +2,12 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, ManglePropertiesOptions, Mangler, NameCache,
+    PropertyMangler,
+};
 use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
 
 fn mangle(source_text: &str, options: MangleOptions) -> String {
@@ -15,6 +19,20 @@ fn mangle(source_text: &str, options: MangleOptions) -> String {
     Codegen::new().with_scoping(Some(symbol_table)).build(&program).code
 }
 
+fn mangle_properties(
+    source_text: &str,
+    options: ManglePropertiesOptions,
+    name_cache: NameCache,
+) -> (String, NameCache) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let name_cache =
+        PropertyMangler::new(options).with_name_cache(name_cache).build(&allocator, &mut program);
+    (Codegen::new().build(&program).code, name_cache)
+}
+
 #[test]
 fn direct_eval() {
     let source_text = "function foo() { let NO_MANGLE; eval('') }";
@@ -93,3 +111,133 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+#[test]
+fn properties() {
+    let options = || ManglePropertiesOptions::new(lazy_regex::Regex::new(r"^_\w+$").unwrap());
+    let cases = [
+        ("x._foo = 1; x._foo; x.bar", "x.e = 1;\nx.e;\nx.bar;\n"),
+        ("x._a; x._b; x._b", "x.t;\nx.e;\nx.e;\n"),
+        ("x = { _foo: 1, bar: 2 }", "x = {\n\te: 1,\n\tbar: 2\n};\n"),
+        ("x = { _foo }", "x = { e: _foo };\n"),
+        ("const { _foo } = x", "const { e: _foo } = x;\n"),
+        ("({ _foo = 1 } = x)", "({e: _foo = 1} = x);\n"),
+        ("x['_foo']; '_foo' in x", "x[\"e\"];\n\"e\" in x;\n"),
+        (
+            "class A { _foo() {} static _bar = 1; #_baz }",
+            "class A {\n\te() {}\n\tstatic t = 1;\n\t#_baz;\n}\n",
+        ),
+        // `e` is used by a property which is not mangled.
+        ("x._foo; x.e", "x.t;\nx.e;\n"),
+    ];
+    for (source_text, expected) in cases {
+        let (mangled, _) = mangle_properties(source_text, options(), NameCache::default());
+        assert_eq!(mangled, expected, "{source_text}");
+    }
+
+    let options_reserved =
+        ManglePropertiesOptions { reserved: vec!["_bar".to_string()], ..options() };
+    let (mangled, _) = mangle_properties("x._foo; x._bar", options_reserved, NameCache::default());
+    assert_eq!(mangled, "x.e;\nx._bar;\n");
+
+    let options_keep_quoted = ManglePropertiesOptions { keep_quoted: true, ..options() };
+    let (mangled, _) =
+        mangle_properties("x._foo; x['_foo']; x._bar", options_keep_quoted, NameCache::default());
+    assert_eq!(mangled, "x._foo;\nx[\"_foo\"];\nx.e;\n");
+}
+
+#[test]
+fn properties_name_cache() {
+    let options = || ManglePropertiesOptions::new(lazy_regex::Regex::new(r"^_\w+$").unwrap());
+    let (_, name_cache) =
+        mangle_properties("x._foo; x._bar; x._bar", options(), NameCache::default());
+    assert_eq!(name_cache.iter().collect::<Vec<_>>(), [("_bar", "e"), ("_foo", "t")]);
+
+    // Names from the cache are kept, new names do not collide with cached names.
+    let (mangled, name_cache) = mangle_properties("x._baz; x._baz; x._foo", options(), name_cache);
+    assert_eq!(mangled, "x.n;\nx.n;\nx.t;\n");
+    assert_eq!(
+        name_cache.iter().collect::<Vec<_>>(),
+        [("_bar", "e"), ("_baz", "n"), ("_foo", "t")],
+    );
+}
+
+#[test]
+fn name_cache() {
+    fn mangle_with_name_cache(source_text: &str, name_cache: &mut NameCache) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let program = ret.program;
+        let options = MangleOptions { top_level: true, ..MangleOptions::default() };
+        let scoping =
+            Mangler::new().with_options(options).with_name_cache(name_cache).build(&program);
+        Codegen::new().with_scoping(Some(scoping)).build(&program).code
+    }
+
+    let mut name_cache = NameCache::default();
+    let first = mangle_with_name_cache(
+        "function foo(a) { return a } let bar = foo(1); export { bar as baz }",
+        &mut name_cache,
+    );
+    assert_eq!(first, "function e(e) {\n\treturn e;\n}\nlet t = e(1);\nexport { t as baz };\n");
+    assert_eq!(name_cache.iter().collect::<Vec<_>>(), [("bar", "t"), ("foo", "e")]);
+
+    // A new, more frequently used symbol does not take over the names of the cached symbols.
+    let second = mangle_with_name_cache(
+        "let qux = 1; qux; qux; function foo(a) { return a + qux } let bar = foo(1); export { bar as baz }",
+        &mut name_cache,
+    );
+    assert_eq!(
+        second,
+        "let n = 1;\nn;\nn;\nfunction e(r) {\n\treturn r + n;\n}\nlet t = e(1);\nexport { t as baz };\n"
+    );
+    assert_eq!(name_cache.iter().collect::<Vec<_>>(), [("bar", "t"), ("foo", "e"), ("qux", "n")]);
+
+    // Works with `build_with_semantic`, and symbols which are not in the program stay in the cache.
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, "let bar = 1; bar", SourceType::mjs()).parse();
+    let mut semantic =
+        SemanticBuilder::new().with_scope_tree_child_ids(true).build(&ret.program).semantic;
+    Mangler::new()
+        .with_options(MangleOptions { top_level: true, ..MangleOptions::default() })
+        .with_name_cache(&mut name_cache)
+        .build_with_semantic(&mut semantic, &ret.program);
+    let code = Codegen::new().with_scoping(Some(semantic.into_scoping())).build(&ret.program).code;
+    assert_eq!(code, "let t = 1;\nt;\n");
+    assert_eq!(name_cache.iter().collect::<Vec<_>>(), [("bar", "t"), ("foo", "e"), ("qux", "n")]);
+}
+
+#[test]
+fn name_cache_shared_by_chunks() {
+    fn mangle_with_name_cache(
+        source_text: &str,
+        top_level: bool,
+        name_cache: &mut NameCache,
+    ) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::cjs()).parse();
+        let program = ret.program;
+        let options = MangleOptions { top_level, ..MangleOptions::default() };
+        let scoping =
+            Mangler::new().with_options(options).with_name_cache(name_cache).build(&program);
+        Codegen::new().with_scoping(Some(scoping)).build(&program).code
+    }
+
+    let mut name_cache = NameCache::default();
+    let a = mangle_with_name_cache("var foo = 1; foo;", true, &mut name_cache);
+    assert_eq!(a, "var e = 1;\ne;\n");
+
+    // Another chunk keeps the entries of the first one, and does not reuse their names.
+    let b = mangle_with_name_cache("var bar = 1; bar;", true, &mut name_cache);
+    assert_eq!(b, "var t = 1;\nt;\n");
+    assert_eq!(name_cache.iter().collect::<Vec<_>>(), [("bar", "t"), ("foo", "e")]);
+
+    // Without `top_level`, the cache is neither used nor changed.
+    let c = mangle_with_name_cache(
+        "var baz = 1; function f(bar) { return bar }",
+        false,
+        &mut name_cache,
+    );
+    assert_eq!(c, "var baz = 1;\nfunction f(e) {\n\treturn e;\n}\n");
+    assert_eq!(name_cache.iter().collect::<Vec<_>>(), [("bar", "t"), ("foo", "e")]);
+}
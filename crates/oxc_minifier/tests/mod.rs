@@ -3,6 +3,7 @@
 mod ecmascript;
 mod mangler;
 mod peephole;
+mod tree_shaker;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
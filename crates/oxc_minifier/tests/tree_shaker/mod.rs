@@ -0,0 +1,180 @@
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{TreeShakeModule, TreeShakeOptions, TreeShaker};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+/// Tree shakes `modules`, where each module is `(specifier, source_text)`.
+/// The first module is the entry.
+fn tree_shake(modules: &[(&str, &str)]) -> Vec<String> {
+    let allocator = Allocator::default();
+    let mut rets = modules
+        .iter()
+        .map(|(_, source_text)| Parser::new(&allocator, source_text, SourceType::mjs()).parse())
+        .collect::<Vec<_>>();
+    let resolved_modules = modules
+        .iter()
+        .enumerate()
+        .map(|(index, (specifier, _))| ((*specifier).to_string(), index))
+        .collect::<FxHashMap<_, _>>();
+    let mut tree_shake_modules = rets
+        .iter_mut()
+        .enumerate()
+        .map(|(index, ret)| TreeShakeModule {
+            program: &mut ret.program,
+            module_record: &ret.module_record,
+            resolved_modules: resolved_modules.clone(),
+            is_entry: index == 0,
+        })
+        .collect::<Vec<_>>();
+    let ret =
+        TreeShaker::new(&allocator, TreeShakeOptions::default()).build(&mut tree_shake_modules);
+    tree_shake_modules
+        .iter()
+        .zip(ret.included)
+        .map(|(module, included)| {
+            if !included {
+                return String::new();
+            }
+            Codegen::new()
+                .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+                .build(module.program)
+                .code
+        })
+        .collect()
+}
+
+#[track_caller]
+fn test(modules: &[(&str, &str)], expected: &[&str]) {
+    let result = tree_shake(modules);
+    let expected = expected
+        .iter()
+        .map(|source_text| {
+            let allocator = Allocator::default();
+            let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+            Codegen::new()
+                .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+                .build(&program)
+                .code
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn unused_exports() {
+    test(
+        &[
+            ("./main", "import { a } from './lib'; console.log(a)"),
+            ("./lib", "export const a = 1; export const b = 2; export function c() {}"),
+        ],
+        &["import { a } from './lib'; console.log(a)", "export const a = 1;"],
+    );
+}
+
+#[test]
+fn unused_export_dependencies() {
+    test(
+        &[
+            ("./main", "import { a } from './lib'; a()"),
+            (
+                "./lib",
+                "import { helper } from './helper';
+                 const unused = /* @__PURE__ */ helper();
+                 function internal() {}
+                 export function a() {}
+                 export function b() { internal(); return unused }",
+            ),
+            ("./helper", "export function helper() {}"),
+        ],
+        &["import { a } from './lib'; a()", "export function a() {}", ""],
+    );
+}
+
+#[test]
+fn side_effects_are_kept() {
+    test(
+        &[
+            ("./main", "import { a } from './lib'; a()"),
+            (
+                "./lib",
+                "import { helper } from './helper'; const unused = helper(); export function a() {}",
+            ),
+            ("./helper", "export function helper() {}"),
+        ],
+        &[
+            "import { a } from './lib'; a()",
+            "import { helper } from './helper'; const unused = helper(); export function a() {}",
+            "export function helper() {}",
+        ],
+    );
+    test(
+        &[
+            ("./main", "import { a } from './lib'; a"),
+            ("./lib", "export const a = 1; export const b = sideEffect(); export default foo();"),
+        ],
+        &["import { a } from './lib'; a", "export const a = 1; const b = sideEffect(); foo();"],
+    );
+}
+
+#[test]
+fn barrel_files() {
+    test(
+        &[
+            ("./main", "import { a, c } from './index'; a(c)"),
+            ("./index", "export { a, b } from './ab'; export * from './c'; export * from './d'"),
+            ("./ab", "export function a() {} export function b() {}"),
+            ("./c", "export const c = 1;"),
+            ("./d", "export const d = 1;"),
+        ],
+        &[
+            "import { a, c } from './index'; a(c)",
+            "export { a } from './ab'; export * from './c';",
+            "export function a() {}",
+            "export const c = 1;",
+            "",
+        ],
+    );
+}
+
+#[test]
+fn namespace_import() {
+    test(
+        &[
+            ("./main", "import * as lib from './lib'; console.log(lib)"),
+            ("./lib", "export const a = 1; export const b = 2;"),
+        ],
+        &[
+            "import * as lib from './lib'; console.log(lib)",
+            "export const a = 1; export const b = 2;",
+        ],
+    );
+}
+
+#[test]
+fn default_export() {
+    test(
+        &[
+            ("./main", "import { a } from './lib'; a"),
+            ("./lib", "export const a = 1; export default function foo() {}"),
+        ],
+        &["import { a } from './lib'; a", "export const a = 1;"],
+    );
+    test(
+        &[
+            ("./main", "import foo from './lib'; foo"),
+            ("./lib", "export const a = 1; export default function foo() {}"),
+        ],
+        &["import foo from './lib'; foo", "export default function foo() {}"],
+    );
+}
+
+#[test]
+fn external_modules() {
+    test(
+        &[("./main", "import { a } from 'external'; export { a }; export const b = 1")],
+        &["import { a } from 'external'; export { a }; export const b = 1"],
+    );
+}
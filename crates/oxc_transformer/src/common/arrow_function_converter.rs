@@ -629,7 +629,7 @@ impl<'a> ArrowFunctionConverter<'a> {
         unreachable!();
     }
 
-    fn transform_arrow_function_expression(
+    pub(crate) fn transform_arrow_function_expression(
         arrow_function_expr: ArenaBox<'a, ArrowFunctionExpression<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
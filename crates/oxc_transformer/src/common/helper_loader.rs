@@ -139,6 +139,7 @@ fn default_as_module_name() -> Cow<'static, str> {
 
 /// Available helpers.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[expect(clippy::enum_variant_names)]
 pub enum Helper {
     AwaitAsyncGenerator,
     AsyncGeneratorDelegate,
@@ -167,7 +168,27 @@ pub enum Helper {
     Decorate,
     DecorateParam,
     DecorateMetadata,
+    ApplyDecs2311,
     UsingCtx,
+    ClassCallCheck,
+    CreateClass,
+    Inherits,
+    CallSuper,
+    PossibleConstructorReturn,
+    AssertThisInitialized,
+    Tdz,
+    SlicedToArray,
+    ToArray,
+    ToConsumableArray,
+    Construct,
+    RegeneratorRuntime,
+    TaggedTemplateLiteral,
+    CreateForOfIteratorHelper,
+    DefineAccessor,
+    Typeof,
+    InteropRequireDefault,
+    InteropRequireWildcard,
+    WrapRegExp,
 }
 
 impl Helper {
@@ -200,12 +221,32 @@ impl Helper {
             Self::Decorate => "decorate",
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
+            Self::ApplyDecs2311 => "applyDecs2311",
             Self::UsingCtx => "usingCtx",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::CreateClass => "createClass",
+            Self::Inherits => "inherits",
+            Self::CallSuper => "callSuper",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::AssertThisInitialized => "assertThisInitialized",
+            Self::Tdz => "tdz",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::Construct => "construct",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::CreateForOfIteratorHelper => "createForOfIteratorHelper",
+            Self::DefineAccessor => "defineAccessor",
+            Self::Typeof => "typeof",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
+            Self::WrapRegExp => "wrapRegExp",
         }
     }
 
     pub const fn pure(self) -> bool {
-        matches!(self, Self::ClassPrivateFieldLooseKey)
+        matches!(self, Self::ClassPrivateFieldLooseKey | Self::WrapRegExp)
     }
 }
 
@@ -301,7 +342,8 @@ impl<'a> HelperLoaderStore<'a> {
     ) -> BoundIdentifier<'a> {
         let helper_name = helper.name();
 
-        let flag = if transform_ctx.source_type.is_module() {
+        // CommonJS transform converts `import`s to `require`s
+        let flag = if transform_ctx.source_type.is_module() && !transform_ctx.module.is_commonjs() {
             SymbolFlags::Import
         } else {
             SymbolFlags::FunctionScopedVariable
@@ -82,6 +82,12 @@ pub struct ModuleImportsStore<'a> {
     imports: RefCell<IndexMap<Atom<'a>, Vec<Import<'a>>>>,
 }
 
+impl Default for ModuleImportsStore<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Public methods
 impl<'a> ModuleImportsStore<'a> {
     /// Create new `ModuleImportsStore`.
@@ -177,16 +183,25 @@ impl<'a> ModuleImportsStore<'a> {
         transform_ctx: &TransformCtx<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        let stmts = self.take_require_statements(ctx);
+        transform_ctx.top_level_statements.insert_statements(stmts);
+    }
+
+    /// Create `require` statements for all imports in store, and empty the store.
+    ///
+    /// CommonJS transform runs after `import`s have been inserted into program,
+    /// so it inserts `require`s for helpers it uses itself.
+    pub fn take_require_statements(&self, ctx: &mut TraverseCtx<'a>) -> Vec<Statement<'a>> {
         let mut imports = self.imports.borrow_mut();
         if imports.is_empty() {
-            return;
+            return vec![];
         }
 
         let require_symbol_id = ctx.scoping().get_root_binding("require");
-        let stmts = imports
+        imports
             .drain(..)
-            .map(|(source, names)| Self::get_require(source, names, require_symbol_id, ctx));
-        transform_ctx.top_level_statements.insert_statements(stmts);
+            .map(|(source, names)| Self::get_require(source, names, require_symbol_id, ctx))
+            .collect()
     }
 
     fn get_import(
@@ -68,6 +68,12 @@ pub struct StatementInjectorStore<'a> {
     insertions: RefCell<FxHashMap<Address, Vec<AdjacentStatement<'a>>>>,
 }
 
+impl Default for StatementInjectorStore<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Public methods
 impl StatementInjectorStore<'_> {
     /// Create new `StatementInjectorStore`.
@@ -47,6 +47,12 @@ pub struct TopLevelStatementsStore<'a> {
     stmts: RefCell<Vec<Statement<'a>>>,
 }
 
+impl Default for TopLevelStatementsStore<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Public methods
 impl<'a> TopLevelStatementsStore<'a> {
     /// Create new `TopLevelStatementsStore`.
@@ -80,6 +80,12 @@ impl<'a> Declarators<'a> {
     }
 }
 
+impl Default for VarDeclarationsStore<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Public methods
 impl<'a> VarDeclarationsStore<'a> {
     /// Create new `VarDeclarationsStore`.
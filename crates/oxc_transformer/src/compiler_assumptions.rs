@@ -32,7 +32,6 @@ pub struct CompilerAssumptions {
     pub ignore_to_primitive_hint: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub iterable_is_array: bool,
 
     #[serde(default)]
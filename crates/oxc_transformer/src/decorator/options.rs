@@ -12,6 +12,14 @@ pub struct DecoratorOptions {
     #[serde(skip)]
     pub legacy: bool,
 
+    /// Enables the transform of TC39 standard decorators, following the `2023-11` version of the proposal.
+    ///
+    /// This also lowers `accessor` class fields. It has no effect when `legacy` is true.
+    ///
+    /// <https://github.com/tc39/proposal-decorators>
+    #[serde(skip)]
+    pub standard: bool,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -0,0 +1,1478 @@
+//! 2023-11 Decorators
+//!
+//! This plugin transforms TC39 standard decorators (the `2023-11` version of the proposal)
+//! by calling the `applyDecs2311` helper, and lowers `accessor` class fields into a private
+//! storage field plus a getter / setter pair.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class C {
+//!   @dec accessor x = 1;
+//!   @dec method() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _C, _initClass, _initProto, _init_x, _init_extra_x;
+//! class C {
+//!   static {
+//!     ({
+//!       e: [_init_x, _init_extra_x, _initProto],
+//!       c: [_C, _initClass]
+//!     } = babelHelpers.applyDecs2311(this, [dec], [[dec, 1, "x"], [dec, 2, "method"]]));
+//!   }
+//!   constructor() {
+//!     _init_extra_x(this);
+//!   }
+//!   #A = (_initProto(this), _init_x(this, 1));
+//!   get x() { return this.#A; }
+//!   set x(value) { this.#A = value; }
+//!   method() {}
+//!   static {
+//!     _initClass();
+//!   }
+//! }
+//! C = _C;
+//! ```
+//!
+//! ## Implementation
+//!
+//! Decorator expressions and computed keys which can't be safely re-evaluated are memoized into
+//! temp vars before the class, so that they're evaluated in the same order as in the original code.
+//!
+//! The class is rewritten before its body is entered, so the fields, accessors and static blocks
+//! it generates are lowered by the class properties and class static block transforms when the
+//! target requires.
+//!
+//! For a decorated class, references to the class name inside the class body are replaced with
+//! the binding which receives the decorated class, and the class binding is updated to the
+//! decorated class straight after the class.
+//!
+//! Implementation based on [@babel/plugin-proposal-decorators](https://github.com/babel/babel/blob/v7.26.10/packages/babel-helper-create-class-features-plugin/src/decorators.ts).
+//!
+//! ## References:
+//! * TC39 proposal: <https://github.com/tc39/proposal-decorators>
+//! * Babel plugin: <https://babeljs.io/docs/babel-plugin-proposal-decorators>
+
+use std::mem;
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ReferenceId, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{
+    BoundIdentifier, MaybeBoundIdentifier, Traverse, ast_operations::to_identifier,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::{
+        ChildScopeCollector,
+        ast_builder::{create_assignment, create_class_constructor},
+    },
+};
+
+// Element kinds, as encoded in the flags of `applyDecs2311`'s `memberDecs`.
+const FIELD: u32 = 0;
+const ACCESSOR: u32 = 1;
+const METHOD: u32 = 2;
+const GETTER: u32 = 3;
+const SETTER: u32 = 4;
+const STATIC: u32 = 8;
+const DECORATORS_HAVE_THIS: u32 = 16;
+
+pub struct StandardDecorator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Bindings which receive decorated classes, keyed by the references to the class name
+    /// which existed before the class was transformed.
+    /// These references are replaced with the bindings while traversing the class body.
+    class_references: FxHashMap<ReferenceId, BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> StandardDecorator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, class_references: FxHashMap::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for StandardDecorator<'a, '_> {
+    // `#[inline]` because this is a hot path and most statement lists contain no decorated class
+    #[inline]
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if stmts
+            .iter_mut()
+            .any(|stmt| get_class_of_statement(stmt).is_some_and(|class| needs_transform(class)))
+        {
+            self.transform_statements(stmts, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ClassExpression(class) if needs_transform(class) => {
+                self.transform_class_expression(expr, ctx);
+            }
+            Expression::Identifier(ident) if !self.class_references.is_empty() => {
+                let reference_id = ident.reference_id();
+                if let Some(binding) = self.class_references.remove(&reference_id) {
+                    let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id().unwrap();
+                    ctx.scoping_mut().delete_resolved_reference(symbol_id, reference_id);
+                    *expr = binding.create_spanned_read_expression(ident.span, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.class_references.is_empty() {
+            if let Some(id) = &class.id {
+                // References outside the class body keep referring to the class binding
+                for reference_id in ctx.scoping().get_resolved_reference_ids(id.symbol_id()) {
+                    self.class_references.remove(reference_id);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> StandardDecorator<'a, '_> {
+    /// Transform classes declared in `stmts`, inserting memoized expressions before each class,
+    /// and the update of the class binding after it.
+    ///
+    /// Done on entering the statement list, so the inserted statements are visited by other transforms.
+    fn transform_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let old_stmts = stmts.take_in(ctx.ast);
+        let mut new_stmts = ctx.ast.vec_with_capacity(old_stmts.len());
+        for mut stmt in old_stmts {
+            let Some(class) =
+                get_class_of_statement(&mut stmt).filter(|class| needs_transform(class))
+            else {
+                new_stmts.push(stmt);
+                continue;
+            };
+
+            // `export default @dec class {}` -> `export default class _default {}`,
+            // so there is a binding to update with the decorated class
+            if class.id.is_none() && !class.decorators.is_empty() {
+                let binding = ctx.generate_uid_in_current_scope("default", SymbolFlags::Class);
+                class.id = Some(binding.create_binding_identifier(ctx));
+            }
+
+            let (prelude, decorated_class_binding) = self.transform_class(class, ctx);
+
+            // `C = _C;`
+            let class_binding_update = decorated_class_binding.map(|decorated_class_binding| {
+                let class_binding = BoundIdentifier::from_binding_ident(class.id.as_ref().unwrap());
+                ctx.ast.statement_expression(
+                    SPAN,
+                    ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        class_binding.create_write_target(ctx),
+                        decorated_class_binding.create_read_expression(ctx),
+                    ),
+                )
+            });
+
+            new_stmts
+                .extend(prelude.into_iter().map(|expr| ctx.ast.statement_expression(SPAN, expr)));
+            new_stmts.push(stmt);
+            new_stmts.extend(class_binding_update);
+        }
+        *stmts = new_stmts;
+    }
+
+    /// `@dec class C {}` -> `(_dec = dec, class C {}, _C)`
+    fn transform_class_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { unreachable!() };
+        let (mut exprs, decorated_class_binding) = self.transform_class(class, ctx);
+        if exprs.is_empty() && decorated_class_binding.is_none() {
+            return;
+        }
+
+        exprs.push(expr.take_in(ctx.ast));
+        if let Some(decorated_class_binding) = decorated_class_binding {
+            exprs.push(decorated_class_binding.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter(exprs));
+    }
+
+    /// Transform decorators and `accessor` fields of a class.
+    ///
+    /// Returns the expressions which need to be evaluated before the class,
+    /// and the binding which receives the decorated class, if the class itself is decorated.
+    fn transform_class(
+        &mut self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Vec<Expression<'a>>, Option<BoundIdentifier<'a>>) {
+        let class_scope_id = class.scope_id();
+        let class_decorators = class.decorators.take_in(ctx.ast);
+        let has_element_decorators = class.body.body.iter().any(ClassElement::has_decorator);
+        let needs_helper = !class_decorators.is_empty() || has_element_decorators;
+
+        let mut state = ClassState {
+            prelude: vec![],
+            members: vec![],
+            instance_initializers: vec![],
+            static_initializers: vec![],
+            instance_brand: None,
+            private_names: class
+                .body
+                .body
+                .iter()
+                .filter_map(|element| element.property_key().and_then(PropertyKey::private_name))
+                .collect(),
+            next_private_name: 0,
+            // Element decorators are moved before the class, so all computed keys need to be too,
+            // to preserve evaluation order
+            memoize_keys: has_element_decorators,
+            class_scope_id,
+            static_block_scope_id: needs_helper
+                .then(|| ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock)),
+        };
+
+        let class_decorators = self.transform_decorators(class_decorators, &mut state, ctx);
+
+        // `class C extends (_B = B) {}`.
+        // Super class is memoized so it can be passed to the helper, which inherits its metadata.
+        let parent = if needs_helper && class.super_class.is_some() {
+            let super_class = class.super_class.as_mut().unwrap();
+            Some(if let Expression::Identifier(ident) = super_class {
+                MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                    .create_read_expression(ctx)
+            } else {
+                let binding =
+                    self.memoize("superClass", super_class.take_in(ctx.ast), &mut state, ctx);
+                *super_class = binding.create_read_expression(ctx);
+                binding.create_read_expression(ctx)
+            })
+        } else {
+            None
+        };
+
+        let decorated_class = (!class_decorators.elements.is_empty()).then(|| {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            let binding = self.ctx.var_declarations.create_uid_var(name, ctx);
+            let init_class = self.ctx.var_declarations.create_uid_var("initClass", ctx);
+            (binding, init_class)
+        });
+
+        let init_proto = needs_init_methods(&class.body.body, false)
+            .then(|| self.ctx.var_declarations.create_uid_var("initProto", ctx));
+        let init_static = needs_init_methods(&class.body.body, true)
+            .then(|| self.ctx.var_declarations.create_uid_var("initStatic", ctx));
+        if let Some(init_proto) = &init_proto {
+            state.instance_initializers.push(init_proto.clone());
+        }
+
+        // Transform class elements
+        let elements = class.body.body.take_in(ctx.ast);
+        let mut new_elements = ctx.ast.vec_with_capacity(elements.len() + 2);
+        for element in elements {
+            match element {
+                ClassElement::PropertyDefinition(prop)
+                    if prop.r#type == PropertyDefinitionType::PropertyDefinition
+                        && !prop.declare =>
+                {
+                    self.transform_property(prop, &mut new_elements, &mut state, ctx);
+                }
+                ClassElement::AccessorProperty(prop)
+                    if prop.r#type == AccessorPropertyType::AccessorProperty =>
+                {
+                    self.transform_accessor(prop, &mut new_elements, &mut state, ctx);
+                }
+                ClassElement::MethodDefinition(method)
+                    if !method.decorators.is_empty()
+                        && method.kind != MethodDefinitionKind::Constructor
+                        && method.value.body.is_some() =>
+                {
+                    self.transform_method(method, &mut new_elements, &mut state, ctx);
+                }
+                mut element => {
+                    if let ClassElement::MethodDefinition(method) = &mut element {
+                        if state.memoize_keys && method.computed {
+                            self.transform_key(&mut method.key, &mut state, ctx);
+                        }
+                    }
+                    new_elements.push(element);
+                }
+            }
+        }
+
+        // Extra initializers of the last instance field, or the initializers of decorated methods
+        // if there are no instance fields, are called in the constructor
+        if !state.instance_initializers.is_empty() {
+            insert_constructor_initializers(
+                &state.instance_initializers,
+                &mut new_elements,
+                class.super_class.is_some(),
+                class_scope_id,
+                ctx,
+            );
+        }
+
+        // `static { _init_extra_x(); }`
+        if !state.static_initializers.is_empty() {
+            let stmts = ctx.ast.vec_from_iter(
+                mem::take(&mut state.static_initializers)
+                    .into_iter()
+                    .map(|binding| create_call_statement(&binding, None, ctx)),
+            );
+            new_elements.push(create_static_block(stmts, class_scope_id, ctx));
+        }
+
+        if let Some(static_block_scope_id) = state.static_block_scope_id {
+            let mut stmts = ctx.ast.vec_with_capacity(2);
+            stmts.push(self.create_apply_decorators(
+                class_decorators,
+                parent,
+                decorated_class.as_ref(),
+                init_proto,
+                init_static.as_ref(),
+                &mut state,
+                ctx,
+            ));
+            // `_initStatic(this);`
+            if let Some(init_static) = &init_static {
+                stmts.push(create_call_statement(
+                    init_static,
+                    Some(ctx.ast.expression_this(SPAN)),
+                    ctx,
+                ));
+            }
+            new_elements.insert(
+                0,
+                ctx.ast.class_element_static_block_with_scope_id(
+                    SPAN,
+                    stmts,
+                    static_block_scope_id,
+                ),
+            );
+        }
+
+        // `static { _initClass(); }`
+        let decorated_class_binding = decorated_class.map(|(binding, init_class)| {
+            let stmts = ctx.ast.vec1(create_call_statement(&init_class, None, ctx));
+            new_elements.push(create_static_block(stmts, class_scope_id, ctx));
+            // References created by other transforms inside the class body must refer to
+            // the original class, so only existing references are recorded
+            if let Some(id) = &class.id {
+                for &reference_id in ctx.scoping().get_resolved_reference_ids(id.symbol_id()) {
+                    self.class_references.insert(reference_id, binding.clone());
+                }
+            }
+            binding
+        });
+
+        class.body.body = new_elements;
+
+        (state.prelude, decorated_class_binding)
+    }
+
+    /// `[_init_x, _init_extra_x] = babelHelpers.applyDecs2311(this, [], [[dec, 0, "x"]]).e;`
+    #[expect(clippy::too_many_arguments)]
+    fn create_apply_decorators(
+        &self,
+        class_decorators: Decorators<'a>,
+        parent: Option<Expression<'a>>,
+        decorated_class: Option<&(BoundIdentifier<'a>, BoundIdentifier<'a>)>,
+        init_proto: Option<BoundIdentifier<'a>>,
+        init_static: Option<&BoundIdentifier<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let members = mem::take(&mut state.members);
+
+        // The helper returns the values for elements in this order:
+        // static non-fields, instance non-fields, static fields, instance fields.
+        let mut outputs = vec![];
+        for group in 0..4 {
+            for member in members.iter().filter(|member| member.group == group) {
+                outputs.extend(member.outputs.iter().cloned());
+            }
+        }
+        outputs.extend(init_proto);
+        outputs.extend(init_static.cloned());
+
+        let member_decorators = ctx.ast.expression_array(
+            SPAN,
+            ctx.ast.vec_from_iter(
+                members.into_iter().map(|member| ArrayExpressionElement::from(member.entry)),
+            ),
+        );
+
+        // `babelHelpers.applyDecs2311(this, classDecs, memberDecs, classDecsHaveThis, instanceBrand, parentClass)`
+        let mut arguments = ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, class_decorators.elements)));
+        arguments.push(Argument::from(member_decorators));
+        let instance_brand = state.instance_brand.take();
+        if class_decorators.have_this || instance_brand.is_some() || parent.is_some() {
+            let value = f64::from(u8::from(class_decorators.have_this));
+            arguments.push(Argument::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                value,
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        if instance_brand.is_some() || parent.is_some() {
+            let brand = match instance_brand {
+                // `_ => #x in _`
+                Some(name) => {
+                    let scope_id = state.static_block_scope_id.unwrap();
+                    create_arrow_function(scope_id, &["_"], ctx, |params, ctx| {
+                        let object = params[0].create_read_expression(ctx);
+                        ctx.ast.expression_private_in(
+                            SPAN,
+                            ctx.ast.private_identifier(SPAN, name),
+                            object,
+                        )
+                    })
+                }
+                None => ctx.ast.void_0(SPAN),
+            };
+            arguments.push(Argument::from(brand));
+        }
+        if let Some(parent) = parent {
+            arguments.push(Argument::from(parent));
+        }
+        let call = self.ctx.helper_call_expr(Helper::ApplyDecs2311, SPAN, arguments, ctx);
+
+        let create_array_target = |bindings: &[BoundIdentifier<'a>], ctx: &mut TraverseCtx<'a>| {
+            let elements = ctx.ast.vec_from_iter(bindings.iter().map(|binding| {
+                Some(AssignmentTargetMaybeDefault::from(
+                    binding.create_target(ReferenceFlags::Write, ctx),
+                ))
+            }));
+            AssignmentTarget::from(
+                ctx.ast.assignment_target_pattern_array_assignment_target(SPAN, elements, None),
+            )
+        };
+
+        let (target, value) = match decorated_class {
+            Some((binding, init_class)) => {
+                let c_bindings = [binding.clone(), init_class.clone()];
+                if outputs.is_empty() {
+                    // `[_C, _initClass] = babelHelpers.applyDecs2311(...).c`
+                    let target = create_array_target(&c_bindings, ctx);
+                    (target, create_static_member(call, "c", ctx))
+                } else {
+                    // `({ e: [...], c: [_C, _initClass] } = babelHelpers.applyDecs2311(...))`
+                    let mut properties = ctx.ast.vec_with_capacity(2);
+                    for (name, bindings) in
+                        [("e", outputs.as_slice()), ("c", c_bindings.as_slice())]
+                    {
+                        let key = ctx.ast.property_key_static_identifier(SPAN, name);
+                        let target = create_array_target(bindings, ctx);
+                        properties.push(
+                            ctx.ast.assignment_target_property_assignment_target_property_property(
+                                SPAN,
+                                key,
+                                AssignmentTargetMaybeDefault::from(target),
+                                false,
+                            ),
+                        );
+                    }
+                    let target = AssignmentTarget::from(
+                        ctx.ast.assignment_target_pattern_object_assignment_target(
+                            SPAN, properties, None,
+                        ),
+                    );
+                    (target, call)
+                }
+            }
+            None if outputs.is_empty() => {
+                return ctx.ast.statement_expression(SPAN, call);
+            }
+            // `[...] = babelHelpers.applyDecs2311(...).e`
+            None => (create_array_target(&outputs, ctx), create_static_member(call, "e", ctx)),
+        };
+
+        ctx.ast.statement_expression(
+            SPAN,
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value),
+        )
+    }
+
+    /// Transform a field.
+    ///
+    /// * `@dec x = 1;` -> `x = _init_x(this, 1);`
+    /// * `@dec #x = 1;` -> `#x = _init_x(this, 1);`
+    ///
+    /// Pending initializers of previous elements are called before the field's own initializer:
+    /// `y = 2;` -> `y = (_init_extra_x(this), 2);`
+    fn transform_property(
+        &self,
+        mut prop: ArenaBox<'a, PropertyDefinition<'a>>,
+        elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_static = prop.r#static;
+        let decorators = prop.decorators.take_in(ctx.ast);
+        let mut value = prop.value.take();
+
+        let mut init_extra = None;
+        if decorators.is_empty() {
+            if state.memoize_keys && prop.computed {
+                self.transform_key(&mut prop.key, state, ctx);
+            }
+        } else {
+            let decorators = self.transform_decorators(decorators, state, ctx);
+            let key = self.transform_key(&mut prop.key, state, ctx);
+            let init = self.create_uid_var("init", &key.name, ctx);
+            let extra = self.create_uid_var("init_extra", &key.name, ctx);
+
+            // `_init_x(this, value)`
+            value = Some(create_initializer_call(&init, is_static, value, ctx));
+
+            let mut entry = vec![];
+            if let Some(name) = prop.key.private_name() {
+                // `_ => _.#x`, `(_, value) => _.#x = value`
+                let scope_id = state.static_block_scope_id.unwrap();
+                entry.push(create_private_getter_function(name, scope_id, ctx));
+                entry.push(create_private_setter_function(name, scope_id, ctx));
+                if !is_static {
+                    state.instance_brand.get_or_insert(name);
+                }
+            }
+            state.members.push(MemberDecorator::new(
+                decorators,
+                FIELD,
+                is_static,
+                key.create_name(ctx),
+                entry,
+                vec![init, extra.clone()],
+                ctx,
+            ));
+            init_extra = Some(extra);
+        }
+
+        prop.value = state.take_initializers(is_static, value, ctx);
+        if prop.value.is_some() {
+            // `x!: T` cannot have an initializer
+            prop.definite = false;
+        }
+        state.initializers_mut(is_static).extend(init_extra);
+        elements.push(ClassElement::PropertyDefinition(prop));
+    }
+
+    /// Transform an `accessor` field into a private storage field plus a getter / setter pair.
+    ///
+    /// * `accessor x = 1;` -> `#A = 1; get x() { return this.#A; } set x(value) { this.#A = value; }`
+    /// * `@dec accessor x = 1;` -> `#A = _init_x(this, 1); get x() { ... } set x(value) { ... }`
+    /// * `@dec accessor #x = 1;` ->
+    ///   `#A = _init_x(this, 1); get #x() { return _get_x(this); } set #x(value) { _set_x(this, value); }`
+    fn transform_accessor(
+        &self,
+        prop: ArenaBox<'a, AccessorProperty<'a>>,
+        elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let AccessorProperty {
+            span,
+            decorators,
+            mut key,
+            mut value,
+            computed,
+            r#static: is_static,
+            ..
+        } = prop.unbox();
+        let class_scope_id = state.class_scope_id;
+        let storage_name = state.generate_private_name(ctx);
+
+        let decorators =
+            (!decorators.is_empty()).then(|| self.transform_decorators(decorators, state, ctx));
+        let element_key = self.transform_key(&mut key, state, ctx);
+        let private_name = key.private_name();
+
+        let (getter, setter) = if let Some(decorators) = decorators {
+            let init = self.create_uid_var("init", &element_key.name, ctx);
+            let init_extra = self.create_uid_var("init_extra", &element_key.name, ctx);
+            value = Some(create_initializer_call(&init, is_static, value, ctx));
+
+            let (entry, outputs, getter, setter) = if let Some(name) = private_name {
+                // `_ => _.#A`, `(_, value) => _.#A = value`
+                let scope_id = state.static_block_scope_id.unwrap();
+                let entry = vec![
+                    create_private_getter_function(storage_name, scope_id, ctx),
+                    create_private_setter_function(storage_name, scope_id, ctx),
+                ];
+                if !is_static {
+                    state.instance_brand.get_or_insert(storage_name);
+                }
+                let get = self.create_uid_var("get", name.as_str(), ctx);
+                let set = self.create_uid_var("set", name.as_str(), ctx);
+                // `get #x() { return _get_x(this); }`, `set #x(value) { _set_x(this, value); }`
+                let getter = {
+                    let get = get.clone();
+                    create_getter(
+                        key.clone_in(ctx.ast.allocator),
+                        false,
+                        is_static,
+                        class_scope_id,
+                        ctx,
+                        move |ctx| {
+                            create_call(
+                                &get,
+                                (!is_static).then(|| ctx.ast.expression_this(SPAN)),
+                                None,
+                                ctx,
+                            )
+                        },
+                    )
+                };
+                let setter = {
+                    let set = set.clone();
+                    create_setter(key, false, is_static, class_scope_id, ctx, move |value, ctx| {
+                        create_call(
+                            &set,
+                            (!is_static).then(|| ctx.ast.expression_this(SPAN)),
+                            Some(value),
+                            ctx,
+                        )
+                    })
+                };
+                (entry, vec![init, get, set, init_extra.clone()], getter, setter)
+            } else {
+                let (getter, setter) = create_storage_accessors(
+                    key,
+                    computed,
+                    is_static,
+                    storage_name,
+                    &element_key,
+                    class_scope_id,
+                    ctx,
+                );
+                (vec![], vec![init, init_extra.clone()], getter, setter)
+            };
+
+            state.members.push(MemberDecorator::new(
+                decorators,
+                ACCESSOR,
+                is_static,
+                element_key.create_name(ctx),
+                entry,
+                outputs,
+                ctx,
+            ));
+            let storage = create_storage_field(span, storage_name, is_static, value, state, ctx);
+            state.initializers_mut(is_static).push(init_extra);
+            elements.push(storage);
+            (getter, setter)
+        } else {
+            elements.push(create_storage_field(span, storage_name, is_static, value, state, ctx));
+            create_storage_accessors(
+                key,
+                computed,
+                is_static,
+                storage_name,
+                &element_key,
+                class_scope_id,
+                ctx,
+            )
+        };
+
+        elements.push(getter);
+        elements.push(setter);
+    }
+
+    /// Transform a decorated method, getter or setter.
+    ///
+    /// * `@dec method() {}` -> `method() {}`
+    /// * `@dec #method() {}` -> `get #method() { return _call_method; }`
+    /// * `@dec get #x() {}` -> `get #x() { return _call_x(this); }`
+    /// * `@dec set #x(v) {}` -> `set #x(value) { _call_x(this, value); }`
+    ///
+    /// The method itself is passed to the helper, which returns the decorated method.
+    fn transform_method(
+        &self,
+        mut method: ArenaBox<'a, MethodDefinition<'a>>,
+        elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_static = method.r#static;
+        let decorators = method.decorators.take_in(ctx.ast);
+        let decorators = self.transform_decorators(decorators, state, ctx);
+        let key = self.transform_key(&mut method.key, state, ctx);
+        let kind = match method.kind {
+            MethodDefinitionKind::Method => METHOD,
+            MethodDefinitionKind::Get => GETTER,
+            MethodDefinitionKind::Set => SETTER,
+            MethodDefinitionKind::Constructor => unreachable!(),
+        };
+
+        let Some(name) = method.key.private_name() else {
+            state.members.push(MemberDecorator::new(
+                decorators,
+                kind,
+                is_static,
+                key.create_name(ctx),
+                vec![],
+                vec![],
+                ctx,
+            ));
+            elements.push(ClassElement::MethodDefinition(method));
+            return;
+        };
+
+        // Move the method into the static block as a function expression
+        let MethodDefinition { key: method_key, value: mut function, .. } = method.unbox();
+        let function_scope_id = function.scope_id();
+        ctx.scoping_mut().change_scope_parent_id(function_scope_id, state.static_block_scope_id);
+        ctx.scoping_mut()
+            .scope_flags_mut(function_scope_id)
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+        function.r#type = FunctionType::FunctionExpression;
+
+        if !is_static {
+            state.instance_brand.get_or_insert(name);
+        }
+        let call = self.create_uid_var("call", name.as_str(), ctx);
+        let class_scope_id = state.class_scope_id;
+        let this = |ctx: &TraverseCtx<'a>| Some(ctx.ast.expression_this(SPAN));
+        let element = match kind {
+            // `get #method() { return _call_method; }`
+            METHOD => {
+                let call = call.clone();
+                create_getter(method_key, false, is_static, class_scope_id, ctx, move |ctx| {
+                    call.create_read_expression(ctx)
+                })
+            }
+            // `get #x() { return _call_x(this); }`
+            GETTER => {
+                let call = call.clone();
+                create_getter(method_key, false, is_static, class_scope_id, ctx, move |ctx| {
+                    create_call(&call, this(ctx), None, ctx)
+                })
+            }
+            // `set #x(value) { _call_x(this, value); }`
+            _ => {
+                let call = call.clone();
+                create_setter(
+                    method_key,
+                    false,
+                    is_static,
+                    class_scope_id,
+                    ctx,
+                    move |value, ctx| create_call(&call, this(ctx), Some(value), ctx),
+                )
+            }
+        };
+
+        state.members.push(MemberDecorator::new(
+            decorators,
+            kind,
+            is_static,
+            key.create_name(ctx),
+            vec![Expression::FunctionExpression(function)],
+            vec![call],
+            ctx,
+        ));
+        elements.push(element);
+    }
+
+    /// Transform decorators into values passed to the helper, memoizing decorator expressions
+    /// which may have side effects.
+    ///
+    /// * `@dec` -> `dec`
+    /// * `@dec()` -> `_dec` (`_dec = dec()` is evaluated before the class)
+    /// * `@obj.dec` -> `obj, _dec` (`_dec = obj.dec`), and the decorators are called with `this`
+    fn transform_decorators(
+        &self,
+        decorators: ArenaVec<'a, Decorator<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Decorators<'a> {
+        let have_this = decorators.iter().any(|decorator| {
+            decorator
+                .expression
+                .as_member_expression()
+                .is_some_and(|member| !member.object().is_super())
+        });
+        let mut elements =
+            ctx.ast.vec_with_capacity(decorators.len() * (1 + usize::from(have_this)));
+        for decorator in decorators {
+            let (this, value) = match decorator.expression {
+                expr @ Expression::Identifier(_) => (None, expr),
+                expr if expr
+                    .as_member_expression()
+                    .is_some_and(|member| !member.object().is_super()) =>
+                {
+                    let mut member = expr.into_member_expression();
+                    let object = member.object_mut();
+                    let this = if let Expression::Identifier(ident) = object {
+                        MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                            .create_read_expression(ctx)
+                    } else {
+                        let binding = self.memoize("obj", object.take_in(ctx.ast), state, ctx);
+                        *object = binding.create_read_expression(ctx);
+                        binding.create_read_expression(ctx)
+                    };
+                    let binding = self.memoize("dec", Expression::from(member), state, ctx);
+                    (Some(this), binding.create_read_expression(ctx))
+                }
+                expr => {
+                    let binding = self.memoize("dec", expr, state, ctx);
+                    (None, binding.create_read_expression(ctx))
+                }
+            };
+            if have_this {
+                let this = this.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                elements.push(ArrayExpressionElement::from(this));
+            }
+            elements.push(ArrayExpressionElement::from(value));
+        }
+        Decorators { elements, have_this }
+    }
+
+    /// Get the name of a class element to pass to the helper, and memoize its key if it is
+    /// computed and not a literal.
+    ///
+    /// `[key()]` -> `[_computedKey]` (`_computedKey = babelHelpers.toPropertyKey(key())` is evaluated before the class)
+    fn transform_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ElementKey<'a> {
+        if let Some(name) = key.private_name() {
+            return ElementKey { name, memoized: None };
+        }
+        if let Some(name) = key.static_name() {
+            return ElementKey { name: ctx.ast.atom(&name), memoized: None };
+        }
+
+        let expr = key.take_in(ctx.ast).into_expression();
+        let arguments = ctx.ast.vec1(Argument::from(expr));
+        let expr = self.ctx.helper_call_expr(Helper::ToPropertyKey, SPAN, arguments, ctx);
+        let binding = self.memoize("computedKey", expr, state, ctx);
+        *key = PropertyKey::from(binding.create_read_expression(ctx));
+        ElementKey { name: Atom::from("computedKey"), memoized: Some(binding) }
+    }
+
+    /// Create a temp var with a name based on `prefix` and an element's name. e.g. `_init_x`.
+    fn create_uid_var(
+        &self,
+        prefix: &str,
+        name: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let name = to_identifier(format!("{prefix}_{name}"));
+        self.ctx.var_declarations.create_uid_var(&name, ctx)
+    }
+
+    /// Memoize `expr` into a temp var, evaluated before the class.
+    ///
+    /// `expr` is moved out of the class, so its child scopes are re-parented to the current scope.
+    fn memoize(
+        &self,
+        name: &str,
+        expr: Expression<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let mut collector = ChildScopeCollector::default();
+        collector.visit_expression(&expr);
+        let current_scope_id = ctx.current_scope_id();
+        for scope_id in collector.scope_ids {
+            ctx.scoping_mut().change_scope_parent_id(scope_id, Some(current_scope_id));
+        }
+
+        let binding = self.ctx.var_declarations.create_uid_var(name, ctx);
+        state.prelude.push(create_assignment(&binding, expr, ctx));
+        binding
+    }
+}
+
+/// State of the class being transformed.
+struct ClassState<'a> {
+    /// Expressions to evaluate before the class
+    prelude: Vec<Expression<'a>>,
+    /// Decorated elements
+    members: Vec<MemberDecorator<'a>>,
+    /// Initializers which need to be called before the next instance field is initialized
+    instance_initializers: Vec<BoundIdentifier<'a>>,
+    /// Initializers which need to be called before the next static field is initialized
+    static_initializers: Vec<BoundIdentifier<'a>>,
+    /// Name of a decorated instance private element, used to brand check instances
+    instance_brand: Option<Atom<'a>>,
+    /// Private names declared in the class
+    private_names: FxHashSet<Atom<'a>>,
+    next_private_name: u32,
+    /// `true` if computed keys need to be memoized
+    memoize_keys: bool,
+    class_scope_id: ScopeId,
+    /// Scope of the static block calling the helper. `None` if the helper is not required.
+    static_block_scope_id: Option<ScopeId>,
+}
+
+impl<'a> ClassState<'a> {
+    fn initializers_mut(&mut self, is_static: bool) -> &mut Vec<BoundIdentifier<'a>> {
+        if is_static { &mut self.static_initializers } else { &mut self.instance_initializers }
+    }
+
+    /// Prefix `value` with calls to pending initializers.
+    ///
+    /// `value` -> `(_init_extra_x(this), value)`
+    fn take_initializers(
+        &mut self,
+        is_static: bool,
+        value: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let initializers = self.initializers_mut(is_static);
+        if initializers.is_empty() {
+            return value;
+        }
+
+        let mut exprs = ctx.ast.vec_with_capacity(initializers.len() + 1);
+        for binding in initializers.drain(..) {
+            let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+            exprs.push(create_call(&binding, this, None, ctx));
+        }
+        exprs.push(value.unwrap_or_else(|| ctx.ast.void_0(SPAN)));
+        Some(ctx.ast.expression_sequence(SPAN, exprs))
+    }
+
+    /// Generate a private name which isn't used in the class. `A`, `B`, ..., `Z`, `AA`, `AB`, ...
+    fn generate_private_name(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        loop {
+            let mut n = self.next_private_name;
+            self.next_private_name += 1;
+            let mut name = String::new();
+            loop {
+                name.insert(0, char::from(b'A' + u8::try_from(n % 26).unwrap()));
+                if n < 26 {
+                    break;
+                }
+                n = n / 26 - 1;
+            }
+            let name = ctx.ast.atom(&name);
+            if self.private_names.insert(name) {
+                return name;
+            }
+        }
+    }
+}
+
+/// Decorators of a class or class element, as passed to the helper.
+struct Decorators<'a> {
+    /// Decorators, each preceded by its `this` value if `have_this` is `true`
+    elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+    have_this: bool,
+}
+
+/// Name of a class element.
+struct ElementKey<'a> {
+    /// Static name of the element (without `#` for private elements), or `computedKey` for
+    /// a memoized computed key. Generated bindings are named based on it.
+    name: Atom<'a>,
+    /// Binding of the memoized computed key
+    memoized: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a> ElementKey<'a> {
+    /// Create name to pass to the helper. `"x"` for `x` and `#x`, `_computedKey` for `[key()]`.
+    fn create_name(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match &self.memoized {
+            Some(binding) => binding.create_read_expression(ctx),
+            None => ctx.ast.expression_string_literal(SPAN, self.name, None),
+        }
+    }
+}
+
+/// A decorated class element.
+struct MemberDecorator<'a> {
+    /// `[decorators, flags, name, ...privateAccess]`
+    entry: Expression<'a>,
+    /// Order in which the helper processes the element.
+    /// 0 = static non-fields, 1 = instance non-fields, 2 = static fields, 3 = instance fields.
+    group: u8,
+    /// Bindings receiving the values the helper returns for this element
+    outputs: Vec<BoundIdentifier<'a>>,
+}
+
+impl<'a> MemberDecorator<'a> {
+    fn new(
+        decorators: Decorators<'a>,
+        kind: u32,
+        is_static: bool,
+        name: Expression<'a>,
+        private_access: Vec<Expression<'a>>,
+        outputs: Vec<BoundIdentifier<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Self {
+        let mut flags = kind;
+        if is_static {
+            flags |= STATIC;
+        }
+        if decorators.have_this {
+            flags |= DECORATORS_HAVE_THIS;
+        }
+
+        let mut decorator_elements = decorators.elements;
+        let decorators = if decorator_elements.len() == 1 {
+            match decorator_elements.pop().unwrap() {
+                ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => {
+                    unreachable!()
+                }
+                element => element.into_expression(),
+            }
+        } else {
+            ctx.ast.expression_array(SPAN, decorator_elements)
+        };
+
+        let mut elements = ctx.ast.vec_with_capacity(3 + private_access.len());
+        elements.push(ArrayExpressionElement::from(decorators));
+        elements.push(ArrayExpressionElement::from(ctx.ast.expression_numeric_literal(
+            SPAN,
+            f64::from(flags),
+            None,
+            NumberBase::Decimal,
+        )));
+        elements.push(ArrayExpressionElement::from(name));
+        elements.extend(private_access.into_iter().map(ArrayExpressionElement::from));
+
+        let is_field = kind == FIELD;
+        let group = match (is_static, is_field) {
+            (true, false) => 0,
+            (false, false) => 1,
+            (true, true) => 2,
+            (false, true) => 3,
+        };
+
+        Self { entry: ctx.ast.expression_array(SPAN, elements), group, outputs }
+    }
+}
+
+/// Returns `true` if class has decorators or `accessor` fields.
+fn needs_transform(class: &Class<'_>) -> bool {
+    !class.declare
+        && (!class.decorators.is_empty()
+            || class.body.body.iter().any(|element| {
+                element.has_decorator()
+                    || matches!(element, ClassElement::AccessorProperty(prop) if prop.r#type == AccessorPropertyType::AccessorProperty)
+            }))
+}
+
+/// Returns `true` if any decorated method, getter or setter with the given staticness exists,
+/// so the helper returns a function to call their extra initializers.
+fn needs_init_methods(elements: &[ClassElement<'_>], is_static: bool) -> bool {
+    elements.iter().any(|element| {
+        matches!(
+            element,
+            ClassElement::MethodDefinition(method)
+                if method.r#static == is_static
+                    && !method.decorators.is_empty()
+                    && method.kind != MethodDefinitionKind::Constructor
+                    && method.value.body.is_some()
+        )
+    })
+}
+
+/// Get class declared by a statement.
+fn get_class_of_statement<'a, 'b>(stmt: &'b mut Statement<'a>) -> Option<&'b mut Class<'a>> {
+    match stmt {
+        Statement::ClassDeclaration(class) => Some(class),
+        Statement::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+            Some(Declaration::ClassDeclaration(class)) => Some(class),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Insert calls to `initializers` in the class constructor, after `super()`.
+///
+/// * No constructor: `constructor() { _init_extra_x(this); }`
+/// * Base class: `constructor() { _init_extra_x(this); ... }`
+/// * Derived class: `super()` -> `_init_extra_x(super())`
+fn insert_constructor_initializers<'a>(
+    initializers: &[BoundIdentifier<'a>],
+    elements: &mut ArenaVec<'a, ClassElement<'a>>,
+    has_super_class: bool,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let constructor = elements.iter_mut().find_map(|element| match element {
+        ClassElement::MethodDefinition(method) if method.kind.is_constructor() => Some(method),
+        _ => None,
+    });
+
+    if let Some(constructor) = constructor {
+        let Some(body) = constructor.value.body.as_mut() else { return };
+        if has_super_class {
+            SuperCallWrapper { initializers, ctx }.visit_function_body(body);
+        } else {
+            let stmts = initializers.iter().map(|binding| {
+                create_call_statement(binding, Some(ctx.ast.expression_this(SPAN)), ctx)
+            });
+            body.statements.splice(0..0, stmts);
+        }
+        return;
+    }
+
+    let scope_id =
+        ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::Constructor);
+    let stmts = initializers
+        .iter()
+        .map(|binding| create_call_statement(binding, Some(ctx.ast.expression_this(SPAN)), ctx))
+        .collect::<Vec<_>>();
+    let constructor = create_class_constructor(stmts, has_super_class, scope_id, ctx);
+    elements.insert(0, constructor);
+}
+
+/// Visitor which wraps `super()` calls in a constructor with calls to initializers.
+///
+/// `super()` -> `_init_extra_x(super())`
+struct SuperCallWrapper<'a, 'b, 'c> {
+    initializers: &'b [BoundIdentifier<'a>],
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for SuperCallWrapper<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+        if let Expression::CallExpression(call) = expr {
+            if call.callee.is_super() {
+                for binding in self.initializers {
+                    let super_call = expr.take_in(self.ctx.ast);
+                    *expr = create_call(binding, Some(super_call), None, self.ctx);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {
+        // `super()` cannot be called in a nested function
+    }
+
+    #[inline]
+    fn visit_class(&mut self, _class: &mut Class<'a>) {
+        // `super()` in a nested class refers to that class
+    }
+}
+
+/// `#A = value`
+fn create_storage_field<'a>(
+    span: Span,
+    name: Atom<'a>,
+    is_static: bool,
+    value: Option<Expression<'a>>,
+    state: &mut ClassState<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let value = state.take_initializers(is_static, value, ctx);
+    ctx.ast.class_element_property_definition(
+        span,
+        PropertyDefinitionType::PropertyDefinition,
+        ctx.ast.vec(),
+        ctx.ast.property_key_private_identifier(SPAN, name),
+        NONE,
+        value,
+        false,
+        is_static,
+        false,
+        false,
+        false,
+        false,
+        false,
+        None,
+    )
+}
+
+/// `get x() { return this.#A; }`, `set x(value) { this.#A = value; }`
+fn create_storage_accessors<'a>(
+    key: PropertyKey<'a>,
+    computed: bool,
+    is_static: bool,
+    storage_name: Atom<'a>,
+    element_key: &ElementKey<'a>,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> (ClassElement<'a>, ClassElement<'a>) {
+    let getter_key = match &element_key.memoized {
+        Some(binding) => PropertyKey::from(binding.create_read_expression(ctx)),
+        None => key.clone_in(ctx.ast.allocator),
+    };
+    let getter = create_getter(getter_key, computed, is_static, class_scope_id, ctx, |ctx| {
+        create_this_private_field(storage_name, ctx)
+    });
+    let setter = create_setter(key, computed, is_static, class_scope_id, ctx, |value, ctx| {
+        ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(create_private_field(
+                ctx.ast.expression_this(SPAN),
+                storage_name,
+                ctx,
+            )),
+            value,
+        )
+    });
+    (getter, setter)
+}
+
+/// `get key() { return value; }`
+fn create_getter<'a>(
+    key: PropertyKey<'a>,
+    computed: bool,
+    is_static: bool,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+    value: impl FnOnce(&mut TraverseCtx<'a>) -> Expression<'a>,
+) -> ClassElement<'a> {
+    let scope_id =
+        ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::GetAccessor);
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::UniqueFormalParameters,
+        ctx.ast.vec(),
+        NONE,
+    );
+    let stmts = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value(ctx))));
+    create_method(MethodDefinitionKind::Get, key, computed, is_static, params, stmts, scope_id, ctx)
+}
+
+/// `set key(value) { body; }`
+fn create_setter<'a>(
+    key: PropertyKey<'a>,
+    computed: bool,
+    is_static: bool,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+    body: impl FnOnce(Expression<'a>, &mut TraverseCtx<'a>) -> Expression<'a>,
+) -> ClassElement<'a> {
+    let scope_id =
+        ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::SetAccessor);
+    let binding =
+        ctx.generate_binding(Atom::from("value"), scope_id, SymbolFlags::FunctionScopedVariable);
+    let param = ctx.ast.formal_parameter(
+        SPAN,
+        ctx.ast.vec(),
+        binding.create_binding_pattern(ctx),
+        None,
+        false,
+        false,
+    );
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::UniqueFormalParameters,
+        ctx.ast.vec1(param),
+        NONE,
+    );
+    let value = binding.create_read_expression(ctx);
+    let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body(value, ctx)));
+    create_method(MethodDefinitionKind::Set, key, computed, is_static, params, stmts, scope_id, ctx)
+}
+
+#[expect(clippy::too_many_arguments)]
+fn create_method<'a>(
+    kind: MethodDefinitionKind,
+    key: PropertyKey<'a>,
+    computed: bool,
+    is_static: bool,
+    params: ArenaBox<'a, FormalParameters<'a>>,
+    stmts: ArenaVec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let function = ctx.ast.alloc_function_with_scope_id(
+        SPAN,
+        FunctionType::FunctionExpression,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts)),
+        scope_id,
+    );
+    ctx.ast.class_element_method_definition(
+        SPAN,
+        MethodDefinitionType::MethodDefinition,
+        ctx.ast.vec(),
+        key,
+        function,
+        kind,
+        computed,
+        is_static,
+        false,
+        false,
+        None,
+    )
+}
+
+/// `_ => _.#name`
+fn create_private_getter_function<'a>(
+    name: Atom<'a>,
+    static_block_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    create_arrow_function(static_block_scope_id, &["_"], ctx, |params, ctx| {
+        let object = params[0].create_read_expression(ctx);
+        Expression::from(create_private_field(object, name, ctx))
+    })
+}
+
+/// `(_, value) => _.#name = value`
+fn create_private_setter_function<'a>(
+    name: Atom<'a>,
+    static_block_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    create_arrow_function(static_block_scope_id, &["_", "value"], ctx, |params, ctx| {
+        let object = params[0].create_read_expression(ctx);
+        let value = params[1].create_read_expression(ctx);
+        ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(create_private_field(object, name, ctx)),
+            value,
+        )
+    })
+}
+
+/// `(a, b) => body`
+fn create_arrow_function<'a>(
+    parent_scope_id: ScopeId,
+    param_names: &[&'static str],
+    ctx: &mut TraverseCtx<'a>,
+    body: impl FnOnce(&[BoundIdentifier<'a>], &mut TraverseCtx<'a>) -> Expression<'a>,
+) -> Expression<'a> {
+    let scope_id =
+        ctx.create_child_scope(parent_scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+    let bindings = param_names
+        .iter()
+        .map(|name| {
+            ctx.generate_binding(Atom::from(*name), scope_id, SymbolFlags::FunctionScopedVariable)
+        })
+        .collect::<Vec<_>>();
+    let items = ctx.ast.vec_from_iter(bindings.iter().map(|binding| {
+        ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            binding.create_binding_pattern(ctx),
+            None,
+            false,
+            false,
+        )
+    }));
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::ArrowFormalParameters,
+        items,
+        NONE,
+    );
+    let body = body(&bindings, ctx);
+    let body = ctx.ast.alloc_function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body)),
+    );
+    ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+        SPAN, true, false, NONE, params, NONE, body, scope_id, false,
+    )
+}
+
+/// `static { stmts }`
+fn create_static_block<'a>(
+    stmts: ArenaVec<'a, Statement<'a>>,
+    class_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+    ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, scope_id)
+}
+
+/// * Instance: `_init_x(this, value)`
+/// * Static: `_init_x(value)`
+fn create_initializer_call<'a>(
+    binding: &BoundIdentifier<'a>,
+    is_static: bool,
+    value: Option<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+    create_call(binding, this, value, ctx)
+}
+
+/// `callee(first, second)`
+fn create_call<'a>(
+    callee: &BoundIdentifier<'a>,
+    first: Option<Expression<'a>>,
+    second: Option<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let arguments = ctx.ast.vec_from_iter(first.into_iter().chain(second).map(Argument::from));
+    ctx.ast.expression_call(SPAN, callee.create_read_expression(ctx), NONE, arguments, false)
+}
+
+/// `callee(argument);`
+fn create_call_statement<'a>(
+    callee: &BoundIdentifier<'a>,
+    argument: Option<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    ctx.ast.statement_expression(SPAN, create_call(callee, argument, None, ctx))
+}
+
+/// `object.#name`
+fn create_private_field<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> MemberExpression<'a> {
+    ctx.ast.member_expression_private_field_expression(
+        SPAN,
+        object,
+        ctx.ast.private_identifier(SPAN, name),
+        false,
+    )
+}
+
+/// `this.#name`
+fn create_this_private_field<'a>(name: Atom<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    Expression::from(create_private_field(ctx.ast.expression_this(SPAN), name, ctx))
+}
+
+/// `object.name`
+fn create_static_member<'a>(
+    object: Expression<'a>,
+    name: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let property = ctx.ast.identifier_name(SPAN, name);
+    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+}
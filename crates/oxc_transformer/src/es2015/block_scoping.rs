@@ -0,0 +1,1270 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations to `var`.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let x = 1;
+//! {
+//!   let x = 2;
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var x = 1;
+//! {
+//!   var _x = 2;
+//! }
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babel.dev/docs/babel-plugin-transform-block-scoping).
+//!
+//! Bindings are hoisted to the closest function (or program) scope. Where hoisting a binding would
+//! make it clash with another binding of the same name, the binding is renamed.
+//!
+//! Loops whose per-iteration bindings are captured by closures have their body moved into a
+//! `_loop` function, so each iteration gets fresh bindings. `break`, `continue` and `return`
+//! statements in the loop body are converted to returns from `_loop`, and the completion is
+//! checked after the call:
+//!
+//! ```js
+//! var _ret = _loop(i);
+//! if (_ret === 1) break;
+//! if (_ret === 2) continue outer;
+//! if (_ret) return _ret.v;
+//! ```
+//!
+//! This transform runs as a separate pass on exit from `Program`, after all other transforms have
+//! run on function bodies, so it sees final scope tree.
+//!
+//! Assignments and updates to `const` bindings throw at runtime, like they do before the transform:
+//!
+//! ```js
+//! const c = 1;
+//! c = 2; // -> `2, babelHelpers.readOnlyError("c");`
+//! c += 2; // -> `c + 2, babelHelpers.readOnlyError("c");`
+//! c++; // -> `+c, babelHelpers.readOnlyError("c");`
+//! ```
+//!
+//! Differences from Babel:
+//!
+//! * Loops containing `yield`, `await`, `super` or `new.target` are not wrapped in a `_loop` function.
+//! * With `tdz` option, only reads of a binding before its declaration in the same function
+//!   throw. Babel also checks references in closures at runtime.
+//! * Only assignments to a `const` binding itself throw, not destructuring assignments or
+//!   `for...in` / `for...of` heads which assign to it.
+//!
+//! Reference:
+//! * Babel docs: <https://babeljs.io/docs/en/babel-plugin-transform-block-scoping>
+//! * Babel implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-block-scoping>
+
+use std::{cell::Cell, mem};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BlockScopingOptions {
+    /// Throw a `ReferenceError` when a binding is read before its declaration,
+    /// instead of reading `undefined`.
+    pub tdz: bool,
+}
+
+pub struct BlockScoping<'a, 'ctx> {
+    options: BlockScopingOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(options: BlockScopingOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for BlockScoping<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut visitor = BlockScopingVisitor::new(self.options, program.scope_id(), self.ctx, ctx);
+        visitor.visit_program(program);
+    }
+}
+
+/// State of a loop whose body is being moved into a `_loop` function.
+struct LoopContext<'a> {
+    /// Label of the loop
+    label: Option<Atom<'a>>,
+    /// Number of loops and `switch` statements inside loop body, which unlabeled `break` targets
+    breakable_depth: u32,
+    /// Number of loops inside loop body, which unlabeled `continue` targets
+    loop_depth: u32,
+    /// Labels of labeled statements inside loop body
+    labels: Vec<Atom<'a>>,
+    /// `true` if loop body contains a `break` out of this loop
+    has_break: bool,
+    /// `true` if loop body contains a `return`
+    has_return: bool,
+    /// `break` / `continue` statements out of loops outside this loop.
+    /// `true` for `break`. Index + 2 is the completion code which `_loop` returns.
+    outer_jumps: Vec<(bool, Atom<'a>)>,
+    /// Loop head bindings which are written to in loop body, and the `_loop` params replacing them.
+    /// Values need to be copied back to the loop head binding at end of each iteration.
+    copy_backs: Vec<(BoundIdentifier<'a>, BoundIdentifier<'a>)>,
+}
+
+/// State for replacing `this` and `arguments` in `_loop` functions.
+#[derive(Default)]
+struct ThisState<'a> {
+    /// Number of `_loop` functions we're inside
+    loop_function_depth: u32,
+    /// Scope where `_this` and `_arguments` are declared
+    scope_id: Option<ScopeId>,
+    this_binding: Option<BoundIdentifier<'a>>,
+    arguments_binding: Option<BoundIdentifier<'a>>,
+}
+
+/// Result of analysing a loop which needs to be wrapped in a `_loop` function.
+struct LoopAnalysis {
+    /// Loop head bindings which are written to in loop body
+    written: FxHashSet<SymbolId>,
+}
+
+struct BlockScopingVisitor<'a, 'c> {
+    options: BlockScopingOptions,
+    transform_ctx: &'c TransformCtx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Stack of scopes entered. `true` if scope is a var scope.
+    scope_stack: Vec<(ScopeId, bool)>,
+    /// New names for bindings which were renamed
+    renamed: FxHashMap<SymbolId, Atom<'a>>,
+    /// Loop head bindings, which references in loop body are rebound to `_loop` function params
+    rebound: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+    /// Statements to insert before the current statement
+    pending_statements: Vec<Statement<'a>>,
+    /// Label of labeled statement whose body is a loop, for the loop to pick up
+    current_label: Option<Atom<'a>>,
+    /// Loops being wrapped in `_loop` functions, within current function
+    loops: Vec<LoopContext<'a>>,
+    this_state: ThisState<'a>,
+    /// Number of loop bodies we're inside, within current function
+    loop_body_depth: u32,
+    /// Number of functions we're inside
+    function_depth: u32,
+    /// Bindings which are subject to TDZ checks, and function depth they're declared at
+    tdz_bindings: FxHashMap<SymbolId, u32>,
+    /// `const` bindings, and their original names
+    const_bindings: FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 'c> BlockScopingVisitor<'a, 'c> {
+    fn new(
+        options: BlockScopingOptions,
+        program_scope_id: ScopeId,
+        transform_ctx: &'c TransformCtx<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            options,
+            transform_ctx,
+            ctx,
+            scope_stack: vec![],
+            renamed: FxHashMap::default(),
+            rebound: FxHashMap::default(),
+            pending_statements: vec![],
+            current_label: None,
+            loops: vec![],
+            this_state: ThisState { scope_id: Some(program_scope_id), ..ThisState::default() },
+            loop_body_depth: 0,
+            function_depth: 0,
+            tdz_bindings: FxHashMap::default(),
+            const_bindings: FxHashMap::default(),
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for BlockScopingVisitor<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.enter_scope_id(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let outer_pending_statements = mem::take(&mut self.pending_statements);
+
+        let mut index = 0;
+        while index < stmts.len() {
+            self.visit_statement(&mut stmts[index]);
+            for stmt in self.pending_statements.drain(..) {
+                stmts.insert(index, stmt);
+                index += 1;
+            }
+            index += 1;
+        }
+
+        self.pending_statements = outer_pending_statements;
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => self.visit_loop(stmt),
+            Statement::BreakStatement(break_stmt) => {
+                let label = break_stmt.label.as_ref().map(|label| label.name);
+                if let Some(new_stmt) = self.transform_jump(true, label) {
+                    *stmt = new_stmt;
+                }
+            }
+            Statement::ContinueStatement(continue_stmt) => {
+                let label = continue_stmt.label.as_ref().map(|label| label.name);
+                if let Some(new_stmt) = self.transform_jump(false, label) {
+                    *stmt = new_stmt;
+                }
+            }
+            Statement::SwitchStatement(_) => {
+                if let Some(context) = self.loops.last_mut() {
+                    context.breakable_depth += 1;
+                }
+                walk_mut::walk_statement(self, stmt);
+                if let Some(context) = self.loops.last_mut() {
+                    context.breakable_depth -= 1;
+                }
+            }
+            Statement::LabeledStatement(labeled) => {
+                let label = labeled.label.name;
+                if labeled.body.is_iteration_statement() {
+                    self.current_label = Some(label);
+                }
+                if let Some(context) = self.loops.last_mut() {
+                    context.labels.push(label);
+                }
+                walk_mut::walk_statement(self, stmt);
+                if let Some(context) = self.loops.last_mut() {
+                    context.labels.pop();
+                }
+            }
+            Statement::VariableDeclaration(decl) => {
+                // `let x;` in a loop body -> `var x = void 0;`, so `x` does not retain its value
+                // from the previous iteration
+                let needs_init =
+                    decl.kind == VariableDeclarationKind::Let && self.loop_body_depth > 0;
+                walk_mut::walk_statement(self, stmt);
+                if needs_init {
+                    let Statement::VariableDeclaration(decl) = stmt else { unreachable!() };
+                    for declarator in &mut decl.declarations {
+                        if declarator.init.is_none() {
+                            declarator.init = Some(self.ctx.ast.void_0(SPAN));
+                        }
+                    }
+                }
+            }
+            _ => walk_mut::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_mut::walk_return_statement(self, stmt);
+        if !self.loops.is_empty() {
+            let argument = stmt.argument.take().unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+            stmt.argument = Some(self.create_return_value(argument));
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+            decl.kind = VariableDeclarationKind::Var;
+            for declarator in &mut decl.declarations {
+                declarator.kind = VariableDeclarationKind::Var;
+            }
+        }
+        walk_mut::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        let loops = mem::take(&mut self.loops);
+        let loop_body_depth = mem::replace(&mut self.loop_body_depth, 0);
+        let this_state = mem::take(&mut self.this_state);
+        self.function_depth += 1;
+
+        walk_mut::walk_function(self, func, flags);
+
+        self.function_depth -= 1;
+        self.this_state = this_state;
+        self.loop_body_depth = loop_body_depth;
+        self.loops = loops;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        // Arrow functions inherit `this` and `arguments`, so `this_state` is not reset
+        let loops = mem::take(&mut self.loops);
+        let loop_body_depth = mem::replace(&mut self.loop_body_depth, 0);
+        self.function_depth += 1;
+
+        walk_mut::walk_arrow_function_expression(self, arrow);
+
+        self.function_depth -= 1;
+        self.loop_body_depth = loop_body_depth;
+        self.loops = loops;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // `this` in class property initializers and static blocks refers to the class or instance
+        let loops = mem::take(&mut self.loops);
+        let this_state = mem::take(&mut self.this_state);
+        self.function_depth += 1;
+
+        walk_mut::walk_class(self, class);
+
+        self.function_depth -= 1;
+        self.this_state = this_state;
+        self.loops = loops;
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) if self.this_state.loop_function_depth > 0 => {
+                let span = this.span;
+                let binding = self.get_this_binding();
+                *expr = binding.create_spanned_read_expression(span, self.ctx);
+            }
+            Expression::Identifier(ident) => {
+                if self.this_state.loop_function_depth > 0
+                    && ident.name == "arguments"
+                    && self.ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+                {
+                    let span = ident.span;
+                    let reference_id = ident.reference_id();
+                    self.ctx
+                        .scoping_mut()
+                        .delete_root_unresolved_reference("arguments", reference_id);
+                    let binding = self.get_arguments_binding();
+                    *expr = binding.create_spanned_read_expression(span, self.ctx);
+                } else if self.options.tdz {
+                    self.visit_identifier_reference(ident);
+                    self.check_tdz(expr);
+                } else {
+                    self.visit_identifier_reference(ident);
+                }
+            }
+            Expression::AssignmentExpression(_) | Expression::UpdateExpression(_) => {
+                // Look up the binding before references are renamed or rebound
+                let const_name = self.get_const_violation(expr);
+                walk_mut::walk_expression(self, expr);
+                if let Some(name) = const_name {
+                    self.transform_const_violation(expr, name);
+                }
+            }
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(&name) = self.renamed.get(&ident.symbol_id()) {
+            ident.name = name;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let Some(symbol_id) = self.ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+
+        if let Some(binding) = self.rebound.get(&symbol_id) {
+            let scoping = self.ctx.scoping_mut();
+            scoping.get_reference_mut(reference_id).set_symbol_id(binding.symbol_id);
+            scoping.delete_resolved_reference(symbol_id, reference_id);
+            scoping.add_resolved_reference(binding.symbol_id, reference_id);
+            ident.name = binding.name;
+        } else if let Some(&name) = self.renamed.get(&symbol_id) {
+            ident.name = name;
+        }
+    }
+}
+
+impl<'a> BlockScopingVisitor<'a, '_> {
+    /// Enter scope.
+    ///
+    /// If it's a block scope, hoist its `let` and `const` bindings to the closest var scope.
+    fn enter_scope_id(&mut self, scope_id: ScopeId) {
+        let is_var = self.ctx.scoping().scope_flags(scope_id).is_var();
+        let symbol_ids = block_scoped_bindings(scope_id, self.ctx.scoping());
+        for &symbol_id in &symbol_ids {
+            let scoping = self.ctx.scoping();
+            if scoping.symbol_flags(symbol_id).is_const_variable() {
+                let name = self.ctx.ast.atom(scoping.symbol_name(symbol_id));
+                self.const_bindings.insert(symbol_id, name);
+            }
+        }
+
+        if is_var {
+            for symbol_id in symbol_ids {
+                *self.ctx.scoping_mut().symbol_flags_mut(symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                if self.options.tdz {
+                    self.tdz_bindings.insert(symbol_id, self.function_depth);
+                }
+            }
+        } else {
+            let var_scope_id = self.current_var_scope_id();
+            for symbol_id in symbol_ids {
+                self.hoist_binding(symbol_id, scope_id, var_scope_id);
+            }
+        }
+
+        self.scope_stack.push((scope_id, is_var));
+    }
+
+    /// Move binding from block scope to var scope, renaming it if it would clash with another binding.
+    fn hoist_binding(&mut self, symbol_id: SymbolId, scope_id: ScopeId, var_scope_id: ScopeId) {
+        let scoping = self.ctx.scoping();
+        let mut name = self.ctx.ast.atom(scoping.symbol_name(symbol_id));
+        let parent_scope_id = scoping.scope_parent_id(scope_id).unwrap();
+        if scoping.find_binding(parent_scope_id, &name).is_some()
+            || scoping.root_unresolved_references().contains_key(name.as_str())
+        {
+            let new_name = self.ctx.generate_uid_name(&name);
+            self.ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &new_name);
+            self.renamed.insert(symbol_id, new_name);
+            name = new_name;
+        }
+
+        let scoping = self.ctx.scoping_mut();
+        scoping.move_binding(scope_id, var_scope_id, &name);
+        scoping.set_symbol_scope_id(symbol_id, var_scope_id);
+        *scoping.symbol_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+
+        if self.options.tdz {
+            self.tdz_bindings.insert(symbol_id, self.function_depth);
+        }
+    }
+
+    fn current_scope_id(&self) -> ScopeId {
+        self.scope_stack.last().unwrap().0
+    }
+
+    fn current_var_scope_id(&self) -> ScopeId {
+        self.scope_stack.iter().rev().find(|(_, is_var)| *is_var).unwrap().0
+    }
+
+    /// `x` -> `babelHelpers.tdz("x")`, if `x` is read before its declaration.
+    fn check_tdz(&mut self, expr: &mut Expression<'a>) {
+        let Expression::Identifier(ident) = expr else { unreachable!() };
+        let reference_id = ident.reference_id();
+        let reference = self.ctx.scoping().get_reference(reference_id);
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        if !reference.is_read() || reference.is_write() {
+            return;
+        }
+        if self.tdz_bindings.get(&symbol_id) != Some(&self.function_depth) {
+            return;
+        }
+        // Ignore references created by other transforms, which have no span
+        if ident.span.is_empty() || ident.span.end > self.ctx.scoping().symbol_span(symbol_id).start
+        {
+            return;
+        }
+
+        let span = ident.span;
+        let name = self.ctx.ast.expression_string_literal(SPAN, ident.name, None);
+        self.ctx.scoping_mut().delete_resolved_reference(symbol_id, reference_id);
+        *expr = self.transform_ctx.helper_call_expr(
+            Helper::Tdz,
+            span,
+            self.ctx.ast.vec1(Argument::from(name)),
+            self.ctx,
+        );
+    }
+
+    /// Get original name of the `const` binding assigned to by assignment or update expression `expr`.
+    fn get_const_violation(&self, expr: &Expression<'a>) -> Option<Atom<'a>> {
+        let ident = match expr {
+            Expression::AssignmentExpression(assign) => match &assign.left {
+                AssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+                _ => return None,
+            },
+            Expression::UpdateExpression(update) => match &update.argument {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.const_bindings.get(&symbol_id).copied()
+    }
+
+    /// Replace assignment or update expression `expr` with one which throws,
+    /// as it assigns to a `const` binding.
+    ///
+    /// * `c = x` -> `(x, babelHelpers.readOnlyError("c"))`
+    /// * `c ||= x` -> `c || (x, babelHelpers.readOnlyError("c"))`
+    /// * `c += x` -> `(c + x, babelHelpers.readOnlyError("c"))`
+    /// * `c++` -> `(+c, babelHelpers.readOnlyError("c"))`
+    fn transform_const_violation(&mut self, expr: &mut Expression<'a>, name: Atom<'a>) {
+        let span = expr.span();
+        let error = self.transform_ctx.helper_call_expr(
+            Helper::ReadOnlyError,
+            SPAN,
+            self.ctx
+                .ast
+                .vec1(Argument::from(self.ctx.ast.expression_string_literal(SPAN, name, None))),
+            self.ctx,
+        );
+
+        *expr = match expr.take_in(self.ctx.ast) {
+            Expression::AssignmentExpression(assign) => {
+                let assign = assign.unbox();
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = assign.left else {
+                    unreachable!()
+                };
+                if assign.operator == AssignmentOperator::Assign {
+                    self.delete_reference(&ident);
+                    self.ctx.ast.expression_sequence(
+                        span,
+                        self.ctx.ast.vec_from_array([assign.right, error]),
+                    )
+                } else if let Some(operator) = assign.operator.to_logical_operator() {
+                    let left = self.read_reference(ident);
+                    let right = self.ctx.ast.expression_sequence(
+                        SPAN,
+                        self.ctx.ast.vec_from_array([assign.right, error]),
+                    );
+                    self.ctx.ast.expression_logical(span, left, operator, right)
+                } else {
+                    let operator = assign.operator.to_binary_operator().unwrap();
+                    let left = self.read_reference(ident);
+                    let value = self.ctx.ast.expression_binary(SPAN, left, operator, assign.right);
+                    self.ctx
+                        .ast
+                        .expression_sequence(span, self.ctx.ast.vec_from_array([value, error]))
+                }
+            }
+            Expression::UpdateExpression(update) => {
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) =
+                    update.unbox().argument
+                else {
+                    unreachable!()
+                };
+                let argument = self.read_reference(ident);
+                let plus = self.ctx.ast.expression_unary(SPAN, UnaryOperator::UnaryPlus, argument);
+                self.ctx.ast.expression_sequence(span, self.ctx.ast.vec_from_array([plus, error]))
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    fn delete_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let scoping = self.ctx.scoping_mut();
+        if let Some(symbol_id) = scoping.get_reference(reference_id).symbol_id() {
+            scoping.delete_resolved_reference(symbol_id, reference_id);
+        }
+    }
+
+    /// Convert write reference `ident` to a read-only identifier expression.
+    fn read_reference(&mut self, ident: ArenaBox<'a, IdentifierReference<'a>>) -> Expression<'a> {
+        *self.ctx.scoping_mut().get_reference_mut(ident.reference_id()).flags_mut() =
+            ReferenceFlags::Read;
+        Expression::Identifier(ident)
+    }
+
+    fn visit_loop(&mut self, stmt: &mut Statement<'a>) {
+        let label = self.current_label.take();
+        if let Some(context) = self.loops.last_mut() {
+            context.breakable_depth += 1;
+            context.loop_depth += 1;
+        }
+
+        if let Some(analysis) = self.analyze_loop(stmt) {
+            self.transform_loop(stmt, label, &analysis);
+        } else {
+            self.loop_body_depth += 1;
+            walk_mut::walk_statement(self, stmt);
+            self.loop_body_depth -= 1;
+        }
+
+        if let Some(context) = self.loops.last_mut() {
+            context.breakable_depth -= 1;
+            context.loop_depth -= 1;
+        }
+    }
+
+    /// Determine if loop needs to be wrapped in a `_loop` function.
+    ///
+    /// It does if any binding declared in loop head or body is referenced in a closure in loop body.
+    fn analyze_loop(&self, stmt: &Statement<'a>) -> Option<LoopAnalysis> {
+        let scoping = self.ctx.scoping();
+        let (head_symbols, body) = match stmt {
+            Statement::ForStatement(for_stmt) => {
+                (block_scoped_bindings(for_stmt.scope_id(), scoping), &for_stmt.body)
+            }
+            Statement::ForInStatement(for_in) => {
+                (block_scoped_bindings(for_in.scope_id(), scoping), &for_in.body)
+            }
+            Statement::ForOfStatement(for_of) => {
+                (block_scoped_bindings(for_of.scope_id(), scoping), &for_of.body)
+            }
+            Statement::WhileStatement(while_stmt) => (vec![], &while_stmt.body),
+            Statement::DoWhileStatement(do_while) => (vec![], &do_while.body),
+            _ => unreachable!(),
+        };
+
+        let mut analyzer = LoopAnalyzer {
+            scoping,
+            candidates: head_symbols.iter().copied().collect(),
+            head_symbols: &head_symbols,
+            written: FxHashSet::default(),
+            function_depth: 0,
+            non_arrow_function_depth: 0,
+            nested_loop_depth: 0,
+            captured: false,
+            unsupported: false,
+        };
+        analyzer.visit_statement(body);
+
+        if analyzer.captured && !analyzer.unsupported {
+            Some(LoopAnalysis { written: analyzer.written })
+        } else {
+            None
+        }
+    }
+
+    /// Move loop body into a `_loop` function.
+    ///
+    /// ```js
+    /// for (let i = 0; i < 3; i++) { fns.push(() => i); }
+    /// ```
+    /// ->
+    /// ```js
+    /// var _loop = function (i) { fns.push(() => i); };
+    /// for (var i = 0; i < 3; i++) { _loop(i); }
+    /// ```
+    fn transform_loop(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        label: Option<Atom<'a>>,
+        analysis: &LoopAnalysis,
+    ) {
+        let outer_scope_id = self.current_scope_id();
+
+        // Enter loop head scope, and visit loop head. This hoists loop head bindings.
+        let head_scope_id = match stmt {
+            Statement::ForStatement(for_stmt) => Some(for_stmt.scope_id()),
+            Statement::ForInStatement(for_in) => Some(for_in.scope_id()),
+            Statement::ForOfStatement(for_of) => Some(for_of.scope_id()),
+            _ => None,
+        };
+        let head_symbols = head_scope_id
+            .map(|scope_id| block_scoped_bindings(scope_id, self.ctx.scoping()))
+            .unwrap_or_default();
+        if let Some(scope_id) = head_scope_id {
+            self.enter_scope_id(scope_id);
+        }
+
+        let body = match stmt {
+            Statement::ForStatement(for_stmt) => {
+                if let Some(init) = &mut for_stmt.init {
+                    self.visit_for_statement_init(init);
+                }
+                if let Some(test) = &mut for_stmt.test {
+                    self.visit_expression(test);
+                }
+                if let Some(update) = &mut for_stmt.update {
+                    self.visit_expression(update);
+                }
+                &mut for_stmt.body
+            }
+            Statement::ForInStatement(for_in) => {
+                self.visit_for_statement_left(&mut for_in.left);
+                self.visit_expression(&mut for_in.right);
+                &mut for_in.body
+            }
+            Statement::ForOfStatement(for_of) => {
+                self.visit_for_statement_left(&mut for_of.left);
+                self.visit_expression(&mut for_of.right);
+                &mut for_of.body
+            }
+            Statement::WhileStatement(while_stmt) => {
+                self.visit_expression(&mut while_stmt.test);
+                &mut while_stmt.body
+            }
+            Statement::DoWhileStatement(do_while) => {
+                self.visit_expression(&mut do_while.test);
+                &mut do_while.body
+            }
+            _ => unreachable!(),
+        };
+
+        // Loop body's scope becomes scope of `_loop` function.
+        // If body is not a block, insert a scope for it.
+        let body_parent_scope_id = head_scope_id.unwrap_or(outer_scope_id);
+        let loop_scope_id = if let Statement::BlockStatement(block) = body {
+            block.scope_id()
+        } else {
+            self.ctx.insert_scope_below_statement_from_scope_id(
+                body,
+                body_parent_scope_id,
+                ScopeFlags::Function,
+            )
+        };
+        {
+            let strict_mode =
+                self.ctx.scoping().scope_flags(outer_scope_id) & ScopeFlags::StrictMode;
+            let scoping = self.ctx.scoping_mut();
+            scoping.change_scope_parent_id(loop_scope_id, Some(outer_scope_id));
+            *scoping.scope_flags_mut(loop_scope_id) = ScopeFlags::Function | strict_mode;
+        }
+
+        // Create `_loop` function params for loop head bindings
+        let mut params = self.ctx.ast.vec_with_capacity(head_symbols.len());
+        let mut arguments = self.ctx.ast.vec_with_capacity(head_symbols.len());
+        let mut copy_backs = vec![];
+        for &symbol_id in &head_symbols {
+            let name = self.ctx.ast.atom(self.ctx.scoping().symbol_name(symbol_id));
+            let head_binding = BoundIdentifier::new(name, symbol_id);
+            let param_binding = if analysis.written.contains(&symbol_id) {
+                let param_binding = self.ctx.generate_uid(
+                    &name,
+                    loop_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                copy_backs.push((head_binding.clone(), param_binding.clone()));
+                param_binding
+            } else {
+                self.ctx.generate_binding(name, loop_scope_id, SymbolFlags::FunctionScopedVariable)
+            };
+            params.push(
+                self.ctx
+                    .ast
+                    .plain_formal_parameter(SPAN, param_binding.create_binding_pattern(self.ctx)),
+            );
+            arguments.push(Argument::from(head_binding.create_read_expression(self.ctx)));
+            self.rebound.insert(symbol_id, param_binding);
+        }
+
+        // Visit loop body
+        if self.this_state.loop_function_depth == 0 {
+            self.this_state.scope_id = Some(self.current_var_scope_id());
+        }
+        self.this_state.loop_function_depth += 1;
+        let loop_body_depth = mem::replace(&mut self.loop_body_depth, 0);
+        let outer_pending_statements = mem::take(&mut self.pending_statements);
+        self.loops.push(LoopContext {
+            label,
+            breakable_depth: 0,
+            loop_depth: 0,
+            labels: vec![],
+            has_break: false,
+            has_return: false,
+            outer_jumps: vec![],
+            copy_backs,
+        });
+
+        let is_block = matches!(body, Statement::BlockStatement(_));
+        if is_block {
+            self.visit_statement(body);
+        } else {
+            self.enter_scope_id(loop_scope_id);
+            self.visit_statement(body);
+            self.leave_scope();
+        }
+
+        let context = self.loops.pop().unwrap();
+        let body_prefix = mem::replace(&mut self.pending_statements, outer_pending_statements);
+        self.loop_body_depth = loop_body_depth;
+        self.this_state.loop_function_depth -= 1;
+        for symbol_id in &head_symbols {
+            self.rebound.remove(symbol_id);
+        }
+
+        // Create `_loop` function
+        let mut stmts = self.ctx.ast.vec_from_iter(body_prefix);
+        match body.take_in(self.ctx.ast) {
+            Statement::BlockStatement(block) => stmts.extend(block.unbox().body),
+            body => stmts.push(body),
+        }
+        if !matches!(
+            stmts.last(),
+            Some(Statement::ReturnStatement(_) | Statement::ThrowStatement(_))
+        ) {
+            // `i = _i;`
+            for (head_binding, param_binding) in &context.copy_backs {
+                let assignment = self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    head_binding.create_write_target(self.ctx),
+                    param_binding.create_read_expression(self.ctx),
+                );
+                stmts.push(self.ctx.ast.statement_expression(SPAN, assignment));
+            }
+        }
+        let params = self.ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let function_body = self.ctx.ast.alloc_function_body(SPAN, self.ctx.ast.vec(), stmts);
+        let loop_function = self.ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(function_body),
+            loop_scope_id,
+        );
+
+        let var_scope_id = self.current_var_scope_id();
+        let loop_binding =
+            self.ctx.generate_uid("loop", var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // Insert `var _this = this, _arguments = arguments;` before outermost loop
+        if self.this_state.loop_function_depth == 0 {
+            self.insert_this_declarations();
+        }
+
+        // `var _loop = function (i) { ... };`
+        let loop_decl = self
+            .create_var_declaration(&loop_binding, Expression::FunctionExpression(loop_function));
+        self.pending_statements.push(loop_decl);
+
+        // Replace loop body with call to `_loop`
+        let call = self.ctx.ast.expression_call(
+            SPAN,
+            loop_binding.create_read_expression(self.ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let body_stmts = self.create_loop_body(call, context, var_scope_id);
+        let block_scope_id = self.ctx.create_child_scope(body_parent_scope_id, ScopeFlags::empty());
+        *body = Statement::BlockStatement(self.ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            body_stmts,
+            block_scope_id,
+        ));
+
+        if head_scope_id.is_some() {
+            self.leave_scope();
+        }
+    }
+
+    /// Create body of loop which calls `_loop` function.
+    ///
+    /// * `_loop(i);`
+    /// * `var _ret = _loop(i); if (_ret === 1) break; if (_ret === 2) continue outer; if (_ret) return _ret.v;`
+    fn create_loop_body(
+        &mut self,
+        call: Expression<'a>,
+        context: LoopContext<'a>,
+        var_scope_id: ScopeId,
+    ) -> ArenaVec<'a, Statement<'a>> {
+        if !context.has_break && !context.has_return && context.outer_jumps.is_empty() {
+            return self.ctx.ast.vec1(self.ctx.ast.statement_expression(SPAN, call));
+        }
+
+        let ret_binding =
+            self.ctx.generate_uid("ret", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut stmts = self.ctx.ast.vec();
+        stmts.push(self.create_var_declaration(&ret_binding, call));
+
+        // `if (_ret === 1) break;`
+        if context.has_break {
+            let break_stmt = self.ctx.ast.statement_break(SPAN, None);
+            stmts.push(self.create_completion_check(&ret_binding, 1, break_stmt));
+        }
+
+        // `if (_ret === 2) continue outer;`
+        for (index, (is_break, label)) in context.outer_jumps.into_iter().enumerate() {
+            let jump = self.transform_jump(is_break, Some(label)).unwrap_or_else(|| {
+                let label = Some(self.ctx.ast.label_identifier(SPAN, label));
+                if is_break {
+                    self.ctx.ast.statement_break(SPAN, label)
+                } else {
+                    self.ctx.ast.statement_continue(SPAN, label)
+                }
+            });
+            #[expect(clippy::cast_possible_truncation)]
+            let code = index as u32 + 2;
+            stmts.push(self.create_completion_check(&ret_binding, code, jump));
+        }
+
+        // `if (_ret) return _ret.v;`
+        if context.has_return {
+            let value = Expression::from(self.ctx.ast.member_expression_static(
+                SPAN,
+                ret_binding.create_read_expression(self.ctx),
+                self.ctx.ast.identifier_name(SPAN, "v"),
+                false,
+            ));
+            let value = if self.loops.is_empty() { value } else { self.create_return_value(value) };
+            let return_stmt = self.ctx.ast.statement_return(SPAN, Some(value));
+            let test = ret_binding.create_read_expression(self.ctx);
+            stmts.push(self.ctx.ast.statement_if(SPAN, test, return_stmt, None));
+        }
+
+        stmts
+    }
+
+    /// `if (_ret === <code>) <stmt>`
+    fn create_completion_check(
+        &mut self,
+        ret_binding: &BoundIdentifier<'a>,
+        code: u32,
+        stmt: Statement<'a>,
+    ) -> Statement<'a> {
+        let test = self.ctx.ast.expression_binary(
+            SPAN,
+            ret_binding.create_read_expression(self.ctx),
+            BinaryOperator::StrictEquality,
+            self.ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(code),
+                None,
+                NumberBase::Decimal,
+            ),
+        );
+        self.ctx.ast.statement_if(SPAN, test, stmt, None)
+    }
+
+    /// Transform `break` or `continue` statement inside a `_loop` function.
+    ///
+    /// Returns `None` if statement should not be transformed.
+    ///
+    /// * `break` out of the loop -> `return 1;`
+    /// * `continue` the loop -> `return;`
+    /// * `break` / `continue` to an outer loop -> `return 2;` etc
+    fn transform_jump(&mut self, is_break: bool, label: Option<Atom<'a>>) -> Option<Statement<'a>> {
+        let context = self.loops.last_mut()?;
+        let targets_loop = match label {
+            None if is_break => context.breakable_depth == 0,
+            None => context.loop_depth == 0,
+            Some(label) => {
+                if context.labels.contains(&label) {
+                    return None;
+                }
+                context.label == Some(label)
+            }
+        };
+
+        let argument = if targets_loop {
+            if is_break {
+                context.has_break = true;
+                Some(1)
+            } else {
+                None
+            }
+        } else if let Some(label) = label {
+            let index = context
+                .outer_jumps
+                .iter()
+                .position(|&jump| jump == (is_break, label))
+                .unwrap_or_else(|| {
+                    context.outer_jumps.push((is_break, label));
+                    context.outer_jumps.len() - 1
+                });
+            #[expect(clippy::cast_possible_truncation)]
+            Some(index as u32 + 2)
+        } else {
+            return None;
+        };
+
+        let argument = if let Some(code) = argument {
+            Some(self.ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(code),
+                None,
+                NumberBase::Decimal,
+            ))
+        } else if context.copy_backs.is_empty() {
+            None
+        } else {
+            // `continue` -> `return void (i = _i);`
+            let assignments = self.ctx.ast.vec_from_iter(context.copy_backs.iter().map(
+                |(head_binding, param_binding)| {
+                    self.ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        head_binding.create_write_target(self.ctx),
+                        param_binding.create_read_expression(self.ctx),
+                    )
+                },
+            ));
+            let assignments = self.ctx.ast.expression_sequence(SPAN, assignments);
+            Some(self.ctx.ast.expression_unary(SPAN, UnaryOperator::Void, assignments))
+        };
+        Some(self.ctx.ast.statement_return(SPAN, argument))
+    }
+
+    /// `value` -> `{ v: value }`
+    fn create_return_value(&mut self, value: Expression<'a>) -> Expression<'a> {
+        if let Some(context) = self.loops.last_mut() {
+            context.has_return = true;
+        }
+        let property = self.ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            self.ctx.ast.property_key_static_identifier(SPAN, "v"),
+            value,
+            false,
+            false,
+            false,
+        );
+        self.ctx.ast.expression_object(SPAN, self.ctx.ast.vec1(property))
+    }
+
+    fn get_this_binding(&mut self) -> BoundIdentifier<'a> {
+        if self.this_state.this_binding.is_none() {
+            let scope_id = self.this_state.scope_id.unwrap();
+            self.this_state.this_binding =
+                Some(self.ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable));
+        }
+        self.this_state.this_binding.clone().unwrap()
+    }
+
+    fn get_arguments_binding(&mut self) -> BoundIdentifier<'a> {
+        if self.this_state.arguments_binding.is_none() {
+            let scope_id = self.this_state.scope_id.unwrap();
+            self.this_state.arguments_binding = Some(self.ctx.generate_uid(
+                "arguments",
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            ));
+        }
+        self.this_state.arguments_binding.clone().unwrap()
+    }
+
+    /// `var _this = this, _arguments = arguments;`
+    fn insert_this_declarations(&mut self) {
+        let this_binding = self.this_state.this_binding.take();
+        let arguments_binding = self.this_state.arguments_binding.take();
+        let mut declarations = self.ctx.ast.vec();
+        if let Some(this_binding) = this_binding {
+            declarations.push(self.ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                this_binding.create_binding_pattern(self.ctx),
+                Some(self.ctx.ast.expression_this(SPAN)),
+                false,
+            ));
+        }
+        if let Some(arguments_binding) = arguments_binding {
+            let arguments = self.ctx.create_unbound_ident_expr(
+                SPAN,
+                Atom::from("arguments"),
+                ReferenceFlags::Read,
+            );
+            declarations.push(self.ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                arguments_binding.create_binding_pattern(self.ctx),
+                Some(arguments),
+                false,
+            ));
+        }
+        if !declarations.is_empty() {
+            self.pending_statements.push(Statement::VariableDeclaration(
+                self.ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    declarations,
+                    false,
+                ),
+            ));
+        }
+    }
+
+    /// `var <binding> = <init>;`
+    fn create_var_declaration(
+        &self,
+        binding: &BoundIdentifier<'a>,
+        init: Expression<'a>,
+    ) -> Statement<'a> {
+        let declarator = self.ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(self.ctx),
+            Some(init),
+            false,
+        );
+        Statement::VariableDeclaration(self.ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            self.ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+}
+
+/// Get `let` and `const` bindings in a scope, in declaration order.
+fn block_scoped_bindings(scope_id: ScopeId, scoping: &Scoping) -> Vec<SymbolId> {
+    let mut symbol_ids = scoping
+        .get_bindings(scope_id)
+        .values()
+        .copied()
+        .filter(|&symbol_id| {
+            scoping.symbol_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable)
+        })
+        .collect::<Vec<_>>();
+    symbol_ids.sort_unstable();
+    symbol_ids
+}
+
+/// Visitor which determines if any bindings declared in a loop are captured by closures in the loop body.
+struct LoopAnalyzer<'s, 'h> {
+    scoping: &'s Scoping,
+    /// Bindings declared in the loop
+    candidates: FxHashSet<SymbolId>,
+    head_symbols: &'h [SymbolId],
+    /// Loop head bindings written to in loop body
+    written: FxHashSet<SymbolId>,
+    function_depth: u32,
+    non_arrow_function_depth: u32,
+    nested_loop_depth: u32,
+    /// `true` if any binding declared in the loop is referenced in a closure
+    captured: bool,
+    /// `true` if loop body contains something which cannot be moved into a function
+    unsupported: bool,
+}
+
+impl LoopAnalyzer<'_, '_> {
+    fn visit_nested_loop(&mut self, f: impl FnOnce(&mut Self)) {
+        self.nested_loop_depth += 1;
+        f(self);
+        self.nested_loop_depth -= 1;
+    }
+}
+
+impl<'a> Visit<'a> for LoopAnalyzer<'_, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        // Bindings of nested loops are handled when transforming those loops
+        if self.function_depth == 0 && self.nested_loop_depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            if !self.scoping.scope_flags(scope_id).is_var() {
+                self.candidates.extend(block_scoped_bindings(scope_id, self.scoping));
+            }
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.scoping.get_reference(ident.reference_id());
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        if self.function_depth > 0 && self.candidates.contains(&symbol_id) {
+            self.captured = true;
+        }
+        if reference.is_write() && self.head_symbols.contains(&symbol_id) {
+            self.written.insert(symbol_id);
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.non_arrow_function_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.non_arrow_function_depth -= 1;
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.function_depth += 1;
+        self.non_arrow_function_depth += 1;
+        walk::walk_class(self, class);
+        self.non_arrow_function_depth -= 1;
+        self.function_depth -= 1;
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        self.visit_nested_loop(|this| walk::walk_for_statement(this, stmt));
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        self.visit_nested_loop(|this| walk::walk_for_in_statement(this, stmt));
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await && self.function_depth == 0 {
+            self.unsupported = true;
+        }
+        self.visit_nested_loop(|this| walk::walk_for_of_statement(this, stmt));
+    }
+
+    fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
+        self.visit_nested_loop(|this| walk::walk_while_statement(this, stmt));
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
+        self.visit_nested_loop(|this| walk::walk_do_while_statement(this, stmt));
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.function_depth == 0 {
+            self.unsupported = true;
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.function_depth == 0 {
+            self.unsupported = true;
+        }
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        if self.non_arrow_function_depth == 0 {
+            self.unsupported = true;
+        }
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        if self.non_arrow_function_depth == 0 {
+            self.unsupported = true;
+        }
+    }
+}
@@ -0,0 +1,452 @@
+//! ES2015: Classes
+//! Transform of `this` and `super` in class methods and constructors.
+
+use oxc_allocator::{CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{
+    VisitMut,
+    walk_mut::{walk_arrow_function_expression, walk_expression, walk_return_statement},
+};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator, UpdateOperator};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    es2015::Spread,
+    utils::ast_builder::create_assignment,
+};
+
+/// Converts the body of a class method or constructor, which is about to be moved out of the class.
+///
+/// * `super.prop` -> `_superPropGet(_Class, "prop", this, 1)`
+/// * `super.method(a)` -> `_superPropGet(_Class, "method", this, 3)([a])`
+/// * `super.prop = value` -> `_superPropSet(_Class, "prop", value, this, 1, 1)`
+///
+/// In constructors of derived classes, `this` is replaced by the value returned from the super class
+/// constructor:
+///
+/// * `super(a)` -> `_this = _callSuper(this, _Class, [a])`
+/// * `this` -> `_this`
+/// * `return value;` -> `return _possibleConstructorReturn(_this, value);`
+///
+/// Nested functions are not visited, as they have their own `this`, and `super` cannot appear in them
+/// (except in object methods, where it refers to the object's prototype). Arrow functions are visited.
+pub(super) struct MethodBodyConverter<'a, 'v> {
+    transform_ctx: &'v TransformCtx<'a>,
+    ctx: &'v mut TraverseCtx<'a>,
+    /// Binding for the class constructor function
+    class_binding: &'v BoundIdentifier<'a>,
+    /// `true` for static methods
+    is_static: bool,
+    /// `_this` binding, if converting constructor of a derived class
+    this_binding: Option<BoundIdentifier<'a>>,
+    /// Scope of the method function, where temp vars are declared
+    scope_id: ScopeId,
+    /// Temp vars to declare at top of the function body
+    temp_bindings: Vec<BoundIdentifier<'a>>,
+    /// Depth of arrow functions we're currently in
+    arrow_depth: u32,
+    /// Lower spread arguments of `super` calls, see [`Spread::arguments_to_array`]
+    spread: bool,
+}
+
+impl<'a, 'v> MethodBodyConverter<'a, 'v> {
+    pub(super) fn new(
+        class_binding: &'v BoundIdentifier<'a>,
+        is_static: bool,
+        this_binding: Option<BoundIdentifier<'a>>,
+        scope_id: ScopeId,
+        spread: bool,
+        transform_ctx: &'v TransformCtx<'a>,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        let temp_bindings = this_binding.iter().cloned().collect();
+        Self {
+            transform_ctx,
+            ctx,
+            class_binding,
+            is_static,
+            this_binding,
+            scope_id,
+            temp_bindings,
+            arrow_depth: 0,
+            spread,
+        }
+    }
+
+    /// Convert params and body of `func`.
+    ///
+    /// Returns `true` if a `var` statement for temp vars was inserted at top of the function body.
+    pub(super) fn convert(mut self, func: &mut Function<'a>) -> bool {
+        self.visit_formal_parameters(&mut func.params);
+        let Some(body) = &mut func.body else { return false };
+        self.visit_function_body(body);
+
+        if self.temp_bindings.is_empty() {
+            return false;
+        }
+        let ctx = self.ctx;
+        let declarations = ctx.ast.vec_from_iter(self.temp_bindings.iter().map(|binding| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            )
+        }));
+        let stmt = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+        body.statements.insert(0, stmt);
+        true
+    }
+}
+
+impl<'a> VisitMut<'a> for MethodBodyConverter<'a, '_> {
+    #[inline] // `#[inline]` because is a no-op
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {
+        // Nested functions have their own `this`, and `super` can only refer to an object literal
+    }
+
+    #[inline] // `#[inline]` because is a no-op
+    fn visit_class(&mut self, _class: &mut Class<'a>) {
+        // Class has already been transformed if it contains anything we need to visit
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    /// `return value;` -> `return _possibleConstructorReturn(_this, value);`
+    /// `return;` -> `return _assertThisInitialized(_this);`
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_return_statement(self, stmt);
+
+        if self.arrow_depth > 0 {
+            return;
+        }
+        let Some(this_binding) = &self.this_binding else { return };
+        let this = Argument::from(this_binding.create_read_expression(self.ctx));
+        stmt.argument = Some(if let Some(argument) = stmt.argument.take() {
+            let arguments = self.ctx.ast.vec_from_array([this, Argument::from(argument)]);
+            self.helper_call(Helper::PossibleConstructorReturn, arguments)
+        } else {
+            self.helper_call(Helper::AssertThisInitialized, self.ctx.ast.vec1(this))
+        });
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) => {
+                if let Some(this_binding) = &self.this_binding {
+                    *expr = this_binding.create_spanned_read_expression(this.span, self.ctx);
+                }
+            }
+            Expression::CallExpression(call) if call.callee.is_super() => {
+                self.transform_super_call(expr);
+            }
+            Expression::CallExpression(call) if is_super_member(&call.callee) => {
+                self.transform_super_member_call(expr);
+            }
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                let property = self.ctx.ast.expression_string_literal(
+                    member.property.span,
+                    member.property.name,
+                    None,
+                );
+                *expr = self.create_super_prop_get(member.span, property, false);
+            }
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                self.visit_expression(&mut member.expression);
+                let span = member.span;
+                let property = member.expression.take_in(self.ctx.ast);
+                *expr = self.create_super_prop_get(span, property, false);
+            }
+            Expression::AssignmentExpression(assign)
+                if assign.left.as_member_expression().is_some_and(|m| m.object().is_super()) =>
+            {
+                self.transform_super_assignment(expr);
+            }
+            Expression::UpdateExpression(update)
+                if update
+                    .argument
+                    .as_member_expression()
+                    .is_some_and(|m| m.object().is_super()) =>
+            {
+                self.transform_super_update(expr);
+            }
+            _ => walk_expression(self, expr),
+        }
+    }
+}
+
+impl<'a> MethodBodyConverter<'a, '_> {
+    /// `super(a, b)` -> `_this = _callSuper(this, _Class, [a, b])`
+    fn transform_super_call(&mut self, expr: &mut Expression<'a>) {
+        let Expression::CallExpression(call) = expr else { unreachable!() };
+        for argument in &mut call.arguments {
+            self.visit_argument(argument);
+        }
+
+        // `super()` is only legal in constructors of derived classes
+        let Some(this_binding) = self.this_binding.clone() else { return };
+
+        let span = call.span;
+        let mut arguments = self.ctx.ast.vec_from_array([
+            Argument::from(self.ctx.ast.expression_this(SPAN)),
+            Argument::from(self.class_binding.create_read_expression(self.ctx)),
+        ]);
+        if !call.arguments.is_empty() {
+            arguments.push(Argument::from(self.arguments_to_array(&mut call.arguments)));
+        }
+        let value = self.helper_call(Helper::CallSuper, arguments);
+        *expr = self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            this_binding.create_write_target(self.ctx),
+            value,
+        );
+    }
+
+    /// `super.method(a, b)` -> `_superPropGet(_Class, "method", this, 3)([a, b])`
+    fn transform_super_member_call(&mut self, expr: &mut Expression<'a>) {
+        let Expression::CallExpression(call) = expr else { unreachable!() };
+        for argument in &mut call.arguments {
+            self.visit_argument(argument);
+        }
+
+        let callee_span = call.callee.span();
+        let property = match call.callee.take_in(self.ctx.ast) {
+            Expression::StaticMemberExpression(member) => self.ctx.ast.expression_string_literal(
+                member.property.span,
+                member.property.name,
+                None,
+            ),
+            Expression::ComputedMemberExpression(member) => {
+                let mut property = member.unbox().expression;
+                self.visit_expression(&mut property);
+                property
+            }
+            _ => unreachable!(),
+        };
+        call.callee = self.create_super_prop_get(callee_span, property, true);
+        let array = self.arguments_to_array(&mut call.arguments);
+        call.arguments = self.ctx.ast.vec1(Argument::from(array));
+    }
+
+    /// * `super.prop = value` -> `_superPropSet(_Class, "prop", value, this, 1, 1)`
+    /// * `super.prop += value`
+    ///   -> `_superPropSet(_Class, "prop", _superPropGet(_Class, "prop", this, 1) + value, this, 1, 1)`
+    /// * `super.prop &&= value`
+    ///   -> `_superPropGet(_Class, "prop", this, 1) && _superPropSet(_Class, "prop", value, this, 1, 1)`
+    fn transform_super_assignment(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr.take_in(self.ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, left, mut right } = assign.unbox();
+        self.visit_expression(&mut right);
+        let property = self.take_super_member_property(left.into_member_expression());
+
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_super_prop_set(span, property, right)
+        } else {
+            let (property1, property2) = self.duplicate_property(property, "super");
+            if let Some(operator) = operator.to_binary_operator() {
+                let get = self.create_super_prop_get(SPAN, property2, false);
+                let value = self.ctx.ast.expression_binary(SPAN, get, operator, right);
+                self.create_super_prop_set(span, property1, value)
+            } else if let Some(operator) = operator.to_logical_operator() {
+                let get = self.create_super_prop_get(SPAN, property1, false);
+                let set = self.create_super_prop_set(SPAN, property2, right);
+                self.ctx.ast.expression_logical(span, get, operator, set)
+            } else {
+                // The above covers all types of `AssignmentOperator`
+                unreachable!()
+            }
+        };
+    }
+
+    /// * `++super.prop` -> `_superPropSet(_Class, "prop", +_superPropGet(_Class, "prop", this, 1) + 1, this, 1, 1)`
+    /// * `super.prop++`
+    ///   -> `(_super = +_superPropGet(_Class, "prop", this, 1), _superPropSet(_Class, "prop", _super + 1, this, 1, 1), _super)`
+    fn transform_super_update(&mut self, expr: &mut Expression<'a>) {
+        let Expression::UpdateExpression(update) = expr.take_in(self.ctx.ast) else {
+            unreachable!()
+        };
+        let UpdateExpression { span, operator, prefix, argument } = update.unbox();
+        let property = self.take_super_member_property(argument.into_member_expression());
+        let (property1, property2) = self.duplicate_property(property, "super");
+
+        let operator = match operator {
+            UpdateOperator::Increment => BinaryOperator::Addition,
+            UpdateOperator::Decrement => BinaryOperator::Subtraction,
+        };
+        let one = self.ctx.ast.expression_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal);
+
+        *expr = if prefix {
+            let get = self.create_super_prop_get(SPAN, property2, false);
+            let get = self.ctx.ast.expression_unary(SPAN, UnaryOperator::UnaryPlus, get);
+            let value = self.ctx.ast.expression_binary(SPAN, get, operator, one);
+            self.create_super_prop_set(span, property1, value)
+        } else {
+            let temp_binding = self.create_temp_binding("super");
+            let get = self.create_super_prop_get(SPAN, property1, false);
+            let get = self.ctx.ast.expression_unary(SPAN, UnaryOperator::UnaryPlus, get);
+            let assignment = create_assignment(&temp_binding, get, self.ctx);
+            let temp = temp_binding.create_read_expression(self.ctx);
+            let value = self.ctx.ast.expression_binary(SPAN, temp, operator, one);
+            let set = self.create_super_prop_set(SPAN, property2, value);
+            let temp = temp_binding.create_read_expression(self.ctx);
+            self.ctx
+                .ast
+                .expression_sequence(span, self.ctx.ast.vec_from_array([assignment, set, temp]))
+        };
+    }
+
+    /// Get property of a `super` member expression, as an expression.
+    ///
+    /// `super.prop` -> `"prop"`, `super[prop]` -> `prop`
+    fn take_super_member_property(&mut self, member: MemberExpression<'a>) -> Expression<'a> {
+        match member {
+            MemberExpression::StaticMemberExpression(member) => self
+                .ctx
+                .ast
+                .expression_string_literal(member.property.span, member.property.name, None),
+            MemberExpression::ComputedMemberExpression(member) => {
+                let mut property = member.unbox().expression;
+                self.visit_expression(&mut property);
+                property
+            }
+            MemberExpression::PrivateFieldExpression(_) => unreachable!(),
+        }
+    }
+
+    /// Duplicate property of a `super` member expression.
+    ///
+    /// Literals are cloned. Anything else is stored in a temp var: `prop` -> `_super = prop`, `_super`.
+    fn duplicate_property(
+        &mut self,
+        property: Expression<'a>,
+        name: &str,
+    ) -> (Expression<'a>, Expression<'a>) {
+        if matches!(property, Expression::StringLiteral(_) | Expression::NumericLiteral(_)) {
+            let clone = property.clone_in(self.ctx.ast.allocator);
+            return (property, clone);
+        }
+        let temp_binding = self.create_temp_binding(name);
+        let assignment = create_assignment(&temp_binding, property, self.ctx);
+        (assignment, temp_binding.create_read_expression(self.ctx))
+    }
+
+    fn create_temp_binding(&mut self, name: &str) -> BoundIdentifier<'a> {
+        let binding =
+            self.ctx.generate_uid(name, self.scope_id, SymbolFlags::FunctionScopedVariable);
+        self.temp_bindings.push(binding.clone());
+        binding
+    }
+
+    /// Member: `_superPropGet(_Class, prop, this, 1)`
+    ///
+    /// Callee: `_superPropGet(_Class, prop, this, 3)`
+    ///
+    /// Flag `1` means the property is looked up on the prototype of the super class (instance methods),
+    /// and flag `2` that the result is a function to call with an array of arguments.
+    fn create_super_prop_get(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        is_callee: bool,
+    ) -> Expression<'a> {
+        let flags = u8::from(!self.is_static) | if is_callee { 2 } else { 0 };
+        let mut arguments = self.ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(self.class_binding.create_read_expression(self.ctx)));
+        arguments.push(Argument::from(property));
+        arguments.push(Argument::from(self.create_receiver()));
+        if flags != 0 {
+            arguments.push(Argument::from(self.ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        self.transform_ctx.helper_call_expr(Helper::SuperPropGet, span, arguments, self.ctx)
+    }
+
+    /// Static method: `_superPropSet(_Class, prop, value, this, 1)`
+    ///
+    /// Instance method: `_superPropSet(_Class, prop, value, this, 1, 1)`
+    fn create_super_prop_set(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = self.ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(self.class_binding.create_read_expression(self.ctx)));
+        arguments.push(Argument::from(property));
+        arguments.push(Argument::from(value));
+        arguments.push(Argument::from(self.create_receiver()));
+        let one = || {
+            Argument::from(self.ctx.ast.expression_numeric_literal(
+                SPAN,
+                1.0,
+                None,
+                NumberBase::Decimal,
+            ))
+        };
+        arguments.push(one());
+        if !self.is_static {
+            arguments.push(one());
+        }
+        self.transform_ctx.helper_call_expr(Helper::SuperPropSet, span, arguments, self.ctx)
+    }
+
+    /// `this`, or `_this` in constructor of a derived class.
+    fn create_receiver(&mut self) -> Expression<'a> {
+        match &self.this_binding {
+            Some(this_binding) => this_binding.create_read_expression(self.ctx),
+            None => self.ctx.ast.expression_this(SPAN),
+        }
+    }
+
+    /// `(a, ...b)` -> `[a, ...b]`, or `[a].concat(babelHelpers.toConsumableArray(b))` if spread
+    /// is lowered.
+    fn arguments_to_array(&mut self, arguments: &mut ArenaVec<'a, Argument<'a>>) -> Expression<'a> {
+        if self.spread && arguments.iter().any(Argument::is_spread) {
+            let arguments = arguments.take_in(self.ctx.ast);
+            return Spread::new(self.transform_ctx).arguments_to_array(arguments, self.ctx);
+        }
+        let elements =
+            self.ctx.ast.vec_from_iter(arguments.drain(..).map(|argument| match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                _ => ArrayExpressionElement::from(argument.into_expression()),
+            }));
+        self.ctx.ast.expression_array(SPAN, elements)
+    }
+
+    fn helper_call(
+        &mut self,
+        helper: Helper,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        self.transform_ctx.helper_call_expr(helper, SPAN, arguments, self.ctx)
+    }
+}
+
+fn is_super_member(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::StaticMemberExpression(member) => member.object.is_super(),
+        Expression::ComputedMemberExpression(member) => member.object.is_super(),
+        _ => false,
+    }
+}
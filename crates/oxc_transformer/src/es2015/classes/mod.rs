@@ -0,0 +1,778 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms classes to functions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class A extends B {
+//!   constructor(x) {
+//!     super(x);
+//!     this.x = x;
+//!   }
+//!   foo() {
+//!     return super.foo();
+//!   }
+//!   static bar() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let A = /*#__PURE__*/ function(_B) {
+//!   function A(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, A);
+//!     _this = babelHelpers.callSuper(this, A, [x]);
+//!     _this.x = x;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(A, _B);
+//!   return babelHelpers.createClass(A, [{
+//!     key: "foo",
+//!     value: function foo() {
+//!       return babelHelpers.superPropGet(A, "foo", this, 3)([]);
+//!     }
+//!   }], [{
+//!     key: "bar",
+//!     value: function bar() {}
+//!   }]);
+//! }(B);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-classes](https://babel.dev/docs/babel-plugin-transform-classes).
+//!
+//! Class declarations are transformed in `exit_statement`, and class expressions in `exit_expression`,
+//! so that class properties, static blocks and private methods have already been transformed by the
+//! ES2022 class properties transform. If any of them remain, an error is raised, as Babel does.
+//!
+//! This transform owns spread arguments of `super(...args)`: it passes them to `callSuper` as an
+//! array, which it builds with the spread transform's
+//! [`Spread::arguments_to_array`](super::Spread::arguments_to_array) when spread is enabled.
+//!
+//! Differences from Babel:
+//!
+//! * Output is always wrapped in an IIFE. Babel omits the IIFE for classes with no super class
+//!   and no methods.
+//! * `this` before `super()` in a derived constructor is not checked. Babel wraps it in
+//!   `assertThisInitialized`.
+//!
+//! Reference:
+//! * Babel docs: <https://babeljs.io/docs/en/babel-plugin-transform-classes>
+//! * Babel implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-classes>
+
+use std::{cell::Cell, mem};
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ChildScopeCollector,
+};
+
+mod method_body;
+use method_body::MethodBodyConverter;
+
+pub struct Classes<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Lower spread arguments of `super` calls, which the spread transform does not see
+    spread: bool,
+    /// `true` if just exited a class expression, which is transformed in `exit_expression`
+    class_expression_pending: bool,
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    pub fn new(spread: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, spread, class_expression_pending: false }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Classes<'a, '_> {
+    fn exit_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if class.is_expression() {
+            self.class_expression_pending = true;
+        }
+    }
+
+    // `#[inline]` for fast exit when not exiting a class expression
+    #[inline]
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if mem::take(&mut self.class_expression_pending) {
+            self.transform_class_expression(expr, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ClassDeclaration(_) => self.transform_class_declaration(stmt, ctx),
+            Statement::ExportNamedDeclaration(decl) => {
+                let Some(Declaration::ClassDeclaration(class)) = &mut decl.declaration else {
+                    return;
+                };
+                if let Some(init) = self.transform_class(class, "Class", ctx) {
+                    let var_decl = Self::create_let_declaration(class, init, ctx);
+                    decl.declaration = Some(Declaration::VariableDeclaration(var_decl));
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                if matches!(decl.declaration, ExportDefaultDeclarationKind::ClassDeclaration(_)) {
+                    self.transform_export_default_class(stmt, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Transform class expression.
+    ///
+    /// The class may have been wrapped by class properties transform, e.g. `(_Class = class {}, ...)`,
+    /// so locate the class within the expression.
+    fn transform_class_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(class_expr) = find_class_expression(expr) else { return };
+        let Expression::ClassExpression(class) = class_expr else { unreachable!() };
+        if let Some(new_expr) = self.transform_class(class, "Class", ctx) {
+            *class_expr = new_expr;
+        }
+    }
+
+    /// `class A {}` -> `let A = function() { ... }();`
+    fn transform_class_declaration(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ClassDeclaration(class) = stmt else { unreachable!() };
+        let Some(init) = self.transform_class(class, "Class", ctx) else { return };
+        let new_stmt =
+            Statement::VariableDeclaration(Self::create_let_declaration(class, init, ctx));
+        self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+        *stmt = new_stmt;
+    }
+
+    /// * `export default class A {}` -> `let A = function() { ... }(); export { A as default };`
+    /// * `export default class {}` -> `export default function() { ... }();`
+    fn transform_export_default_class(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ExportDefaultDeclaration(decl) = stmt else { unreachable!() };
+        let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut decl.declaration else {
+            unreachable!()
+        };
+        let Some(init) = self.transform_class(class, "default", ctx) else { return };
+
+        if class.id.is_none() {
+            decl.declaration = ExportDefaultDeclarationKind::from(init);
+            return;
+        }
+
+        let binding = BoundIdentifier::from_binding_ident(class.id.as_ref().unwrap());
+        let new_stmt =
+            Statement::VariableDeclaration(Self::create_let_declaration(class, init, ctx));
+        self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+
+        // `export { A as default };`
+        let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+        let exported = ModuleExportName::IdentifierName(ctx.ast.identifier_name(SPAN, "default"));
+        let specifier = ctx.ast.export_specifier(SPAN, local, exported, ImportOrExportKind::Value);
+        let export_stmt = Statement::from(ctx.ast.module_declaration_export_named_declaration(
+            SPAN,
+            None,
+            ctx.ast.vec1(specifier),
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        ));
+        self.ctx.statement_injector.insert_after(&new_stmt, export_stmt);
+
+        *stmt = new_stmt;
+    }
+
+    /// `let A = <init>;`
+    fn create_let_declaration(
+        class: &mut Class<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, VariableDeclaration<'a>> {
+        let id = class.id.take().unwrap();
+        *ctx.scoping_mut().symbol_flags_mut(id.symbol_id()) = SymbolFlags::BlockScopedVariable;
+        let kind = VariableDeclarationKind::Let;
+        let id = ctx.ast.binding_pattern(
+            BindingPatternKind::BindingIdentifier(ctx.ast.alloc(id)),
+            NONE,
+            false,
+        );
+        let declarator = ctx.ast.variable_declarator(class.span, kind, id, Some(init), false);
+        ctx.ast.alloc_variable_declaration(class.span, kind, ctx.ast.vec1(declarator), false)
+    }
+
+    /// Transform class to an IIFE which creates the constructor function.
+    ///
+    /// Returns `None` if the class cannot be transformed.
+    ///
+    /// For class declarations, `class.id` is left in place, for caller to create a `let` binding from.
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        name_hint: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if class.declare || !self.check_class_body(class) {
+            return None;
+        }
+
+        let class_scope_id = class.scope_id();
+        let parent_scope_id = ctx.scoping().scope_parent_id(class_scope_id).unwrap();
+
+        // Binding for constructor function inside the IIFE, which is in the class's scope
+        let (class_binding, constructor_id) = match &class.id {
+            Some(id) if class.is_declaration() => {
+                // Class name is bound in parent scope. Create a new binding in class scope,
+                // and point references within class body to it.
+                let outer_symbol_id = id.symbol_id();
+                let binding = ctx.generate_binding(id.name, class_scope_id, SymbolFlags::Function);
+                let mut rebinder =
+                    ReferenceRebinder { outer_symbol_id, inner_symbol_id: binding.symbol_id, ctx };
+                rebinder.visit_class_body(&class.body);
+                let constructor_id = binding.create_binding_identifier(ctx);
+                (binding, constructor_id)
+            }
+            Some(id) => {
+                // Class expression name is bound in class scope already
+                let binding = BoundIdentifier::from_binding_ident(id);
+                *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) = SymbolFlags::Function;
+                (binding, class.id.take().unwrap())
+            }
+            None => {
+                let binding = ctx.generate_uid(name_hint, class_scope_id, SymbolFlags::Function);
+                let constructor_id = binding.create_binding_identifier(ctx);
+                (binding, constructor_id)
+            }
+        };
+
+        // Class scope becomes the scope of the IIFE.
+        // Class body is strict mode code, but functions moved out of it are only strict mode
+        // if the code surrounding the class is.
+        let strict_mode = ctx.scoping().scope_flags(parent_scope_id) & ScopeFlags::StrictMode;
+        *ctx.scoping_mut().scope_flags_mut(class_scope_id) = ScopeFlags::Function | strict_mode;
+
+        // Super class is passed as argument to the IIFE
+        let super_class = class.super_class.take().map(|super_class| {
+            let mut collector = ChildScopeCollector::default();
+            collector.visit_expression(&super_class);
+            for scope_id in collector.scope_ids {
+                ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_scope_id));
+            }
+            let binding = ctx.generate_uid_based_on_node(
+                &super_class,
+                class_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            (super_class, binding)
+        });
+
+        let mut constructor = None;
+        let mut proto_descriptors = Descriptors::default();
+        let mut static_descriptors = Descriptors::default();
+        for element in class.body.body.take_in(ctx.ast) {
+            let ClassElement::MethodDefinition(method) = element else { continue };
+            let MethodDefinition { key, value: mut func, kind, computed, r#static, .. } =
+                method.unbox();
+            if func.body.is_none() {
+                // TypeScript overload signature
+                continue;
+            }
+
+            if kind == MethodDefinitionKind::Constructor {
+                constructor = Some(func);
+                continue;
+            }
+
+            let scope_id = func.scope_id();
+            MethodBodyConverter::new(
+                &class_binding,
+                r#static,
+                None,
+                scope_id,
+                self.spread,
+                self.ctx,
+                ctx,
+            )
+            .convert(&mut func);
+            update_function_scope_flags(&func, strict_mode, ctx);
+
+            let key_name =
+                if computed { None } else { key.static_name().map(|name| ctx.ast.atom(&name)) };
+            if kind == MethodDefinitionKind::Method {
+                if let Some(name) = key_name {
+                    if Self::can_name_method(name, class_scope_id, scope_id, ctx) {
+                        func.id = Some(
+                            ctx.generate_binding(name, scope_id, SymbolFlags::Function)
+                                .create_binding_identifier(ctx),
+                        );
+                    }
+                }
+            }
+
+            let key = match key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    ctx.ast.expression_string_literal(ident.span, ident.name, None)
+                }
+                PropertyKey::PrivateIdentifier(_) => unreachable!(),
+                key => key.into_expression(),
+            };
+            let descriptors =
+                if r#static { &mut static_descriptors } else { &mut proto_descriptors };
+            descriptors.add(key, key_name, kind, func);
+        }
+
+        let constructor = self.create_constructor(
+            constructor,
+            &class_binding,
+            constructor_id,
+            super_class.is_some(),
+            class_scope_id,
+            ctx,
+        );
+        update_function_scope_flags(&constructor, strict_mode, ctx);
+
+        // Build IIFE body
+        let mut stmts = ctx.ast.vec_with_capacity(3);
+        stmts.push(Statement::FunctionDeclaration(constructor));
+
+        if let Some((_, super_binding)) = &super_class {
+            // `babelHelpers.inherits(A, _B);`
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(class_binding.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(Helper::Inherits, SPAN, arguments, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        // `return babelHelpers.createClass(A, [...], [...]);`
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        arguments.push(Argument::from(class_binding.create_read_expression(ctx)));
+        if !proto_descriptors.is_empty() || !static_descriptors.is_empty() {
+            arguments.push(Argument::from(if proto_descriptors.is_empty() {
+                ctx.ast.expression_null_literal(SPAN)
+            } else {
+                proto_descriptors.into_array(ctx)
+            }));
+        }
+        if !static_descriptors.is_empty() {
+            arguments.push(Argument::from(static_descriptors.into_array(ctx)));
+        }
+        let create_class = self.ctx.helper_call_expr(Helper::CreateClass, SPAN, arguments, ctx);
+        stmts.push(ctx.ast.statement_return(SPAN, Some(create_class)));
+
+        // `function(_B) { ... }(B)`
+        let (params, arguments) = match super_class {
+            Some((super_class, super_binding)) => (
+                ctx.ast.vec1(
+                    ctx.ast.plain_formal_parameter(SPAN, super_binding.create_binding_pattern(ctx)),
+                ),
+                ctx.ast.vec1(Argument::from(super_class)),
+            ),
+            None => (ctx.ast.vec(), ctx.ast.vec()),
+        };
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        let iife = ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            class_scope_id,
+        );
+        Some(ctx.ast.expression_call_with_pure(
+            class.span,
+            Expression::FunctionExpression(iife),
+            NONE,
+            arguments,
+            false,
+            true,
+        ))
+    }
+
+    /// Check class only contains elements which this transform can handle.
+    ///
+    /// Class properties, accessor properties, static blocks and private methods are transformed by
+    /// class properties transform. Raise an error if they're still present.
+    fn check_class_body(&self, class: &Class<'a>) -> bool {
+        let is_supported = class.body.body.iter().all(|element| match element {
+            ClassElement::MethodDefinition(method) => !method.key.is_private_identifier(),
+            ClassElement::TSIndexSignature(_) => true,
+            ClassElement::PropertyDefinition(prop) => prop.declare,
+            ClassElement::AccessorProperty(_) | ClassElement::StaticBlock(_) => false,
+        });
+        if !is_supported {
+            self.ctx.error(
+                OxcDiagnostic::error("Missing class properties transform.").with_label(class.span),
+            );
+        }
+        is_supported
+    }
+
+    /// Create constructor function declaration.
+    ///
+    /// * Base class: `function A() { babelHelpers.classCallCheck(this, A); ... }`
+    /// * Derived class: `function A() { var _this; babelHelpers.classCallCheck(this, A); ... }`
+    fn create_constructor(
+        &self,
+        constructor: Option<ArenaBox<'a, Function<'a>>>,
+        class_binding: &BoundIdentifier<'a>,
+        constructor_id: BindingIdentifier<'a>,
+        is_derived: bool,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        // `babelHelpers.classCallCheck(this, A);`
+        let class_call_check = {
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(class_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(Helper::ClassCallCheck, SPAN, arguments, ctx);
+            ctx.ast.statement_expression(SPAN, call)
+        };
+
+        let Some(mut func) = constructor else {
+            return self.create_default_constructor(
+                class_call_check,
+                class_binding,
+                constructor_id,
+                is_derived,
+                class_scope_id,
+                ctx,
+            );
+        };
+
+        let scope_id = func.scope_id();
+        func.r#type = FunctionType::FunctionDeclaration;
+        func.id = Some(constructor_id);
+
+        if !is_derived {
+            MethodBodyConverter::new(
+                class_binding,
+                false,
+                None,
+                scope_id,
+                self.spread,
+                self.ctx,
+                ctx,
+            )
+            .convert(&mut func);
+            func.body.as_mut().unwrap().statements.insert(0, class_call_check);
+            return func;
+        }
+
+        let has_top_level_super_call = func.body.as_ref().unwrap().statements.iter().any(|stmt| {
+            matches!(
+                stmt,
+                Statement::ExpressionStatement(expr_stmt)
+                    if matches!(&expr_stmt.expression, Expression::CallExpression(call) if call.callee.is_super())
+            )
+        });
+
+        let this_binding = ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable);
+        MethodBodyConverter::new(
+            class_binding,
+            false,
+            Some(this_binding.clone()),
+            scope_id,
+            self.spread,
+            self.ctx,
+            ctx,
+        )
+        .convert(&mut func);
+
+        let body = func.body.as_mut().unwrap();
+        // Insert after `var _this;`
+        body.statements.insert(1, class_call_check);
+
+        // `return _this;` or `return babelHelpers.assertThisInitialized(_this);`
+        if !matches!(
+            body.statements.last(),
+            Some(Statement::ReturnStatement(_) | Statement::ThrowStatement(_))
+        ) {
+            let this = this_binding.create_read_expression(ctx);
+            let argument = if has_top_level_super_call {
+                this
+            } else {
+                self.ctx.helper_call_expr(
+                    Helper::AssertThisInitialized,
+                    SPAN,
+                    ctx.ast.vec1(Argument::from(this)),
+                    ctx,
+                )
+            };
+            body.statements.push(ctx.ast.statement_return(SPAN, Some(argument)));
+        }
+
+        func
+    }
+
+    /// Create constructor function declaration for class with no constructor.
+    ///
+    /// * Base class: `function A() { babelHelpers.classCallCheck(this, A); }`
+    /// * Derived class:
+    ///   `function A() { babelHelpers.classCallCheck(this, A); return babelHelpers.callSuper(this, A, arguments); }`
+    fn create_default_constructor(
+        &self,
+        class_call_check: Statement<'a>,
+        class_binding: &BoundIdentifier<'a>,
+        constructor_id: BindingIdentifier<'a>,
+        is_derived: bool,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let strict_mode = ctx.scoping().scope_flags(class_scope_id) & ScopeFlags::StrictMode;
+        let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::Function | strict_mode);
+
+        let mut stmts = ctx.ast.vec_with_capacity(2);
+        stmts.push(class_call_check);
+        if is_derived {
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(class_binding.create_read_expression(ctx)),
+                Argument::from(ctx.create_unbound_ident_expr(
+                    SPAN,
+                    Atom::from("arguments"),
+                    ReferenceFlags::Read,
+                )),
+            ]);
+            let call = self.ctx.helper_call_expr(Helper::CallSuper, SPAN, arguments, ctx);
+            stmts.push(ctx.ast.statement_return(SPAN, Some(call)));
+        }
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionDeclaration,
+            Some(constructor_id),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        )
+    }
+
+    /// Check if method function can be given the method's name (`value: function foo() {}`),
+    /// without shadowing any binding it references, or clashing with a binding in its own scope.
+    fn can_name_method(
+        name: Atom<'a>,
+        class_scope_id: ScopeId,
+        func_scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        is_identifier_name(&name)
+            && !is_reserved_keyword(&name)
+            && ctx.scoping().find_binding(class_scope_id, &name).is_none()
+            && ctx.scoping().get_binding(func_scope_id, &name).is_none()
+            && !ctx.scoping().root_unresolved_references().contains_key(name.as_str())
+    }
+}
+
+/// Locate class expression within an expression.
+///
+/// * `class {}`
+/// * `_Class = class {}`
+/// * `(_Class = class {}, _Class.x = 1, _Class)`
+fn find_class_expression<'e, 'a>(expr: &'e mut Expression<'a>) -> Option<&'e mut Expression<'a>> {
+    match expr {
+        Expression::ClassExpression(_) => Some(expr),
+        Expression::AssignmentExpression(assign) => find_class_expression(&mut assign.right),
+        Expression::SequenceExpression(sequence) => {
+            sequence.expressions.iter_mut().find_map(find_class_expression)
+        }
+        Expression::ParenthesizedExpression(paren) => find_class_expression(&mut paren.expression),
+        _ => None,
+    }
+}
+
+/// Property descriptors for methods, for passing to `createClass` helper.
+#[derive(Default)]
+struct Descriptors<'a> {
+    descriptors: Vec<Descriptor<'a>>,
+}
+
+struct Descriptor<'a> {
+    key: Expression<'a>,
+    /// Key name, if key is not computed
+    key_name: Option<Atom<'a>>,
+    value: Option<ArenaBox<'a, Function<'a>>>,
+    get: Option<ArenaBox<'a, Function<'a>>>,
+    set: Option<ArenaBox<'a, Function<'a>>>,
+}
+
+impl<'a> Descriptors<'a> {
+    fn is_empty(&self) -> bool {
+        self.descriptors.is_empty()
+    }
+
+    /// Add method. Getter and setter with same key are combined into one descriptor.
+    /// A later method overrides an earlier one with the same key.
+    fn add(
+        &mut self,
+        key: Expression<'a>,
+        key_name: Option<Atom<'a>>,
+        kind: MethodDefinitionKind,
+        func: ArenaBox<'a, Function<'a>>,
+    ) {
+        let existing = key_name.and_then(|key_name| {
+            self.descriptors.iter_mut().find(|descriptor| descriptor.key_name == Some(key_name))
+        });
+        let descriptor = if let Some(descriptor) = existing {
+            descriptor
+        } else {
+            self.descriptors.push(Descriptor { key, key_name, value: None, get: None, set: None });
+            self.descriptors.last_mut().unwrap()
+        };
+
+        match kind {
+            MethodDefinitionKind::Get => {
+                descriptor.value = None;
+                descriptor.get = Some(func);
+            }
+            MethodDefinitionKind::Set => {
+                descriptor.value = None;
+                descriptor.set = Some(func);
+            }
+            MethodDefinitionKind::Method | MethodDefinitionKind::Constructor => {
+                descriptor.get = None;
+                descriptor.set = None;
+                descriptor.value = Some(func);
+            }
+        }
+    }
+
+    /// `[{ key: "foo", value: function foo() {} }, { key: "bar", get: function () {} }]`
+    fn into_array(self, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let elements = ctx.ast.vec_from_iter(self.descriptors.into_iter().map(|descriptor| {
+            let mut properties = ctx.ast.vec_with_capacity(3);
+            let mut push = |name: &'static str, value: Expression<'a>| {
+                properties.push(ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    ctx.ast.property_key_static_identifier(SPAN, name),
+                    value,
+                    false,
+                    false,
+                    false,
+                ));
+            };
+            push("key", descriptor.key);
+            for (name, func) in
+                [("value", descriptor.value), ("get", descriptor.get), ("set", descriptor.set)]
+            {
+                if let Some(func) = func {
+                    push(name, Expression::FunctionExpression(func));
+                }
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_object(SPAN, properties))
+        }));
+        ctx.ast.expression_array(SPAN, elements)
+    }
+}
+
+/// Visitor which points references to the class name within class body to the binding inside the IIFE.
+struct ReferenceRebinder<'a, 'c> {
+    outer_symbol_id: SymbolId,
+    inner_symbol_id: SymbolId,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ReferenceRebinder<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let scoping = self.ctx.scoping_mut();
+        let reference = scoping.get_reference_mut(reference_id);
+        if reference.symbol_id() == Some(self.outer_symbol_id) {
+            reference.set_symbol_id(self.inner_symbol_id);
+            scoping.delete_resolved_reference(self.outer_symbol_id, reference_id);
+            scoping.add_resolved_reference(self.inner_symbol_id, reference_id);
+        }
+    }
+}
+
+/// Set scope flags of a method or constructor function which is moved out of class body.
+///
+/// Function is no longer a constructor or accessor, and it and its descendant scopes are no longer
+/// strict mode, unless code surrounding the class is, or function contains a `"use strict"` directive.
+fn update_function_scope_flags<'a>(
+    func: &Function<'a>,
+    strict_mode: ScopeFlags,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let scope_id = func.scope_id();
+    if strict_mode.is_empty() && !func.has_use_strict_directive() {
+        *ctx.scoping_mut().scope_flags_mut(scope_id) = ScopeFlags::Function;
+        let mut remover = StrictModeRemover { ctx };
+        remover.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            remover.visit_function_body(body);
+        }
+    } else {
+        *ctx.scoping_mut().scope_flags_mut(scope_id) =
+            ScopeFlags::Function | ScopeFlags::StrictMode;
+    }
+}
+
+/// Visitor which removes `StrictMode` flag from all scopes, except those in strict mode functions
+/// and classes.
+struct StrictModeRemover<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for StrictModeRemover<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.ctx
+            .scoping_mut()
+            .scope_flags_mut(scope_id.get().unwrap())
+            .remove(ScopeFlags::StrictMode);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if !func.has_use_strict_directive() {
+            walk::walk_function(self, func, flags);
+        }
+    }
+
+    #[inline] // `#[inline]` because is a no-op
+    fn visit_class(&mut self, _class: &Class<'a>) {
+        // Class body is always strict mode
+    }
+}
@@ -0,0 +1,165 @@
+//! ES2015: Computed Properties
+//!
+//! This plugin transforms object literals with computed property keys.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var a = { [b]: 1 };
+//! var c = { d: 1, [e]: 2, f: 3, get [g]() {} };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj;
+//! var a = babelHelpers.defineProperty({}, b, 1);
+//! var c = (
+//!   _obj = { d: 1 },
+//!   babelHelpers.defineProperty(_obj, e, 2),
+//!   babelHelpers.defineProperty(_obj, "f", 3),
+//!   babelHelpers.defineAccessor("get", _obj, g, function () {}),
+//!   _obj
+//! );
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-computed-properties](https://babel.dev/docs/babel-plugin-transform-computed-properties).
+//!
+//! Properties before the first computed key stay in the object literal. All properties from the
+//! first computed key onwards are defined with helpers, to preserve order of evaluation of keys
+//! and values, and order of properties.
+//!
+//! Objects containing spread properties are not transformed. Object rest spread transform
+//! removes them when targeting ES5.
+//!
+//! Objects are also not transformed if a method or accessor defined with a helper references
+//! `super`, as `super` is only valid in methods.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-computed-properties>
+//! * Object Initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_semantic::ScopeFlags;
+use oxc_span::SPAN;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::function_references_super,
+};
+
+pub struct ComputedProperties<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ComputedProperties<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+
+    pub fn transform_object_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ObjectExpression(obj) = expr else { unreachable!() };
+        let Some(first_computed_index) = obj.properties.iter().position(
+            |property| matches!(property, ObjectPropertyKind::ObjectProperty(prop) if prop.computed),
+        ) else {
+            return;
+        };
+        if obj.properties.iter().any(ObjectPropertyKind::is_spread) {
+            return;
+        }
+        if obj.properties[first_computed_index..].iter().any(|property| {
+            matches!(property, ObjectPropertyKind::ObjectProperty(prop)
+                if (prop.method || prop.kind != PropertyKind::Init)
+                    && function_references_super(&prop.value))
+        }) {
+            return;
+        }
+
+        let span = obj.span;
+        let properties = obj.properties.split_off(first_computed_index);
+        let init = expr.take_in(ctx.ast);
+
+        // `{ [a]: b }` -> `babelHelpers.defineProperty({}, a, b)`
+        if properties.len() == 1 {
+            let ObjectPropertyKind::ObjectProperty(prop) = &properties[0] else { unreachable!() };
+            if prop.kind == PropertyKind::Init {
+                let ObjectPropertyKind::ObjectProperty(prop) =
+                    properties.into_iter().next().unwrap()
+                else {
+                    unreachable!()
+                };
+                *expr = self.create_define_property(init, prop.unbox(), ctx);
+                return;
+            }
+        }
+
+        // `(_obj = {}, babelHelpers.defineProperty(_obj, a, b), ..., _obj)`
+        let binding = self.ctx.var_declarations.create_uid_var("obj", ctx);
+        let mut expressions = ArenaVec::with_capacity_in(properties.len() + 2, ctx.ast.allocator);
+        expressions.push(ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            init,
+        ));
+        for property in properties {
+            let ObjectPropertyKind::ObjectProperty(prop) = property else { unreachable!() };
+            let obj = binding.create_read_expression(ctx);
+            expressions.push(self.create_define_property(obj, prop.unbox(), ctx));
+        }
+        expressions.push(binding.create_read_expression(ctx));
+        *expr = ctx.ast.expression_sequence(span, expressions);
+    }
+
+    /// `babelHelpers.defineProperty(obj, key, value)`
+    /// or `babelHelpers.defineAccessor("get", obj, key, value)`
+    fn create_define_property(
+        &self,
+        obj: Expression<'a>,
+        prop: ObjectProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ObjectProperty { kind, key, value, computed, .. } = prop;
+        let key = Self::create_key(key, computed, ctx);
+        let (helper, kind) = match kind {
+            PropertyKind::Init => (Helper::DefineProperty, None),
+            PropertyKind::Get => (Helper::DefineAccessor, Some("get")),
+            PropertyKind::Set => (Helper::DefineAccessor, Some("set")),
+        };
+        // Getter / setter becomes a plain function expression
+        if kind.is_some() {
+            if let Expression::FunctionExpression(func) = &value {
+                ctx.scoping_mut()
+                    .scope_flags_mut(func.scope_id())
+                    .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+            }
+        }
+        let mut arguments = ArenaVec::with_capacity_in(4, ctx.ast.allocator);
+        if let Some(kind) = kind {
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, kind, None)));
+        }
+        arguments.extend([Argument::from(obj), Argument::from(key), Argument::from(value)]);
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// Convert property key to an expression. `a` -> `"a"`.
+    fn create_key(key: PropertyKey<'a>, computed: bool, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) if !computed => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            key => key.into_expression(),
+        }
+    }
+}
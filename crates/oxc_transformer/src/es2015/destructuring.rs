@@ -0,0 +1,925 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms array and object destructuring into plain variable declarations and assignments.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var { a, b: { c = 1 } } = obj;
+//! var [d, ...e] = arr;
+//! [x, y] = [y, x];
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = obj.a, _obj$b$c = obj.b.c, c = _obj$b$c === void 0 ? 1 : _obj$b$c;
+//! var _arr = babelHelpers.toArray(arr), d = _arr[0], e = _arr.slice(1);
+//! var _ref;
+//! _ref = babelHelpers.slicedToArray([y, x], 2), x = _ref[0], y = _ref[1];
+//! ```
+//!
+//! Destructuring is transformed in:
+//! * Variable declarations: `var { a } = obj`.
+//! * Assignments: `({ a } = obj)`.
+//! * Function parameters: `function f({ a }) {}`.
+//! * `for in` / `for of` heads: `for (const [k, v] of entries) {}`.
+//! * Catch parameters: `try {} catch ({ message }) {}`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babel.dev/docs/babel-plugin-transform-destructuring).
+//!
+//! With `iterableIsArray` assumption, array patterns read elements by index directly,
+//! instead of converting the value to an array with `slicedToArray` / `toArray` helpers:
+//!
+//! ```js
+//! var [d, ...e] = arr;
+//! // ->
+//! var d = arr[0], e = arr.slice(1);
+//! ```
+//!
+//! Object rest properties (`{ a, ...rest }`) are usually transformed by object rest spread plugin first.
+//! If they are still present, they're transformed with `objectWithoutProperties` helper.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+
+use std::mem;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{AstBuilder, NONE, ast::*};
+use oxc_ecmascript::{
+    BoundNames, ToJsString, is_global_reference::WithoutGlobalReferenceInformation,
+};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::operator::BinaryOperator;
+use oxc_traverse::{
+    Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse,
+    ast_operations::get_var_name_from_node,
+};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Destructuring<'a, '_> {
+    // `var { a } = obj;`
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.transform_variable_declaration(decl, ctx);
+    }
+
+    // `({ a } = obj);`
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::AssignmentExpression(assign_expr) = expr {
+            if assign_expr.operator == AssignmentOperator::Assign
+                && assign_expr.left.is_assignment_target_pattern()
+            {
+                self.transform_assignment_expression(expr, ctx);
+            }
+        }
+    }
+
+    // `function f({ a }) {}`
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        if let Some(body) = func.body.as_mut() {
+            self.transform_parameters(&mut func.params, body, scope_id, ctx);
+        }
+    }
+
+    // `try {} catch ({ message }) {}`
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.transform_catch_clause(clause, ctx);
+    }
+
+    // `for (const [k, v] in obj) {}`
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `for (const [k, v] of entries) {}`
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+}
+
+impl<'a> Destructuring<'a, '_> {
+    /// Transform `var { a, b: [c] } = obj;` -> `var a = obj.a, _obj$b = ..., c = _obj$b[0];`
+    pub fn transform_variable_declaration(
+        &self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !decl.declarations.iter().any(|declarator| {
+            declarator.init.is_some() && declarator.id.kind.is_destructuring_pattern()
+        }) {
+            return;
+        }
+
+        let kind = decl.kind;
+        let mut declarators = ctx.ast.vec_with_capacity(decl.declarations.len());
+        for declarator in decl.declarations.take_in(ctx.ast) {
+            if declarator.init.is_none() || !declarator.id.kind.is_destructuring_pattern() {
+                declarators.push(declarator);
+                continue;
+            }
+            let state = DeclarationState::new(kind, &declarator.id, ctx);
+            let mut lowered = Lowered::new(Some(state));
+            let pattern = Pattern::from_binding_pattern(declarator.id);
+            let names = pattern.names();
+            self.lower(pattern, declarator.init.unwrap(), &names, &mut lowered, ctx);
+            declarators.extend(lowered.declarators);
+        }
+        decl.declarations = declarators;
+    }
+
+    /// Transform `[a, b] = arr` -> `_arr = babelHelpers.slicedToArray(arr, 2), a = _arr[0], b = _arr[1], arr`.
+    ///
+    /// Value of the assignment expression is omitted if it is not used.
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let value_is_used = !ctx
+            .ancestors()
+            .find(|ancestor| !matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_)))
+            .is_some_and(|ancestor| matches!(ancestor, Ancestor::ExpressionStatementExpression(_)));
+        let Expression::AssignmentExpression(assign_expr) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, left, right, .. } = assign_expr.unbox();
+
+        let pattern = Pattern::from_assignment_target(left, ctx.ast);
+        let names = pattern.names();
+        let mut lowered = Lowered::new(None);
+        if value_is_used {
+            let mut reference = self.create_reference(right, &names, &mut lowered, ctx);
+            let value = reference.read(ctx);
+            self.lower(pattern, value, &names, &mut lowered, ctx);
+            let value = reference.read(ctx);
+            lowered.expressions.push(value);
+        } else {
+            self.lower(pattern, right, &names, &mut lowered, ctx);
+        }
+
+        *expr = match lowered.expressions.len() {
+            0 => ctx.ast.void_0(span),
+            1 => lowered.expressions.pop().unwrap(),
+            _ => ctx.ast.expression_sequence(span, ctx.ast.vec_from_iter(lowered.expressions)),
+        };
+    }
+
+    /// Transform `function f({ a }, [b] = []) {}` -> `function f(_ref, _ref2 = []) { var a = _ref.a; ... }`.
+    pub fn transform_parameters(
+        &self,
+        params: &mut FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut statements = vec![];
+        for param in &mut params.items {
+            let pattern = match &mut param.pattern.kind {
+                BindingPatternKind::AssignmentPattern(assign_pat) => &mut assign_pat.left,
+                _ => &mut param.pattern,
+            };
+            if pattern.kind.is_destructuring_pattern() {
+                statements.push(self.replace_parameter_pattern(pattern, scope_id, ctx));
+            }
+        }
+        if let Some(rest) = &mut params.rest {
+            if rest.argument.kind.is_destructuring_pattern() {
+                statements.push(self.replace_parameter_pattern(&mut rest.argument, scope_id, ctx));
+            }
+        }
+
+        if !statements.is_empty() {
+            body.statements.splice(0..0, statements);
+        }
+    }
+
+    /// Replace parameter pattern with `_ref`, and return `var <pattern> = _ref;` transformed.
+    fn replace_parameter_pattern(
+        &self,
+        pattern: &mut BindingPattern<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let binding = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+        let pattern = mem::replace(pattern, binding.create_binding_pattern(ctx));
+        let state = DeclarationState {
+            kind: VariableDeclarationKind::Var,
+            scope_id,
+            symbol_flags: SymbolFlags::FunctionScopedVariable,
+        };
+        let value = binding.create_read_expression(ctx);
+        self.create_declaration(pattern, value, state, ctx)
+    }
+
+    /// Transform `try {} catch ({ message }) {}` -> `try {} catch (_ref) { let message = _ref.message; }`.
+    fn transform_catch_clause(&self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        if !param.pattern.kind.is_destructuring_pattern() {
+            return;
+        }
+
+        let scope_id = clause.body.scope_id();
+        param.pattern.bound_names(&mut |ident| {
+            ctx.scoping_mut()
+                .symbol_flags_mut(ident.symbol_id())
+                .remove(SymbolFlags::CatchVariable);
+        });
+        let binding = ctx.generate_uid(
+            "ref",
+            scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+        );
+        let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+        let state = DeclarationState {
+            kind: VariableDeclarationKind::Let,
+            scope_id,
+            symbol_flags: SymbolFlags::BlockScopedVariable,
+        };
+        let value = binding.create_read_expression(ctx);
+        let stmt = self.create_declaration(pattern, value, state, ctx);
+        clause.body.body.insert(0, stmt);
+    }
+
+    /// Transform `for (const [k, v] of entries) {}` -> `for (const _ref of entries) { const k = ..., v = ...; }`.
+    /// Transform `for ([k, v] of entries) {}` -> `for (var _ref of entries) { k = ..., v = ...; }`.
+    fn transform_for_statement_left(
+        &self,
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left {
+            let kind = decl.kind;
+            let declarator = &mut decl.declarations[0];
+            if !declarator.id.kind.is_destructuring_pattern() {
+                return;
+            }
+
+            let state = DeclarationState::new(kind, &declarator.id, ctx);
+            let binding = ctx.generate_uid("ref", state.scope_id, state.symbol_flags);
+            let pattern = mem::replace(&mut declarator.id, binding.create_binding_pattern(ctx));
+
+            let body_scope_id = Self::ensure_block_statement(body, scope_id, ctx);
+            let state = if kind.is_var() {
+                state
+            } else {
+                // Move bindings from `for` head scope into loop body scope
+                pattern.bound_names(&mut |ident| {
+                    let scoping = ctx.scoping_mut();
+                    scoping.set_symbol_scope_id(ident.symbol_id(), body_scope_id);
+                    scoping.move_binding(scope_id, body_scope_id, &ident.name);
+                });
+                DeclarationState { scope_id: body_scope_id, ..state }
+            };
+
+            let value = binding.create_read_expression(ctx);
+            let stmt = self.create_declaration(pattern, value, state, ctx);
+            let Statement::BlockStatement(block) = body else { unreachable!() };
+            block.body.insert(0, stmt);
+        } else {
+            if !left.to_assignment_target().is_assignment_target_pattern() {
+                return;
+            }
+
+            let binding = ctx.generate_uid(
+                "ref",
+                ctx.current_hoist_scope_id(),
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let kind = VariableDeclarationKind::Var;
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            );
+            let decl =
+                ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+            let target = mem::replace(left, ForStatementLeft::VariableDeclaration(decl))
+                .into_assignment_target();
+
+            Self::ensure_block_statement(body, scope_id, ctx);
+            let pattern = Pattern::from_assignment_target(target, ctx.ast);
+            let names = pattern.names();
+            let mut lowered = Lowered::new(None);
+            let value = binding.create_read_expression(ctx);
+            self.lower(pattern, value, &names, &mut lowered, ctx);
+            let expr =
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter(lowered.expressions));
+            let Statement::BlockStatement(block) = body else { unreachable!() };
+            block.body.insert(0, ctx.ast.statement_expression(SPAN, expr));
+        }
+    }
+
+    /// Wrap statement in a block, if it isn't one already. Returns `ScopeId` of the block.
+    fn ensure_block_statement(
+        stmt: &mut Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        if let Statement::BlockStatement(block) = stmt {
+            return block.scope_id();
+        }
+        let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let span = stmt.span();
+        let stmts = if matches!(stmt, Statement::EmptyStatement(_)) {
+            ctx.ast.vec()
+        } else {
+            ctx.ast.vec1(stmt.take_in(ctx.ast))
+        };
+        *stmt = ctx.ast.statement_block_with_scope_id(span, stmts, scope_id);
+        scope_id
+    }
+
+    /// Create `var <pattern> = <value>;`, with the pattern transformed.
+    fn create_declaration(
+        &self,
+        pattern: BindingPattern<'a>,
+        value: Expression<'a>,
+        state: DeclarationState,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let pattern = Pattern::from_binding_pattern(pattern);
+        let names = pattern.names();
+        let mut lowered = Lowered::new(Some(state));
+        self.lower(pattern, value, &names, &mut lowered, ctx);
+        let declarators = ctx.ast.vec_from_iter(lowered.declarators);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            state.kind,
+            declarators,
+            false,
+        ))
+    }
+
+    /// Lower destructuring of `value` into `pattern`.
+    ///
+    /// `names` are the names of all bindings / identifiers assigned to by the whole pattern.
+    fn lower(
+        &self,
+        pattern: Pattern<'a>,
+        value: Expression<'a>,
+        names: &[Atom<'a>],
+        lowered: &mut Lowered<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match pattern {
+            Pattern::Binding(ident) => {
+                let state = lowered.declaration.unwrap();
+                let id = ctx.ast.binding_pattern(
+                    BindingPatternKind::BindingIdentifier(ctx.ast.alloc(ident)),
+                    NONE,
+                    false,
+                );
+                lowered.declarators.push(ctx.ast.variable_declarator(
+                    SPAN,
+                    state.kind,
+                    id,
+                    Some(value),
+                    false,
+                ));
+            }
+            Pattern::Target(target) => {
+                lowered.expressions.push(ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                ));
+            }
+            Pattern::Default(pattern, default) => {
+                // `_value === void 0 ? default : _value`
+                let name = get_var_name_from_node(&value);
+                let binding = self.create_temp(&name, value, lowered, ctx);
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    binding.create_read_expression(ctx),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let value = ctx.ast.expression_conditional(
+                    SPAN,
+                    test,
+                    default,
+                    binding.create_read_expression(ctx),
+                );
+                self.lower(*pattern, value, names, lowered, ctx);
+            }
+            Pattern::Object(properties, rest) => {
+                self.lower_object(properties, rest, value, names, lowered, ctx);
+            }
+            Pattern::Array(elements, rest) => {
+                self.lower_array(elements, rest, value, names, lowered, ctx);
+            }
+        }
+    }
+
+    /// `var { a, b: c, [d]: e } = obj` -> `var a = obj.a, c = obj.b, e = obj[d]`
+    fn lower_object(
+        &self,
+        properties: Vec<(PropertyKey<'a>, Pattern<'a>)>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        names: &[Atom<'a>],
+        lowered: &mut Lowered<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `var {} = obj` -> `var _ref = babelHelpers.objectDestructuringEmpty(obj)`
+        if properties.is_empty() && rest.is_none() {
+            let expr = self.ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                SPAN,
+                ctx.ast.vec1(Argument::from(value)),
+                ctx,
+            );
+            if lowered.declaration.is_some() {
+                self.create_temp("ref", expr, lowered, ctx);
+            } else {
+                lowered.expressions.push(expr);
+            }
+            return;
+        }
+
+        // Value only needs to be stored in a temp var if it's read more than once
+        let mut reference = if properties.len() == 1 && rest.is_none() {
+            ValueReference { expr: Some(value), binding: None }
+        } else {
+            self.create_reference(value, names, lowered, ctx)
+        };
+
+        let mut excluded_keys = vec![];
+        let mut all_primitive_keys = true;
+        for (key, pattern) in properties {
+            let object = reference.read(ctx);
+            let member = match key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    if rest.is_some() {
+                        excluded_keys
+                            .push(ctx.ast.expression_string_literal(SPAN, ident.name, None));
+                    }
+                    let property = ctx.ast.identifier_name(ident.span, ident.name);
+                    Expression::from(
+                        ctx.ast.member_expression_static(SPAN, object, property, false),
+                    )
+                }
+                PropertyKey::PrivateIdentifier(_) => unreachable!(),
+                key => {
+                    let mut key = key.into_expression();
+                    if rest.is_some() {
+                        if key.is_literal() {
+                            let s =
+                                key.to_js_string(&WithoutGlobalReferenceInformation {}).unwrap();
+                            let s = ctx.ast.atom_from_cow(&s);
+                            excluded_keys.push(ctx.ast.expression_string_literal(SPAN, s, None));
+                        } else {
+                            // Computed key is read twice, so store it in a temp var
+                            all_primitive_keys = false;
+                            let name = get_var_name_from_node(&key);
+                            let binding = self.create_temp(&name, key, lowered, ctx);
+                            excluded_keys.push(binding.create_read_expression(ctx));
+                            key = binding.create_read_expression(ctx);
+                        }
+                    }
+                    Expression::from(ctx.ast.member_expression_computed(SPAN, object, key, false))
+                }
+            };
+            self.lower(pattern, member, names, lowered, ctx);
+        }
+
+        if let Some(rest) = rest {
+            let value = if excluded_keys.is_empty() {
+                // `babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_obj), _obj))`
+                let check = self.ctx.helper_call_expr(
+                    Helper::ObjectDestructuringEmpty,
+                    SPAN,
+                    ctx.ast.vec1(Argument::from(reference.read(ctx))),
+                    ctx,
+                );
+                let sequence = ctx.ast.expression_sequence(
+                    SPAN,
+                    ctx.ast.vec_from_array([check, reference.read(ctx)]),
+                );
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_object(SPAN, ctx.ast.vec())),
+                    Argument::from(sequence),
+                ]);
+                self.ctx.helper_call_expr(Helper::Extends, SPAN, arguments, ctx)
+            } else {
+                // `babelHelpers.objectWithoutProperties(_obj, ["a", "b"])`
+                let keys = ctx.ast.expression_array(
+                    SPAN,
+                    ctx.ast
+                        .vec_from_iter(excluded_keys.into_iter().map(ArrayExpressionElement::from)),
+                );
+                let keys = if all_primitive_keys {
+                    keys
+                } else {
+                    // `[_key].map(babelHelpers.toPropertyKey)`
+                    let callee = Expression::from(ctx.ast.member_expression_static(
+                        SPAN,
+                        keys,
+                        ctx.ast.identifier_name(SPAN, "map"),
+                        false,
+                    ));
+                    let to_property_key = self.ctx.helper_load(Helper::ToPropertyKey, ctx);
+                    ctx.ast.expression_call(
+                        SPAN,
+                        callee,
+                        NONE,
+                        ctx.ast.vec1(Argument::from(to_property_key)),
+                        false,
+                    )
+                };
+                let arguments = ctx
+                    .ast
+                    .vec_from_array([Argument::from(reference.read(ctx)), Argument::from(keys)]);
+                self.ctx.helper_call_expr(Helper::ObjectWithoutProperties, SPAN, arguments, ctx)
+            };
+            self.lower(*rest, value, names, lowered, ctx);
+        }
+
+        reference.finish(ctx);
+    }
+
+    /// `var [a, , b, ...c] = arr` -> `var _arr = babelHelpers.toArray(arr), a = _arr[0], b = _arr[2], c = _arr.slice(3)`
+    fn lower_array(
+        &self,
+        elements: Vec<Option<Pattern<'a>>>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        names: &[Atom<'a>],
+        lowered: &mut Lowered<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut reference = if self.ctx.assumptions.iterable_is_array {
+            if elements.iter().flatten().count() == 1 && rest.is_none() {
+                ValueReference { expr: Some(value), binding: None }
+            } else {
+                self.create_reference(value, names, lowered, ctx)
+            }
+        } else {
+            // `babelHelpers.slicedToArray(arr, 2)` or `babelHelpers.toArray(arr)`
+            let name = get_var_name_from_node(&value);
+            let value = if rest.is_some() {
+                self.ctx.helper_call_expr(
+                    Helper::ToArray,
+                    SPAN,
+                    ctx.ast.vec1(Argument::from(value)),
+                    ctx,
+                )
+            } else {
+                let len = create_number(elements.len(), ctx);
+                self.ctx.helper_call_expr(
+                    Helper::SlicedToArray,
+                    SPAN,
+                    ctx.ast.vec_from_array([Argument::from(value), Argument::from(len)]),
+                    ctx,
+                )
+            };
+            let binding = self.create_temp(&name, value, lowered, ctx);
+            ValueReference { expr: None, binding: Some(binding.to_maybe_bound_identifier()) }
+        };
+
+        let len = elements.len();
+        for (index, element) in elements.into_iter().enumerate() {
+            let Some(pattern) = element else { continue };
+            // `_arr[0]`
+            let index = create_number(index, ctx);
+            let member = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                reference.read(ctx),
+                index,
+                false,
+            ));
+            self.lower(pattern, member, names, lowered, ctx);
+        }
+
+        if let Some(rest) = rest {
+            // `_arr.slice(3)`
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                reference.read(ctx),
+                ctx.ast.identifier_name(SPAN, "slice"),
+                false,
+            ));
+            let arguments = if len == 0 {
+                ctx.ast.vec()
+            } else {
+                ctx.ast.vec1(Argument::from(create_number(len, ctx)))
+            };
+            let value = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            self.lower(*rest, value, names, lowered, ctx);
+        }
+
+        reference.finish(ctx);
+    }
+
+    /// Create a reference to `value` which can be read multiple times.
+    ///
+    /// Identifiers are used directly, unless they're assigned to by the pattern.
+    /// Any other value is stored in a temp var.
+    fn create_reference(
+        &self,
+        value: Expression<'a>,
+        names: &[Atom<'a>],
+        lowered: &mut Lowered<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ValueReference<'a> {
+        if let Expression::Identifier(ident) = &value {
+            if !names.contains(&ident.name) {
+                let binding = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+                return ValueReference { expr: Some(value), binding: Some(binding) };
+            }
+        }
+        let name = get_var_name_from_node(&value);
+        let binding = self.create_temp(&name, value, lowered, ctx);
+        ValueReference { expr: None, binding: Some(binding.to_maybe_bound_identifier()) }
+    }
+
+    /// Create a temp var initialized to `init`.
+    ///
+    /// In declarations, this is `_temp = init` declarator.
+    /// In assignments, `var _temp` is declared in enclosing scope, and `_temp = init` assignment added.
+    fn create_temp(
+        &self,
+        name: &str,
+        init: Expression<'a>,
+        lowered: &mut Lowered<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(state) = lowered.declaration {
+            let binding = ctx.generate_uid(name, state.scope_id, state.symbol_flags);
+            lowered.declarators.push(ctx.ast.variable_declarator(
+                SPAN,
+                state.kind,
+                binding.create_binding_pattern(ctx),
+                Some(init),
+                false,
+            ));
+            binding
+        } else {
+            let binding = self.ctx.var_declarations.create_uid_var(name, ctx);
+            lowered.expressions.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_write_target(ctx),
+                init,
+            ));
+            binding
+        }
+    }
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+}
+
+/// Output of lowering a destructuring pattern.
+struct Lowered<'a> {
+    /// `Some` when lowering a declaration, `None` when lowering an assignment
+    declaration: Option<DeclarationState>,
+    /// Declarators (for declarations)
+    declarators: Vec<VariableDeclarator<'a>>,
+    /// Assignment expressions (for assignments)
+    expressions: Vec<Expression<'a>>,
+}
+
+impl Lowered<'_> {
+    fn new(declaration: Option<DeclarationState>) -> Self {
+        Self { declaration, declarators: vec![], expressions: vec![] }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct DeclarationState {
+    kind: VariableDeclarationKind,
+    /// Scope to create temp vars in
+    scope_id: ScopeId,
+    /// Flags for temp vars
+    symbol_flags: SymbolFlags,
+}
+
+impl DeclarationState {
+    /// Get state for declaration of `pattern`.
+    /// Temp vars are created in same scope as bindings in the pattern.
+    fn new(
+        kind: VariableDeclarationKind,
+        pattern: &BindingPattern<'_>,
+        ctx: &TraverseCtx<'_>,
+    ) -> Self {
+        let mut scope_id = None;
+        pattern.bound_names(&mut |ident| {
+            scope_id.get_or_insert_with(|| ctx.scoping().symbol_scope_id(ident.symbol_id()));
+        });
+        let scope_id = scope_id.unwrap_or_else(|| {
+            if kind.is_var() { ctx.current_hoist_scope_id() } else { ctx.current_scope_id() }
+        });
+        let symbol_flags = match kind {
+            VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+            VariableDeclarationKind::Const => {
+                SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+            }
+            _ => SymbolFlags::BlockScopedVariable,
+        };
+        Self { kind, scope_id, symbol_flags }
+    }
+}
+
+/// Reference to a value which may be read multiple times.
+struct ValueReference<'a> {
+    /// Original expression. Used for first read.
+    expr: Option<Expression<'a>>,
+    /// Binding used for subsequent reads
+    binding: Option<MaybeBoundIdentifier<'a>>,
+}
+
+impl<'a> ValueReference<'a> {
+    fn read(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        self.expr
+            .take()
+            .unwrap_or_else(|| self.binding.as_ref().unwrap().create_read_expression(ctx))
+    }
+
+    /// Delete reference for original identifier, if it was never read.
+    fn finish(self, ctx: &mut TraverseCtx<'a>) {
+        if let Some(Expression::Identifier(ident)) = &self.expr {
+            ctx.delete_reference_for_identifier(ident);
+        }
+    }
+}
+
+/// Destructuring pattern, from either a `BindingPattern` or an `AssignmentTarget`.
+enum Pattern<'a> {
+    /// `a` in `var { a } = obj`
+    Binding(BindingIdentifier<'a>),
+    /// `a` or `x.y` in `({ a, b: x.y } = obj)`
+    Target(SimpleAssignmentTarget<'a>),
+    /// `a = 1` in `var { a = 1 } = obj`
+    Default(Box<Pattern<'a>>, Expression<'a>),
+    /// `{ a, ...b }`
+    Object(Vec<(PropertyKey<'a>, Pattern<'a>)>, Option<Box<Pattern<'a>>>),
+    /// `[a, , ...b]`
+    Array(Vec<Option<Pattern<'a>>>, Option<Box<Pattern<'a>>>),
+}
+
+impl<'a> Pattern<'a> {
+    fn from_binding_pattern(pattern: BindingPattern<'a>) -> Self {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => Self::Binding(ident.unbox()),
+            BindingPatternKind::AssignmentPattern(assign_pat) => {
+                let AssignmentPattern { left, right, .. } = assign_pat.unbox();
+                Self::Default(Box::new(Self::from_binding_pattern(left)), right)
+            }
+            BindingPatternKind::ObjectPattern(object_pat) => {
+                let ObjectPattern { properties, rest, .. } = object_pat.unbox();
+                let properties = properties
+                    .into_iter()
+                    .map(|property| (property.key, Self::from_binding_pattern(property.value)))
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Object(properties, rest)
+            }
+            BindingPatternKind::ArrayPattern(array_pat) => {
+                let ArrayPattern { elements, rest, .. } = array_pat.unbox();
+                let elements = elements
+                    .into_iter()
+                    .map(|element| element.map(Self::from_binding_pattern))
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Array(elements, rest)
+            }
+        }
+    }
+
+    fn from_assignment_target(target: AssignmentTarget<'a>, ast: AstBuilder<'a>) -> Self {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(object_target) => {
+                let ObjectAssignmentTarget { properties, rest, .. } = object_target.unbox();
+                let properties = properties
+                    .into_iter()
+                    .map(|property| match property {
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                            let AssignmentTargetPropertyIdentifier { binding, init, .. } =
+                                property.unbox();
+                            let key =
+                                ast.property_key_static_identifier(binding.span, binding.name);
+                            let pattern =
+                                Self::Target(SimpleAssignmentTarget::AssignmentTargetIdentifier(
+                                    ast.alloc(binding),
+                                ));
+                            let pattern = match init {
+                                Some(init) => Self::Default(Box::new(pattern), init),
+                                None => pattern,
+                            };
+                            (key, pattern)
+                        }
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let AssignmentTargetPropertyProperty { name, binding, .. } =
+                                property.unbox();
+                            (name, Self::from_assignment_target_maybe_default(binding, ast))
+                        }
+                    })
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_assignment_target(rest.target, ast)));
+                Self::Object(properties, rest)
+            }
+            AssignmentTarget::ArrayAssignmentTarget(array_target) => {
+                let ArrayAssignmentTarget { elements, rest, .. } = array_target.unbox();
+                let elements = elements
+                    .into_iter()
+                    .map(|element| {
+                        element
+                            .map(|element| Self::from_assignment_target_maybe_default(element, ast))
+                    })
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_assignment_target(rest.target, ast)));
+                Self::Array(elements, rest)
+            }
+            target => Self::Target(target.into_simple_assignment_target()),
+        }
+    }
+
+    fn from_assignment_target_maybe_default(
+        target: AssignmentTargetMaybeDefault<'a>,
+        ast: AstBuilder<'a>,
+    ) -> Self {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = target.unbox();
+                Self::Default(Box::new(Self::from_assignment_target(binding, ast)), init)
+            }
+            target => Self::from_assignment_target(target.into_assignment_target(), ast),
+        }
+    }
+
+    /// Get names of all identifiers which are assigned to by this pattern.
+    fn names(&self) -> Vec<Atom<'a>> {
+        let mut names = vec![];
+        self.collect_names(&mut names);
+        names
+    }
+
+    fn collect_names(&self, names: &mut Vec<Atom<'a>>) {
+        match self {
+            Self::Binding(ident) => names.push(ident.name),
+            Self::Target(SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)) => {
+                names.push(ident.name);
+            }
+            Self::Target(_) => {}
+            Self::Default(pattern, _) => pattern.collect_names(names),
+            Self::Object(properties, rest) => {
+                for (_, pattern) in properties {
+                    pattern.collect_names(names);
+                }
+                if let Some(rest) = rest {
+                    rest.collect_names(names);
+                }
+            }
+            Self::Array(elements, rest) => {
+                for pattern in elements.iter().flatten() {
+                    pattern.collect_names(names);
+                }
+                if let Some(rest) = rest {
+                    rest.collect_names(names);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! ES2015: Duplicate Keys
+//!
+//! This plugin converts duplicate keys in object literals to computed keys, as duplicate keys
+//! are a syntax error in ES5 strict mode.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = { a: 1, b: 2, a: 3 };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = { a: 1, b: 2, ["a"]: 3 };
+//! ```
+//!
+//! Computed keys are then transformed by computed properties transform.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-duplicate-keys](https://babel.dev/docs/babel-plugin-transform-duplicate-keys).
+//!
+//! A getter and a setter with same key are not duplicates of each other.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-duplicate-keys>
+//! * Object Initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+
+use crate::context::TraverseCtx;
+
+pub struct DuplicateKeys;
+
+impl DuplicateKeys {
+    /// `{ a: 1, a: 2 }` -> `{ a: 1, ["a"]: 2 }`
+    pub fn transform_object_expression<'a>(obj: &mut ObjectExpression<'a>, ctx: &TraverseCtx<'a>) {
+        let mut data = FxHashSet::default();
+        let mut getters = FxHashSet::default();
+        let mut setters = FxHashSet::default();
+        for property in &mut obj.properties {
+            let ObjectPropertyKind::ObjectProperty(prop) = property else { continue };
+            if prop.computed {
+                continue;
+            }
+            let Some(name) = prop.key.static_name() else { continue };
+            let is_duplicate = match prop.kind {
+                PropertyKind::Get => data.contains(&name) || !getters.insert(name.clone()),
+                PropertyKind::Set => data.contains(&name) || !setters.insert(name.clone()),
+                PropertyKind::Init => {
+                    getters.contains(&name) || setters.contains(&name) || !data.insert(name.clone())
+                }
+            };
+            if is_duplicate {
+                prop.key = PropertyKey::from(ctx.ast.expression_string_literal(
+                    SPAN,
+                    ctx.ast.atom(&name),
+                    None,
+                ));
+                prop.computed = true;
+                prop.shorthand = false;
+            }
+        }
+    }
+}
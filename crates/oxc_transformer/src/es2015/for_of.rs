@@ -0,0 +1,434 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for ... of` statements.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of items) {
+//!   foo(x);
+//! }
+//! for (const y of [1, 2, 3]) {
+//!   bar(y);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(items), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     foo(x);
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! for (var _i = 0, _arr = [1, 2, 3]; _i < _arr.length; _i++) {
+//!   const y = _arr[_i];
+//!   bar(y);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babel.dev/docs/babel-plugin-transform-for-of).
+//!
+//! Array literals are iterated over by index. With `iterableIsArray` assumption, all iterables
+//! are iterated over by index.
+//!
+//! `for await` is not transformed here. It's handled by async generator functions transform.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-for-of>
+//! * `for ... of` statement: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator, UpdateOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ChildScopeCollector,
+};
+
+pub struct ForOf<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ForOf<'a, '_> {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Labeled `for ... of` statements are transformed when exiting the outermost label
+        if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+            return;
+        }
+
+        // `a: b: for (x of y) {}`
+        let mut labels = vec![];
+        let mut body = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = body {
+            labels.push(labeled.label.clone());
+            body = &mut labeled.body;
+        }
+        let Statement::ForOfStatement(for_of) = body else { return };
+        if for_of.r#await {
+            return;
+        }
+
+        let is_array = self.ctx.assumptions.iterable_is_array
+            || matches!(for_of.right, Expression::ArrayExpression(_));
+        if is_array {
+            // Labels are left in place
+            *body = Self::transform_array(for_of, ctx);
+            return;
+        }
+        *stmt = self.transform_iterator(stmt, labels, ctx);
+    }
+}
+
+impl<'a> ForOf<'a, '_> {
+    /// `for (const x of [a, b]) {}`
+    /// -> `for (var _i = 0, _arr = [a, b]; _i < _arr.length; _i++) { const x = _arr[_i]; }`
+    fn transform_array(
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let array = ctx.generate_uid_in_current_hoist_scope("arr");
+        let index = ctx.generate_uid_in_current_hoist_scope("i");
+
+        // `var _i = 0, _arr = [a, b]`
+        let right = for_of.right.take_in(ctx.ast);
+        let declarations = ctx.ast.vec_from_array([
+            Self::create_var_declarator(
+                &index,
+                Some(ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal)),
+                ctx,
+            ),
+            Self::create_var_declarator(&array, Some(right), ctx),
+        ]);
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        );
+        // `_i < _arr.length`
+        let length = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            array.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        ));
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+        // `_i++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+        // `_arr[_i]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+
+        let for_scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+        let body = Self::create_body(for_of, value, for_scope_id, ctx);
+        ctx.ast.statement_for_with_scope_id(
+            for_of.span,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        )
+    }
+
+    /// ```js
+    /// for (const x of y) {}
+    /// ```
+    /// ->
+    /// ```js
+    /// var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;
+    /// try {
+    ///   for (_iterator.s(); !(_step = _iterator.n()).done;) { const x = _step.value; }
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    fn transform_iterator(
+        &self,
+        stmt: &mut Statement<'a>,
+        labels: Vec<LabelIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let mut for_of = {
+            let mut body = stmt.take_in(ctx.ast);
+            while let Statement::LabeledStatement(labeled) = body {
+                body = labeled.unbox().body;
+            }
+            let Statement::ForOfStatement(for_of) = body else { unreachable!() };
+            for_of
+        };
+
+        // If statement is not in a statement list, wrap it in a block,
+        // so `var` declaration can be inserted before it
+        let allow_multiple_statements = matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        );
+        let parent_scope_id = if allow_multiple_statements {
+            ctx.current_scope_id()
+        } else {
+            ctx.create_child_scope_of_current(ScopeFlags::empty())
+        };
+
+        // `var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;`
+        let iterator = ctx.generate_uid_in_current_hoist_scope("iterator");
+        let step = ctx.generate_uid_in_current_hoist_scope("step");
+        let right = for_of.right.take_in(ctx.ast);
+        let helper_call = self.ctx.helper_call_expr(
+            Helper::CreateForOfIteratorHelper,
+            SPAN,
+            ctx.ast.vec1(Argument::from(right)),
+            ctx,
+        );
+        let declarations = ctx.ast.vec_from_array([
+            Self::create_var_declarator(&iterator, Some(helper_call), ctx),
+            Self::create_var_declarator(&step, None, ctx),
+        ]);
+        let var_declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+
+        // `for (_iterator.s(); !(_step = _iterator.n()).done;) { const x = _step.value; }`
+        let try_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let for_scope_id = ctx.create_child_scope(try_scope_id, ScopeFlags::empty());
+        let init = ForStatementInit::from(Self::create_iterator_call(&iterator, "s", None, ctx));
+        let next = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            step.create_write_target(ctx),
+            Self::create_iterator_call(&iterator, "n", None, ctx),
+        );
+        let done = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            next,
+            ctx.ast.identifier_name(SPAN, "done"),
+            false,
+        ));
+        let test = ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, done);
+        let value = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            step.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, "value"),
+            false,
+        ));
+        let body = Self::create_body(&mut for_of, value, for_scope_id, ctx);
+        let mut for_stmt = ctx.ast.statement_for_with_scope_id(
+            for_of.span,
+            Some(init),
+            Some(test),
+            None,
+            body,
+            for_scope_id,
+        );
+        for label in labels.into_iter().rev() {
+            for_stmt = ctx.ast.statement_labeled(SPAN, label, for_stmt);
+        }
+        let block =
+            ctx.ast.alloc_block_statement_with_scope_id(SPAN, ctx.ast.vec1(for_stmt), try_scope_id);
+
+        // `catch (err) { _iterator.e(err); }`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            Atom::from("err"),
+            catch_body_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let call = Self::create_iterator_call(&iterator, "e", Some(&err), ctx);
+        let catch_body = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            catch_body_scope_id,
+        );
+        let handler = ctx.ast.alloc_catch_clause_with_scope_id(
+            SPAN,
+            Some(ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx))),
+            catch_body,
+            catch_scope_id,
+        );
+
+        // `finally { _iterator.f(); }`
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let call = Self::create_iterator_call(&iterator, "f", None, ctx);
+        let finalizer = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            finally_scope_id,
+        );
+
+        let try_stmt = ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer));
+        if allow_multiple_statements {
+            self.ctx.statement_injector.insert_before(&try_stmt, var_declaration);
+            try_stmt
+        } else {
+            ctx.ast.statement_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_array([var_declaration, try_stmt]),
+                parent_scope_id,
+            )
+        }
+    }
+
+    /// Create body of `for` statement, which assigns `value` to left side of `for ... of`.
+    ///
+    /// Scope of `for ... of` statement is reused as scope of the body block, so bindings
+    /// declared with `let` or `const` on the left are in the body block.
+    /// If original body is a block, its statements are merged into the new body block,
+    /// unless its bindings clash with bindings on the left.
+    fn create_body(
+        for_of: &mut ForOfStatement<'a>,
+        value: Expression<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let assignment = match &mut for_of.left {
+            // `const x = _step.value;`
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let mut declarator = decl.declarations.pop().unwrap();
+                declarator.init = Some(value);
+                Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    declarator.kind,
+                    ctx.ast.vec1(declarator),
+                    false,
+                ))
+            }
+            // `x = _step.value;`
+            left @ match_assignment_target!(ForStatementLeft) => {
+                let target = left.to_assignment_target_mut().take_in(ctx.ast);
+                let expr =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, expr)
+            }
+        };
+
+        let body_scope_id = for_of.scope_id();
+        ctx.scoping_mut().change_scope_parent_id(body_scope_id, Some(for_scope_id));
+
+        let mut statements = ctx.ast.vec1(assignment);
+        match for_of.body.take_in(ctx.ast) {
+            Statement::BlockStatement(block) => {
+                let block_scope_id = block.scope_id();
+                let scoping = ctx.scoping();
+                let has_clash = scoping.iter_bindings_in(block_scope_id).any(|symbol_id| {
+                    scoping.scope_has_binding(body_scope_id, scoping.symbol_name(symbol_id))
+                });
+                if has_clash {
+                    statements.push(Statement::BlockStatement(block));
+                } else {
+                    let block = block.unbox();
+                    Self::merge_scope_into_parent(block_scope_id, body_scope_id, &block.body, ctx);
+                    statements.extend(block.body);
+                }
+            }
+            Statement::EmptyStatement(_) => {}
+            body => statements.push(body),
+        }
+        ctx.ast.statement_block_with_scope_id(SPAN, statements, body_scope_id)
+    }
+
+    /// Move bindings and child scopes of `scope_id` to `parent_scope_id`, and delete the scope.
+    fn merge_scope_into_parent(
+        scope_id: ScopeId,
+        parent_scope_id: ScopeId,
+        statements: &ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scoping = ctx.scoping_mut();
+        let symbol_ids = scoping.iter_bindings_in(scope_id).collect::<Vec<_>>();
+        for symbol_id in symbol_ids {
+            let name = scoping.symbol_name(symbol_id).to_string();
+            scoping.move_binding(scope_id, parent_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, parent_scope_id);
+        }
+        let mut collector = ChildScopeCollector::default();
+        collector.visit_statements(statements);
+        for child_id in collector.scope_ids {
+            scoping.change_scope_parent_id(child_id, Some(parent_scope_id));
+        }
+        scoping.delete_scope(scope_id);
+    }
+
+    /// `_iterator.<method>()` or `_iterator.<method>(err)`
+    fn create_iterator_call(
+        iterator: &BoundIdentifier<'a>,
+        method: &'static str,
+        argument: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, method),
+            false,
+        ));
+        let arguments = match argument {
+            Some(argument) => ctx.ast.vec1(Argument::from(argument.create_read_expression(ctx))),
+            None => ctx.ast.vec(),
+        };
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    fn create_var_declarator(
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            init,
+            false,
+        )
+    }
+}
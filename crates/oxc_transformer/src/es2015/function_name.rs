@@ -0,0 +1,143 @@
+//! ES2015: Function Name
+//!
+//! This plugin names anonymous function expressions after the variable or property they are
+//! assigned to, so `Function.prototype.name` has same value as it would in ES2015+.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var foo = function () {};
+//! bar = function () {};
+//! var obj = { qux: function () {} };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var foo = function foo() {};
+//! bar = function bar() {};
+//! var obj = { qux: function qux() {} };
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-function-name](https://babel.dev/docs/babel-plugin-transform-function-name).
+//!
+//! A function is not named if giving it a name would change what a reference inside the function
+//! refers to, e.g. `var foo = function () { return foo; }`. Babel handles that case by renaming
+//! bindings, or wrapping the function, but naming a function is only cosmetic, so we skip it.
+//!
+//! Names which are not valid binding identifiers (e.g. `{ "foo-bar": function () {} }`,
+//! `{ delete: function () {} }`) are also skipped.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-function-name>
+//! * Babel helper implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-helper-function-name>
+//! * `SetFunctionName`: <https://tc39.es/ecma262/#sec-setfunctionname>
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword_or_global_object};
+
+use crate::context::TraverseCtx;
+
+pub struct FunctionName;
+
+impl FunctionName {
+    /// `var foo = function () {}` -> `var foo = function foo() {}`
+    pub fn transform_variable_declarator<'a>(
+        declarator: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (BindingPatternKind::BindingIdentifier(id), Some(Expression::FunctionExpression(func))) =
+            (&declarator.id.kind, &mut declarator.init)
+        else {
+            return;
+        };
+        Self::name_function(func, id.name, ctx);
+    }
+
+    /// `foo = function () {}` -> `foo = function foo() {}`
+    pub fn transform_assignment_expression<'a>(
+        assignment: &mut AssignmentExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if assignment.operator != AssignmentOperator::Assign {
+            return;
+        }
+        let (
+            AssignmentTarget::AssignmentTargetIdentifier(ident),
+            Expression::FunctionExpression(func),
+        ) = (&assignment.left, &mut assignment.right)
+        else {
+            return;
+        };
+        Self::name_function(func, ident.name, ctx);
+    }
+
+    /// `{ foo: function () {} }` -> `{ foo: function foo() {} }`
+    pub fn transform_object_property<'a>(prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if prop.kind != PropertyKind::Init || prop.method {
+            return;
+        }
+        let Expression::FunctionExpression(func) = &mut prop.value else { return };
+        let Some(name) = prop.key.static_name() else { return };
+        let name = ctx.ast.atom(&name);
+        Self::name_function(func, name, ctx);
+    }
+
+    fn name_function<'a>(func: &mut Function<'a>, name: Atom<'a>, ctx: &mut TraverseCtx<'a>) {
+        if func.id.is_some()
+            || !is_identifier_name(&name)
+            || is_reserved_keyword_or_global_object(&name)
+            || matches!(name.as_str(), "arguments" | "eval")
+        {
+            return;
+        }
+
+        let scope_id = func.scope_id();
+        if ctx.scoping().scope_has_binding(scope_id, &name) {
+            return;
+        }
+        let mut finder = OuterReferenceFinder { name, scope_id, ctx, found: false };
+        finder.visit_function(func, ScopeFlags::Function);
+        if finder.found {
+            return;
+        }
+
+        let binding = ctx.generate_binding(name, scope_id, SymbolFlags::Function);
+        func.id = Some(binding.create_binding_identifier(ctx));
+    }
+}
+
+/// Visitor to find a reference to `name` inside a function, which refers to a binding outside
+/// the function (or is unresolved).
+struct OuterReferenceFinder<'a, 'c> {
+    name: Atom<'a>,
+    /// Scope of the function
+    scope_id: ScopeId,
+    ctx: &'c TraverseCtx<'a>,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for OuterReferenceFinder<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name != self.name {
+            return;
+        }
+        let scoping = self.ctx.scoping();
+        let symbol_id = scoping.get_reference(ident.reference_id()).symbol_id();
+        let is_inside = symbol_id.is_some_and(|symbol_id| {
+            scoping
+                .scope_ancestors(scoping.symbol_scope_id(symbol_id))
+                .any(|id| id == self.scope_id)
+        });
+        if !is_inside {
+            self.found = true;
+        }
+    }
+}
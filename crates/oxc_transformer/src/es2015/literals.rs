@@ -0,0 +1,65 @@
+//! ES2015: Literals
+//!
+//! This plugin transforms binary and octal numeric literals, and unicode code point escapes
+//! in strings, which are not supported in ES5.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var a = 0b11;
+//! var b = 0o7;
+//! var c = "\u{1F600}";
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 3;
+//! var b = 7;
+//! var c = "😀";
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-literals](https://babel.dev/docs/babel-plugin-transform-literals).
+//!
+//! Like Babel, the raw source text of these literals is discarded, so they are printed from
+//! their values.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-literals>
+//! * Numeric Literals: <https://tc39.es/ecma262/#sec-literals-numeric-literals>
+//! * String Literals: <https://tc39.es/ecma262/#sec-literals-string-literals>
+
+use oxc_ast::ast::*;
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct Literals;
+
+impl Literals {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Literals {
+    // `0b11` -> `3`
+    fn exit_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if matches!(lit.base, NumberBase::Binary | NumberBase::Octal) {
+            lit.raw = None;
+        }
+    }
+
+    // `"\u{1F600}"` -> `"😀"`
+    fn exit_string_literal(&mut self, lit: &mut StringLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if lit.raw.is_some_and(|raw| raw.contains("\\u{")) {
+            lit.raw = None;
+        }
+    }
+}
@@ -1,29 +1,304 @@
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_traverse::Traverse;
 
-use crate::{context::TransformCtx, state::TransformState};
+use crate::{
+    common::arrow_function_converter::ArrowFunctionConverter,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod computed_properties;
+mod destructuring;
+mod duplicate_keys;
+mod for_of;
+mod function_name;
+mod literals;
+mod new_target;
 mod options;
+mod parameters;
+mod regenerator;
+mod shorthand_properties;
+mod spread;
+mod template_literals;
+mod typeof_symbol;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::{BlockScoping, BlockScopingOptions};
+pub use classes::Classes;
+pub use computed_properties::ComputedProperties;
+pub use destructuring::Destructuring;
+pub use duplicate_keys::DuplicateKeys;
+pub use for_of::ForOf;
+pub use function_name::FunctionName;
+pub use literals::Literals;
+pub use new_target::NewTarget;
 pub use options::ES2015Options;
+pub use parameters::Parameters;
+pub use regenerator::Regenerator;
+pub use shorthand_properties::ShorthandProperties;
+pub use spread::Spread;
+pub use template_literals::TemplateLiterals;
+pub use typeof_symbol::TypeofSymbol;
 
 pub struct ES2015<'a, 'ctx> {
-    #[expect(unused)]
     options: ES2015Options,
 
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions<'a, 'ctx>,
+    classes: Classes<'a, 'ctx>,
+    block_scoping: BlockScoping<'a, 'ctx>,
+    destructuring: Destructuring<'a, 'ctx>,
+    spread: Spread<'a, 'ctx>,
+    parameters: Parameters<'a, 'ctx>,
+    regenerator: Regenerator<'a, 'ctx>,
+    template_literals: TemplateLiterals<'a, 'ctx>,
+    for_of: ForOf<'a, 'ctx>,
+    computed_properties: ComputedProperties<'a, 'ctx>,
+    new_target: NewTarget<'a, 'ctx>,
+    typeof_symbol: TypeofSymbol<'a, 'ctx>,
+    literals: Literals,
+    ctx: &'ctx TransformCtx<'a>,
 }
 
 impl<'a, 'ctx> ES2015<'a, 'ctx> {
     pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default(), ctx),
+            classes: Classes::new(options.spread, ctx),
+            block_scoping: BlockScoping::new(options.block_scoping.unwrap_or_default(), ctx),
+            destructuring: Destructuring::new(ctx),
+            spread: Spread::new(ctx),
+            parameters: Parameters::new(ctx),
+            regenerator: Regenerator::new(ctx),
+            template_literals: TemplateLiterals::new(ctx),
+            for_of: ForOf::new(ctx),
+            computed_properties: ComputedProperties::new(ctx),
+            new_target: NewTarget::new(ctx),
+            typeof_symbol: TypeofSymbol::new(ctx),
+            literals: Literals::new(),
+            ctx,
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {}
+impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_program(program, ctx);
+        }
+        if self.options.regenerator {
+            self.regenerator.exit_program(program, ctx);
+        }
+        if self.options.template_literals {
+            self.template_literals.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.new_target {
+            self.new_target.enter_function(func, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.new_target {
+            self.new_target.enter_expression(expr, ctx);
+        }
+    }
+
+    fn exit_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.literals {
+            self.literals.exit_numeric_literal(lit, ctx);
+        }
+    }
+
+    fn exit_string_literal(&mut self, lit: &mut StringLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.literals {
+            self.literals.exit_string_literal(lit, ctx);
+        }
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes {
+            self.classes.exit_class(class, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes {
+            self.classes.exit_expression(expr, ctx);
+        }
+        match expr {
+            // Arrow function with rest parameter is converted to a function expression.
+            Expression::ArrowFunctionExpression(arrow) if arrow.params.rest.is_some() => {
+                if self.options.parameters {
+                    self.transform_arrow_function_with_rest(expr, ctx);
+                }
+            }
+            // Arrow functions converted to function expressions by arrow functions transform
+            // have not been visited by `exit_function`.
+            Expression::FunctionExpression(func) => self.transform_function(func, ctx),
+            Expression::ObjectExpression(_) => self.transform_object_expression(expr, ctx),
+            Expression::AssignmentExpression(assignment) => {
+                if self.options.function_name {
+                    FunctionName::transform_assignment_expression(assignment, ctx);
+                }
+            }
+            _ => {}
+        }
+        if self.options.template_literals {
+            self.template_literals.exit_expression(expr, ctx);
+        }
+        if self.options.typeof_symbol {
+            self.typeof_symbol.exit_expression(expr, ctx);
+        }
+        if self.options.destructuring {
+            self.destructuring.exit_expression(expr, ctx);
+        }
+        if self.options.spread {
+            self.spread.exit_expression(expr, ctx);
+        }
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Arrow functions with rest parameter are handled in `exit_expression`
+        if arrow.params.rest.is_some() && self.options.parameters {
+            return;
+        }
+        // Destructuring first, so that default values are applied before destructuring them
+        if self.options.destructuring {
+            let scope_id = arrow.scope_id();
+            self.destructuring.transform_parameters(
+                &mut arrow.params,
+                &mut arrow.body,
+                scope_id,
+                ctx,
+            );
+        }
+        if self.options.parameters {
+            Parameters::transform_arrow_function(arrow, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.transform_function(func, ctx);
+        if self.options.new_target {
+            self.new_target.exit_function(func, ctx);
+        }
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.function_name {
+            for declarator in &mut decl.declarations {
+                FunctionName::transform_variable_declarator(declarator, ctx);
+            }
+        }
+        if self.options.destructuring {
+            self.destructuring.exit_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_catch_clause(clause, ctx);
+        }
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring {
+            self.destructuring.exit_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes {
+            self.classes.exit_statement(stmt, ctx);
+        }
+        if self.options.for_of {
+            self.for_of.exit_statement(stmt, ctx);
+        }
+    }
+}
+
+impl<'a> ES2015<'a, '_> {
+    fn transform_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Destructuring first, so that default values are applied before destructuring them
+        if self.options.destructuring {
+            self.destructuring.exit_function(func, ctx);
+        }
+        if self.options.parameters {
+            self.parameters.exit_function(func, ctx);
+        }
+    }
+
+    /// Transform properties of object literal, and then computed properties, which may replace
+    /// the object literal with a sequence expression.
+    fn transform_object_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(obj) = expr else { unreachable!() };
+        if self.options.duplicate_keys {
+            DuplicateKeys::transform_object_expression(obj, ctx);
+        }
+        if self.options.shorthand_properties || self.options.function_name {
+            for property in &mut obj.properties {
+                let ObjectPropertyKind::ObjectProperty(prop) = property else { continue };
+                if self.options.shorthand_properties {
+                    ShorthandProperties::transform_object_property(prop, ctx);
+                }
+                if self.options.function_name {
+                    FunctionName::transform_object_property(prop, ctx);
+                }
+            }
+        }
+        if self.options.computed_properties {
+            self.computed_properties.transform_object_expression(expr, ctx);
+        }
+    }
+
+    /// `(a, ...rest) => {}` -> `function (a) { for (...) { rest[_key - 1] = arguments[_key]; } }`
+    ///
+    /// Arrow function is converted to a function expression, so that `arguments` can be used.
+    fn transform_arrow_function_with_rest(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ArrowFunctionExpression(arrow) = expr else { unreachable!() };
+        if !Parameters::arrow_can_be_function(arrow) {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Rest parameters in arrow functions which use `this`, `arguments`, `super` or `new.target` \
+                    cannot be transformed without arrow functions transform.",
+                )
+                .with_label(arrow.span),
+            );
+            return;
+        }
+        let Expression::ArrowFunctionExpression(arrow) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        *expr = ArrowFunctionConverter::transform_arrow_function_expression(arrow, ctx);
+        let Expression::FunctionExpression(func) = expr else { unreachable!() };
+        self.transform_function(func, ctx);
+    }
+}
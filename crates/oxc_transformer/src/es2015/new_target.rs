@@ -0,0 +1,193 @@
+//! ES2015: New Target
+//!
+//! This plugin transforms `new.target` meta property.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function Foo() {
+//!   console.log(new.target);
+//! }
+//! var bar = function () {
+//!   console.log(new.target);
+//! };
+//! class Baz {
+//!   constructor() {
+//!     console.log(new.target);
+//!   }
+//!   method() {
+//!     console.log(new.target);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! function Foo() {
+//!   console.log(this instanceof Foo ? this.constructor : void 0);
+//! }
+//! var bar = function _target() {
+//!   console.log(this instanceof _target ? this.constructor : void 0);
+//! };
+//! class Baz {
+//!   constructor() {
+//!     console.log(this.constructor);
+//!   }
+//!   method() {
+//!     console.log(void 0);
+//!   }
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-new-target](https://babel.dev/docs/babel-plugin-transform-new-target).
+//!
+//! Anonymous functions are given a name, so they can be referred to in `instanceof` check.
+//!
+//! `new.target` in arrow functions refers to `new.target` of enclosing function. It's transformed
+//! on entering, so arrow functions transform then replaces `this` in the output.
+//!
+//! Babel renames bindings which shadow function's name. We don't support that, and raise an error.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-new-target>
+//! * `new.target`: <https://tc39.es/ecma262/#sec-meta-properties>
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::BinaryOperator;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+/// Kind of function `new.target` refers to.
+enum FunctionKind<'a> {
+    /// Class constructor. `new.target` -> `this.constructor`.
+    Constructor,
+    /// Object or class method. `new.target` -> `void 0`.
+    Method,
+    /// Function which can be called with `new`.
+    /// `new.target` -> `this instanceof F ? this.constructor : void 0`.
+    Function {
+        /// Binding for function's name. `None` if function is anonymous, and no name created yet.
+        binding: Option<BoundIdentifier<'a>>,
+        /// Scope to create binding for function's name in, if it's anonymous
+        name_scope_id: ScopeId,
+    },
+}
+
+pub struct NewTarget<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Stack of enclosing non-arrow functions
+    function_stack: Vec<FunctionKind<'a>>,
+}
+
+impl<'a, 'ctx> NewTarget<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, function_stack: vec![] }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for NewTarget<'a, '_> {
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let kind = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) => {
+                if *method.kind() == MethodDefinitionKind::Constructor {
+                    FunctionKind::Constructor
+                } else {
+                    FunctionKind::Method
+                }
+            }
+            Ancestor::ObjectPropertyValue(prop)
+                if *prop.method() || *prop.kind() != PropertyKind::Init =>
+            {
+                FunctionKind::Method
+            }
+            _ => {
+                let binding = func.id.as_ref().map(BoundIdentifier::from_binding_ident);
+                // Name of function expression is bound in function's own scope.
+                // Name of function declaration (`export default function() {}`) is bound
+                // in parent scope.
+                let name_scope_id =
+                    if func.is_expression() { func.scope_id() } else { ctx.current_scope_id() };
+                FunctionKind::Function { binding, name_scope_id }
+            }
+        };
+        self.function_stack.push(kind);
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(FunctionKind::Function { binding: Some(binding), .. }) =
+            self.function_stack.pop()
+        {
+            if func.id.is_none() {
+                func.id = Some(binding.create_binding_identifier(ctx));
+            }
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::MetaProperty(meta) = expr else { return };
+        if meta.meta.name != "new" || meta.property.name != "target" {
+            return;
+        }
+        let span = meta.span;
+        let Some(kind) = self.function_stack.last_mut() else { return };
+        *expr = match kind {
+            FunctionKind::Constructor => Self::create_this_constructor(span, ctx),
+            FunctionKind::Method => ctx.ast.void_0(span),
+            FunctionKind::Function { binding, name_scope_id } => {
+                let binding = binding.get_or_insert_with(|| {
+                    ctx.generate_uid("target", *name_scope_id, SymbolFlags::Function)
+                });
+                // Check function's name is not shadowed
+                if ctx.scoping().find_binding(ctx.current_scope_id(), &binding.name)
+                    != Some(binding.symbol_id)
+                {
+                    self.ctx.error(
+                        OxcDiagnostic::error(
+                            "`new.target` cannot be transformed when function's name is shadowed.",
+                        )
+                        .with_label(span),
+                    );
+                    return;
+                }
+                // `this instanceof F ? this.constructor : void 0`
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    ctx.ast.expression_this(SPAN),
+                    BinaryOperator::Instanceof,
+                    binding.create_read_expression(ctx),
+                );
+                ctx.ast.expression_conditional(
+                    span,
+                    test,
+                    Self::create_this_constructor(SPAN, ctx),
+                    ctx.ast.void_0(SPAN),
+                )
+            }
+        };
+    }
+}
+
+impl<'a> NewTarget<'a, '_> {
+    /// `this.constructor`
+    fn create_this_constructor(span: Span, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        Expression::from(ctx.ast.member_expression_static(
+            span,
+            ctx.ast.expression_this(SPAN),
+            ctx.ast.identifier_name(SPAN, "constructor"),
+            false,
+        ))
+    }
+}
@@ -1,10 +1,55 @@
 use serde::Deserialize;
 
-use super::ArrowFunctionsOptions;
+use super::{ArrowFunctionsOptions, BlockScopingOptions};
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct ES2015Options {
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    #[serde(skip)]
+    pub classes: bool,
+
+    #[serde(skip)]
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    #[serde(skip)]
+    pub destructuring: bool,
+
+    #[serde(skip)]
+    pub spread: bool,
+
+    #[serde(skip)]
+    pub parameters: bool,
+
+    #[serde(skip)]
+    pub regenerator: bool,
+
+    #[serde(skip)]
+    pub template_literals: bool,
+
+    #[serde(skip)]
+    pub for_of: bool,
+
+    #[serde(skip)]
+    pub computed_properties: bool,
+
+    #[serde(skip)]
+    pub shorthand_properties: bool,
+
+    #[serde(skip)]
+    pub function_name: bool,
+
+    #[serde(skip)]
+    pub new_target: bool,
+
+    #[serde(skip)]
+    pub typeof_symbol: bool,
+
+    #[serde(skip)]
+    pub literals: bool,
+
+    #[serde(skip)]
+    pub duplicate_keys: bool,
 }
@@ -0,0 +1,476 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters and rest parameters.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function foo(a, b = 1, ...rest) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function foo(a) {
+//!   var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+//!   for (var _len = arguments.length, rest = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+//!     rest[_key - 2] = arguments[_key];
+//!   }
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-parameters](https://babel.dev/docs/babel-plugin-transform-parameters).
+//!
+//! Parameters from the first one with a default value onwards are moved into the function body,
+//! so that `length` property of the function is unchanged.
+//!
+//! With `ignoreFunctionLength` assumption, and in arrow functions (which have no `arguments`),
+//! parameters are kept and defaults are applied in function body instead:
+//!
+//! ```js
+//! function foo(a, b = 1) {}
+//! // ->
+//! function foo(a, b) {
+//!   if (b === void 0) b = 1;
+//! }
+//! ```
+//!
+//! If destructuring plugin is enabled, it transforms patterns in parameters before this plugin runs.
+//! Otherwise, patterns are moved into `var` declarations at top of function body.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-parameters>
+//! * Function definitions: <https://tc39.es/ecma262/#sec-function-definitions>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UpdateOperator};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct Parameters<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Parameters<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Parameters<'a, '_> {
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        if let Some(body) = func.body.as_mut() {
+            let keep_params = self.ctx.assumptions.ignore_function_length;
+            Self::transform_parameters(&mut func.params, body, scope_id, keep_params, ctx);
+        }
+    }
+}
+
+impl<'a> Parameters<'a, '_> {
+    /// Transform default parameters of an arrow function.
+    ///
+    /// Arrow functions have no `arguments`, so defaults are always applied in function body.
+    /// Rest parameter must be removed before calling this, by converting arrow function to a function expression.
+    pub fn transform_arrow_function(
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        debug_assert!(arrow.params.rest.is_none());
+        let scope_id = arrow.scope_id();
+        Self::transform_parameters(&mut arrow.params, &mut arrow.body, scope_id, true, ctx);
+    }
+
+    /// Returns `true` if arrow function can be converted to a function expression without changing
+    /// its behavior, i.e. it doesn't refer to `this`, `arguments`, `super` or `new.target`.
+    pub fn arrow_can_be_function(arrow: &ArrowFunctionExpression<'a>) -> bool {
+        let mut finder = LexicalContextFinder { found: false };
+        finder.visit_formal_parameters(&arrow.params);
+        finder.visit_function_body(&arrow.body);
+        !finder.found
+    }
+
+    /// Returns `true` if function's parameters need transforming.
+    pub fn needs_transform(params: &FormalParameters<'a>) -> bool {
+        params.rest.is_some()
+            || params.items.iter().any(|param| param.pattern.kind.is_assignment_pattern())
+    }
+
+    fn transform_parameters(
+        params: &mut FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+        scope_id: ScopeId,
+        keep_params: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !Self::needs_transform(params) {
+            return;
+        }
+
+        let params_len = params.items.len();
+        let mut statements = ctx.ast.vec();
+        if keep_params {
+            Self::transform_defaults_in_place(params, scope_id, &mut statements, ctx);
+        } else {
+            Self::transform_defaults_to_arguments(params, &mut statements, ctx);
+        }
+        if let Some(rest) = params.rest.take() {
+            Self::transform_rest(rest.unbox().argument, params_len, scope_id, &mut statements, ctx);
+        }
+
+        body.statements.splice(0..0, statements);
+    }
+
+    /// `function f(a = 1, { b } = {}) {}`
+    /// ->
+    /// `function f(a, _ref) { if (a === void 0) a = 1; var { b } = _ref === void 0 ? {} : _ref; }`
+    fn transform_defaults_in_place(
+        params: &mut FormalParameters<'a>,
+        scope_id: ScopeId,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for param in &mut params.items {
+            if !param.pattern.kind.is_assignment_pattern() {
+                continue;
+            }
+            let BindingPatternKind::AssignmentPattern(assign_pat) =
+                param.pattern.kind.take_in(ctx.ast)
+            else {
+                unreachable!()
+            };
+            let AssignmentPattern { left, right, .. } = assign_pat.unbox();
+
+            if let BindingPatternKind::BindingIdentifier(ident) = &left.kind {
+                // `if (a === void 0) a = 1;`
+                let binding = BoundIdentifier::from_binding_ident(ident);
+                let test = Self::create_is_undefined(binding.create_read_expression(ctx), ctx);
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_write_target(ctx),
+                    right,
+                );
+                let consequent = ctx.ast.statement_expression(SPAN, assignment);
+                statements.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+                param.pattern = left;
+            } else {
+                // `var { b } = _ref === void 0 ? {} : _ref;`
+                let binding =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let test = Self::create_is_undefined(binding.create_read_expression(ctx), ctx);
+                let init = ctx.ast.expression_conditional(
+                    SPAN,
+                    test,
+                    right,
+                    binding.create_read_expression(ctx),
+                );
+                statements.push(Self::create_var_declaration(left, init, ctx));
+                param.pattern = binding.create_binding_pattern(ctx);
+            }
+        }
+    }
+
+    /// `function f(a, b = 1, c) {}`
+    /// ->
+    /// ```js
+    /// function f(a) {
+    ///   var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+    ///   var c = arguments.length > 2 ? arguments[2] : void 0;
+    /// }
+    /// ```
+    fn transform_defaults_to_arguments(
+        params: &mut FormalParameters<'a>,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(first_default_index) =
+            params.items.iter().position(|param| param.pattern.kind.is_assignment_pattern())
+        else {
+            return;
+        };
+
+        for (index, param) in params.items.drain(first_default_index..).enumerate() {
+            let index = first_default_index + index;
+            let (pattern, init) = if let BindingPatternKind::AssignmentPattern(assign_pat) =
+                param.pattern.kind
+            {
+                let AssignmentPattern { left, right, .. } = assign_pat.unbox();
+                // `arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1`
+                let is_present = Self::create_arguments_length_check(index, ctx);
+                let is_not_undefined = ctx.ast.expression_binary(
+                    SPAN,
+                    Self::create_arguments_member(index, ctx),
+                    BinaryOperator::StrictInequality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let test = ctx.ast.expression_logical(
+                    SPAN,
+                    is_present,
+                    LogicalOperator::And,
+                    is_not_undefined,
+                );
+                let consequent = Self::create_arguments_member(index, ctx);
+                (left, ctx.ast.expression_conditional(SPAN, test, consequent, right))
+            } else {
+                // `arguments.length > 2 ? arguments[2] : void 0`
+                let test = Self::create_arguments_length_check(index, ctx);
+                let consequent = Self::create_arguments_member(index, ctx);
+                let init =
+                    ctx.ast.expression_conditional(SPAN, test, consequent, ctx.ast.void_0(SPAN));
+                (param.pattern, init)
+            };
+            statements.push(Self::create_var_declaration(pattern, init, ctx));
+        }
+    }
+
+    /// `function f(a, ...rest) {}`
+    /// ->
+    /// ```js
+    /// function f(a) {
+    ///   for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    ///     rest[_key - 1] = arguments[_key];
+    ///   }
+    /// }
+    /// ```
+    fn transform_rest(
+        pattern: BindingPattern<'a>,
+        start: usize,
+        scope_id: ScopeId,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `function f(...[a, b]) {}` -> `for (var _len = ..., _ref = new Array(_len), ...) {} var [a, b] = _ref;`
+        let (binding, binding_pattern, pattern) =
+            if let BindingPatternKind::BindingIdentifier(ident) = &pattern.kind {
+                (BoundIdentifier::from_binding_ident(ident), pattern, None)
+            } else {
+                let binding =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let binding_pattern = binding.create_binding_pattern(ctx);
+                (binding, binding_pattern, Some(pattern))
+            };
+
+        let len = ctx.generate_uid("len", scope_id, SymbolFlags::FunctionScopedVariable);
+        let key = ctx.generate_uid("key", scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `new Array(_len > 1 ? _len - 1 : 0)` or `new Array(_len)`
+        let array_len = if start == 0 {
+            len.create_read_expression(ctx)
+        } else {
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                len.create_read_expression(ctx),
+                BinaryOperator::GreaterThan,
+                Self::create_number(start, ctx),
+            );
+            let consequent = ctx.ast.expression_binary(
+                SPAN,
+                len.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                Self::create_number(start, ctx),
+            );
+            ctx.ast.expression_conditional(SPAN, test, consequent, Self::create_number(0, ctx))
+        };
+        let array_callee =
+            ctx.create_unbound_ident_expr(SPAN, "Array".into(), ReferenceFlags::Read);
+        let array = ctx.ast.expression_new(
+            SPAN,
+            array_callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(array_len)),
+        );
+
+        // `var _len = arguments.length, rest = new Array(...), _key = 1`
+        let kind = VariableDeclarationKind::Var;
+        let arguments = Self::create_arguments(ctx);
+        let arguments_length = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            arguments,
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        ));
+        let declarations = ctx.ast.vec_from_array([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                len.create_binding_pattern(ctx),
+                Some(arguments_length),
+                false,
+            ),
+            ctx.ast.variable_declarator(SPAN, kind, binding_pattern, Some(array), false),
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                key.create_binding_pattern(ctx),
+                Some(Self::create_number(start, ctx)),
+                false,
+            ),
+        ]);
+        let init = ctx.ast.for_statement_init_variable_declaration(SPAN, kind, declarations, false);
+
+        // `_key < _len`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len.create_read_expression(ctx),
+        );
+        // `_key++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            key.create_read_write_simple_target(ctx),
+        );
+
+        // `rest[_key - 1] = arguments[_key];`
+        let index = if start == 0 {
+            key.create_read_expression(ctx)
+        } else {
+            ctx.ast.expression_binary(
+                SPAN,
+                key.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                Self::create_number(start, ctx),
+            )
+        };
+        let target = AssignmentTarget::from(ctx.ast.member_expression_computed(
+            SPAN,
+            binding.create_read_expression(ctx),
+            index,
+            false,
+        ));
+        let arguments = Self::create_arguments(ctx);
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            arguments,
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+        let body = ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            block_scope_id,
+        );
+        statements.push(ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        ));
+
+        if let Some(pattern) = pattern {
+            let init = binding.create_read_expression(ctx);
+            statements.push(Self::create_var_declaration(pattern, init, ctx));
+        }
+    }
+
+    /// `var <pattern> = <init>;`
+    fn create_var_declaration(
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// `<expr> === void 0`
+    fn create_is_undefined(expr: Expression<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_binary(SPAN, expr, BinaryOperator::StrictEquality, ctx.ast.void_0(SPAN))
+    }
+
+    /// `arguments.length > <index>`
+    fn create_arguments_length_check(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = Self::create_arguments(ctx);
+        let length = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            arguments,
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        ));
+        ctx.ast.expression_binary(
+            SPAN,
+            length,
+            BinaryOperator::GreaterThan,
+            Self::create_number(index, ctx),
+        )
+    }
+
+    /// `arguments[<index>]`
+    fn create_arguments_member(index: usize, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = Self::create_arguments(ctx);
+        Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            arguments,
+            Self::create_number(index, ctx),
+            false,
+        ))
+    }
+
+    fn create_arguments(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.create_unbound_ident_expr(SPAN, "arguments".into(), ReferenceFlags::Read)
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn create_number(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+    }
+}
+
+/// Visitor to find references to `this`, `arguments`, `super` or `new.target`
+/// which are inherited from the enclosing scope.
+struct LexicalContextFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LexicalContextFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        self.found = true;
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "arguments" {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        // Functions have their own `this`, `arguments`, `super` and `new.target`
+    }
+}
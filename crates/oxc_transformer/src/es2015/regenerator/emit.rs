@@ -0,0 +1,1470 @@
+//! Explosion of generator function body into a state machine.
+//!
+//! Statements and expressions which contain "leaps" (`yield`, `break`, `continue`, `return` or
+//! `throw`) are broken down into a flat listing of simple statements. Locations in the listing
+//! which can be jumped to are marked, and become cases of the `switch` statement in the
+//! dispatch loop:
+//!
+//! ```js
+//! while (1) switch (_context.prev = _context.next) {
+//!   case 0:
+//!     // ...
+//!     _context.next = 3;
+//!     break;
+//!   case 3:
+//!     // ...
+//!   case 6:
+//!   case "end":
+//!     return _context.stop();
+//! }
+//! ```
+//!
+//! Intermediate values of expressions which must be evaluated before a `yield` are stored in
+//! temp properties of the context object (`_context.t0`, `_context.t1`, ...), so they survive
+//! the function returning and being re-entered.
+//!
+//! Statements which do not contain leaps are emitted unchanged.
+//!
+//! Based on [regenerator-transform's `Emitter`](https://github.com/facebook/regenerator/blob/main/packages/transform/src/emit.js).
+
+use oxc_allocator::{CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+};
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier};
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+use super::{binding_pattern_to_assignment_target, create_runtime_method_call};
+
+/// Raw value of numeric literals which are placeholders for locations.
+/// Replaced with location's offset in listing once all locations are marked.
+const LOC_PLACEHOLDER: &str = "@@loc";
+
+/// A location in the listing which can be jumped to.
+///
+/// Index into [`Emitter::locs`].
+#[derive(Clone, Copy)]
+struct Loc(usize);
+
+/// An entry in stack of statements which `break` and `continue` can jump out of.
+struct LeapEntry<'a> {
+    break_loc: Loc,
+    /// `None` for `switch` and labeled statements
+    continue_loc: Option<Loc>,
+    label: Option<Atom<'a>>,
+    /// `true` for labeled statements, which can only be target of labeled `break`
+    is_labeled_statement: bool,
+}
+
+/// Locations of a `try` statement, for `tryLocsList` argument of `wrap`.
+struct TryEntry {
+    try_loc: usize,
+    catch_loc: Option<Loc>,
+    /// Locations of `finally` block and location after it
+    finally_locs: Option<(Loc, Loc)>,
+}
+
+pub struct Emitter<'a, 'c> {
+    transform_ctx: &'c TransformCtx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+    /// `_context` binding
+    context: BoundIdentifier<'a>,
+    /// Scope of `switch` statement in dispatch loop
+    switch_scope_id: ScopeId,
+    listing: Vec<Statement<'a>>,
+    /// `true` for offsets in listing which are marked as jump targets
+    marked: Vec<bool>,
+    /// Offsets in listing of locations. `None` if location is not marked yet.
+    locs: Vec<Option<usize>>,
+    /// Location of end of function
+    final_loc: Loc,
+    leap_entries: Vec<LeapEntry<'a>>,
+    try_entries: Vec<TryEntry>,
+    /// Number of `_context.t<n>` temps created
+    temp_count: usize,
+}
+
+impl<'a, 'c> Emitter<'a, 'c> {
+    pub fn new(
+        context: BoundIdentifier<'a>,
+        switch_scope_id: ScopeId,
+        transform_ctx: &'c TransformCtx<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        let mut emitter = Self {
+            transform_ctx,
+            ctx,
+            context,
+            switch_scope_id,
+            listing: vec![],
+            marked: vec![true],
+            locs: vec![],
+            final_loc: Loc(0),
+            leap_entries: vec![],
+            try_entries: vec![],
+            temp_count: 0,
+        };
+        emitter.final_loc = emitter.loc();
+        emitter
+    }
+
+    pub fn explode_statements(&mut self, stmts: ArenaVec<'a, Statement<'a>>) {
+        for stmt in stmts {
+            self.explode_statement(stmt, None);
+        }
+    }
+
+    /// Build dispatch loop from listing, and `tryLocsList` argument for `wrap`.
+    ///
+    /// `while (1) switch (_context.prev = _context.next) { ... }`
+    pub fn finish(mut self) -> (Statement<'a>, Option<Expression<'a>>) {
+        let final_offset = self.listing.len();
+        self.locs[self.final_loc.0] = Some(final_offset);
+
+        // Replace location placeholders with offsets
+        let mut patcher = LocPatcher { locs: &self.locs };
+        for stmt in &mut self.listing {
+            patcher.visit_statement(stmt);
+        }
+
+        // Split listing into cases. Statements after a completion statement are unreachable
+        // until next marked location, so are dropped.
+        let mut cases = self.ctx.ast.vec();
+        let mut current: Option<(usize, ArenaVec<'a, Statement<'a>>)> = None;
+        let mut already_ended = false;
+        let listing = std::mem::take(&mut self.listing);
+        for (offset, stmt) in listing.into_iter().enumerate() {
+            if self.is_marked(offset) {
+                if let Some((case_offset, consequent)) = current.take() {
+                    cases.push(self.create_case(case_offset, consequent));
+                }
+                current = Some((offset, self.ctx.ast.vec()));
+                already_ended = false;
+            }
+            if already_ended {
+                ReferenceRemover { ctx: self.ctx }.visit_statement(&stmt);
+            } else {
+                already_ended = matches!(
+                    stmt,
+                    Statement::ReturnStatement(_)
+                        | Statement::ThrowStatement(_)
+                        | Statement::BreakStatement(_)
+                        | Statement::ContinueStatement(_)
+                );
+                current.as_mut().unwrap().1.push(stmt);
+            }
+        }
+        if let Some((case_offset, consequent)) = current.take() {
+            cases.push(self.create_case(case_offset, consequent));
+        } else {
+            // Empty listing. `case 0:` is still needed.
+            cases.push(self.create_case(0, self.ctx.ast.vec()));
+        }
+        if final_offset > 0 {
+            cases.push(self.create_case(final_offset, self.ctx.ast.vec()));
+        }
+
+        // `case "end": return _context.stop();`
+        let stop = self.create_context_call("stop", self.ctx.ast.vec());
+        cases.push(self.ctx.ast.switch_case(
+            SPAN,
+            Some(self.ctx.ast.expression_string_literal(SPAN, "end", None)),
+            self.ctx.ast.vec1(self.ctx.ast.statement_return(SPAN, Some(stop))),
+        ));
+
+        // `switch (_context.prev = _context.next) { ... }`
+        let next = self.create_context_property("next");
+        let discriminant = self.create_context_assignment("prev", next);
+        let switch = self.ctx.ast.statement_switch_with_scope_id(
+            SPAN,
+            discriminant,
+            cases,
+            self.switch_scope_id,
+        );
+        // `while (1) switch (...) { ... }`
+        let dispatch_loop = self.ctx.ast.statement_while(SPAN, create_number(1, self.ctx), switch);
+
+        let try_locs = self.create_try_locs_list();
+        (dispatch_loop, try_locs)
+    }
+
+    fn is_marked(&self, offset: usize) -> bool {
+        self.marked.get(offset).copied().unwrap_or(false)
+    }
+
+    /// `case <offset>: <consequent>`
+    fn create_case(
+        &self,
+        offset: usize,
+        consequent: ArenaVec<'a, Statement<'a>>,
+    ) -> SwitchCase<'a> {
+        self.ctx.ast.switch_case(SPAN, Some(create_number(offset, self.ctx)), consequent)
+    }
+
+    /// `[[1, 5], [8, , 12, 15]]`
+    fn create_try_locs_list(&self) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let entries = self.ctx.ast.vec_from_iter(self.try_entries.iter().map(|entry| {
+            let mut locs = self.ctx.ast.vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::from(create_number(entry.try_loc, self.ctx)));
+            locs.push(match entry.catch_loc {
+                Some(loc) => ArrayExpressionElement::from(self.resolved_loc_expr(loc)),
+                None => self.ctx.ast.array_expression_element_elision(SPAN),
+            });
+            if let Some((finally_loc, after_loc)) = entry.finally_locs {
+                locs.push(ArrayExpressionElement::from(self.resolved_loc_expr(finally_loc)));
+                locs.push(ArrayExpressionElement::from(self.resolved_loc_expr(after_loc)));
+            }
+            ArrayExpressionElement::from(self.ctx.ast.expression_array(SPAN, locs))
+        }));
+        Some(self.ctx.ast.expression_array(SPAN, entries))
+    }
+
+    // ---------- Locations ----------
+
+    /// Create a new location, not yet marked.
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Mark location as current offset in the listing.
+    fn mark(&mut self, loc: Loc) {
+        let offset = self.listing.len();
+        self.locs[loc.0] = Some(offset);
+        if self.marked.len() <= offset {
+            self.marked.resize(offset + 1, false);
+        }
+        self.marked[offset] = true;
+    }
+
+    /// Expression for a location. Placeholder which is replaced once all locations are marked.
+    fn loc_expr(&self, loc: Loc) -> Expression<'a> {
+        self.ctx.ast.expression_numeric_literal(
+            SPAN,
+            create_f64(loc.0),
+            Some(Atom::from(LOC_PLACEHOLDER)),
+            NumberBase::Decimal,
+        )
+    }
+
+    /// Expression for a location which has already been marked.
+    fn resolved_loc_expr(&self, loc: Loc) -> Expression<'a> {
+        create_number(self.locs[loc.0].unwrap(), self.ctx)
+    }
+
+    // ---------- Emitting ----------
+
+    fn emit(&mut self, stmt: Statement<'a>) {
+        self.listing.push(stmt);
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>) {
+        let stmt = self.ctx.ast.statement_expression(SPAN, expr);
+        self.emit(stmt);
+    }
+
+    /// `_context.<name>`
+    fn create_context_property(&mut self, name: &str) -> Expression<'a> {
+        let object = self.context.create_read_expression(self.ctx);
+        Expression::from(self.ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            self.ctx.ast.identifier_name(SPAN, self.ctx.ast.atom(name)),
+            false,
+        ))
+    }
+
+    /// `_context.<name> = <value>`
+    fn create_context_assignment(&mut self, name: &str, value: Expression<'a>) -> Expression<'a> {
+        let object = self.context.create_read_expression(self.ctx);
+        let target = SimpleAssignmentTarget::from(self.ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            self.ctx.ast.identifier_name(SPAN, self.ctx.ast.atom(name)),
+            false,
+        ));
+        self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            value,
+        )
+    }
+
+    /// `_context.<method>(<arguments>)`
+    fn create_context_call(
+        &mut self,
+        method: &str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let callee = self.create_context_property(method);
+        self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// Create a new temp `_context.t<n>`.
+    fn make_temp(&mut self) -> usize {
+        self.temp_count += 1;
+        self.temp_count - 1
+    }
+
+    fn temp_name(temp: usize) -> String {
+        format!("t{temp}")
+    }
+
+    /// `_context.t<n>`
+    fn temp_expr(&mut self, temp: usize) -> Expression<'a> {
+        self.create_context_property(&Self::temp_name(temp))
+    }
+
+    /// `_context.t<n> = <value>;`
+    fn emit_assign_temp(&mut self, temp: usize, value: Expression<'a>) {
+        let assignment = self.create_context_assignment(&Self::temp_name(temp), value);
+        self.emit_expression(assignment);
+    }
+
+    /// `_context.next = <loc>; break;`
+    fn jump(&mut self, loc: Loc) {
+        let loc = self.loc_expr(loc);
+        self.jump_to_expr(loc);
+    }
+
+    /// `_context.next = <target>; break;`
+    fn jump_to_expr(&mut self, target: Expression<'a>) {
+        let assignment = self.create_context_assignment("next", target);
+        self.emit_expression(assignment);
+        self.emit(self.ctx.ast.statement_break(SPAN, None));
+    }
+
+    /// `if (<test>) { _context.next = <loc>; break; }`
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        let loc = self.loc_expr(loc);
+        let assignment = self.create_context_assignment("next", loc);
+        let scope_id = self.ctx.create_child_scope(self.switch_scope_id, ScopeFlags::empty());
+        let block = self.ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            self.ctx.ast.vec_from_array([
+                self.ctx.ast.statement_expression(SPAN, assignment),
+                self.ctx.ast.statement_break(SPAN, None),
+            ]),
+            scope_id,
+        );
+        self.emit(self.ctx.ast.statement_if(SPAN, test, block, None));
+    }
+
+    /// `if (!<test>) { _context.next = <loc>; break; }`
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc) {
+        let test = match test {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => self.ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.jump_if(test, loc);
+    }
+
+    /// `return _context.abrupt("<kind>", <argument>);`
+    fn emit_abrupt_completion(&mut self, kind: &'static str, argument: Option<Expression<'a>>) {
+        let mut arguments = self.ctx.ast.vec_with_capacity(2);
+        arguments.push(Argument::from(self.ctx.ast.expression_string_literal(SPAN, kind, None)));
+        if let Some(argument) = argument {
+            arguments.push(Argument::from(argument));
+        }
+        let abrupt = self.create_context_call("abrupt", arguments);
+        self.emit(self.ctx.ast.statement_return(SPAN, Some(abrupt)));
+    }
+
+    /// `_context.prev = <loc>;`
+    fn update_context_prev_loc(&mut self, loc: Expression<'a>) {
+        let assignment = self.create_context_assignment("prev", loc);
+        self.emit_expression(assignment);
+    }
+
+    fn unsupported(&self, span: Span) {
+        self.transform_ctx.error(
+            OxcDiagnostic::error(
+                "This syntax containing `yield` is not supported by regenerator transform.",
+            )
+            .with_label(span),
+        );
+    }
+
+    // ---------- Statements ----------
+
+    fn explode_statement(&mut self, stmt: Statement<'a>, label: Option<Atom<'a>>) {
+        if !contains_leap_in_statement(&stmt) {
+            // Declarations have been hoisted, so remaining empty statements are not needed
+            if !matches!(stmt, Statement::EmptyStatement(_)) {
+                self.emit(stmt);
+            }
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(expr_stmt) => {
+                self.explode_expression(expr_stmt.unbox().expression, true);
+            }
+            Statement::BlockStatement(block) => {
+                for stmt in block.unbox().body {
+                    self.explode_statement(stmt, None);
+                }
+            }
+            Statement::LabeledStatement(labeled) => {
+                let LabeledStatement { label, body, .. } = labeled.unbox();
+                let after = self.loc();
+                self.leap_entries.push(LeapEntry {
+                    break_loc: after,
+                    continue_loc: None,
+                    label: Some(label.name),
+                    is_labeled_statement: true,
+                });
+                self.explode_statement(body, Some(label.name));
+                self.leap_entries.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(while_stmt) => {
+                let WhileStatement { test, body, .. } = while_stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode_expression(test, false).unwrap();
+                self.jump_if_not(test, after);
+                self.explode_loop_body(body, after, before, label);
+                self.jump(before);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(do_while) => {
+                let DoWhileStatement { body, test, .. } = do_while.unbox();
+                let first = self.loc();
+                let test_loc = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.explode_loop_body(body, after, test_loc, label);
+                self.mark(test_loc);
+                let test = self.explode_expression(test, false).unwrap();
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(for_stmt) => {
+                let ForStatement { init, test, update, body, .. } = for_stmt.unbox();
+                let head = self.loc();
+                let update_loc = self.loc();
+                let after = self.loc();
+                if let Some(init) = init {
+                    match init {
+                        ForStatementInit::VariableDeclaration(decl) => {
+                            // Hoister converts declarations to expressions
+                            self.unsupported(decl.span);
+                        }
+                        init => {
+                            self.explode_expression(init.into_expression(), true);
+                        }
+                    }
+                }
+                self.mark(head);
+                if let Some(test) = test {
+                    let test = self.explode_expression(test, false).unwrap();
+                    self.jump_if_not(test, after);
+                }
+                self.explode_loop_body(body, after, update_loc, label);
+                self.mark(update_loc);
+                if let Some(update) = update {
+                    self.explode_expression(update, true);
+                }
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::ForInStatement(for_in) => {
+                let ForInStatement { left, right, body, .. } = for_in.unbox();
+                let Some(left) = self.explode_for_statement_left(left) else { return };
+                let head = self.loc();
+                let after = self.loc();
+
+                // `_context.t0 = babelHelpers.regeneratorRuntime().keys(obj);`
+                let right = self.explode_expression(right, false).unwrap();
+                let keys = create_runtime_method_call(
+                    "keys",
+                    self.ctx.ast.vec1(Argument::from(right)),
+                    self.transform_ctx,
+                    self.ctx,
+                );
+                let keys_temp = self.make_temp();
+                self.emit_assign_temp(keys_temp, keys);
+
+                // `if ((_context.t1 = _context.t0()).done) { _context.next = <after>; break; }`
+                self.mark(head);
+                let key_temp = self.make_temp();
+                let next_fn = self.temp_expr(keys_temp);
+                let next =
+                    self.ctx.ast.expression_call(SPAN, next_fn, NONE, self.ctx.ast.vec(), false);
+                self.jump_to_done_check(key_temp, next, after);
+
+                // `x = _context.t1.value;`
+                self.emit_iteration_value_assignment(left, key_temp);
+                self.explode_loop_body(body, after, head, label);
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::ForOfStatement(for_of) => {
+                let ForOfStatement { span, r#await, left, right, body, .. } = for_of.unbox();
+                if r#await {
+                    self.unsupported(span);
+                    return;
+                }
+                let Some(left) = self.explode_for_statement_left(left) else { return };
+                let head = self.loc();
+                let after = self.loc();
+
+                // `_context.t0 = arr[Symbol.iterator]();`
+                let right = self.explode_expression(right, false).unwrap();
+                let symbol = self.ctx.create_unbound_ident_expr(
+                    SPAN,
+                    Atom::from("Symbol"),
+                    ReferenceFlags::Read,
+                );
+                let iterator_key = Expression::from(self.ctx.ast.member_expression_static(
+                    SPAN,
+                    symbol,
+                    self.ctx.ast.identifier_name(SPAN, "iterator"),
+                    false,
+                ));
+                let iterator_fn = Expression::from(self.ctx.ast.member_expression_computed(
+                    SPAN,
+                    right,
+                    iterator_key,
+                    false,
+                ));
+                let iterator = self.ctx.ast.expression_call(
+                    SPAN,
+                    iterator_fn,
+                    NONE,
+                    self.ctx.ast.vec(),
+                    false,
+                );
+                let iterator_temp = self.make_temp();
+                self.emit_assign_temp(iterator_temp, iterator);
+
+                // `if ((_context.t1 = _context.t0.next()).done) { _context.next = <after>; break; }`
+                self.mark(head);
+                let step_temp = self.make_temp();
+                let iterator = self.temp_expr(iterator_temp);
+                let next_fn = Expression::from(self.ctx.ast.member_expression_static(
+                    SPAN,
+                    iterator,
+                    self.ctx.ast.identifier_name(SPAN, "next"),
+                    false,
+                ));
+                let next =
+                    self.ctx.ast.expression_call(SPAN, next_fn, NONE, self.ctx.ast.vec(), false);
+                self.jump_to_done_check(step_temp, next, after);
+
+                // `x = _context.t1.value;`
+                self.emit_iteration_value_assignment(left, step_temp);
+                self.explode_loop_body(body, after, head, label);
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::IfStatement(if_stmt) => {
+                let IfStatement { test, consequent, alternate, .. } = if_stmt.unbox();
+                let else_loc = alternate.as_ref().map(|_| self.loc());
+                let after = self.loc();
+                let test = self.explode_expression(test, false).unwrap();
+                self.jump_if_not(test, else_loc.unwrap_or(after));
+                self.explode_statement(consequent, None);
+                if let (Some(else_loc), Some(alternate)) = (else_loc, alternate) {
+                    self.jump(after);
+                    self.mark(else_loc);
+                    self.explode_statement(alternate, None);
+                }
+                self.mark(after);
+            }
+            Statement::SwitchStatement(switch) => {
+                self.explode_switch_statement(switch.unbox());
+            }
+            Statement::ReturnStatement(ret) => {
+                let argument =
+                    ret.unbox().argument.map(|arg| self.explode_expression(arg, false).unwrap());
+                self.emit_abrupt_completion("return", argument);
+            }
+            Statement::BreakStatement(break_stmt) => {
+                let break_stmt = break_stmt.unbox();
+                let label = break_stmt.label.as_ref().map(|label| label.name);
+                if let Some(loc) = self.find_leap_location(label, false) {
+                    let loc = self.loc_expr(loc);
+                    self.emit_abrupt_completion("break", Some(loc));
+                } else {
+                    self.unsupported(break_stmt.span);
+                }
+            }
+            Statement::ContinueStatement(continue_stmt) => {
+                let continue_stmt = continue_stmt.unbox();
+                let label = continue_stmt.label.as_ref().map(|label| label.name);
+                if let Some(loc) = self.find_leap_location(label, true) {
+                    let loc = self.loc_expr(loc);
+                    self.emit_abrupt_completion("continue", Some(loc));
+                } else {
+                    self.unsupported(continue_stmt.span);
+                }
+            }
+            Statement::ThrowStatement(throw) => {
+                let throw = throw.unbox();
+                let argument = self.explode_expression(throw.argument, false).unwrap();
+                self.emit(self.ctx.ast.statement_throw(throw.span, argument));
+            }
+            Statement::TryStatement(try_stmt) => {
+                self.explode_try_statement(try_stmt.unbox());
+            }
+            stmt => {
+                self.unsupported(stmt.span());
+                self.emit(stmt);
+            }
+        }
+    }
+
+    fn explode_loop_body(
+        &mut self,
+        body: Statement<'a>,
+        break_loc: Loc,
+        continue_loc: Loc,
+        label: Option<Atom<'a>>,
+    ) {
+        self.leap_entries.push(LeapEntry {
+            break_loc,
+            continue_loc: Some(continue_loc),
+            label,
+            is_labeled_statement: false,
+        });
+        self.explode_statement(body, None);
+        self.leap_entries.pop();
+    }
+
+    /// Find location to jump to for `break` or `continue`.
+    fn find_leap_location(&self, label: Option<Atom<'a>>, is_continue: bool) -> Option<Loc> {
+        self.leap_entries.iter().rev().find_map(|entry| {
+            let loc = if is_continue { entry.continue_loc? } else { entry.break_loc };
+            match label {
+                Some(label) => (entry.label == Some(label)).then_some(loc),
+                None => (!entry.is_labeled_statement).then_some(loc),
+            }
+        })
+    }
+
+    /// Left side of `for ... in` or `for ... of` statement. Hoister converts declarations to
+    /// assignment targets.
+    fn explode_for_statement_left(
+        &self,
+        left: ForStatementLeft<'a>,
+    ) -> Option<AssignmentTarget<'a>> {
+        if let ForStatementLeft::VariableDeclaration(decl) = &left {
+            self.unsupported(decl.span);
+            return None;
+        }
+        let left = left.into_assignment_target();
+        if contains_leap_in_assignment_target(&left) {
+            self.unsupported(left.span());
+            return None;
+        }
+        Some(left)
+    }
+
+    /// `if ((_context.t<n> = <next>).done) { _context.next = <after>; break; }`
+    fn jump_to_done_check(&mut self, temp: usize, next: Expression<'a>, after: Loc) {
+        let assignment = self.create_context_assignment(&Self::temp_name(temp), next);
+        let assignment = self.ctx.ast.expression_parenthesized(SPAN, assignment);
+        let done = Expression::from(self.ctx.ast.member_expression_static(
+            SPAN,
+            assignment,
+            self.ctx.ast.identifier_name(SPAN, "done"),
+            false,
+        ));
+        self.jump_if(done, after);
+    }
+
+    /// `<left> = _context.t<n>.value;`
+    fn emit_iteration_value_assignment(&mut self, left: AssignmentTarget<'a>, temp: usize) {
+        let step = self.temp_expr(temp);
+        let value = Expression::from(self.ctx.ast.member_expression_static(
+            SPAN,
+            step,
+            self.ctx.ast.identifier_name(SPAN, "value"),
+            false,
+        ));
+        let assignment =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, left, value);
+        self.emit_expression(assignment);
+    }
+
+    fn explode_switch_statement(&mut self, switch: SwitchStatement<'a>) {
+        let SwitchStatement { discriminant, cases, .. } = switch;
+
+        // Save discriminant in a temp, in case case tests change values like `_context.sent`
+        let discriminant = self.explode_expression(discriminant, false).unwrap();
+        let disc_temp = self.make_temp();
+        self.emit_assign_temp(disc_temp, discriminant);
+
+        let after = self.loc();
+        let default_loc = self.loc();
+        let case_locs = cases
+            .iter()
+            .map(|case| if case.test.is_some() { self.loc() } else { default_loc })
+            .collect::<Vec<_>>();
+
+        // `_context.t0 === a ? 1 : _context.t0 === b ? 2 : <default>`
+        let mut condition = self.loc_expr(default_loc);
+        let mut consequents = Vec::with_capacity(cases.len());
+        for (case, &case_loc) in cases.into_iter().zip(&case_locs).rev() {
+            let SwitchCase { test, consequent, .. } = case;
+            if let Some(test) = test {
+                let disc = self.temp_expr(disc_temp);
+                let test = self.ctx.ast.expression_binary(
+                    SPAN,
+                    disc,
+                    BinaryOperator::StrictEquality,
+                    test,
+                );
+                let loc = self.loc_expr(case_loc);
+                condition = self.ctx.ast.expression_conditional(SPAN, test, loc, condition);
+            }
+            consequents.push(consequent);
+        }
+        let target = self.explode_expression(condition, false).unwrap();
+        self.jump_to_expr(target);
+
+        self.leap_entries.push(LeapEntry {
+            break_loc: after,
+            continue_loc: None,
+            label: None,
+            is_labeled_statement: false,
+        });
+        for (consequent, case_loc) in consequents.into_iter().rev().zip(case_locs) {
+            self.mark(case_loc);
+            for stmt in consequent {
+                self.explode_statement(stmt, None);
+            }
+        }
+        self.leap_entries.pop();
+
+        self.mark(after);
+        if self.locs[default_loc.0].is_none() {
+            self.mark(default_loc);
+        }
+    }
+
+    fn explode_try_statement(&mut self, try_stmt: TryStatement<'a>) {
+        let TryStatement { block, handler, finalizer, .. } = try_stmt;
+        let after = self.loc();
+        let catch_loc = handler.as_ref().map(|_| self.loc());
+        let finally_loc = finalizer.as_ref().map(|_| self.loc());
+
+        let try_loc = self.listing.len();
+        self.try_entries.push(TryEntry {
+            try_loc,
+            catch_loc,
+            finally_locs: finally_loc.map(|finally_loc| (finally_loc, after)),
+        });
+        let try_loc_expr = create_number(try_loc, self.ctx);
+        self.update_context_prev_loc(try_loc_expr);
+
+        for stmt in block.unbox().body {
+            self.explode_statement(stmt, None);
+        }
+
+        if let (Some(catch_loc), Some(handler)) = (catch_loc, handler) {
+            // Jump over `catch` block
+            self.jump(finally_loc.unwrap_or(after));
+
+            self.mark(catch_loc);
+            let catch_loc_expr = self.loc_expr(catch_loc);
+            self.update_context_prev_loc(catch_loc_expr);
+
+            // `e = _context["catch"](<try_loc>);`
+            let object = self.context.create_read_expression(self.ctx);
+            let property = self.ctx.ast.expression_string_literal(SPAN, "catch", None);
+            let catch_fn = Expression::from(
+                self.ctx.ast.member_expression_computed(SPAN, object, property, false),
+            );
+            let catch_call = self.ctx.ast.expression_call(
+                SPAN,
+                catch_fn,
+                NONE,
+                self.ctx.ast.vec1(Argument::from(create_number(try_loc, self.ctx))),
+                false,
+            );
+            let CatchClause { param, body, .. } = handler.unbox();
+            if let Some(param) = param {
+                let target = binding_pattern_to_assignment_target(param.pattern, self.ctx);
+                let assignment = self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    catch_call,
+                );
+                self.emit_expression(assignment);
+            } else {
+                self.emit_expression(catch_call);
+            }
+
+            for stmt in body.unbox().body {
+                self.explode_statement(stmt, None);
+            }
+        }
+
+        if let (Some(finally_loc), Some(finalizer)) = (finally_loc, finalizer) {
+            self.mark(finally_loc);
+            let finally_loc_expr = self.loc_expr(finally_loc);
+            self.update_context_prev_loc(finally_loc_expr);
+            for stmt in finalizer.unbox().body {
+                self.explode_statement(stmt, None);
+            }
+            // `return _context.finish(<finally_loc>);`
+            let finally_loc_expr = self.loc_expr(finally_loc);
+            let finish = self
+                .create_context_call("finish", self.ctx.ast.vec1(Argument::from(finally_loc_expr)));
+            self.emit(self.ctx.ast.statement_return(SPAN, Some(finish)));
+        }
+
+        self.mark(after);
+    }
+
+    // ---------- Expressions ----------
+
+    /// Explode an expression.
+    ///
+    /// If `ignore_result` is `true`, the expression is emitted as a statement and `None` is returned.
+    /// Otherwise returns an expression for the result, which does not contain leaps.
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if !contains_leap_in_expression(&expr) {
+            return self.finish_expression(expr, ignore_result);
+        }
+
+        match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                self.explode_expression(paren.unbox().expression, ignore_result)
+            }
+            Expression::StaticMemberExpression(member) => {
+                let StaticMemberExpression { span, object, property, .. } = member.unbox();
+                let object = self.explode_via_temp(None, object);
+                let expr = Expression::from(
+                    self.ctx.ast.member_expression_static(span, object, property, false),
+                );
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let ComputedMemberExpression { span, object, expression, .. } = member.unbox();
+                let object = self.explode_via_temp(None, object);
+                let expression = self.explode_via_temp(None, expression);
+                let expr = Expression::from(
+                    self.ctx.ast.member_expression_computed(span, object, expression, false),
+                );
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::PrivateFieldExpression(member) => {
+                let PrivateFieldExpression { span, object, field, .. } = member.unbox();
+                let object = self.explode_via_temp(None, object);
+                let expr = Expression::from(
+                    self.ctx
+                        .ast
+                        .member_expression_private_field_expression(span, object, field, false),
+                );
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::CallExpression(call) => {
+                let expr = self.explode_call_expression(call.unbox());
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::NewExpression(new_expr) => {
+                let NewExpression { span, callee, arguments, .. } = new_expr.unbox();
+                let callee = self.explode_via_temp(None, callee);
+                let arguments = self.explode_arguments(arguments);
+                let expr = self.ctx.ast.expression_new(span, callee, NONE, arguments);
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::ObjectExpression(object) => {
+                let ObjectExpression { span, properties } = object.unbox();
+                let properties = self.ctx.ast.vec_from_iter(properties.into_iter().map(
+                    |property| match property {
+                        ObjectPropertyKind::ObjectProperty(mut prop)
+                            if prop.kind == PropertyKind::Init && !prop.method =>
+                        {
+                            if prop.computed && contains_leap_in_property_key(&prop.key) {
+                                let key = prop.key.take_in(self.ctx.ast).into_expression();
+                                prop.key = PropertyKey::from(self.explode_via_temp(None, key));
+                            }
+                            let value = prop.value.take_in(self.ctx.ast);
+                            prop.value = self.explode_via_temp(None, value);
+                            ObjectPropertyKind::ObjectProperty(prop)
+                        }
+                        ObjectPropertyKind::SpreadProperty(mut spread) => {
+                            let argument = spread.argument.take_in(self.ctx.ast);
+                            spread.argument = self.explode_via_temp(None, argument);
+                            ObjectPropertyKind::SpreadProperty(spread)
+                        }
+                        property @ ObjectPropertyKind::ObjectProperty(_) => property,
+                    },
+                ));
+                let expr = self.ctx.ast.expression_object(span, properties);
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::ArrayExpression(array) => {
+                let ArrayExpression { span, elements } = array.unbox();
+                let elements =
+                    self.ctx.ast.vec_from_iter(elements.into_iter().map(|element| match element {
+                        ArrayExpressionElement::SpreadElement(mut spread) => {
+                            let argument = spread.argument.take_in(self.ctx.ast);
+                            spread.argument = self.explode_via_temp(None, argument);
+                            ArrayExpressionElement::SpreadElement(spread)
+                        }
+                        ArrayExpressionElement::Elision(elision) => {
+                            ArrayExpressionElement::Elision(elision)
+                        }
+                        element => ArrayExpressionElement::from(
+                            self.explode_via_temp(None, element.into_expression()),
+                        ),
+                    }));
+                let expr = self.ctx.ast.expression_array(span, elements);
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let mut expressions = sequence.unbox().expressions;
+                let last = expressions.pop().unwrap();
+                for expr in expressions {
+                    self.explode_expression(expr, true);
+                }
+                self.explode_expression(last, ignore_result)
+            }
+            Expression::LogicalExpression(logical) => {
+                let LogicalExpression { left, operator, right, .. } = logical.unbox();
+                let after = self.loc();
+                let result = (!ignore_result).then(|| self.make_temp());
+                let left = self.explode_via_temp(result, left);
+                match operator {
+                    LogicalOperator::And => self.jump_if_not(left, after),
+                    LogicalOperator::Or => self.jump_if(left, after),
+                    LogicalOperator::Coalesce => {
+                        let null = self.ctx.ast.expression_null_literal(SPAN);
+                        let test = self.ctx.ast.expression_binary(
+                            SPAN,
+                            left,
+                            BinaryOperator::Inequality,
+                            null,
+                        );
+                        self.jump_if(test, after);
+                    }
+                }
+                self.explode_via_temp_or_ignore(result, right);
+                self.mark(after);
+                result.map(|temp| self.temp_expr(temp))
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let ConditionalExpression { test, consequent, alternate, .. } = conditional.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode_expression(test, false).unwrap();
+                let result = (!ignore_result).then(|| self.make_temp());
+                self.jump_if_not(test, else_loc);
+                self.explode_via_temp_or_ignore(result, consequent);
+                self.jump(after);
+                self.mark(else_loc);
+                self.explode_via_temp_or_ignore(result, alternate);
+                self.mark(after);
+                result.map(|temp| self.temp_expr(temp))
+            }
+            Expression::UnaryExpression(unary) => {
+                let UnaryExpression { span, operator, argument } = unary.unbox();
+                let argument = self.explode_expression(argument, false).unwrap();
+                let expr = self.ctx.ast.expression_unary(span, operator, argument);
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::BinaryExpression(binary) => {
+                let BinaryExpression { span, left, operator, right } = binary.unbox();
+                let left = self.explode_via_temp(None, left);
+                let right = self.explode_via_temp(None, right);
+                let expr = self.ctx.ast.expression_binary(span, left, operator, right);
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::PrivateInExpression(private_in) => {
+                let PrivateInExpression { span, left, right } = private_in.unbox();
+                let right = self.explode_via_temp(None, right);
+                let expr = self.ctx.ast.expression_private_in(span, left, right);
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::AssignmentExpression(assignment) => {
+                let expr = self.explode_assignment_expression(assignment.unbox());
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::UpdateExpression(update) => {
+                let UpdateExpression { span, operator, prefix, argument } = update.unbox();
+                let argument = self.explode_simple_assignment_target(argument);
+                let expr = self.ctx.ast.expression_update(span, operator, prefix, argument);
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::YieldExpression(yield_expr) => {
+                let YieldExpression { delegate, argument, .. } = yield_expr.unbox();
+                let after = self.loc();
+                let argument = argument.map(|arg| self.explode_expression(arg, false).unwrap());
+                if delegate {
+                    // `return _context.delegateYield(<argument>, "t0", <after>);`
+                    let result = self.make_temp();
+                    let argument = argument.unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+                    let result_name = self.ctx.ast.atom(&Self::temp_name(result));
+                    let arguments = self.ctx.ast.vec_from_array([
+                        Argument::from(argument),
+                        Argument::from(self.ctx.ast.expression_string_literal(
+                            SPAN,
+                            result_name,
+                            None,
+                        )),
+                        Argument::from(self.loc_expr(after)),
+                    ]);
+                    let delegate_yield = self.create_context_call("delegateYield", arguments);
+                    self.emit(self.ctx.ast.statement_return(SPAN, Some(delegate_yield)));
+                    self.mark(after);
+                    return (!ignore_result).then(|| self.temp_expr(result));
+                }
+                // `_context.next = <after>; return <argument>;`
+                let loc = self.loc_expr(after);
+                let assignment = self.create_context_assignment("next", loc);
+                self.emit_expression(assignment);
+                self.emit(self.ctx.ast.statement_return(SPAN, argument));
+                self.mark(after);
+                (!ignore_result).then(|| self.create_context_property("sent"))
+            }
+            Expression::TemplateLiteral(template) => {
+                let TemplateLiteral { span, quasis, expressions } = template.unbox();
+                let expressions = self.explode_expressions_via_temp(expressions);
+                let expr = self.ctx.ast.expression_template_literal(span, quasis, expressions);
+                self.finish_expression(expr, ignore_result)
+            }
+            Expression::TaggedTemplateExpression(tagged) => {
+                let TaggedTemplateExpression { span, tag, quasi, .. } = tagged.unbox();
+                let tag = self.explode_via_temp(None, tag);
+                let TemplateLiteral { span: quasi_span, quasis, expressions } = quasi;
+                let expressions = self.explode_expressions_via_temp(expressions);
+                let quasi = self.ctx.ast.template_literal(quasi_span, quasis, expressions);
+                let expr = self.ctx.ast.expression_tagged_template(span, tag, NONE, quasi);
+                self.finish_expression(expr, ignore_result)
+            }
+            expr => {
+                self.unsupported(expr.span());
+                self.finish_expression(expr, ignore_result)
+            }
+        }
+    }
+
+    /// Emit expression as a statement if `ignore_result` is `true`, otherwise return it.
+    fn finish_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Explode expression, and store result in a temp, so it's evaluated before any following
+    /// leaps. Literals do not need to be stored in a temp, unless `temp` is provided.
+    fn explode_via_temp(&mut self, temp: Option<usize>, expr: Expression<'a>) -> Expression<'a> {
+        let result = self.explode_expression(expr, false).unwrap();
+        if temp.is_none() && is_literal(&result) {
+            return result;
+        }
+        let temp = temp.unwrap_or_else(|| self.make_temp());
+        self.emit_assign_temp(temp, result);
+        self.temp_expr(temp)
+    }
+
+    /// Explode expression, storing result in `temp`, or emitting it as a statement if `temp` is `None`.
+    fn explode_via_temp_or_ignore(&mut self, temp: Option<usize>, expr: Expression<'a>) {
+        if let Some(temp) = temp {
+            let result = self.explode_expression(expr, false).unwrap();
+            self.emit_assign_temp(temp, result);
+        } else {
+            self.explode_expression(expr, true);
+        }
+    }
+
+    fn explode_expressions_via_temp(
+        &mut self,
+        expressions: ArenaVec<'a, Expression<'a>>,
+    ) -> ArenaVec<'a, Expression<'a>> {
+        self.ctx
+            .ast
+            .vec_from_iter(expressions.into_iter().map(|expr| self.explode_via_temp(None, expr)))
+    }
+
+    fn explode_arguments(
+        &mut self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> ArenaVec<'a, Argument<'a>> {
+        self.ctx.ast.vec_from_iter(arguments.into_iter().map(|argument| match argument {
+            Argument::SpreadElement(mut spread) => {
+                let argument = spread.argument.take_in(self.ctx.ast);
+                spread.argument = self.explode_via_temp(None, argument);
+                Argument::SpreadElement(spread)
+            }
+            argument => Argument::from(self.explode_via_temp(None, argument.into_expression())),
+        }))
+    }
+
+    /// Explode call expression.
+    ///
+    /// If arguments contain leaps, callee's object is stored in a temp, and the call is converted
+    /// to `.call` with the temp as `this`:
+    /// `obj.foo(yield x)` -> `_context.t0 = obj; ... _context.t0.foo.call(_context.t0, _context.sent)`
+    fn explode_call_expression(&mut self, call: CallExpression<'a>) -> Expression<'a> {
+        let CallExpression { span, callee, arguments, optional, .. } = call;
+        if optional {
+            self.unsupported(span);
+        }
+        let has_leaping_arguments = arguments.iter().any(contains_leap_in_argument);
+
+        let (callee, this_arg) = match callee {
+            callee if has_leaping_arguments && callee.is_member_expression() => {
+                let temp = self.make_temp();
+                let callee = match callee {
+                    Expression::StaticMemberExpression(member) => {
+                        let StaticMemberExpression { span, object, property, .. } = member.unbox();
+                        let object = self.explode_via_temp(Some(temp), object);
+                        Expression::from(
+                            self.ctx.ast.member_expression_static(span, object, property, false),
+                        )
+                    }
+                    Expression::ComputedMemberExpression(member) => {
+                        let ComputedMemberExpression { span, object, expression, .. } =
+                            member.unbox();
+                        let object = self.explode_via_temp(Some(temp), object);
+                        let expression = self.explode_via_temp(None, expression);
+                        Expression::from(
+                            self.ctx
+                                .ast
+                                .member_expression_computed(span, object, expression, false),
+                        )
+                    }
+                    Expression::PrivateFieldExpression(member) => {
+                        let PrivateFieldExpression { span, object, field, .. } = member.unbox();
+                        let object = self.explode_via_temp(Some(temp), object);
+                        Expression::from(
+                            self.ctx.ast.member_expression_private_field_expression(
+                                span, object, field, false,
+                            ),
+                        )
+                    }
+                    _ => unreachable!(),
+                };
+                let callee = Expression::from(self.ctx.ast.member_expression_static(
+                    SPAN,
+                    callee,
+                    self.ctx.ast.identifier_name(SPAN, "call"),
+                    false,
+                ));
+                (callee, Some(self.temp_expr(temp)))
+            }
+            callee if callee.is_member_expression() => {
+                (self.explode_expression(callee, false).unwrap(), None)
+            }
+            callee => {
+                let callee = self.explode_via_temp(None, callee);
+                // `_context.t0()` -> `(0, _context.t0)()`, so `this` is not `_context`
+                let callee = if callee.is_member_expression() {
+                    self.ctx.ast.expression_sequence(
+                        SPAN,
+                        self.ctx.ast.vec_from_array([create_number(0, self.ctx), callee]),
+                    )
+                } else {
+                    callee
+                };
+                (callee, None)
+            }
+        };
+
+        let mut arguments =
+            if has_leaping_arguments { self.explode_arguments(arguments) } else { arguments };
+        if let Some(this_arg) = this_arg {
+            arguments.insert(0, Argument::from(this_arg));
+        }
+        self.ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    fn explode_assignment_expression(
+        &mut self,
+        assignment: AssignmentExpression<'a>,
+    ) -> Expression<'a> {
+        let AssignmentExpression { span, operator, left, right } = assignment;
+
+        if operator == AssignmentOperator::Assign {
+            let left =
+                if contains_leap_in_assignment_target(&left) {
+                    match left {
+                        left @ match_simple_assignment_target!(AssignmentTarget) => {
+                            AssignmentTarget::from(self.explode_simple_assignment_target(
+                                left.into_simple_assignment_target(),
+                            ))
+                        }
+                        left => {
+                            self.unsupported(left.span());
+                            left
+                        }
+                    }
+                } else {
+                    left
+                };
+            let right = self.explode_expression(right, false).unwrap();
+            return self.ctx.ast.expression_assignment(span, operator, left, right);
+        }
+
+        // `a += yield b` -> `_context.t0 = a; ...; a = _context.t0 + _context.sent`
+        let Some(binary_operator) = operator.to_binary_operator() else {
+            self.unsupported(span);
+            return self.ctx.ast.expression_assignment(span, operator, left, right);
+        };
+        if !left.is_simple_assignment_target() {
+            self.unsupported(span);
+            return self.ctx.ast.expression_assignment(span, operator, left, right);
+        }
+        let left = self.explode_simple_assignment_target(left.into_simple_assignment_target());
+        let current_value = self.simple_assignment_target_to_expression(&left);
+        let temp = self.make_temp();
+        self.emit_assign_temp(temp, current_value);
+        let current_value = self.temp_expr(temp);
+        // Target was read and written by compound assignment, now it's only written
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &left {
+            if let Some(reference_id) = ident.reference_id.get() {
+                *self.ctx.scoping_mut().get_reference_mut(reference_id).flags_mut() =
+                    ReferenceFlags::Write;
+            }
+        }
+        let right = self.explode_expression(right, false).unwrap();
+        let value = self.ctx.ast.expression_binary(SPAN, current_value, binary_operator, right);
+        self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(left),
+            value,
+        )
+    }
+
+    /// Store object (and computed property) of a member expression target in temps.
+    fn explode_simple_assignment_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+    ) -> SimpleAssignmentTarget<'a> {
+        match target {
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let StaticMemberExpression { span, object, property, .. } = member.unbox();
+                let object = self.explode_via_temp(None, object);
+                SimpleAssignmentTarget::from(
+                    self.ctx.ast.member_expression_static(span, object, property, false),
+                )
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let ComputedMemberExpression { span, object, expression, .. } = member.unbox();
+                let object = self.explode_via_temp(None, object);
+                let expression = self.explode_via_temp(None, expression);
+                SimpleAssignmentTarget::from(
+                    self.ctx.ast.member_expression_computed(span, object, expression, false),
+                )
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let PrivateFieldExpression { span, object, field, .. } = member.unbox();
+                let object = self.explode_via_temp(None, object);
+                SimpleAssignmentTarget::from(
+                    self.ctx
+                        .ast
+                        .member_expression_private_field_expression(span, object, field, false),
+                )
+            }
+            target => target,
+        }
+    }
+
+    /// Create expression reading current value of an exploded assignment target.
+    fn simple_assignment_target_to_expression(
+        &mut self,
+        target: &SimpleAssignmentTarget<'a>,
+    ) -> Expression<'a> {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                MaybeBoundIdentifier::from_identifier_reference(ident, self.ctx)
+                    .create_read_expression(self.ctx)
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let object = self.clone_operand(&member.object);
+                Expression::from(self.ctx.ast.member_expression_static(
+                    SPAN,
+                    object,
+                    member.property.clone(),
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let object = self.clone_operand(&member.object);
+                let expression = self.clone_operand(&member.expression);
+                Expression::from(
+                    self.ctx.ast.member_expression_computed(SPAN, object, expression, false),
+                )
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let object = self.clone_operand(&member.object);
+                Expression::from(self.ctx.ast.member_expression_private_field_expression(
+                    SPAN,
+                    object,
+                    member.field.clone(),
+                    false,
+                ))
+            }
+            target => {
+                self.unsupported(target.span());
+                self.ctx.ast.void_0(SPAN)
+            }
+        }
+    }
+
+    /// Clone an operand returned by [`Self::explode_via_temp`], which is either a temp or a literal.
+    fn clone_operand(&mut self, expr: &Expression<'a>) -> Expression<'a> {
+        if let Expression::StaticMemberExpression(member) = expr {
+            if let Expression::Identifier(object) = &member.object {
+                if object.name == self.context.name {
+                    return self.create_context_property(&member.property.name);
+                }
+            }
+        }
+        expr.clone_in(self.ctx.ast.allocator)
+    }
+}
+
+/// Delete references in a statement which is removed from AST.
+///
+/// Scopes in the statement are detached by `ScopeParentFixer` once whole function body is built.
+struct ReferenceRemover<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ReferenceRemover<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.ctx.delete_reference_for_identifier(ident);
+    }
+}
+
+/// `true` if expression is a literal, which does not need to be stored in a temp.
+fn is_literal(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::RegExpLiteral(_)
+    )
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn create_f64(value: usize) -> f64 {
+    value as f64
+}
+
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, create_f64(value), None, NumberBase::Decimal)
+}
+
+/// Visitor which replaces location placeholders with offsets in listing.
+struct LocPatcher<'l> {
+    locs: &'l [Option<usize>],
+}
+
+impl<'a> VisitMut<'a> for LocPatcher<'_> {
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn visit_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>) {
+        if lit.raw.is_some_and(|raw| raw == LOC_PLACEHOLDER) {
+            let loc = lit.value as usize;
+            lit.value = create_f64(self.locs[loc].unwrap());
+            lit.raw = None;
+        }
+    }
+}
+
+/// Visitor which finds leaps: `yield`, `break`, `continue`, `return` and `throw`.
+/// Does not enter nested functions.
+#[derive(Default)]
+struct LeapFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _stmt: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _stmt: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_throw_statement(&mut self, _stmt: &ThrowStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+fn contains_leap_in_statement(stmt: &Statement) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+pub fn contains_leap_in_try_statement(stmt: &TryStatement) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_try_statement(stmt);
+    finder.found
+}
+
+fn contains_leap_in_expression(expr: &Expression) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_expression(expr);
+    finder.found
+}
+
+fn contains_leap_in_argument(argument: &Argument) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_argument(argument);
+    finder.found
+}
+
+fn contains_leap_in_property_key(key: &PropertyKey) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_property_key(key);
+    finder.found
+}
+
+fn contains_leap_in_assignment_target(target: &AssignmentTarget) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_assignment_target(target);
+    finder.found
+}
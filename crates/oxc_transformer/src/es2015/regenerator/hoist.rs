@@ -0,0 +1,390 @@
+//! Hoisting of declarations in generator function body.
+//!
+//! Body of generator function is moved into an inner function, and split into cases of a `switch`
+//! statement. So declarations in the body cannot remain where they are:
+//!
+//! * `var`, `let` and `const` declarations are converted to assignments, and the bindings
+//!   are declared in outer function. `let` and `const` bindings in nested blocks are renamed
+//!   if they would clash with another binding.
+//! * Class declarations are converted to assignments of class expressions.
+//! * Function declarations are moved to top of outer function.
+//! * Params of `catch` clauses which will be exploded are declared in outer function.
+//! * `arguments` is replaced with `_args`, which is declared in outer function as
+//!   `var _args = arguments`.
+//!
+//! Based on [regenerator-transform's `hoist`](https://github.com/facebook/regenerator/blob/main/packages/transform/src/hoist.js).
+
+use std::cell::Cell;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+use super::{binding_pattern_to_assignment_target, emit::contains_leap_in_try_statement};
+
+/// Declarations hoisted out of generator function body.
+pub struct Hoisted<'a> {
+    /// Bindings to declare with `var` in outer function
+    pub vars: Vec<BindingIdentifier<'a>>,
+    /// Function declarations to insert at top of outer function
+    pub functions: Vec<Statement<'a>>,
+    /// `_args` binding, if function body uses `arguments`
+    pub arguments: Option<BoundIdentifier<'a>>,
+    /// `true` if function body uses `this`
+    pub uses_this: bool,
+}
+
+pub struct Hoister<'a, 'c> {
+    transform_ctx: &'c TransformCtx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Scope of generator function
+    scope_id: ScopeId,
+    /// Bindings of generator function's params
+    param_symbols: FxHashSet<SymbolId>,
+    /// Number of functions, arrow functions and classes we're inside
+    function_depth: u32,
+    /// Number of non-arrow functions we're inside
+    this_depth: u32,
+    /// New names for bindings which were renamed
+    renamed: FxHashMap<SymbolId, Atom<'a>>,
+    vars: Vec<SymbolId>,
+    var_set: FxHashSet<SymbolId>,
+    functions: Vec<Statement<'a>>,
+    arguments: Option<BoundIdentifier<'a>>,
+    uses_this: bool,
+}
+
+impl<'a, 'c> Hoister<'a, 'c> {
+    pub fn new(
+        scope_id: ScopeId,
+        params: &FormalParameters<'a>,
+        transform_ctx: &'c TransformCtx<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        let mut param_symbols = FxHashSet::default();
+        params.bound_names(&mut |ident| {
+            param_symbols.insert(ident.symbol_id());
+        });
+        Self {
+            transform_ctx,
+            ctx,
+            scope_id,
+            param_symbols,
+            function_depth: 0,
+            this_depth: 0,
+            renamed: FxHashMap::default(),
+            vars: vec![],
+            var_set: FxHashSet::default(),
+            functions: vec![],
+            arguments: None,
+            uses_this: false,
+        }
+    }
+
+    pub fn into_hoisted(self) -> Hoisted<'a> {
+        let scoping = self.ctx.scoping();
+        let vars = self
+            .vars
+            .into_iter()
+            .map(|symbol_id| {
+                let span = scoping.symbol_span(symbol_id);
+                let name = self.ctx.ast.atom(scoping.symbol_name(symbol_id));
+                self.ctx.ast.binding_identifier_with_symbol_id(span, name, symbol_id)
+            })
+            .collect();
+        Hoisted {
+            vars,
+            functions: self.functions,
+            arguments: self.arguments,
+            uses_this: self.uses_this,
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for Hoister<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.function_depth > 0 {
+            return;
+        }
+        // Hoist all bindings in block scope to function scope, except `catch` params
+        // of `try` statements which are not exploded
+        let scope_id = scope_id.get().unwrap();
+        let scoping = self.ctx.scoping();
+        let mut symbol_ids = scoping
+            .get_bindings(scope_id)
+            .values()
+            .copied()
+            .filter(|&symbol_id| {
+                !scoping.symbol_flags(symbol_id).contains(SymbolFlags::CatchVariable)
+            })
+            .collect::<Vec<_>>();
+        symbol_ids.sort_unstable();
+        for symbol_id in symbol_ids {
+            self.hoist_binding(symbol_id, scope_id);
+        }
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        if self.function_depth > 0 {
+            walk_mut::walk_statements(self, stmts);
+            return;
+        }
+
+        let old_stmts = stmts.take_in(self.ctx.ast);
+        for mut stmt in old_stmts {
+            self.visit_statement(&mut stmt);
+            match stmt {
+                Statement::FunctionDeclaration(_) => self.functions.push(stmt),
+                // Remains of variable declarations with no initializers
+                Statement::EmptyStatement(_) => {}
+                stmt => stmts.push(stmt),
+            }
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        walk_mut::walk_statement(self, stmt);
+        if self.function_depth > 0 {
+            return;
+        }
+        match stmt {
+            Statement::VariableDeclaration(decl) => {
+                let decl = decl.take_in(self.ctx.ast);
+                *stmt = match self.convert_variable_declaration(decl) {
+                    Some(expr) => self.ctx.ast.statement_expression(SPAN, expr),
+                    None => self.ctx.ast.statement_empty(SPAN),
+                };
+            }
+            Statement::ClassDeclaration(class) => {
+                // `class A {}` -> `A = class {};`
+                let mut class = class.take_in_box(self.ctx.ast);
+                let id = class.id.take().unwrap();
+                self.add_var(id.symbol_id());
+                class.r#type = ClassType::ClassExpression;
+                let assignment = self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    BoundIdentifier::from_binding_ident(&id).create_write_target(self.ctx),
+                    Expression::ClassExpression(class),
+                );
+                *stmt = self.ctx.ast.statement_expression(SPAN, assignment);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        walk_mut::walk_for_statement(self, stmt);
+        if self.function_depth == 0 {
+            if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+                let decl = decl.take_in(self.ctx.ast);
+                stmt.init = self.convert_variable_declaration(decl).map(ForStatementInit::from);
+            }
+        }
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        walk_mut::walk_for_in_statement(self, stmt);
+        if self.function_depth == 0 {
+            self.convert_for_statement_left(&mut stmt.left);
+        }
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        walk_mut::walk_for_of_statement(self, stmt);
+        if self.function_depth == 0 {
+            self.convert_for_statement_left(&mut stmt.left);
+        }
+    }
+
+    fn visit_try_statement(&mut self, stmt: &mut TryStatement<'a>) {
+        // `catch` clause will be removed if `try` statement is exploded, so hoist its param
+        if self.function_depth == 0 && contains_leap_in_try_statement(stmt) {
+            if let Some(param) = stmt.handler.as_ref().and_then(|handler| handler.param.as_ref()) {
+                let mut symbol_ids = vec![];
+                param.pattern.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id()));
+                for symbol_id in symbol_ids {
+                    let scope_id = self.ctx.scoping().symbol_scope_id(symbol_id);
+                    self.hoist_binding(symbol_id, scope_id);
+                }
+            }
+        }
+        walk_mut::walk_try_statement(self, stmt);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.this_depth -= 1;
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.function_depth -= 1;
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(_) if self.this_depth == 0 => self.uses_this = true,
+            Expression::Identifier(ident)
+                if self.this_depth == 0
+                    && ident.name == "arguments"
+                    && self
+                        .ctx
+                        .scoping()
+                        .get_reference(ident.reference_id())
+                        .symbol_id()
+                        .is_none() =>
+            {
+                // `arguments` -> `_args`
+                let span = ident.span;
+                let reference_id = ident.reference_id();
+                self.ctx.scoping_mut().delete_root_unresolved_reference("arguments", reference_id);
+                let binding = self.get_arguments_binding();
+                *expr = binding.create_spanned_read_expression(span, self.ctx);
+            }
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_super(&mut self, sup: &mut Super) {
+        if self.this_depth == 0 {
+            self.transform_ctx.error(
+                OxcDiagnostic::error("`super` in generator functions is not supported.")
+                    .with_label(sup.span),
+            );
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(&name) = self.renamed.get(&ident.symbol_id()) {
+            ident.name = name;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        if let Some(symbol_id) = self.ctx.scoping().get_reference(reference_id).symbol_id() {
+            if let Some(&name) = self.renamed.get(&symbol_id) {
+                ident.name = name;
+            }
+        }
+    }
+}
+
+impl<'a> Hoister<'a, '_> {
+    /// Move binding from block scope to function scope, renaming it if it would clash with
+    /// another binding.
+    fn hoist_binding(&mut self, symbol_id: SymbolId, scope_id: ScopeId) {
+        let scoping = self.ctx.scoping();
+        let mut name = self.ctx.ast.atom(scoping.symbol_name(symbol_id));
+        let parent_scope_id = scoping.scope_parent_id(scope_id).unwrap();
+        if scoping.find_binding(parent_scope_id, &name).is_some()
+            || scoping.root_unresolved_references().contains_key(name.as_str())
+        {
+            let new_name = self.ctx.generate_uid_name(&name);
+            self.ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &new_name);
+            self.renamed.insert(symbol_id, new_name);
+            name = new_name;
+        }
+
+        let scoping = self.ctx.scoping_mut();
+        scoping.move_binding(scope_id, self.scope_id, &name);
+        scoping.set_symbol_scope_id(symbol_id, self.scope_id);
+        if !scoping.symbol_flags(symbol_id).contains(SymbolFlags::Function) {
+            self.add_var(symbol_id);
+        }
+    }
+
+    /// Record binding to be declared with `var` in outer function.
+    ///
+    /// All declarations of the symbol are merged into that one `var` (or parameter),
+    /// so it has no redeclarations any more.
+    fn add_var(&mut self, symbol_id: SymbolId) {
+        let scoping = self.ctx.scoping_mut();
+        *scoping.symbol_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+        scoping.remove_symbol_redeclarations(symbol_id);
+        if !self.param_symbols.contains(&symbol_id) && self.var_set.insert(symbol_id) {
+            self.vars.push(symbol_id);
+        }
+    }
+
+    /// `var a = 1, { b } = c, d;` -> `a = 1, { b: b } = c`
+    ///
+    /// `let` declarations without initializer are initialized to `void 0`, as they may be in a loop.
+    fn convert_variable_declaration(
+        &mut self,
+        decl: VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        if matches!(decl.kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+        {
+            self.transform_ctx.error(
+                OxcDiagnostic::error(
+                    "`using` declarations in generator functions are not supported.",
+                )
+                .with_label(decl.span),
+            );
+        }
+
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations {
+            declarator.id.bound_names(&mut |ident| self.add_var(ident.symbol_id()));
+            let init = match declarator.init {
+                Some(init) => init,
+                None if decl.kind.is_lexical() => self.ctx.ast.void_0(SPAN),
+                None => continue,
+            };
+            let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+            expressions.push(self.ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(SPAN, expressions)),
+        }
+    }
+
+    /// `for (var x of y)` -> `for (x of y)`
+    fn convert_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        let mut decl = decl.take_in(self.ctx.ast);
+        let declarator = decl.declarations.pop().unwrap();
+        declarator.id.bound_names(&mut |ident| self.add_var(ident.symbol_id()));
+        *left =
+            ForStatementLeft::from(binding_pattern_to_assignment_target(declarator.id, self.ctx));
+    }
+
+    fn get_arguments_binding(&mut self) -> BoundIdentifier<'a> {
+        if let Some(binding) = &self.arguments {
+            return binding.clone();
+        }
+        let binding =
+            self.ctx.generate_uid("args", self.scope_id, SymbolFlags::FunctionScopedVariable);
+        self.arguments = Some(binding.clone());
+        binding
+    }
+}
@@ -0,0 +1,542 @@
+//! ES2015: Regenerator
+//!
+//! This plugin transforms generator functions to state machines driven by the regenerator runtime,
+//! so output can run in ES5 engines.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* foo(x) {
+//!   var y = yield x;
+//!   return y + 1;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = babelHelpers.regeneratorRuntime().mark(foo);
+//! function foo(x) {
+//!   var y;
+//!   return babelHelpers.regeneratorRuntime().wrap(function foo$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.next = 2;
+//!         return x;
+//!       case 2:
+//!         y = _context.sent;
+//!         return _context.abrupt("return", y + 1);
+//!       case 4:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [regenerator-transform](https://github.com/facebook/regenerator/tree/main/packages/transform),
+//! which [@babel/plugin-transform-regenerator](https://babel.dev/docs/babel-plugin-transform-regenerator) uses.
+//!
+//! Transformation of each generator function has 3 steps:
+//!
+//! 1. Hoist declarations in the function body ([`hoist`]).
+//!    Variable declarations are converted to assignments, with the bindings declared by a single
+//!    `var` statement in the outer function. Function declarations are moved to top of the outer
+//!    function. `arguments` is replaced with a reference to `_args` var.
+//! 2. Explode statements and expressions containing `yield` (or other statements which change
+//!    control flow) into a flat listing of statements, with marked locations which can be jumped to
+//!    ([`emit`]).
+//! 3. Wrap the listing in a `switch` statement in a loop, inside an inner function which is passed
+//!    to `regeneratorRuntime().wrap`.
+//!
+//! This transform runs as a separate pass on exit from `Program`, after all other transforms have
+//! run, so it also transforms generator functions created by async functions and async generator
+//! functions transforms.
+//!
+//! Differences from regenerator-transform:
+//!
+//! * Async functions are not transformed. Use async-to-generator transform to convert them to
+//!   generator functions first.
+//! * Catch clause params are hoisted to outer function, instead of replaced with a temp var.
+//! * Generator methods use `null` as outer function, instead of an undeclared `_callee` reference.
+//! * `for ... of` loops containing `yield` do not close iterator on early exit.
+//! * `super` in generator functions is not supported.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-regenerator>
+//! * regenerator-transform: <https://github.com/facebook/regenerator/tree/main/packages/transform/src>
+//! * regenerator runtime helper: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-helpers/src/helpers/regeneratorRuntime.js>
+
+use std::{cell::Cell, mem};
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod emit;
+mod hoist;
+
+use emit::Emitter;
+use hoist::Hoister;
+
+pub struct Regenerator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Regenerator<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut visitor = RegeneratorVisitor::new(self.ctx, ctx);
+        visitor.visit_program(program);
+    }
+}
+
+struct RegeneratorVisitor<'a, 'c> {
+    transform_ctx: &'c TransformCtx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Stack of scopes entered. `true` if scope is a var scope.
+    scope_stack: Vec<(ScopeId, bool)>,
+    /// `_marked` declarators to insert at top of current statement list
+    marked_declarators: Vec<VariableDeclarator<'a>>,
+    /// `true` if function about to be visited is a function expression (not a method)
+    is_function_expression: bool,
+    /// `true` if function expression just visited was transformed, and needs wrapping in `mark` call
+    needs_mark: bool,
+}
+
+impl<'a, 'c> RegeneratorVisitor<'a, 'c> {
+    fn new(transform_ctx: &'c TransformCtx<'a>, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self {
+            transform_ctx,
+            ctx,
+            scope_stack: vec![],
+            marked_declarators: vec![],
+            is_function_expression: false,
+            needs_mark: false,
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for RegeneratorVisitor<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        let is_var = self.ctx.scoping().scope_flags(scope_id).is_var();
+        self.scope_stack.push((scope_id, is_var));
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let outer_marked_declarators = mem::take(&mut self.marked_declarators);
+
+        walk_mut::walk_statements(self, stmts);
+
+        let marked_declarators =
+            mem::replace(&mut self.marked_declarators, outer_marked_declarators);
+        if !marked_declarators.is_empty() {
+            // `var _marked = babelHelpers.regeneratorRuntime().mark(foo);`
+            let decl = self.ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                self.ctx.ast.vec_from_iter(marked_declarators),
+                false,
+            );
+            stmts.insert(0, Statement::VariableDeclaration(decl));
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::FunctionExpression(func) = expr {
+            self.is_function_expression = true;
+            self.visit_function(func, ScopeFlags::Function);
+            if mem::take(&mut self.needs_mark) {
+                // `function* () {}` -> `babelHelpers.regeneratorRuntime().mark(function () {})`
+                let func = expr.take_in(self.ctx.ast);
+                *expr = self.create_runtime_call("mark", func);
+            }
+        } else {
+            walk_mut::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        // Object methods are not function expressions, and cannot be wrapped in `mark` call
+        if prop.method {
+            if let Expression::FunctionExpression(func) = &mut prop.value {
+                self.visit_property_key(&mut prop.key);
+                self.visit_function(func, ScopeFlags::Function);
+                return;
+            }
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        let is_function_expression = mem::take(&mut self.is_function_expression);
+
+        walk_mut::walk_function(self, func, flags);
+
+        if !func.generator || func.r#async || func.body.is_none() {
+            return;
+        }
+
+        let outer_fn = if func.is_declaration() {
+            func.id.as_ref().map(|id| self.create_marked_binding(id))
+        } else if is_function_expression {
+            self.needs_mark = true;
+            Some(self.get_function_expression_name(func))
+        } else {
+            None
+        };
+        self.transform_generator(func, outer_fn);
+    }
+}
+
+impl<'a> RegeneratorVisitor<'a, '_> {
+    /// Transform generator function.
+    ///
+    /// `outer_fn` is the expression passed to `wrap` as outer function, which has been marked
+    /// as a generator function. `None` for methods.
+    fn transform_generator(&mut self, func: &mut Function<'a>, outer_fn: Option<Expression<'a>>) {
+        let scope_id = func.scope_id();
+        let inner_fn_name = self.get_inner_function_name(func);
+        let mut statements = func.body.as_mut().unwrap().statements.take_in(self.ctx.ast);
+
+        // Hoist declarations
+        let mut hoister = Hoister::new(scope_id, &func.params, self.transform_ctx, self.ctx);
+        hoister.visit_statements(&mut statements);
+        let hoisted = hoister.into_hoisted();
+
+        // Explode function body into a `switch` statement
+        let inner_scope_id = self.ctx.create_child_scope(scope_id, ScopeFlags::Function);
+        let switch_scope_id = self.ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+        let context =
+            self.ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut emitter =
+            Emitter::new(context.clone(), switch_scope_id, self.transform_ctx, self.ctx);
+        emitter.explode_statements(statements);
+        let (dispatch_loop, try_locs) = emitter.finish();
+
+        // `function foo$(_context) { while (1) switch (...) { ... } }`
+        let inner_fn_binding =
+            self.ctx.generate_binding(inner_fn_name, inner_scope_id, SymbolFlags::Function);
+        let params = self.ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            self.ctx.ast.vec1(
+                self.ctx.ast.plain_formal_parameter(SPAN, context.create_binding_pattern(self.ctx)),
+            ),
+            NONE,
+        );
+        let inner_body = self.ctx.ast.alloc_function_body(
+            SPAN,
+            self.ctx.ast.vec(),
+            self.ctx.ast.vec1(dispatch_loop),
+        );
+        let inner_fn = Expression::FunctionExpression(self.ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            Some(inner_fn_binding.create_binding_identifier(self.ctx)),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+            inner_scope_id,
+        ));
+
+        // `babelHelpers.regeneratorRuntime().wrap(innerFn, outerFn, this, tryLocsList)`
+        let mut arguments = self.ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(inner_fn));
+        arguments.push(Argument::from(
+            outer_fn.unwrap_or_else(|| self.ctx.ast.expression_null_literal(SPAN)),
+        ));
+        if hoisted.uses_this {
+            arguments.push(Argument::from(self.ctx.ast.expression_this(SPAN)));
+        } else if try_locs.is_some() {
+            arguments.push(Argument::from(self.ctx.ast.expression_null_literal(SPAN)));
+        }
+        if let Some(try_locs) = try_locs {
+            arguments.push(Argument::from(try_locs));
+        }
+        let wrap = self.create_runtime_method_call("wrap", arguments);
+
+        // Build new function body
+        let body = func.body.as_mut().unwrap();
+        let mut statements = self.ctx.ast.vec_from_iter(hoisted.functions);
+        let mut declarators = self.ctx.ast.vec_from_iter(hoisted.vars.into_iter().map(|binding| {
+            self.ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                self.ctx.ast.binding_pattern(
+                    BindingPatternKind::BindingIdentifier(self.ctx.ast.alloc(binding)),
+                    NONE,
+                    false,
+                ),
+                None,
+                false,
+            )
+        }));
+        if let Some(args_binding) = hoisted.arguments {
+            // `_args = arguments`
+            let arguments = self.ctx.create_unbound_ident_expr(
+                SPAN,
+                Atom::from("arguments"),
+                ReferenceFlags::Read,
+            );
+            declarators.push(self.ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                args_binding.create_binding_pattern(self.ctx),
+                Some(arguments),
+                false,
+            ));
+        }
+        if !declarators.is_empty() {
+            statements.push(Statement::VariableDeclaration(
+                self.ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    declarators,
+                    false,
+                ),
+            ));
+        }
+        statements.push(self.ctx.ast.statement_return(SPAN, Some(wrap)));
+        body.statements = statements;
+
+        // Scopes in function body have moved. Update parents of scopes to match new AST,
+        // and detach scopes of statements which have been removed.
+        let mut fixer = ScopeParentFixer {
+            scoping: self.ctx.scoping_mut(),
+            stack: vec![scope_id],
+            visited: FxHashSet::default(),
+        };
+        fixer.visit_function_body(body);
+        fixer.visit_formal_parameters(&func.params);
+        fixer.remove_detached_scopes(scope_id);
+
+        func.generator = false;
+    }
+
+    /// Create `var _marked = babelHelpers.regeneratorRuntime().mark(foo);` declarator, to be
+    /// inserted at top of current statement list. Returns reference to `_marked`.
+    fn create_marked_binding(&mut self, id: &BindingIdentifier<'a>) -> Expression<'a> {
+        let var_scope_id = self.scope_stack.iter().rev().find(|(_, is_var)| *is_var).unwrap().0;
+        let binding =
+            self.ctx.generate_uid("marked", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let func = BoundIdentifier::from_binding_ident(id).create_read_expression(self.ctx);
+        let init = self.create_runtime_call("mark", func);
+        self.marked_declarators.push(self.ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(self.ctx),
+            Some(init),
+            false,
+        ));
+        binding.create_read_expression(self.ctx)
+    }
+
+    /// Get reference to function expression's name, to use as outer function.
+    /// If function expression is anonymous, give it a name `_callee`.
+    fn get_function_expression_name(&mut self, func: &mut Function<'a>) -> Expression<'a> {
+        let binding = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let binding = self.ctx.generate_uid("callee", func.scope_id(), SymbolFlags::Function);
+            func.id = Some(binding.create_binding_identifier(self.ctx));
+            binding
+        };
+        binding.create_read_expression(self.ctx)
+    }
+
+    /// Get name for inner function. `foo$` for function `foo`, `_callee$` for methods.
+    fn get_inner_function_name(&mut self, func: &Function<'a>) -> Atom<'a> {
+        let name = func.id.as_ref().map_or("_callee", |id| id.name.as_str());
+        let name = format!("{name}$");
+        let scoping = self.ctx.scoping();
+        if scoping.find_binding(func.scope_id(), &name).is_some()
+            || scoping.root_unresolved_references().contains_key(name.as_str())
+        {
+            self.ctx.generate_uid_name(&name)
+        } else {
+            self.ctx.ast.atom(&name)
+        }
+    }
+
+    /// `babelHelpers.regeneratorRuntime().<method>(<argument>)`
+    fn create_runtime_call(
+        &mut self,
+        method: &'static str,
+        argument: Expression<'a>,
+    ) -> Expression<'a> {
+        let arguments = self.ctx.ast.vec1(Argument::from(argument));
+        self.create_runtime_method_call(method, arguments)
+    }
+
+    /// `babelHelpers.regeneratorRuntime().<method>(<arguments>)`
+    fn create_runtime_method_call(
+        &mut self,
+        method: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        create_runtime_method_call(method, arguments, self.transform_ctx, self.ctx)
+    }
+}
+
+/// `babelHelpers.regeneratorRuntime().<method>(<arguments>)`
+fn create_runtime_method_call<'a>(
+    method: &'static str,
+    arguments: ArenaVec<'a, Argument<'a>>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let runtime =
+        transform_ctx.helper_call_expr(Helper::RegeneratorRuntime, SPAN, ctx.ast.vec(), ctx);
+    let callee = Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        runtime,
+        ctx.ast.identifier_name(SPAN, method),
+        false,
+    ));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// Convert a binding pattern to an assignment target.
+///
+/// `{ a, b: [c = 1], ...d }` -> `{ a: a, b: [c = 1], ...d }`
+fn binding_pattern_to_assignment_target<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    match pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            BoundIdentifier::from_binding_ident(&ident).create_spanned_write_target(ident.span, ctx)
+        }
+        BindingPatternKind::ObjectPattern(object) => {
+            let ObjectPattern { span, properties, rest } = object.unbox();
+            let properties = ctx.ast.vec_from_iter(properties.into_iter().map(|property| {
+                let binding =
+                    binding_pattern_to_assignment_target_maybe_default(property.value, ctx);
+                ctx.ast.assignment_target_property_assignment_target_property_property(
+                    property.span,
+                    property.key,
+                    binding,
+                    property.computed,
+                )
+            }));
+            let rest = rest.map(|rest| {
+                let rest = rest.unbox();
+                let target = binding_pattern_to_assignment_target(rest.argument, ctx);
+                ctx.ast.assignment_target_rest(rest.span, target)
+            });
+            AssignmentTarget::from(
+                ctx.ast.assignment_target_pattern_object_assignment_target(span, properties, rest),
+            )
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            let ArrayPattern { span, elements, rest } = array.unbox();
+            let elements = ctx.ast.vec_from_iter(elements.into_iter().map(|element| {
+                element
+                    .map(|element| binding_pattern_to_assignment_target_maybe_default(element, ctx))
+            }));
+            let rest = rest.map(|rest| {
+                let rest = rest.unbox();
+                let target = binding_pattern_to_assignment_target(rest.argument, ctx);
+                ctx.ast.assignment_target_rest(rest.span, target)
+            });
+            AssignmentTarget::from(
+                ctx.ast.assignment_target_pattern_array_assignment_target(span, elements, rest),
+            )
+        }
+        BindingPatternKind::AssignmentPattern(assignment) => {
+            // Only valid in positions handled by `binding_pattern_to_assignment_target_maybe_default`
+            binding_pattern_to_assignment_target(assignment.unbox().left, ctx)
+        }
+    }
+}
+
+fn binding_pattern_to_assignment_target_maybe_default<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    if let BindingPatternKind::AssignmentPattern(assignment) = pattern.kind {
+        let AssignmentPattern { span, left, right } = assignment.unbox();
+        let binding = binding_pattern_to_assignment_target(left, ctx);
+        ctx.ast.assignment_target_maybe_default_assignment_target_with_default(span, binding, right)
+    } else {
+        AssignmentTargetMaybeDefault::from(binding_pattern_to_assignment_target(pattern, ctx))
+    }
+}
+
+/// Visitor which sets parent of every scope to the closest enclosing scope in the AST.
+struct ScopeParentFixer<'s> {
+    scoping: &'s mut Scoping,
+    stack: Vec<ScopeId>,
+    /// Scopes which are still in AST
+    visited: FxHashSet<ScopeId>,
+}
+
+impl ScopeParentFixer<'_> {
+    /// Remove scopes which are no longer in AST from child scopes of `scope_id`
+    /// and of all scopes within it.
+    fn remove_detached_scopes(&mut self, scope_id: ScopeId) {
+        if !self.scoping.has_scope_child_ids() {
+            return;
+        }
+        let parent_ids = self.visited.iter().copied().chain([scope_id]).collect::<Vec<_>>();
+        for parent_id in parent_ids {
+            let detached_ids = self
+                .scoping
+                .get_scope_child_ids(parent_id)
+                .iter()
+                .copied()
+                .filter(|child_id| !self.visited.contains(child_id))
+                .collect::<Vec<_>>();
+            if !detached_ids.is_empty() {
+                self.scoping.remove_child_scopes(parent_id, &detached_ids);
+            }
+        }
+    }
+}
+
+impl Visit<'_> for ScopeParentFixer<'_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        let parent_id = *self.stack.last().unwrap();
+        if self.scoping.scope_parent_id(scope_id) != Some(parent_id) {
+            self.scoping.change_scope_parent_id(scope_id, Some(parent_id));
+        }
+        self.stack.push(scope_id);
+        self.visited.insert(scope_id);
+    }
+
+    fn leave_scope(&mut self) {
+        self.stack.pop();
+    }
+}
@@ -0,0 +1,67 @@
+//! ES2015: Shorthand Properties
+//!
+//! This plugin transforms shorthand properties and methods in object literals.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = {
+//!   a,
+//!   b() {},
+//!   __proto__() {},
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = {
+//!   a: a,
+//!   b: function () {},
+//!   ["__proto__"]: function () {},
+//! };
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-shorthand-properties](https://babel.dev/docs/babel-plugin-transform-shorthand-properties).
+//!
+//! `__proto__` shorthand properties and methods define an own property called `__proto__`,
+//! whereas `__proto__: value` sets the object's prototype. So the key is made computed,
+//! to preserve semantics.
+//!
+//! Methods which reference `super` are not transformed, as `super` is only valid in methods.
+//! Babel transforms them with `transform-object-super`, which Oxc does not implement yet.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-shorthand-properties>
+//! * Object Initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+
+use crate::{context::TraverseCtx, utils::function_references_super};
+
+pub struct ShorthandProperties;
+
+impl ShorthandProperties {
+    /// `{ a, b() {} }` -> `{ a: a, b: function () {} }`
+    pub fn transform_object_property<'a>(prop: &mut ObjectProperty<'a>, ctx: &TraverseCtx<'a>) {
+        if !prop.shorthand && !prop.method {
+            return;
+        }
+        if prop.method && function_references_super(&prop.value) {
+            return;
+        }
+        prop.shorthand = false;
+        prop.method = false;
+        if !prop.computed && prop.key.is_specific_static_name("__proto__") {
+            prop.key =
+                PropertyKey::from(ctx.ast.expression_string_literal(SPAN, "__proto__", None));
+            prop.computed = true;
+        }
+    }
+}
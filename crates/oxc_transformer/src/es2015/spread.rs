@@ -0,0 +1,319 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, function calls and `new` expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var a = [b, ...c, d];
+//! foo(...args);
+//! obj.method(x, ...args);
+//! new Foo(...args);
+//! foo?.(...args);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = [b].concat(babelHelpers.toConsumableArray(c), [d]);
+//! foo.apply(void 0, babelHelpers.toConsumableArray(args));
+//! obj.method.apply(obj, [x].concat(babelHelpers.toConsumableArray(args)));
+//! babelHelpers.construct(Foo, babelHelpers.toConsumableArray(args));
+//! foo?.apply(void 0, babelHelpers.toConsumableArray(args));
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread).
+//!
+//! With `iterableIsArray` assumption, spread values are assumed to be arrays,
+//! and are used directly instead of being converted with `toConsumableArray` helper:
+//!
+//! ```js
+//! var a = [b, ...c];
+//! // ->
+//! var a = [b].concat(c);
+//! ```
+//!
+//! Method calls on an optional chain which need a temporary variable for `this`
+//! (`a?.b.c(...args)`) are not transformed, as assigning `a?.b` to the variable would break the
+//! short circuit. The optional chaining transform lowers such chains first when both are enabled.
+//!
+//! Spread arguments of `super(...args)` are not transformed here, as `super()` cannot be called
+//! with `apply`. They are owned by the classes transform, which lowers them with
+//! [`Spread::arguments_to_array`] when it rewrites `super()` calls.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-spread>
+//! * Spread syntax: <https://tc39.es/ecma262/#sec-array-initializer>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::SPAN;
+use oxc_traverse::{MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct Spread<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Spread<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(array) => {
+                if array.elements.iter().any(ArrayExpressionElement::is_spread) {
+                    let span = array.span;
+                    let elements =
+                        array.elements.take_in(ctx.ast).into_iter().map(|element| match element {
+                            ArrayExpressionElement::SpreadElement(spread) => {
+                                Element::Spread(spread.unbox().argument)
+                            }
+                            element => Element::Normal(element),
+                        });
+                    *expr = self.build_array(elements, span, false, ctx);
+                }
+            }
+            Expression::CallExpression(call) => {
+                if !matches!(call.callee, Expression::Super(_))
+                    && call.arguments.iter().any(Argument::is_spread)
+                {
+                    self.transform_call_expression(call, ctx);
+                }
+            }
+            // `foo?.(...args)`. Calls nested in the chain are `Expression::CallExpression`s.
+            Expression::ChainExpression(chain) => {
+                if let ChainElement::CallExpression(call) = &mut chain.expression {
+                    if call.arguments.iter().any(Argument::is_spread) {
+                        self.transform_call_expression(call, ctx);
+                    }
+                }
+            }
+            Expression::NewExpression(new_expr) => {
+                if new_expr.arguments.iter().any(Argument::is_spread) {
+                    self.transform_new_expression(expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Spread<'a, '_> {
+    /// `foo(...args)` -> `foo.apply(void 0, babelHelpers.toConsumableArray(args))`
+    /// `obj.foo(...args)` -> `obj.foo.apply(obj, babelHelpers.toConsumableArray(args))`
+    /// `foo?.(...args)` -> `foo?.apply(void 0, babelHelpers.toConsumableArray(args))`
+    fn transform_call_expression(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(member) = call.callee.as_member_expression() {
+            let object = member.object();
+            if !matches!(
+                object,
+                Expression::Super(_) | Expression::ThisExpression(_) | Expression::Identifier(_)
+            ) && is_optional_chain(object)
+            {
+                return;
+            }
+        }
+
+        let this_arg = if let Some(member) = call.callee.as_member_expression_mut() {
+            let object = member.object_mut();
+            match object {
+                Expression::Super(_) | Expression::ThisExpression(_) => {
+                    ctx.ast.expression_this(SPAN)
+                }
+                Expression::Identifier(ident) => {
+                    MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                        .create_read_expression(ctx)
+                }
+                _ => {
+                    // `a.b.c(...args)` -> `(_a$b = a.b).c.apply(_a$b, ...)`
+                    let binding =
+                        self.ctx.var_declarations.create_uid_var_based_on_node(object, ctx);
+                    let value = object.take_in(ctx.ast);
+                    *object = ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        binding.create_write_target(ctx),
+                        value,
+                    );
+                    binding.create_read_expression(ctx)
+                }
+            }
+        } else {
+            ctx.ast.void_0(SPAN)
+        };
+
+        let arguments = self.build_arguments(call.arguments.take_in(ctx.ast), call.span, ctx);
+        let callee = call.callee.take_in(ctx.ast);
+        // `foo?.()` short circuits on `foo`, and so does `foo?.apply()`.
+        call.callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            callee,
+            ctx.ast.identifier_name(SPAN, "apply"),
+            call.optional,
+        ));
+        call.optional = false;
+        call.arguments =
+            ctx.ast.vec_from_array([Argument::from(this_arg), Argument::from(arguments)]);
+    }
+
+    /// `new Foo(...args)` -> `babelHelpers.construct(Foo, babelHelpers.toConsumableArray(args))`
+    fn transform_new_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::NewExpression(new_expr) = expr.take_in(ctx.ast) else { unreachable!() };
+        let NewExpression { span, callee, arguments, .. } = new_expr.unbox();
+        let arguments = self.build_arguments(arguments, span, ctx);
+        *expr = self.ctx.helper_call_expr(
+            Helper::Construct,
+            span,
+            ctx.ast.vec_from_array([Argument::from(callee), Argument::from(arguments)]),
+            ctx,
+        );
+    }
+
+    /// `(a, ...b)` -> `[a].concat(babelHelpers.toConsumableArray(b))`
+    ///
+    /// For the classes transform, which passes arguments of `super` calls as an array.
+    pub(crate) fn arguments_to_array(
+        &self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        self.build_arguments(arguments, SPAN, ctx)
+    }
+
+    /// Convert arguments list containing spread elements to an array expression.
+    fn build_arguments(
+        &self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = arguments.into_iter().map(|argument| match argument {
+            Argument::SpreadElement(spread) => Element::Spread(spread.unbox().argument),
+            argument => Element::Normal(ArrayExpressionElement::from(argument.into_expression())),
+        });
+        self.build_array(elements, span, true, ctx)
+    }
+
+    /// Build an array from elements, some of which are spread.
+    ///
+    /// `[a, ...b, c]` -> `[a].concat(babelHelpers.toConsumableArray(b), [c])`
+    ///
+    /// If `is_arguments` is `true`, a lone spread element does not need to be copied,
+    /// as its value is only used as arguments to `Function.prototype.apply`.
+    fn build_array(
+        &self,
+        elements: impl Iterator<Item = Element<'a>>,
+        span: Span,
+        is_arguments: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut segments = vec![];
+        let mut current = ctx.ast.vec();
+        for element in elements {
+            match element {
+                Element::Normal(element) => current.push(element),
+                Element::Spread(argument) => {
+                    if !current.is_empty() {
+                        segments.push(Segment::Array(ctx.ast.expression_array(SPAN, current)));
+                        current = ctx.ast.vec();
+                    }
+                    segments.push(self.create_spread_segment(argument, ctx));
+                }
+            }
+        }
+        if !current.is_empty() {
+            segments.push(Segment::Array(ctx.ast.expression_array(SPAN, current)));
+        }
+
+        if segments.len() == 1 {
+            match segments.pop().unwrap() {
+                Segment::Array(expr) | Segment::Copied(expr) => return expr,
+                Segment::Raw(expr) if is_arguments => return expr,
+                // `[...a]` -> `[].concat(a)`
+                Segment::Raw(expr) => segments.push(Segment::Raw(expr)),
+            }
+        }
+
+        // `[a].concat(...)` or `[].concat(...)`
+        let mut segments = segments.into_iter().map(Segment::into_expression).peekable();
+        let object = if matches!(segments.peek(), Some(Expression::ArrayExpression(_))) {
+            segments.next().unwrap()
+        } else {
+            ctx.ast.expression_array(SPAN, ctx.ast.vec())
+        };
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(SPAN, "concat"),
+            false,
+        ));
+        let arguments = ctx.ast.vec_from_iter(segments.map(Argument::from));
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// `...a` -> `babelHelpers.toConsumableArray(a)`, or `a` with `iterableIsArray` assumption.
+    fn create_spread_segment(
+        &self,
+        argument: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Segment<'a> {
+        if self.ctx.assumptions.iterable_is_array {
+            Segment::Raw(argument)
+        } else {
+            Segment::Copied(self.ctx.helper_call_expr(
+                Helper::ToConsumableArray,
+                SPAN,
+                ctx.ast.vec1(Argument::from(argument)),
+                ctx,
+            ))
+        }
+    }
+}
+
+/// `true` if `expr` is part of an optional chain which short circuits, e.g. `a?.b` or `a?.()`.
+fn is_optional_chain(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::CallExpression(call) => call.optional || is_optional_chain(&call.callee),
+        expr if expr.is_member_expression() => {
+            let member = expr.to_member_expression();
+            member.optional() || is_optional_chain(member.object())
+        }
+        _ => false,
+    }
+}
+
+enum Element<'a> {
+    Normal(ArrayExpressionElement<'a>),
+    Spread(Expression<'a>),
+}
+
+enum Segment<'a> {
+    /// Array literal of non-spread elements
+    Array(Expression<'a>),
+    /// Spread value converted to a new array
+    Copied(Expression<'a>),
+    /// Spread value used as is
+    Raw(Expression<'a>),
+}
+
+impl<'a> Segment<'a> {
+    fn into_expression(self) -> Expression<'a> {
+        match self {
+            Self::Array(expr) | Self::Copied(expr) | Self::Raw(expr) => expr,
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals and tagged templates.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `foo${bar}baz${qux}`;
+//! tag`foo${bar}\n`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "foo".concat(bar, "baz").concat(qux);
+//! tag(
+//!   _templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", "\n"], ["foo", "\\n"])),
+//!   bar,
+//! );
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babel.dev/docs/babel-plugin-transform-template-literals).
+//!
+//! Only the first expression of each `concat` call may be a non-literal, so values are converted
+//! to primitives in same order as template literal would.
+//!
+//! Template objects are cached in a `var` at top level of the program, so that each evaluation
+//! of a tagged template passes the same object to the tag function, as spec requires.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-template-literals>
+//! * Template Literals: <https://tc39.es/ecma262/#sec-template-literals>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::SymbolFlags;
+use oxc_span::SPAN;
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct TemplateLiterals<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// `_templateObject` bindings, to be declared at top level of program
+    template_objects: Vec<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, template_objects: vec![] }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TemplateLiterals<'a, '_> {
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.template_objects.is_empty() {
+            return;
+        }
+        // `var _templateObject, _templateObject2;`
+        let declarations = ctx.ast.vec_from_iter(self.template_objects.drain(..).map(|binding| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            )
+        }));
+        let stmt = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+        self.ctx.top_level_statements.insert_statement(stmt);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(template) => {
+                *expr = Self::transform_template_literal(template, ctx);
+            }
+            Expression::TaggedTemplateExpression(_) => {
+                self.transform_tagged_template(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> TemplateLiterals<'a, '_> {
+    /// `` `a${b}c${d}` `` -> `"a".concat(b, "c").concat(d)`
+    fn transform_template_literal(
+        template: &mut TemplateLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut quasis = template.quasis.iter();
+        let mut nodes = vec![];
+        for expression in template.expressions.take_in(ctx.ast) {
+            let quasi = quasis.next().unwrap();
+            if let Some(literal) = Self::create_string_literal(quasi, ctx) {
+                nodes.push(literal);
+            }
+            nodes.push(expression);
+        }
+        if let Some(literal) = Self::create_string_literal(quasis.next().unwrap(), ctx) {
+            nodes.push(literal);
+        }
+
+        // Ensure first node is a string, so result is a string
+        if !matches!(nodes.first(), Some(Expression::StringLiteral(_))) {
+            nodes.insert(0, ctx.ast.expression_string_literal(SPAN, "", None));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let mut result = nodes.next().unwrap();
+        // Only first argument of each `concat` call can be a non-literal, to preserve order of
+        // `ToPrimitive` conversions
+        for node in nodes {
+            if let Expression::CallExpression(call) = &mut result {
+                if matches!(node, Expression::StringLiteral(_)) {
+                    call.arguments.push(Argument::from(node));
+                    continue;
+                }
+            }
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                result,
+                ctx.ast.identifier_name(SPAN, "concat"),
+                false,
+            ));
+            let arguments = ctx.ast.vec1(Argument::from(node));
+            result = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        }
+        result
+    }
+
+    /// Create string literal from cooked value of a quasi. Returns `None` if it's empty.
+    fn create_string_literal(
+        quasi: &TemplateElement<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let value = quasi.value.cooked.unwrap_or(quasi.value.raw);
+        (!value.is_empty()).then(|| ctx.ast.expression_string_literal(SPAN, value, None))
+    }
+
+    /// `` tag`a${b}c` `` ->
+    /// `tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "c"])), b)`
+    fn transform_tagged_template(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TaggedTemplateExpression(tagged) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let TaggedTemplateExpression { span, tag, quasi, .. } = tagged.unbox();
+        let TemplateLiteral { quasis, expressions, .. } = quasi;
+
+        // Raw strings only need to be passed if they differ from cooked strings
+        let needs_raw = quasis.iter().any(|quasi| quasi.value.cooked != Some(quasi.value.raw));
+        let cooked = ctx.ast.vec_from_iter(quasis.iter().map(|quasi| {
+            ArrayExpressionElement::from(match quasi.value.cooked {
+                Some(cooked) => ctx.ast.expression_string_literal(SPAN, cooked, None),
+                None => ctx.ast.void_0(SPAN),
+            })
+        }));
+        let mut helper_arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_array(SPAN, cooked)));
+        if needs_raw {
+            let raw = ctx.ast.vec_from_iter(quasis.iter().map(|quasi| {
+                ArrayExpressionElement::from(ctx.ast.expression_string_literal(
+                    SPAN,
+                    quasi.value.raw,
+                    None,
+                ))
+            }));
+            helper_arguments.push(Argument::from(ctx.ast.expression_array(SPAN, raw)));
+        }
+        let template_object =
+            self.ctx.helper_call_expr(Helper::TaggedTemplateLiteral, SPAN, helper_arguments, ctx);
+
+        // `_templateObject || (_templateObject = ...)`
+        let binding =
+            ctx.generate_uid_in_root_scope("templateObject", SymbolFlags::FunctionScopedVariable);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            template_object,
+        );
+        let template_object = ctx.ast.expression_logical(
+            SPAN,
+            binding.create_read_expression(ctx),
+            LogicalOperator::Or,
+            assignment,
+        );
+        self.template_objects.push(binding);
+
+        let mut arguments = ArenaVec::with_capacity_in(expressions.len() + 1, ctx.ast.allocator);
+        arguments.push(Argument::from(template_object));
+        arguments.extend(expressions.into_iter().map(Argument::from));
+        *expr = ctx.ast.expression_call(span, tag, NONE, arguments, false);
+    }
+}
@@ -0,0 +1,119 @@
+//! ES2015: Typeof Symbol
+//!
+//! This plugin transforms `typeof` expressions, so they return `"symbol"` for symbols created by
+//! a `Symbol` polyfill.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! typeof sym === "symbol";
+//! typeof a.b;
+//! typeof x === "string";
+//! ```
+//!
+//! Output:
+//! ```js
+//! (typeof sym === "undefined" ? "undefined" : babelHelpers.typeof(sym)) === "symbol";
+//! babelHelpers.typeof(a.b);
+//! typeof x === "string";
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-typeof-symbol](https://babel.dev/docs/babel-plugin-transform-typeof-symbol).
+//!
+//! Comparisons of `typeof` against string literals other than `"symbol"` and `"object"` cannot
+//! be affected by symbols, so are not transformed.
+//!
+//! `typeof` on an unresolved identifier must not throw a `ReferenceError`, so it's only passed
+//! to the helper if it's defined.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-typeof-symbol>
+//! * `typeof` operator: <https://tc39.es/ecma262/#sec-typeof-operator>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::SPAN;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_traverse::{Ancestor, MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub struct TypeofSymbol<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> TypeofSymbol<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TypeofSymbol<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UnaryExpression(unary) = expr else { return };
+        if unary.operator != UnaryOperator::Typeof || Self::is_compared_to_non_symbol_type(ctx) {
+            return;
+        }
+
+        let Expression::UnaryExpression(mut unary) = expr.take_in(ctx.ast) else { unreachable!() };
+        *expr = match &unary.argument {
+            // `typeof x` -> `typeof x === "undefined" ? "undefined" : babelHelpers.typeof(x)`
+            Expression::Identifier(ident) if ident.is_global_reference(ctx.scoping()) => {
+                let span = unary.span;
+                let binding = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+                let call = self.create_typeof_call(binding.create_read_expression(ctx), ctx);
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    Expression::UnaryExpression(unary),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.expression_string_literal(SPAN, "undefined", None),
+                );
+                ctx.ast.expression_conditional(
+                    span,
+                    test,
+                    ctx.ast.expression_string_literal(SPAN, "undefined", None),
+                    call,
+                )
+            }
+            // `typeof a.b` -> `babelHelpers.typeof(a.b)`
+            _ => self.create_typeof_call(unary.argument.take_in(ctx.ast), ctx),
+        };
+    }
+}
+
+impl<'a> TypeofSymbol<'a, '_> {
+    /// `true` if `typeof` expression is compared to a string literal which is not
+    /// `"symbol"` or `"object"` (e.g. `typeof x === "string"`).
+    fn is_compared_to_non_symbol_type(ctx: &TraverseCtx<'a>) -> bool {
+        let (operator, opposite) = match ctx.parent() {
+            Ancestor::BinaryExpressionLeft(binary) => (*binary.operator(), binary.right()),
+            Ancestor::BinaryExpressionRight(binary) => (*binary.operator(), binary.left()),
+            _ => return false,
+        };
+        operator.is_equality()
+            && matches!(
+                opposite,
+                Expression::StringLiteral(lit) if lit.value != "symbol" && lit.value != "object"
+            )
+    }
+
+    /// `babelHelpers.typeof(argument)`
+    fn create_typeof_call(
+        &self,
+        argument: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        self.ctx.helper_call_expr(Helper::Typeof, SPAN, ctx.ast.vec1(Argument::from(argument)), ctx)
+    }
+}
@@ -80,11 +80,6 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
                 "Compiler assumption `objectRestNoSymbols` is not implemented for object-rest-spread.",
             ));
         }
-        if ctx.assumptions.ignore_function_length {
-            ctx.error(OxcDiagnostic::error(
-                "Compiler assumption `ignoreFunctionLength` is not implemented for object-rest-spread.",
-            ));
-        }
         Self { ctx, options, excluded_variable_declarators: vec![] }
     }
 }
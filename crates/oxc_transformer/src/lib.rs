@@ -19,6 +19,7 @@ mod common;
 mod compiler_assumptions;
 mod context;
 mod options;
+mod plugins;
 mod state;
 mod utils;
 
@@ -32,6 +33,8 @@ mod es2020;
 mod es2021;
 mod es2022;
 mod jsx;
+mod modules_commonjs;
+mod polyfill;
 mod proposals;
 mod regexp;
 mod typescript;
@@ -39,7 +42,6 @@ mod typescript;
 mod decorator;
 
 use common::Common;
-use context::{TransformCtx, TraverseCtx};
 use decorator::Decorator;
 use es2015::ES2015;
 use es2016::ES2016;
@@ -50,15 +52,18 @@ use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
 use jsx::Jsx;
+use modules_commonjs::ModulesCommonJS;
+use plugins::{PluginFactory, Plugins, WithPlugins};
+use polyfill::Polyfill;
 use proposals::ExplicitResourceManagement;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
-use state::TransformState;
 use typescript::TypeScript;
 
 pub use crate::{
     common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions},
     compiler_assumptions::CompilerAssumptions,
+    context::{TransformCtx, TraverseCtx},
     decorator::DecoratorOptions,
     es2015::{ArrowFunctionsOptions, ES2015Options},
     es2016::ES2016Options,
@@ -69,11 +74,15 @@ pub use crate::{
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
     jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
+    modules_commonjs::{ImportInterop, ModulesCommonJSOptions},
     options::{
         ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
     },
+    plugins::{PluginPosition, TransformStage},
+    polyfill::PolyfillOptions,
     proposals::ProposalOptions,
+    state::TransformState,
     typescript::{RewriteExtensionsMode, TypeScriptOptions},
 };
 
@@ -95,6 +104,8 @@ pub struct Transformer<'a> {
     jsx: JsxOptions,
     env: EnvOptions,
     proposals: ProposalOptions,
+
+    plugins: Vec<(PluginPosition, PluginFactory<'a>)>,
 }
 
 impl<'a> Transformer<'a> {
@@ -108,7 +119,33 @@ impl<'a> Transformer<'a> {
             jsx: options.jsx.clone(),
             env: options.env,
             proposals: options.proposals,
-        }
+            plugins: vec![],
+        }
+    }
+
+    /// Register a custom [`Traverse`] pass to run in the same traversal as the built-in transforms.
+    ///
+    /// `factory` is called when the transform starts, with the [`TransformCtx`] used by the built-in
+    /// transforms. The pass can use it to load helpers, and to insert statements and `var` declarations.
+    /// They are inserted after all passes have exited the statement list or the program they belong to.
+    ///
+    /// ```ignore
+    /// let ret = Transformer::new(&allocator, path, &options)
+    ///     .with_plugin(PluginPosition::After(TransformStage::TypeScript), |ctx| {
+    ///         Box::new(FeatureFlags::new(ctx))
+    ///     })
+    ///     .build_with_scoping(scoping, &mut program);
+    /// ```
+    #[must_use]
+    pub fn with_plugin<F>(mut self, position: PluginPosition, factory: F) -> Self
+    where
+        F: for<'ctx> FnOnce(
+                &'ctx TransformCtx<'a>,
+            ) -> Box<dyn Traverse<'a, TransformState<'a>> + 'ctx>
+            + 'a,
+    {
+        self.plugins.push((position, Box::new(factory)));
+        self
     }
 
     pub fn build_with_scoping(
@@ -133,6 +170,7 @@ impl<'a> Transformer<'a> {
 
         let mut transformer = TransformerImpl {
             common: Common::new(&self.env, &self.ctx),
+            polyfill: self.env.polyfill.usage.then(|| Polyfill::new(self.env.polyfill, &self.ctx)),
             decorator: Decorator::new(self.decorator, &self.ctx),
             explicit_resource_management: self
                 .proposals
@@ -157,10 +195,20 @@ impl<'a> Transformer<'a> {
             x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            x5_modules_commonjs: self
+                .env
+                .module
+                .is_commonjs()
+                .then(|| ModulesCommonJS::new(self.env.commonjs, &self.ctx)),
+            plugins: Plugins::new(self.plugins, &self.ctx),
         };
 
         let state = TransformState::default();
-        let scoping = traverse_mut(&mut transformer, allocator, program, scoping, state);
+        let scoping = if transformer.plugins.is_empty() {
+            traverse_mut(&mut transformer, allocator, program, scoping, state)
+        } else {
+            traverse_mut(&mut WithPlugins(transformer), allocator, program, scoping, state)
+        };
         let helpers_used = self.ctx.helper_loader.used_helpers.borrow_mut().drain().collect();
         #[expect(deprecated)]
         TransformerReturn { errors: self.ctx.take_errors(), scoping, helpers_used }
@@ -169,6 +217,8 @@ impl<'a> Transformer<'a> {
 
 struct TransformerImpl<'a, 'ctx> {
     // NOTE: all callbacks must run in order.
+    // Runs first, to detect built-ins used in the original code
+    polyfill: Option<Polyfill<'a, 'ctx>>,
     x0_typescript: Option<TypeScript<'a, 'ctx>>,
     decorator: Decorator<'a, 'ctx>,
     explicit_resource_management: Option<ExplicitResourceManagement<'a, 'ctx>>,
@@ -180,17 +230,34 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
+    x5_modules_commonjs: Option<ModulesCommonJS<'a, 'ctx>>,
     common: Common<'a, 'ctx>,
+    // Custom passes, which run before or after the built-in stages
+    plugins: Plugins<'a, 'ctx>,
+}
+
+/// Run the custom passes positioned before a built-in stage, or all the remaining ones,
+/// which have not run yet for the current node.
+macro_rules! run_plugins {
+    ($self:ident, before $stage:ident, $hook:ident($node:ident, $ctx:ident)) => {
+        $self.plugins.run_before(TransformStage::$stage, $node, $ctx, |pass, node, ctx| {
+            pass.$hook(node, ctx)
+        })
+    };
+    ($self:ident, rest, $hook:ident($node:ident, $ctx:ident)) => {
+        $self.plugins.run_rest($node, $ctx, |pass, node, ctx| pass.$hook(node, ctx))
+    };
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_program(program, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_program(program, ctx);
         }
+        run_plugins!(self, before Jsx, enter_program(program, ctx));
         self.x1_jsx.enter_program(program, ctx);
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_program(program, ctx);
@@ -198,13 +265,26 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before Jsx, exit_program(program, ctx));
         self.x1_jsx.exit_program(program, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_program(program, ctx);
         }
+        run_plugins!(self, before ES2022, exit_program(program, ctx));
         self.x2_es2022.exit_program(program, ctx);
+        run_plugins!(self, before ES2018, exit_program(program, ctx));
         self.x2_es2018.exit_program(program, ctx);
+        run_plugins!(self, before ES2015, exit_program(program, ctx));
+        self.x3_es2015.exit_program(program, ctx);
+        if let Some(polyfill) = self.polyfill.as_mut() {
+            polyfill.exit_program(program, ctx);
+        }
+        run_plugins!(self, rest, exit_program(program, ctx));
         self.common.exit_program(program, ctx);
+        // Runs last, to transform `import`s inserted by other transforms
+        if let Some(modules_commonjs) = self.x5_modules_commonjs.as_mut() {
+            modules_commonjs.exit_program(program, ctx);
+        }
     }
 
     // ALPHASORT
@@ -213,10 +293,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_arrow_function_expression(arrow, ctx));
         self.common.enter_arrow_function_expression(arrow, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_arrow_function_expression(arrow, ctx);
         }
+        run_plugins!(self, before ES2018, enter_arrow_function_expression(arrow, ctx));
         self.x2_es2018.enter_arrow_function_expression(arrow, ctx);
     }
 
@@ -225,28 +307,51 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         decl: &mut VariableDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before ES2018, enter_variable_declaration(decl, ctx));
         self.x2_es2018.enter_variable_declaration(decl, ctx);
     }
 
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        run_plugins!(self, before ES2015, exit_variable_declaration(decl, ctx));
+        self.x3_es2015.exit_variable_declaration(decl, ctx);
+    }
+
     fn enter_variable_declarator(
         &mut self,
         decl: &mut VariableDeclarator<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_variable_declarator(decl, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_variable_declarator(decl, ctx);
         }
     }
 
     fn enter_big_int_literal(&mut self, node: &mut BigIntLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before ES2020, enter_big_int_literal(node, ctx));
         self.x2_es2020.enter_big_int_literal(node, ctx);
     }
 
+    fn exit_numeric_literal(&mut self, node: &mut NumericLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before ES2015, exit_numeric_literal(node, ctx));
+        self.x3_es2015.exit_numeric_literal(node, ctx);
+    }
+
+    fn exit_string_literal(&mut self, node: &mut StringLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before ES2015, exit_string_literal(node, ctx));
+        self.x3_es2015.exit_string_literal(node, ctx);
+    }
+
     fn enter_binding_identifier(
         &mut self,
         node: &mut BindingIdentifier<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_binding_identifier(node, ctx));
         self.common.enter_binding_identifier(node, ctx);
     }
 
@@ -255,29 +360,38 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut IdentifierReference<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_identifier_reference(node, ctx));
         self.common.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_binding_pattern(pat, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_binding_pattern(pat, ctx);
         }
     }
 
     fn enter_call_expression(&mut self, expr: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_call_expression(expr, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_call_expression(expr, ctx);
         }
+        run_plugins!(self, before Jsx, enter_call_expression(expr, ctx));
         self.x1_jsx.enter_call_expression(expr, ctx);
     }
 
     fn enter_chain_element(&mut self, element: &mut ChainElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(polyfill) = self.polyfill.as_mut() {
+            polyfill.enter_chain_element(element, ctx);
+        }
+        run_plugins!(self, before TypeScript, enter_chain_element(element, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_chain_element(element, ctx);
         }
     }
 
     fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_class(class, ctx));
         self.decorator.enter_class(class, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_class(class, ctx);
@@ -285,53 +399,83 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, exit_class(class, ctx));
         self.decorator.exit_class(class, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_class(class, ctx);
         }
+        run_plugins!(self, before ES2022, exit_class(class, ctx));
         self.x2_es2022.exit_class(class, ctx);
+        run_plugins!(self, before ES2015, exit_class(class, ctx));
+        self.x3_es2015.exit_class(class, ctx);
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_class_body(body, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_class_body(body, ctx);
         }
+        run_plugins!(self, before ES2022, enter_class_body(body, ctx));
         self.x2_es2022.enter_class_body(body, ctx);
     }
 
     fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_static_block(block, ctx));
         self.common.enter_static_block(block, ctx);
+        run_plugins!(self, before ES2022, enter_static_block(block, ctx));
         self.x2_es2022.enter_static_block(block, ctx);
     }
 
     fn exit_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, exit_static_block(block, ctx));
         self.common.exit_static_block(block, ctx);
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.exit_static_block(block, ctx);
         }
+        run_plugins!(self, before ES2022, exit_static_block(block, ctx));
         self.x2_es2022.exit_static_block(block, ctx);
     }
 
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(polyfill) = self.polyfill.as_mut() {
+            polyfill.enter_expression(expr, ctx);
+        }
+        run_plugins!(self, before TypeScript, enter_expression(expr, ctx));
         self.common.enter_expression(expr, ctx);
+        self.decorator.enter_expression(expr, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
         }
+        run_plugins!(self, before ES2022, enter_expression(expr, ctx));
         self.x2_es2022.enter_expression(expr, ctx);
+        run_plugins!(self, before ES2021, enter_expression(expr, ctx));
         self.x2_es2021.enter_expression(expr, ctx);
+        run_plugins!(self, before ES2020, enter_expression(expr, ctx));
         self.x2_es2020.enter_expression(expr, ctx);
+        run_plugins!(self, before ES2018, enter_expression(expr, ctx));
         self.x2_es2018.enter_expression(expr, ctx);
+        run_plugins!(self, before ES2016, enter_expression(expr, ctx));
         self.x2_es2016.enter_expression(expr, ctx);
+        run_plugins!(self, before ES2015, enter_expression(expr, ctx));
+        self.x3_es2015.enter_expression(expr, ctx);
+        run_plugins!(self, before RegExp, enter_expression(expr, ctx));
         self.x4_regexp.enter_expression(expr, ctx);
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, exit_expression(expr, ctx));
         self.common.exit_expression(expr, ctx);
+        run_plugins!(self, before Jsx, exit_expression(expr, ctx));
         self.x1_jsx.exit_expression(expr, ctx);
+        run_plugins!(self, before ES2022, exit_expression(expr, ctx));
         self.x2_es2022.exit_expression(expr, ctx);
+        run_plugins!(self, before ES2018, exit_expression(expr, ctx));
         self.x2_es2018.exit_expression(expr, ctx);
+        run_plugins!(self, before ES2017, exit_expression(expr, ctx));
         self.x2_es2017.exit_expression(expr, ctx);
+        run_plugins!(self, before ES2015, exit_expression(expr, ctx));
+        self.x3_es2015.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -339,6 +483,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut SimpleAssignmentTarget<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_simple_assignment_target(node, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_simple_assignment_target(node, ctx);
         }
@@ -349,9 +494,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut AssignmentTarget<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_assignment_target(node, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_assignment_target(node, ctx);
         }
+        run_plugins!(self, before ES2022, enter_assignment_target(node, ctx));
         self.x2_es2022.enter_assignment_target(node, ctx);
     }
 
@@ -360,6 +507,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut FormalParameters<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before ES2020, enter_formal_parameters(node, ctx));
         self.x2_es2020.enter_formal_parameters(node, ctx);
     }
 
@@ -368,6 +516,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut FormalParameters<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before ES2020, exit_formal_parameters(node, ctx));
         self.x2_es2020.exit_formal_parameters(node, ctx);
     }
 
@@ -376,27 +525,39 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         param: &mut FormalParameter<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_formal_parameter(param, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_formal_parameter(param, ctx);
         }
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_function(func, ctx));
         self.common.enter_function(func, ctx);
+        run_plugins!(self, before ES2018, enter_function(func, ctx));
         self.x2_es2018.enter_function(func, ctx);
+        run_plugins!(self, before ES2015, enter_function(func, ctx));
+        self.x3_es2015.enter_function(func, ctx);
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, exit_function(func, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_function(func, ctx);
         }
+        run_plugins!(self, before Jsx, exit_function(func, ctx));
         self.x1_jsx.exit_function(func, ctx);
+        run_plugins!(self, before ES2018, exit_function(func, ctx));
         self.x2_es2018.exit_function(func, ctx);
+        run_plugins!(self, before ES2017, exit_function(func, ctx));
         self.x2_es2017.exit_function(func, ctx);
+        run_plugins!(self, before ES2015, exit_function(func, ctx));
+        self.x3_es2015.exit_function(func, ctx);
         self.common.exit_function(func, ctx);
     }
 
     fn enter_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_function_body(body, ctx));
         self.common.enter_function_body(body, ctx);
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_function_body(body, ctx);
@@ -404,16 +565,19 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn exit_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, exit_function_body(body, ctx));
         self.common.exit_function_body(body, ctx);
     }
 
     fn enter_jsx_element(&mut self, node: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_jsx_element(node, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_jsx_element(node, ctx);
         }
     }
 
     fn enter_jsx_element_name(&mut self, node: &mut JSXElementName<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_jsx_element_name(node, ctx));
         self.common.enter_jsx_element_name(node, ctx);
     }
 
@@ -422,10 +586,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut JSXMemberExpressionObject<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_jsx_member_expression_object(node, ctx));
         self.common.enter_jsx_member_expression_object(node, ctx);
     }
 
     fn enter_jsx_fragment(&mut self, node: &mut JSXFragment<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_jsx_fragment(node, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_jsx_fragment(node, ctx);
         }
@@ -436,9 +602,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         elem: &mut JSXOpeningElement<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_jsx_opening_element(elem, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_jsx_opening_element(elem, ctx);
         }
+        run_plugins!(self, before Jsx, enter_jsx_opening_element(elem, ctx));
         self.x1_jsx.enter_jsx_opening_element(elem, ctx);
     }
 
@@ -447,6 +615,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         def: &mut MethodDefinition<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_method_definition(def, ctx));
         self.decorator.enter_method_definition(def, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_method_definition(def, ctx);
@@ -454,6 +623,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn enter_new_expression(&mut self, expr: &mut NewExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_new_expression(expr, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_new_expression(expr, ctx);
         }
@@ -464,10 +634,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         def: &mut PropertyDefinition<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_property_definition(def, ctx));
         self.decorator.enter_property_definition(def, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_property_definition(def, ctx);
         }
+        run_plugins!(self, before ES2022, enter_property_definition(def, ctx));
         self.x2_es2022.enter_property_definition(def, ctx);
     }
 
@@ -476,6 +648,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         def: &mut PropertyDefinition<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before ES2022, exit_property_definition(def, ctx));
         self.x2_es2022.exit_property_definition(def, ctx);
     }
 
@@ -484,6 +657,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut AccessorProperty<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_accessor_property(node, ctx));
         self.decorator.enter_accessor_property(node, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_accessor_property(node, ctx);
@@ -495,7 +669,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         stmts: &mut ArenaVec<'a, Statement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_statements(stmts, ctx));
         self.common.enter_statements(stmts, ctx);
+        self.decorator.enter_statements(stmts, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statements(stmts, ctx);
         }
@@ -506,7 +682,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, exit_arrow_function_expression(arrow, ctx));
         self.common.exit_arrow_function_expression(arrow, ctx);
+        run_plugins!(self, before ES2015, exit_arrow_function_expression(arrow, ctx));
+        self.x3_es2015.exit_arrow_function_expression(arrow, ctx);
 
         // Some plugins may add new statements to the ArrowFunctionExpression's body,
         // which can cause issues with the `() => x;` case, as it only allows a single statement.
@@ -544,19 +723,26 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         stmts: &mut ArenaVec<'a, Statement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, exit_statements(stmts, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_statements(stmts, ctx);
         }
+        run_plugins!(self, rest, exit_statements(stmts, ctx));
         self.common.exit_statements(stmts, ctx);
     }
 
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, exit_statement(stmt, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_statement(stmt, ctx);
         }
         self.decorator.exit_statement(stmt, ctx);
+        run_plugins!(self, before ES2018, exit_statement(stmt, ctx));
         self.x2_es2018.exit_statement(stmt, ctx);
+        run_plugins!(self, before ES2017, exit_statement(stmt, ctx));
         self.x2_es2017.exit_statement(stmt, ctx);
+        run_plugins!(self, before ES2015, exit_statement(stmt, ctx));
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -564,15 +750,18 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         expr: &mut TaggedTemplateExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_tagged_template_expression(expr, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_tagged_template_expression(expr, ctx);
         }
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_statement(stmt, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statement(stmt, ctx);
         }
+        run_plugins!(self, before ES2018, enter_statement(stmt, ctx));
         self.x2_es2018.enter_statement(stmt, ctx);
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_statement(stmt, ctx);
@@ -580,18 +769,21 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_declaration(decl, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_declaration(decl, ctx);
         }
     }
 
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_if_statement(stmt, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_if_statement(stmt, ctx);
         }
     }
 
     fn enter_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_while_statement(stmt, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_while_statement(stmt, ctx);
         }
@@ -602,34 +794,50 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         stmt: &mut DoWhileStatement<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_do_while_statement(stmt, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_do_while_statement(stmt, ctx);
         }
     }
 
     fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_for_statement(stmt, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_for_of_statement(stmt, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_of_statement(stmt, ctx);
         }
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_for_of_statement(stmt, ctx);
         }
+        run_plugins!(self, before ES2018, enter_for_of_statement(stmt, ctx));
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
     }
 
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before ES2015, exit_for_of_statement(stmt, ctx));
+        self.x3_es2015.exit_for_of_statement(stmt, ctx);
+    }
+
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before TypeScript, enter_for_in_statement(stmt, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_in_statement(stmt, ctx);
         }
+        run_plugins!(self, before ES2018, enter_for_in_statement(stmt, ctx));
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
     }
 
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before ES2015, exit_for_in_statement(stmt, ctx));
+        self.x3_es2015.exit_for_in_statement(stmt, ctx);
+    }
+
     fn enter_try_statement(&mut self, stmt: &mut TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_try_statement(stmt, ctx);
@@ -637,15 +845,23 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before ES2019, enter_catch_clause(clause, ctx));
         self.x2_es2019.enter_catch_clause(clause, ctx);
+        run_plugins!(self, before ES2018, enter_catch_clause(clause, ctx));
         self.x2_es2018.enter_catch_clause(clause, ctx);
     }
 
+    fn exit_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        run_plugins!(self, before ES2015, exit_catch_clause(clause, ctx));
+        self.x3_es2015.exit_catch_clause(clause, ctx);
+    }
+
     fn enter_import_declaration(
         &mut self,
         node: &mut ImportDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_import_declaration(node, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_import_declaration(node, ctx);
         }
@@ -656,6 +872,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut ExportAllDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_export_all_declaration(node, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_export_all_declaration(node, ctx);
         }
@@ -666,6 +883,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut ExportNamedDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_export_named_declaration(node, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_export_named_declaration(node, ctx);
         }
@@ -676,6 +894,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         export_assignment: &mut TSExportAssignment<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        run_plugins!(self, before TypeScript, enter_ts_export_assignment(export_assignment, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_ts_export_assignment(export_assignment, ctx);
         }
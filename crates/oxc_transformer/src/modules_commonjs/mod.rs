@@ -0,0 +1,1087 @@
+//! Modules: CommonJS
+//!
+//! This plugin transforms ES modules to CommonJS.
+//!
+//! > This plugin is included in `preset-env` with `modules: "commonjs"` option
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export * from "baz";
+//! export { qux as quux } from "qux";
+//! export let count = foo(bar);
+//! export function increment() {
+//!   count++;
+//! }
+//! export default class {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! "use strict";
+//! Object.defineProperty(exports, "__esModule", { value: true });
+//! var _exportNames = { count: true, increment: true, quux: true };
+//! exports.count = exports.default = void 0;
+//! exports.increment = increment;
+//! Object.defineProperty(exports, "quux", {
+//!   enumerable: true,
+//!   get: function () {
+//!     return _qux.qux;
+//!   },
+//! });
+//! var _foo = babelHelpers.interopRequireWildcard(require("foo"));
+//! var _baz = require("baz");
+//! Object.keys(_baz).forEach(function (key) {
+//!   if (key === "default" || key === "__esModule") return;
+//!   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+//!   if (key in exports && exports[key] === _baz[key]) return;
+//!   Object.defineProperty(exports, key, {
+//!     enumerable: true,
+//!     get: function () {
+//!       return _baz[key];
+//!     },
+//!   });
+//! });
+//! var _qux = require("qux");
+//! let count = exports.count = (0, _foo.default)(_foo.bar);
+//! function increment() {
+//!   exports.count = ++count;
+//! }
+//! class _default {}
+//! exports.default = _default;
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-commonjs](https://babel.dev/docs/babel-plugin-transform-modules-commonjs).
+//!
+//! Transform runs on exiting `Program`, after all other transforms, so `import`s inserted by
+//! other transforms (JSX runtime, helpers) are transformed too.
+//!
+//! * `import`s become `require` calls at top of the file. References to imported bindings are
+//!   replaced with member expressions on the module object, so imports remain live bindings.
+//! * Exported local bindings are initialized to `void 0` at top of the file, and every assignment
+//!   to an exported binding also assigns to `exports`. Exported functions are assigned at top
+//!   of the file, as function declarations are hoisted.
+//! * Re-exports from other modules are defined as getters on `exports`.
+//! * `import("x")` becomes `Promise.resolve().then(function () { return require("x"); })`.
+//! * Top level `this` is replaced with `void 0`, unless `allowTopLevelThis` option is set.
+//!
+//! Babel renames local bindings which shadow `exports`, `require` or `Object`.
+//! We don't support that, and assignments to exported bindings in `for (x in y)` / `for (x of y)`
+//! heads are not reflected in `exports`.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-modules-commonjs>
+//! * Babel helper implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-helper-module-transforms>
+
+use std::path::Path;
+
+use indexmap::IndexMap;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::VisitMut;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{BoundIdentifier, Traverse, ast_operations::to_identifier};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod rewrite;
+
+use rewrite::ModuleReferenceRewriter;
+
+/// How `default` and namespace imports interoperate with CommonJS modules.
+///
+/// <https://babel.dev/docs/babel-plugin-transform-modules-commonjs#importinterop>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportInterop {
+    /// `default` import is `module.exports.default`, unless the module is not marked with
+    /// `__esModule`, in which case it is `module.exports`.
+    #[default]
+    Babel,
+    /// `default` import is always `module.exports`, like Node.js does when importing CommonJS.
+    Node,
+    /// No interop helpers. `default` import is `module.exports.default`.
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ModulesCommonJSOptions {
+    /// How imports interoperate with CommonJS modules.
+    pub import_interop: ImportInterop,
+
+    /// Do not replace top level `this` with `void 0`.
+    pub allow_top_level_this: bool,
+}
+
+/// What an import binding refers to in its source module.
+#[derive(Clone, Copy)]
+enum ImportKind<'a> {
+    Default,
+    Named(Atom<'a>),
+    Namespace,
+}
+
+/// Replacement for references to an import binding.
+enum ImportReplacement<'a> {
+    /// `_foo.name`
+    Member(BoundIdentifier<'a>, Atom<'a>),
+    /// `_foo`
+    Object(BoundIdentifier<'a>),
+}
+
+/// A module which is imported from, or re-exported from.
+struct ModuleSource<'a> {
+    /// Binding for the module object. `None` if module is only imported for its side effects.
+    binding: Option<BoundIdentifier<'a>>,
+    uses_default: bool,
+    uses_named: bool,
+    uses_namespace: bool,
+    /// `export * from "x"`
+    export_star: bool,
+}
+
+impl ModuleSource<'_> {
+    fn new() -> Self {
+        Self {
+            binding: None,
+            uses_default: false,
+            uses_named: false,
+            uses_namespace: false,
+            export_star: false,
+        }
+    }
+
+    fn record_use(&mut self, kind: ImportKind) {
+        match kind {
+            ImportKind::Default => self.uses_default = true,
+            ImportKind::Named(_) => self.uses_named = true,
+            ImportKind::Namespace => self.uses_namespace = true,
+        }
+    }
+
+    fn needs_binding(&self) -> bool {
+        self.uses_default || self.uses_named || self.uses_namespace || self.export_star
+    }
+}
+
+/// Information about imports and exports of a module, collected before transforming it.
+#[derive(Default)]
+struct ModuleInfo<'a> {
+    sources: IndexMap<Atom<'a>, ModuleSource<'a>>,
+    /// Import bindings, and what they refer to
+    imports: FxHashMap<SymbolId, (usize, ImportKind<'a>)>,
+    /// Exported local bindings, and names they are exported as
+    local_exports: FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// Names which are initialized to `void 0` at top of the file
+    void_exports: Vec<Atom<'a>>,
+    /// Exported function declarations, which are assigned to `exports` at top of the file
+    hoisted_exports: Vec<(Atom<'a>, BoundIdentifier<'a>)>,
+    /// Names re-exported from other modules, defined as getters
+    reexports: Vec<(Atom<'a>, usize, ImportKind<'a>)>,
+    has_exports: bool,
+}
+
+impl<'a> ModuleInfo<'a> {
+    fn source_index(&mut self, source: Atom<'a>) -> usize {
+        let entry = self.sources.entry(source);
+        let index = entry.index();
+        entry.or_insert_with(ModuleSource::new);
+        index
+    }
+
+    fn add_local_export(&mut self, name: Atom<'a>, symbol_id: SymbolId) {
+        self.local_exports.entry(symbol_id).or_default().push(name);
+    }
+}
+
+pub struct ModulesCommonJS<'a, 'ctx> {
+    options: ModulesCommonJSOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ModulesCommonJS<'a, 'ctx> {
+    pub fn new(options: ModulesCommonJSOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ModulesCommonJS<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+
+        // ES modules are always strict mode
+        if !program.directives.iter().any(Directive::is_use_strict) {
+            program.directives.insert(0, ctx.ast.use_strict_directive());
+        }
+
+        let mut info = ModuleInfo::default();
+        for stmt in &program.body {
+            if let Statement::ImportDeclaration(decl) = stmt {
+                Self::collect_import(decl, &mut info, ctx);
+            }
+        }
+        for stmt in &mut program.body {
+            Self::collect_export(stmt, &mut info, ctx);
+        }
+
+        let imports = self.create_source_bindings(&mut info, ctx);
+
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+        for stmt in program.body.take_in(ctx.ast) {
+            Self::transform_statement(stmt, &mut body, &info, ctx);
+        }
+
+        let mut rewriter = ModuleReferenceRewriter::new(self, &imports, &info.local_exports, ctx);
+        rewriter.visit_statements(&mut body);
+        let temp_bindings = rewriter.into_temp_bindings();
+
+        let mut stmts = self.create_header(&info, ctx);
+        if !temp_bindings.is_empty() {
+            let declarations = ctx.ast.vec_from_iter(temp_bindings.iter().map(|binding| {
+                ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    binding.create_binding_pattern(ctx),
+                    None,
+                    false,
+                )
+            }));
+            stmts.push(Statement::from(ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarations,
+                false,
+            )));
+        }
+        stmts.extend(body);
+        program.body = ctx.ast.vec_from_iter(stmts);
+    }
+}
+
+// Collecting imports and exports
+impl<'a> ModulesCommonJS<'a, '_> {
+    fn collect_import(
+        decl: &ImportDeclaration<'a>,
+        info: &mut ModuleInfo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let index = info.source_index(decl.source.value);
+        let Some(specifiers) = &decl.specifiers else { return };
+        let root_scope_id = ctx.scoping().root_scope_id();
+        for specifier in specifiers {
+            let (local, kind) = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if specifier.import_kind.is_type() {
+                        continue;
+                    }
+                    let imported = specifier.imported.name();
+                    let kind = if imported == "default" {
+                        ImportKind::Default
+                    } else {
+                        ImportKind::Named(imported)
+                    };
+                    (&specifier.local, kind)
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    (&specifier.local, ImportKind::Default)
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    (&specifier.local, ImportKind::Namespace)
+                }
+            };
+            let source = &mut info.sources[index];
+            source.record_use(kind);
+
+            // First namespace import's binding is reused as binding for the module object
+            if matches!(kind, ImportKind::Namespace) && source.binding.is_none() {
+                source.binding = Some(BoundIdentifier::from_binding_ident(local));
+                *ctx.scoping_mut().symbol_flags_mut(local.symbol_id()) =
+                    SymbolFlags::FunctionScopedVariable;
+            } else {
+                ctx.scoping_mut().remove_binding(root_scope_id, &local.name);
+            }
+            info.imports.insert(local.symbol_id(), (index, kind));
+        }
+    }
+
+    fn collect_export(
+        stmt: &mut Statement<'a>,
+        info: &mut ModuleInfo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match stmt {
+            Statement::ExportNamedDeclaration(decl) => {
+                if decl.export_kind.is_type() {
+                    return;
+                }
+                info.has_exports = true;
+                if let Some(source) = &decl.source {
+                    let index = info.source_index(source.value);
+                    for specifier in &decl.specifiers {
+                        if specifier.export_kind.is_type() {
+                            continue;
+                        }
+                        let local = specifier.local.name();
+                        let kind = if local == "default" {
+                            ImportKind::Default
+                        } else {
+                            ImportKind::Named(local)
+                        };
+                        info.sources[index].record_use(kind);
+                        info.reexports.push((specifier.exported.name(), index, kind));
+                    }
+                } else if let Some(declaration) = &decl.declaration {
+                    Self::collect_export_declaration(declaration, info);
+                } else {
+                    for specifier in &decl.specifiers {
+                        if specifier.export_kind.is_type() {
+                            continue;
+                        }
+                        let ModuleExportName::IdentifierReference(ident) = &specifier.local else {
+                            continue;
+                        };
+                        let symbol_id =
+                            ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                        // Statement is removed, so is the reference
+                        ctx.delete_reference_for_identifier(ident);
+                        let Some(symbol_id) = symbol_id else { continue };
+                        Self::collect_export_specifier(
+                            specifier.exported.name(),
+                            ident,
+                            symbol_id,
+                            info,
+                            ctx,
+                        );
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                let name = Atom::from("default");
+                match &mut decl.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                        info.has_exports = true;
+                        let id = func.id.get_or_insert_with(|| {
+                            ctx.generate_uid_in_root_scope("default", SymbolFlags::Function)
+                                .create_binding_identifier(ctx)
+                        });
+                        info.add_local_export(name, id.symbol_id());
+                        info.hoisted_exports.push((name, BoundIdentifier::from_binding_ident(id)));
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                        info.has_exports = true;
+                        let id = class.id.get_or_insert_with(|| {
+                            ctx.generate_uid_in_root_scope("default", SymbolFlags::Class)
+                                .create_binding_identifier(ctx)
+                        });
+                        info.add_local_export(name, id.symbol_id());
+                        info.void_exports.push(name);
+                    }
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+                    _ => {
+                        info.has_exports = true;
+                        info.void_exports.push(name);
+                    }
+                }
+            }
+            Statement::ExportAllDeclaration(decl) => {
+                if decl.export_kind.is_type() {
+                    return;
+                }
+                info.has_exports = true;
+                let index = info.source_index(decl.source.value);
+                let source = &mut info.sources[index];
+                if let Some(exported) = &decl.exported {
+                    source.record_use(ImportKind::Namespace);
+                    info.reexports.push((exported.name(), index, ImportKind::Namespace));
+                } else {
+                    source.export_star = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `export function f() {}`, `export class C {}`, `export let a, b;`
+    fn collect_export_declaration(declaration: &Declaration<'a>, info: &mut ModuleInfo<'a>) {
+        match declaration {
+            Declaration::FunctionDeclaration(func) => {
+                if let Some(id) = &func.id {
+                    info.add_local_export(id.name, id.symbol_id());
+                    info.hoisted_exports.push((id.name, BoundIdentifier::from_binding_ident(id)));
+                }
+            }
+            Declaration::ClassDeclaration(class) => {
+                if let Some(id) = &class.id {
+                    info.add_local_export(id.name, id.symbol_id());
+                    info.void_exports.push(id.name);
+                }
+            }
+            Declaration::VariableDeclaration(decl) => {
+                decl.bound_names(&mut |id| {
+                    info.add_local_export(id.name, id.symbol_id());
+                    info.void_exports.push(id.name);
+                });
+            }
+            // TypeScript declarations have been removed by TypeScript transform
+            _ => {}
+        }
+    }
+
+    /// `export { a as b }`
+    fn collect_export_specifier(
+        exported: Atom<'a>,
+        ident: &IdentifierReference<'a>,
+        symbol_id: SymbolId,
+        info: &mut ModuleInfo<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if let Some(&(index, kind)) = info.imports.get(&symbol_id) {
+            info.reexports.push((exported, index, kind));
+        } else if ctx.scoping().symbol_flags(symbol_id).is_function() {
+            info.add_local_export(exported, symbol_id);
+            let binding = BoundIdentifier::new(ident.name, symbol_id);
+            info.hoisted_exports.push((exported, binding));
+        } else {
+            info.add_local_export(exported, symbol_id);
+            info.void_exports.push(exported);
+        }
+    }
+
+    /// Create bindings for module objects, and return replacements for references to imports.
+    fn create_source_bindings(
+        &self,
+        info: &mut ModuleInfo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> FxHashMap<SymbolId, ImportReplacement<'a>> {
+        for (specifier, source) in &mut info.sources {
+            if source.binding.is_none() && source.needs_binding() {
+                let name = Self::source_binding_name(specifier);
+                source.binding = Some(
+                    ctx.generate_uid_in_root_scope(&name, SymbolFlags::FunctionScopedVariable),
+                );
+            }
+        }
+
+        info.imports
+            .iter()
+            .filter_map(|(&symbol_id, &(index, kind))| {
+                let source = &info.sources[index];
+                // References to namespace import which is reused as module object stay as is
+                if source.binding.as_ref().is_some_and(|binding| binding.symbol_id == symbol_id) {
+                    return None;
+                }
+                Some((symbol_id, self.create_import_replacement(source, kind)))
+            })
+            .collect()
+    }
+
+    fn create_import_replacement(
+        &self,
+        source: &ModuleSource<'a>,
+        kind: ImportKind<'a>,
+    ) -> ImportReplacement<'a> {
+        let binding = source.binding.clone().unwrap();
+        match kind {
+            ImportKind::Named(name) => ImportReplacement::Member(binding, name),
+            ImportKind::Default
+                if self.options.import_interop == ImportInterop::Node && !source.uses_namespace =>
+            {
+                ImportReplacement::Object(binding)
+            }
+            ImportKind::Default => ImportReplacement::Member(binding, Atom::from("default")),
+            ImportKind::Namespace => ImportReplacement::Object(binding),
+        }
+    }
+
+    /// Name for module object binding. `"./foo/bar-baz.js"` -> `"barBaz"`.
+    fn source_binding_name(specifier: &str) -> String {
+        let path = Path::new(specifier);
+        let name = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or(specifier);
+        to_identifier(name.to_string())
+    }
+}
+
+// Transforming statements
+impl<'a> ModulesCommonJS<'a, '_> {
+    fn transform_statement(
+        stmt: Statement<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        info: &ModuleInfo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match stmt {
+            Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => {}
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = decl.unbox().declaration {
+                    Self::push_statement(Statement::from(declaration), body, info, ctx);
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => match decl.unbox().declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    body.push(Statement::FunctionDeclaration(func));
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                    Self::push_statement(Statement::ClassDeclaration(class), body, info, ctx);
+                }
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+                declaration => {
+                    // `export default foo` -> `exports.default = foo`
+                    let expr = create_exports_assignment(
+                        &[Atom::from("default")],
+                        declaration.into_expression(),
+                        ctx,
+                    );
+                    body.push(ctx.ast.statement_expression(SPAN, expr));
+                }
+            },
+            stmt => Self::push_statement(stmt, body, info, ctx),
+        }
+    }
+
+    /// Push statement to body, followed by assignments to `exports` for exported bindings
+    /// it declares, which are not handled by [`ModuleReferenceRewriter`].
+    ///
+    /// * `class C {}` -> `class C {} exports.C = C;`
+    /// * `var { a } = obj;` -> `var { a } = obj; exports.a = a;`
+    fn push_statement(
+        stmt: Statement<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        info: &ModuleInfo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut bindings = vec![];
+        match &stmt {
+            Statement::ClassDeclaration(class) => {
+                if let Some(id) = &class.id {
+                    bindings.push(BoundIdentifier::from_binding_ident(id));
+                }
+            }
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &decl.declarations {
+                    if !declarator.id.kind.is_binding_identifier() {
+                        declarator.id.bound_names(&mut |id| {
+                            bindings.push(BoundIdentifier::from_binding_ident(id));
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+        body.push(stmt);
+
+        for binding in bindings {
+            if let Some(names) = info.local_exports.get(&binding.symbol_id) {
+                let value = binding.create_read_expression(ctx);
+                let expr = create_exports_assignment(names, value, ctx);
+                body.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+        }
+    }
+}
+
+// Creating header
+impl<'a> ModulesCommonJS<'a, '_> {
+    /// Create statements which go at top of the file, before the module body.
+    fn create_header(
+        &self,
+        info: &ModuleInfo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        let mut stmts = vec![];
+
+        if info.has_exports {
+            // `Object.defineProperty(exports, "__esModule", { value: true });`
+            let value = ctx.ast.expression_boolean_literal(SPAN, true);
+            let descriptor = create_object(vec![("value", value)], ctx);
+            let key = ctx.ast.expression_string_literal(SPAN, "__esModule", None);
+            stmts.push(create_define_property_on_exports(key, descriptor, ctx));
+        }
+
+        // `var _exportNames = { a: true };`
+        let export_names = Self::create_export_names(info, ctx);
+        let export_names_binding = export_names.map(|(binding, init)| {
+            stmts.push(create_var_declaration(&binding, init, ctx));
+            binding
+        });
+
+        // `exports.a = exports.b = void 0;`
+        let mut void_exports = vec![];
+        for &name in &info.void_exports {
+            if !void_exports.contains(&name) {
+                void_exports.push(name);
+            }
+        }
+        if !void_exports.is_empty() {
+            let expr = create_exports_assignment(&void_exports, ctx.ast.void_0(SPAN), ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, expr));
+        }
+
+        // `exports.f = f;`
+        for (name, binding) in &info.hoisted_exports {
+            let value = binding.create_read_expression(ctx);
+            let expr = create_exports_assignment(&[*name], value, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, expr));
+        }
+
+        // `Object.defineProperty(exports, "b", { enumerable: true, get: function () { return _foo.a; } });`
+        let root_scope_id = ctx.scoping().root_scope_id();
+        for &(name, index, kind) in &info.reexports {
+            let value = self.create_import_replacement(&info.sources[index], kind).create_expr(ctx);
+            let descriptor = create_getter_descriptor(value, root_scope_id, ctx);
+            let key = ctx.ast.expression_string_literal(SPAN, name, None);
+            stmts.push(create_define_property_on_exports(key, descriptor, ctx));
+        }
+
+        // `var _foo = require("foo");`
+        let mut requires = vec![];
+        for (&specifier, source) in &info.sources {
+            self.create_require(
+                specifier,
+                source,
+                export_names_binding.as_ref(),
+                &mut requires,
+                ctx,
+            );
+        }
+
+        // Helpers used in this module. Must be after all uses of helpers have been created.
+        stmts.extend(self.ctx.module_imports.take_require_statements(ctx));
+        stmts.extend(requires);
+        stmts
+    }
+
+    /// `var _exportNames = { a: true, b: true };`
+    ///
+    /// Only required if module has both `export *` and other exports.
+    fn create_export_names(
+        info: &ModuleInfo<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<(BoundIdentifier<'a>, Expression<'a>)> {
+        if !info.sources.values().any(|source| source.export_star) {
+            return None;
+        }
+        let mut names = vec![];
+        let all_names = info
+            .void_exports
+            .iter()
+            .chain(info.hoisted_exports.iter().map(|(name, _)| name))
+            .chain(info.reexports.iter().map(|(name, _, _)| name));
+        for &name in all_names {
+            if name != "default" && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        if names.is_empty() {
+            return None;
+        }
+
+        let properties = names
+            .into_iter()
+            .map(|name| (name.as_str(), ctx.ast.expression_boolean_literal(SPAN, true)))
+            .collect();
+        let init = create_object(properties, ctx);
+        let binding =
+            ctx.generate_uid_in_root_scope("exportNames", SymbolFlags::FunctionScopedVariable);
+        Some((binding, init))
+    }
+
+    /// * `var _foo = require("foo");`
+    /// * `var _foo = babelHelpers.interopRequireDefault(require("foo"));`
+    /// * `require("foo");`
+    ///
+    /// followed by loop defining getters for `export * from "foo"`.
+    fn create_require(
+        &self,
+        specifier: Atom<'a>,
+        source: &ModuleSource<'a>,
+        export_names: Option<&BoundIdentifier<'a>>,
+        stmts: &mut Vec<Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let specifier = ctx.ast.expression_string_literal(SPAN, specifier, None);
+        let require = create_require_call(specifier, ctx);
+        let Some(binding) = &source.binding else {
+            stmts.push(ctx.ast.statement_expression(SPAN, require));
+            return;
+        };
+
+        let init = match self.options.import_interop {
+            ImportInterop::Babel
+                if source.uses_namespace || (source.uses_default && source.uses_named) =>
+            {
+                self.ctx.helper_call_expr(
+                    Helper::InteropRequireWildcard,
+                    SPAN,
+                    ctx.ast.vec1(Argument::from(require)),
+                    ctx,
+                )
+            }
+            ImportInterop::Babel if source.uses_default => self.ctx.helper_call_expr(
+                Helper::InteropRequireDefault,
+                SPAN,
+                ctx.ast.vec1(Argument::from(require)),
+                ctx,
+            ),
+            ImportInterop::Node if source.uses_namespace => {
+                self.create_interop_require_wildcard(require, ctx)
+            }
+            _ => require,
+        };
+        stmts.push(create_var_declaration(binding, init, ctx));
+
+        if source.export_star {
+            stmts.push(Self::create_export_star_loop(binding, export_names, ctx));
+        }
+    }
+
+    /// `babelHelpers.interopRequireWildcard(require("foo"))`,
+    /// or `babelHelpers.interopRequireWildcard(require("foo"), true)` with `importInterop: "node"`,
+    /// or `require("foo")` with `importInterop: "none"`.
+    fn create_interop_require_wildcard(
+        &self,
+        require: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec1(Argument::from(require));
+        match self.options.import_interop {
+            ImportInterop::Babel => {}
+            ImportInterop::Node => {
+                arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+            }
+            ImportInterop::None => return arguments.pop().unwrap().into_expression(),
+        }
+        self.ctx.helper_call_expr(Helper::InteropRequireWildcard, SPAN, arguments, ctx)
+    }
+
+    /// ```js
+    /// Object.keys(_foo).forEach(function (key) {
+    ///   if (key === "default" || key === "__esModule") return;
+    ///   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    ///   if (key in exports && exports[key] === _foo[key]) return;
+    ///   Object.defineProperty(exports, key, {
+    ///     enumerable: true,
+    ///     get: function () {
+    ///       return _foo[key];
+    ///     },
+    ///   });
+    /// });
+    /// ```
+    fn create_export_star_loop(
+        source: &BoundIdentifier<'a>,
+        export_names: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(ctx.scoping().root_scope_id(), ScopeFlags::Function);
+        let key =
+            ctx.generate_binding(Atom::from("key"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut body = ctx.ast.vec_with_capacity(4);
+
+        // `if (key === "default" || key === "__esModule") return;`
+        let is_default = create_strict_equals_string(&key, "default", ctx);
+        let is_es_module = create_strict_equals_string(&key, "__esModule", ctx);
+        let test = ctx.ast.expression_logical(SPAN, is_default, LogicalOperator::Or, is_es_module);
+        body.push(create_if_return(test, ctx));
+
+        // `if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;`
+        if let Some(export_names) = export_names {
+            let callee = ["prototype", "hasOwnProperty", "call"]
+                .into_iter()
+                .fold(create_global_ident("Object", ctx), |object, property| {
+                    create_static_member(object, property, ctx)
+                });
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(export_names.create_read_expression(ctx)),
+                Argument::from(key.create_read_expression(ctx)),
+            ]);
+            let test = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            body.push(create_if_return(test, ctx));
+        }
+
+        // `if (key in exports && exports[key] === _foo[key]) return;`
+        let key_in_exports = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::In,
+            create_global_ident("exports", ctx),
+        );
+        let exports_key = create_computed_member(
+            create_global_ident("exports", ctx),
+            key.create_read_expression(ctx),
+            ctx,
+        );
+        let source_key = create_computed_member(
+            source.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            ctx,
+        );
+        let is_same = ctx.ast.expression_binary(
+            SPAN,
+            exports_key,
+            BinaryOperator::StrictEquality,
+            source_key,
+        );
+        let test = ctx.ast.expression_logical(SPAN, key_in_exports, LogicalOperator::And, is_same);
+        body.push(create_if_return(test, ctx));
+
+        // `Object.defineProperty(exports, key, { enumerable: true, get: function () { return _foo[key]; } });`
+        let value = create_computed_member(
+            source.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            ctx,
+        );
+        let descriptor = create_getter_descriptor(value, scope_id, ctx);
+        body.push(create_define_property_on_exports(
+            key.create_read_expression(ctx),
+            descriptor,
+            ctx,
+        ));
+
+        // `Object.keys(_foo).forEach(function (key) { ... })`
+        let callback = create_function(Some(&key), body, scope_id, ctx);
+        let keys = create_static_member(create_global_ident("Object", ctx), "keys", ctx);
+        let keys = ctx.ast.expression_call(
+            SPAN,
+            keys,
+            NONE,
+            ctx.ast.vec1(Argument::from(source.create_read_expression(ctx))),
+            false,
+        );
+        let for_each = create_static_member(keys, "forEach", ctx);
+        let call = ctx.ast.expression_call(
+            SPAN,
+            for_each,
+            NONE,
+            ctx.ast.vec1(Argument::from(callback)),
+            false,
+        );
+        ctx.ast.statement_expression(SPAN, call)
+    }
+}
+
+impl<'a> ImportReplacement<'a> {
+    /// Create expression which an import binding's references are replaced with.
+    fn create_expr(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Member(binding, name) => {
+                let object = binding.create_read_expression(ctx);
+                Expression::from(create_member(object, *name, ctx))
+            }
+            Self::Object(binding) => binding.create_read_expression(ctx),
+        }
+    }
+}
+
+/// Create reference to a global, e.g. `exports`, `Object`.
+fn create_global_ident<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    ctx.create_unbound_ident_expr(SPAN, Atom::from(name), ReferenceFlags::Read)
+}
+
+/// `object.property`
+fn create_static_member<'a>(
+    object: Expression<'a>,
+    property: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        object,
+        ctx.ast.identifier_name(SPAN, property),
+        false,
+    ))
+}
+
+/// `object[property]`
+fn create_computed_member<'a>(
+    object: Expression<'a>,
+    property: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::from(ctx.ast.member_expression_computed(SPAN, object, property, false))
+}
+
+/// `object.name`, or `object["not-identifier"]`
+fn create_member<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> MemberExpression<'a> {
+    if is_identifier_name(&name) {
+        ctx.ast.member_expression_static(SPAN, object, ctx.ast.identifier_name(SPAN, name), false)
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, name, None);
+        ctx.ast.member_expression_computed(SPAN, object, property, false)
+    }
+}
+
+/// `exports.a = exports.b = value`
+fn create_exports_assignment<'a>(
+    names: &[Atom<'a>],
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    names.iter().rev().fold(value, |value, &name| {
+        let exports = create_global_ident("exports", ctx);
+        let target = AssignmentTarget::from(create_member(exports, name, ctx));
+        ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+    })
+}
+
+/// `require("foo")`
+fn create_require_call<'a>(specifier: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let require_symbol_id = ctx.scoping().get_root_binding("require");
+    let callee =
+        ctx.create_ident_expr(SPAN, Atom::from("require"), require_symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(specifier)), false)
+}
+
+/// `function (param) { body }`
+fn create_function<'a>(
+    param: Option<&BoundIdentifier<'a>>,
+    body: ArenaVec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let items = ctx.ast.vec_from_iter(param.map(|param| {
+        ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            param.create_binding_pattern(ctx),
+            None,
+            false,
+            false,
+        )
+    }));
+    let params =
+        ctx.ast.alloc_formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, NONE);
+    let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), body);
+    ctx.ast.expression_function_with_scope_id_and_pure(
+        SPAN,
+        FunctionType::FunctionExpression,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+        scope_id,
+        false,
+    )
+}
+
+/// `{ key: value, ... }`
+fn create_object<'a>(
+    properties: Vec<(&str, Expression<'a>)>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let properties = ctx.ast.vec_from_iter(properties.into_iter().map(|(key, value)| {
+        let key = if is_identifier_name(key) {
+            ctx.ast.property_key_static_identifier(SPAN, ctx.ast.atom(key))
+        } else {
+            PropertyKey::from(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(key), None))
+        };
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }));
+    ctx.ast.expression_object(SPAN, properties)
+}
+
+/// `{ enumerable: true, get: function () { return value; } }`
+fn create_getter_descriptor<'a>(
+    value: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+    let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+    let getter = create_function(None, body, scope_id, ctx);
+    let enumerable = ctx.ast.expression_boolean_literal(SPAN, true);
+    create_object(vec![("enumerable", enumerable), ("get", getter)], ctx)
+}
+
+/// `Object.defineProperty(exports, key, descriptor);`
+fn create_define_property_on_exports<'a>(
+    key: Expression<'a>,
+    descriptor: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let callee = create_static_member(create_global_ident("Object", ctx), "defineProperty", ctx);
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(create_global_ident("exports", ctx)),
+        Argument::from(key),
+        Argument::from(descriptor),
+    ]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    ctx.ast.statement_expression(SPAN, call)
+}
+
+/// `var binding = init;`
+fn create_var_declaration<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    // Namespace import's binding is reused, so preserve its span
+    let span = ctx.scoping().symbol_span(binding.symbol_id);
+    let id = ctx.ast.binding_pattern(
+        ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+            span,
+            binding.name,
+            binding.symbol_id,
+        ),
+        NONE,
+        false,
+    );
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+    Statement::from(ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false))
+}
+
+/// `binding === "value"`
+fn create_strict_equals_string<'a>(
+    binding: &BoundIdentifier<'a>,
+    value: &'static str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_binary(
+        SPAN,
+        binding.create_read_expression(ctx),
+        BinaryOperator::StrictEquality,
+        ctx.ast.expression_string_literal(SPAN, value, None),
+    )
+}
+
+/// `if (test) return;`
+fn create_if_return<'a>(test: Expression<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+    ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None)
+}
@@ -0,0 +1,435 @@
+//! Visitor which rewrites module body for CommonJS.
+//!
+//! * References to imports: `foo` -> `_foo.foo`, `foo()` -> `(0, _foo.foo)()`.
+//! * Assignments to exported bindings: `x = 1` -> `exports.x = x = 1`.
+//! * Exported bindings assigned in `for in` / `for of` heads:
+//!   `for (x of y) {}` -> `for (var _x of y) { exports.x = x = _x; }`.
+//! * Dynamic imports: `import("foo")` -> `Promise.resolve().then(function () { return require("foo"); })`.
+//! * Top level `this`: `this` -> `void 0`.
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::TraverseCtx;
+
+use super::{
+    ImportReplacement, ModulesCommonJS, create_exports_assignment, create_function,
+    create_global_ident, create_require_call, create_static_member,
+};
+
+pub(super) struct ModuleReferenceRewriter<'a, 'ctx, 'r> {
+    transform: &'r ModulesCommonJS<'a, 'ctx>,
+    /// Import bindings, and what their references are replaced with
+    imports: &'r FxHashMap<SymbolId, ImportReplacement<'a>>,
+    /// Exported local bindings, and names they are exported as
+    local_exports: &'r FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    ctx: &'r mut TraverseCtx<'a>,
+    /// Stack of scopes, and whether each scope has its own `this`
+    scope_stack: Vec<(ScopeId, bool)>,
+    /// Number of enclosing functions and classes which have their own `this`
+    this_depth: usize,
+    /// `_old` temp var for postfix update expressions
+    old_binding: Option<BoundIdentifier<'a>>,
+    /// `_ref` temp var for destructuring assignments
+    ref_binding: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx, 'r> ModuleReferenceRewriter<'a, 'ctx, 'r> {
+    pub(super) fn new(
+        transform: &'r ModulesCommonJS<'a, 'ctx>,
+        imports: &'r FxHashMap<SymbolId, ImportReplacement<'a>>,
+        local_exports: &'r FxHashMap<SymbolId, Vec<Atom<'a>>>,
+        ctx: &'r mut TraverseCtx<'a>,
+    ) -> Self {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        Self {
+            transform,
+            imports,
+            local_exports,
+            ctx,
+            scope_stack: vec![(root_scope_id, false)],
+            this_depth: 0,
+            old_binding: None,
+            ref_binding: None,
+        }
+    }
+
+    /// Consume rewriter, and return temp vars which need to be declared at top level.
+    pub(super) fn into_temp_bindings(self) -> Vec<BoundIdentifier<'a>> {
+        self.old_binding.into_iter().chain(self.ref_binding).collect()
+    }
+}
+
+impl<'a> VisitMut<'a> for ModuleReferenceRewriter<'a, '_, '_> {
+    fn enter_scope(&mut self, flags: ScopeFlags, scope_id: &std::cell::Cell<Option<ScopeId>>) {
+        let has_this = flags.is_function() && !flags.is_arrow();
+        if has_this {
+            self.this_depth += 1;
+        }
+        self.scope_stack.push((scope_id.get().unwrap(), has_this));
+    }
+
+    fn leave_scope(&mut self) {
+        if let Some((_, true)) = self.scope_stack.pop() {
+            self.this_depth -= 1;
+        }
+    }
+
+    fn visit_class_body(&mut self, body: &mut ClassBody<'a>) {
+        self.this_depth += 1;
+        walk_mut::walk_class_body(self, body);
+        self.this_depth -= 1;
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if let Some(replacement) = self.replace_import_reference(ident) {
+                    *expr = replacement;
+                }
+                return;
+            }
+            Expression::ThisExpression(this) => {
+                if self.this_depth == 0 && !self.transform.options.allow_top_level_this {
+                    *expr = self.ctx.ast.void_0(this.span);
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        walk_mut::walk_expression(self, expr);
+
+        match expr {
+            Expression::AssignmentExpression(_) => self.transform_assignment_expression(expr),
+            Expression::UpdateExpression(_) => self.transform_update_expression(expr),
+            Expression::ImportExpression(_) => self.transform_import_expression(expr),
+            _ => {}
+        }
+    }
+
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        self.transform_callee(&mut call.callee);
+        walk_mut::walk_call_expression(self, call);
+    }
+
+    fn visit_tagged_template_expression(&mut self, tagged: &mut TaggedTemplateExpression<'a>) {
+        self.transform_callee(&mut tagged.tag);
+        walk_mut::walk_tagged_template_expression(self, tagged);
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        // `x++;` -> `exports.x = ++x;`. Result is unused, so no need for a temp var.
+        if let Expression::UpdateExpression(update) = &mut stmt.expression {
+            if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument {
+                if self.exported_names(ident).is_some() {
+                    update.prefix = true;
+                }
+            }
+        }
+        walk_mut::walk_expression_statement(self, stmt);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        let scope_id = stmt.scope_id();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id);
+        walk_mut::walk_for_in_statement(self, stmt);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        let scope_id = stmt.scope_id();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id);
+        walk_mut::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
+        walk_mut::walk_variable_declarator(self, declarator);
+
+        // `let x = 1` -> `let x = exports.x = 1`
+        let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else { return };
+        let Some(names) = self.local_exports.get(&id.symbol_id()) else { return };
+        if let Some(init) = &mut declarator.init {
+            let value = init.take_in(self.ctx.ast);
+            *init = create_exports_assignment(names, value, self.ctx);
+        }
+    }
+}
+
+impl<'a, 'r> ModuleReferenceRewriter<'a, '_, 'r> {
+    /// Get replacement for reference to an import binding, if `ident` refers to one.
+    fn replace_import_reference(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<Expression<'a>> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        let replacement = self.imports.get(&symbol_id)?;
+        self.ctx.delete_reference_for_identifier(ident);
+        Some(replacement.create_expr(self.ctx))
+    }
+
+    /// `foo()` -> `(0, _foo.foo)()`, so function is not called with module object as `this`.
+    fn transform_callee(&mut self, callee: &mut Expression<'a>) {
+        let Expression::Identifier(ident) = callee else { return };
+        let Some(replacement) = self.replace_import_reference(ident) else { return };
+        *callee = if replacement.is_member_expression() {
+            let zero =
+                self.ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+            self.ctx.ast.expression_sequence(SPAN, self.ctx.ast.vec_from_array([zero, replacement]))
+        } else {
+            replacement
+        };
+    }
+
+    /// Get names an exported local binding is exported as, if `ident` refers to one.
+    fn exported_names(&self, ident: &IdentifierReference<'a>) -> Option<&'r Vec<Atom<'a>>> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.local_exports.get(&symbol_id)
+    }
+
+    /// * `x = 1` -> `exports.x = x = 1`
+    /// * `[x, y] = arr` -> `(_ref = [x, y] = arr, exports.x = x, exports.y = y, _ref)`
+    fn transform_assignment_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+        match &assign.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let Some(names) = self.exported_names(ident) else { return };
+                let value = expr.take_in(self.ctx.ast);
+                *expr = create_exports_assignment(names, value, self.ctx);
+            }
+            match_assignment_target_pattern!(AssignmentTarget) => {
+                let mut collector = ExportedTargetsCollector {
+                    scoping: self.ctx.scoping(),
+                    local_exports: self.local_exports,
+                    targets: vec![],
+                };
+                collector.visit_assignment_target(&assign.left);
+                let targets = collector.targets;
+                if targets.is_empty() {
+                    return;
+                }
+
+                let binding = self.ref_binding.get_or_insert_with(|| {
+                    self.ctx.generate_uid_in_root_scope("ref", SymbolFlags::FunctionScopedVariable)
+                });
+                let binding = binding.clone();
+                let mut expressions = self.ctx.ast.vec_with_capacity(targets.len() + 2);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_write_target(self.ctx),
+                    expr.take_in(self.ctx.ast),
+                ));
+                for (name, symbol_id) in targets {
+                    let value = self.ctx.create_bound_ident_expr(
+                        SPAN,
+                        name,
+                        symbol_id,
+                        ReferenceFlags::Read,
+                    );
+                    let names = &self.local_exports[&symbol_id];
+                    expressions.push(create_exports_assignment(names, value, self.ctx));
+                }
+                expressions.push(binding.create_read_expression(self.ctx));
+                *expr = self.ctx.ast.expression_sequence(SPAN, expressions);
+            }
+            _ => {}
+        }
+    }
+
+    /// `for (x of y) {}` -> `for (var _x of y) { x = _x; }`, if `x` is an exported binding.
+    ///
+    /// The assignment inserted into the loop body is then transformed to `exports.x = x = _x`
+    /// when the body is visited.
+    fn transform_for_statement_left(
+        &mut self,
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+    ) {
+        let Some(target) = left.as_assignment_target() else { return };
+        let name = match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                if self.exported_names(ident).is_none() {
+                    return;
+                }
+                ident.name.as_str()
+            }
+            match_assignment_target_pattern!(AssignmentTarget) => {
+                let mut collector = ExportedTargetsCollector {
+                    scoping: self.ctx.scoping(),
+                    local_exports: self.local_exports,
+                    targets: vec![],
+                };
+                collector.visit_assignment_target(target);
+                if collector.targets.is_empty() {
+                    return;
+                }
+                "ref"
+            }
+            _ => return,
+        };
+
+        // Declare `var` in enclosing function scope, as block scoping transform has already run
+        let var_scope_id = self
+            .scope_stack
+            .iter()
+            .rev()
+            .map(|&(scope_id, _)| scope_id)
+            .find(|&scope_id| self.ctx.scoping().scope_flags(scope_id).is_var())
+            .unwrap();
+        let binding =
+            self.ctx.generate_uid(name, var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let target = left.take_in(self.ctx.ast).into_assignment_target();
+        let declarator = self.ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(self.ctx),
+            None,
+            false,
+        );
+        *left = self.ctx.ast.for_statement_left_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            self.ctx.ast.vec1(declarator),
+            false,
+        );
+
+        let assignment = self.ctx.ast.statement_expression(
+            SPAN,
+            self.ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                target,
+                binding.create_read_expression(self.ctx),
+            ),
+        );
+        if let Statement::BlockStatement(block) = body {
+            block.body.insert(0, assignment);
+        } else {
+            let block_scope_id = self.ctx.insert_scope_below_statement_from_scope_id(
+                body,
+                scope_id,
+                ScopeFlags::empty(),
+            );
+            let statements = self.ctx.ast.vec_from_array([assignment, body.take_in(self.ctx.ast)]);
+            *body = self.ctx.ast.statement_block_with_scope_id(SPAN, statements, block_scope_id);
+        }
+    }
+
+    /// * `++x` -> `exports.x = ++x`
+    /// * `x++` -> `(_old = x++, exports.x = x, _old)`
+    fn transform_update_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::UpdateExpression(update) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument else {
+            return;
+        };
+        let Some(names) = self.exported_names(ident) else { return };
+
+        if update.prefix {
+            let value = expr.take_in(self.ctx.ast);
+            *expr = create_exports_assignment(names, value, self.ctx);
+            return;
+        }
+
+        let name = ident.name;
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id().unwrap();
+        let binding = self.old_binding.get_or_insert_with(|| {
+            self.ctx.generate_uid_in_root_scope("old", SymbolFlags::FunctionScopedVariable)
+        });
+        let binding = binding.clone();
+        let save_old = self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(self.ctx),
+            expr.take_in(self.ctx.ast),
+        );
+        let value = self.ctx.create_bound_ident_expr(SPAN, name, symbol_id, ReferenceFlags::Read);
+        let assign_exports = create_exports_assignment(names, value, self.ctx);
+        let old = binding.create_read_expression(self.ctx);
+        *expr = self.ctx.ast.expression_sequence(
+            SPAN,
+            self.ctx.ast.vec_from_array([save_old, assign_exports, old]),
+        );
+    }
+
+    /// * `import("foo")` -> `Promise.resolve().then(function () { return babelHelpers.interopRequireWildcard(require("foo")); })`
+    /// * `import(foo)` -> `Promise.resolve(foo).then(function (s) { return babelHelpers.interopRequireWildcard(require(s)); })`
+    fn transform_import_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ImportExpression(import) = expr else { unreachable!() };
+        let span = import.span;
+        let source = import.source.take_in(self.ctx.ast);
+
+        let parent_scope_id = self.scope_stack.last().unwrap().0;
+        let scope_id = self.ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+        let (specifier, promise_argument, param) = if matches!(source, Expression::StringLiteral(_))
+        {
+            (source, None, None)
+        } else {
+            let param = self.ctx.generate_binding(
+                Atom::from("s"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            (param.create_read_expression(self.ctx), Some(source), Some(param))
+        };
+        let require = create_require_call(specifier, self.ctx);
+        let value = self.transform.create_interop_require_wildcard(require, self.ctx);
+        let body = self.ctx.ast.vec1(self.ctx.ast.statement_return(SPAN, Some(value)));
+        let callback = create_function(param.as_ref(), body, scope_id, self.ctx);
+
+        let resolve =
+            create_static_member(create_global_ident("Promise", self.ctx), "resolve", self.ctx);
+        let arguments = self.ctx.ast.vec_from_iter(promise_argument.map(Argument::from));
+        let promise = self.ctx.ast.expression_call(SPAN, resolve, NONE, arguments, false);
+        let then = create_static_member(promise, "then", self.ctx);
+        let arguments = self.ctx.ast.vec1(Argument::from(callback));
+        *expr = self.ctx.ast.expression_call(span, then, NONE, arguments, false);
+    }
+}
+
+/// Visitor to find identifiers in an assignment target pattern which are exported bindings.
+struct ExportedTargetsCollector<'a, 'r> {
+    scoping: &'r Scoping,
+    local_exports: &'r FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    targets: Vec<(Atom<'a>, SymbolId)>,
+}
+
+impl<'a> ExportedTargetsCollector<'a, '_> {
+    fn collect(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        if self.local_exports.contains_key(&symbol_id)
+            && !self.targets.iter().any(|&(_, id)| id == symbol_id)
+        {
+            self.targets.push((ident.name, symbol_id));
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ExportedTargetsCollector<'a, '_> {
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        // Don't visit member expressions. `obj.x` is not an assignment to binding `obj`.
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            self.collect(ident);
+        }
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        property: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.collect(&property.binding);
+    }
+
+    // Don't visit default values or computed keys
+    fn visit_expression(&mut self, _expr: &Expression<'a>) {}
+}
@@ -36,10 +36,9 @@ pub struct BabelEnvOptions {
     #[deprecated = "Not Implemented"]
     pub exclude: Option<serde_json::Value>,
 
-    #[deprecated = "Not Implemented"]
-    pub use_built_ins: Option<serde_json::Value>,
+    pub use_built_ins: BabelUseBuiltIns,
 
-    #[deprecated = "Not Implemented"]
+    /// core-js version, e.g. `3`, `"3.38"` or `{ "version": "3.38" }`.
     pub corejs: Option<serde_json::Value>,
 
     #[deprecated = "Not Implemented"]
@@ -55,6 +54,48 @@ pub struct BabelEnvOptions {
     pub shipped_proposals: bool,
 }
 
+impl BabelEnvOptions {
+    /// Only core-js 3 is supported.
+    ///
+    /// # Errors
+    ///
+    /// * `corejs` is not a version of core-js 3.
+    pub fn check_corejs_version(&self) -> Result<(), String> {
+        let version = match &self.corejs {
+            None => return Ok(()),
+            Some(serde_json::Value::Object(o)) => o.get("version"),
+            Some(value) => Some(value),
+        };
+        let major = match version {
+            Some(serde_json::Value::Number(n)) => n.to_string(),
+            Some(serde_json::Value::String(s)) => s.clone(),
+            _ => String::new(),
+        };
+        if major.split('.').next() == Some("3") {
+            Ok(())
+        } else {
+            Err(format!("core-js version {major:?} is not supported, only core-js 3 is supported."))
+        }
+    }
+}
+
+/// <https://babeljs.io/docs/babel-preset-env#usebuiltins>
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum BabelUseBuiltIns {
+    #[serde(rename = "usage")]
+    Usage,
+    #[serde(rename = "entry")]
+    Entry,
+    #[serde(untagged)]
+    Boolean(bool),
+}
+
+impl Default for BabelUseBuiltIns {
+    fn default() -> Self {
+        Self::Boolean(false)
+    }
+}
+
 #[derive(Default, Debug, Clone, Deserialize)]
 pub enum BabelModule {
     #[default]
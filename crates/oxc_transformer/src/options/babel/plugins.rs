@@ -1,8 +1,12 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::{ArrowFunctionsOptions, BlockScopingOptions},
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
+    modules_commonjs::ModulesCommonJSOptions,
 };
 
 use super::PluginPresetEntries;
@@ -39,7 +43,7 @@ pub struct BabelPlugins {
     pub react_jsx_source: bool,
     pub react_display_name: bool,
     // modules
-    pub modules_commonjs: bool,
+    pub modules_commonjs: Option<ModulesCommonJSOptions>,
     // regexp
     pub sticky_flag: bool,
     pub unicode_flag: bool,
@@ -52,6 +56,21 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub classes: bool,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub destructuring: bool,
+    pub spread: bool,
+    pub parameters: bool,
+    pub regenerator: bool,
+    pub template_literals: bool,
+    pub for_of: bool,
+    pub computed_properties: bool,
+    pub shorthand_properties: bool,
+    pub function_name: bool,
+    pub new_target: bool,
+    pub typeof_symbol: bool,
+    pub literals: bool,
+    pub duplicate_keys: bool,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -120,7 +139,12 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-display-name" => p.react_display_name = true,
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
-                "transform-modules-commonjs" => p.modules_commonjs = true,
+                "transform-modules-commonjs" => {
+                    p.modules_commonjs = entry
+                        .value::<ModulesCommonJSOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
                 "transform-dotall-regex" => p.dot_all_flag = true,
@@ -135,6 +159,24 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-classes" => p.classes = true,
+                "transform-block-scoping" => {
+                    p.block_scoping =
+                        entry.value::<BlockScopingOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-destructuring" => p.destructuring = true,
+                "transform-spread" => p.spread = true,
+                "transform-parameters" => p.parameters = true,
+                "transform-regenerator" => p.regenerator = true,
+                "transform-template-literals" => p.template_literals = true,
+                "transform-for-of" => p.for_of = true,
+                "transform-computed-properties" => p.computed_properties = true,
+                "transform-shorthand-properties" => p.shorthand_properties = true,
+                "transform-function-name" => p.function_name = true,
+                "transform-new-target" => p.new_target = true,
+                "transform-typeof-symbol" => p.typeof_symbol = true,
+                "transform-literals" => p.literals = true,
+                "transform-duplicate-keys" => p.duplicate_keys = true,
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -52,8 +52,13 @@ impl EngineTargets {
     }
 
     pub fn has_feature(&self, feature: ESFeature) -> bool {
-        let feature_engine_targets = &features()[&feature];
-        for (engine, feature_version) in feature_engine_targets.iter() {
+        self.is_any_older_than(&features()[&feature])
+    }
+
+    /// Returns true if any of the targets is older than the minimum version of the same engine
+    /// in `versions`. Engines which are not in `versions` are ignored.
+    pub fn is_any_older_than(&self, versions: &EngineTargets) -> bool {
+        for (engine, feature_version) in versions.iter() {
             if let Some(target_version) = self.get(engine) {
                 if *engine == Engine::Es {
                     return target_version.0 < feature_version.0;
@@ -4,7 +4,7 @@ use serde::Deserialize;
 
 use crate::{
     EngineTargets,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{ArrowFunctionsOptions, BlockScopingOptions, ES2015Options},
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
@@ -12,10 +12,15 @@ use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
+    modules_commonjs::ModulesCommonJSOptions,
+    polyfill::PolyfillOptions,
     regexp::RegExpOptions,
 };
 
-use super::{ESFeature, ESTarget, Engine, Module, babel::BabelEnvOptions};
+use super::{
+    ESFeature, ESTarget, Engine, Module,
+    babel::{BabelEnvOptions, BabelUseBuiltIns},
+};
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(try_from = "BabelEnvOptions")]
@@ -23,6 +28,9 @@ pub struct EnvOptions {
     /// Specify what module code is generated.
     pub module: Module,
 
+    /// Options of the CommonJS transform, used when `module` is [`Module::CommonJS`].
+    pub commonjs: ModulesCommonJSOptions,
+
     pub regexp: RegExpOptions,
 
     pub es2015: ES2015Options,
@@ -40,6 +48,9 @@ pub struct EnvOptions {
     pub es2021: ES2021Options,
 
     pub es2022: ES2022Options,
+
+    /// Inject core-js polyfills for built-ins.
+    pub polyfill: PolyfillOptions,
 }
 
 impl EnvOptions {
@@ -50,6 +61,7 @@ impl EnvOptions {
     pub fn enable_all(include_unfinished_plugins: bool) -> Self {
         Self {
             module: Module::default(),
+            commonjs: ModulesCommonJSOptions::default(),
             regexp: RegExpOptions {
                 sticky_flag: true,
                 unicode_flag: true,
@@ -67,6 +79,25 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                classes: include_unfinished_plugins,
+                block_scoping: if include_unfinished_plugins {
+                    Some(BlockScopingOptions::default())
+                } else {
+                    None
+                },
+                destructuring: include_unfinished_plugins,
+                spread: include_unfinished_plugins,
+                parameters: include_unfinished_plugins,
+                regenerator: include_unfinished_plugins,
+                template_literals: include_unfinished_plugins,
+                for_of: include_unfinished_plugins,
+                computed_properties: include_unfinished_plugins,
+                shorthand_properties: include_unfinished_plugins,
+                function_name: include_unfinished_plugins,
+                new_target: include_unfinished_plugins,
+                typeof_symbol: include_unfinished_plugins,
+                literals: include_unfinished_plugins,
+                duplicate_keys: include_unfinished_plugins,
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2017: ES2017Options { async_to_generator: true },
@@ -86,6 +117,7 @@ impl EnvOptions {
                 class_static_block: true,
                 class_properties: Some(ClassPropertiesOptions::default()),
             },
+            polyfill: PolyfillOptions::default(),
         }
     }
 
@@ -140,9 +172,23 @@ impl EnvOptions {
     }
 }
 
-impl From<BabelEnvOptions> for EnvOptions {
-    fn from(o: BabelEnvOptions) -> Self {
-        Self::from(o.targets)
+impl TryFrom<BabelEnvOptions> for EnvOptions {
+    type Error = String;
+
+    fn try_from(o: BabelEnvOptions) -> Result<Self, Self::Error> {
+        let usage = match o.use_built_ins {
+            BabelUseBuiltIns::Usage => true,
+            BabelUseBuiltIns::Boolean(false) => false,
+            BabelUseBuiltIns::Entry | BabelUseBuiltIns::Boolean(true) => {
+                return Err(format!("{:?} useBuiltIns is not implemented.", o.use_built_ins));
+            }
+        };
+        if usage {
+            o.check_corejs_version()?;
+        }
+        let mut env = Self::from(o.targets);
+        env.polyfill.usage = usage;
+        Ok(env)
     }
 }
 
@@ -152,6 +198,7 @@ impl From<EngineTargets> for EnvOptions {
         use ESFeature::*;
         Self {
             module: Module::default(),
+            commonjs: ModulesCommonJSOptions::default(),
             regexp: RegExpOptions {
                 sticky_flag: o.has_feature(ES2015StickyRegex),
                 unicode_flag: o.has_feature(ES2015UnicodeRegex),
@@ -164,6 +211,21 @@ impl From<EngineTargets> for EnvOptions {
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                classes: o.has_feature(ES2015Classes),
+                block_scoping: o.has_feature(ES2015BlockScoping).then(Default::default),
+                destructuring: o.has_feature(ES2015Destructuring),
+                spread: o.has_feature(ES2015Spread),
+                parameters: o.has_feature(ES2015Parameters),
+                regenerator: o.has_feature(ES2015Regenerator),
+                template_literals: o.has_feature(ES2015TemplateLiterals),
+                for_of: o.has_feature(ES2015ForOf),
+                computed_properties: o.has_feature(ES2015ComputedProperties),
+                shorthand_properties: o.has_feature(ES2015ShorthandProperties),
+                function_name: o.has_feature(ES2015FunctionName),
+                new_target: o.has_feature(ES2015NewTarget),
+                typeof_symbol: o.has_feature(ES2015TypeofSymbol),
+                literals: o.has_feature(ES2015Literals),
+                duplicate_keys: o.has_feature(ES2015DuplicateKeys),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
@@ -188,6 +250,7 @@ impl From<EngineTargets> for EnvOptions {
                 class_static_block: o.has_feature(ES2022ClassStaticBlock),
                 class_properties: o.has_feature(ES2022ClassProperties).then(Default::default),
             },
+            polyfill: PolyfillOptions::from(&o),
         }
     }
 }
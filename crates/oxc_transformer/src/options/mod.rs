@@ -82,7 +82,11 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
-            decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+            decorator: DecoratorOptions {
+                legacy: true,
+                standard: false,
+                emit_decorator_metadata: true,
+            },
             jsx: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
@@ -157,6 +161,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let decorator = DecoratorOptions {
             legacy: options.plugins.legacy_decorator.is_some(),
+            standard: options
+                .plugins
+                .proposal_decorators
+                .as_ref()
+                .is_some_and(|o| o.version == "2023-11"),
             emit_decorator_metadata: options
                 .plugins
                 .legacy_decorator
@@ -187,6 +196,8 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             options.presets.env.as_ref().map(|env| env.module).unwrap_or_default()
         });
 
+        let commonjs = options.plugins.modules_commonjs.unwrap_or(env.commonjs);
+
         let regexp = RegExpOptions {
             sticky_flag: env.regexp.sticky_flag || options.plugins.sticky_flag,
             unicode_flag: env.regexp.unicode_flag || options.plugins.unicode_flag,
@@ -203,6 +214,23 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            classes: options.plugins.classes || env.es2015.classes,
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            destructuring: options.plugins.destructuring || env.es2015.destructuring,
+            spread: options.plugins.spread || env.es2015.spread,
+            parameters: options.plugins.parameters || env.es2015.parameters,
+            regenerator: options.plugins.regenerator || env.es2015.regenerator,
+            template_literals: options.plugins.template_literals || env.es2015.template_literals,
+            for_of: options.plugins.for_of || env.es2015.for_of,
+            computed_properties: options.plugins.computed_properties
+                || env.es2015.computed_properties,
+            shorthand_properties: options.plugins.shorthand_properties
+                || env.es2015.shorthand_properties,
+            function_name: options.plugins.function_name || env.es2015.function_name,
+            new_target: options.plugins.new_target || env.es2015.new_target,
+            typeof_symbol: options.plugins.typeof_symbol || env.es2015.typeof_symbol,
+            literals: options.plugins.literals || env.es2015.literals,
+            duplicate_keys: options.plugins.duplicate_keys || env.es2015.duplicate_keys,
         };
 
         let es2016 = ES2016Options {
@@ -266,6 +294,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             jsx,
             env: EnvOptions {
                 module,
+                commonjs,
                 regexp,
                 es2015,
                 es2016,
@@ -275,6 +304,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2020,
                 es2021,
                 es2022,
+                polyfill: env.polyfill,
             },
             proposals: ProposalOptions {
                 explicit_resource_management: options.plugins.explicit_resource_management,
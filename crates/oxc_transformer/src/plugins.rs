@@ -0,0 +1,160 @@
+//! Custom [`Traverse`] passes registered with [`Transformer::with_plugin`].
+//!
+//! Passes run in the same traversal as the built-in transforms:
+//!
+//! * [`TransformerImpl`] runs the passes positioned before a built-in stage right before it calls
+//!   that stage.
+//! * [`WithPlugins`] runs the rest of the passes after [`TransformerImpl`], for every method of
+//!   [`Traverse`].
+//!
+//! [`Transformer::with_plugin`]: crate::Transformer::with_plugin
+
+use oxc_traverse::Traverse;
+
+use crate::{TransformCtx, TransformState, TransformerImpl, TraverseCtx};
+
+/// A built-in transform stage. For each node, stages run in the order they are declared in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformStage {
+    /// TypeScript and decorators.
+    TypeScript,
+    Jsx,
+    ES2022,
+    ES2021,
+    ES2020,
+    ES2019,
+    ES2018,
+    ES2017,
+    ES2016,
+    ES2015,
+    RegExp,
+}
+
+/// Where a custom [`Traverse`] pass registered with [`Transformer::with_plugin`] runs,
+/// relative to the built-in transform stages.
+///
+/// For each node, the enter / exit methods of passes with the same position are called in the order
+/// the passes were registered. Passes positioned after a stage run before passes positioned before
+/// the next stage.
+///
+/// [`Transformer::with_plugin`]: crate::Transformer::with_plugin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginPosition {
+    /// Run before the stage.
+    ///
+    /// `Before(TransformStage::TypeScript)` runs before all built-in transforms, on the original
+    /// AST, which may contain TypeScript and JSX. Same as Babel plugins, which run before presets.
+    Before(TransformStage),
+    /// Run after the stage.
+    After(TransformStage),
+}
+
+impl PluginPosition {
+    /// Order of the position relative to other positions.
+    fn slot(self) -> u8 {
+        match self {
+            Self::Before(stage) => stage as u8 * 2,
+            Self::After(stage) => stage as u8 * 2 + 1,
+        }
+    }
+}
+
+/// Creates a custom [`Traverse`] pass, which can use the [`TransformCtx`] shared with the built-in
+/// transforms.
+pub type PluginFactory<'a> =
+    Box<dyn for<'ctx> FnOnce(&'ctx TransformCtx<'a>) -> Pass<'a, 'ctx> + 'a>;
+
+type Pass<'a, 'ctx> = Box<DynPass<'a, 'ctx>>;
+
+type DynPass<'a, 'ctx> = dyn Traverse<'a, TransformState<'a>> + 'ctx;
+
+/// Custom passes, sorted by position.
+pub struct Plugins<'a, 'ctx> {
+    passes: Vec<(u8, Pass<'a, 'ctx>)>,
+    /// Number of passes which have already run for the current node.
+    ran: usize,
+}
+
+impl<'a, 'ctx> Plugins<'a, 'ctx> {
+    pub fn new(
+        factories: Vec<(PluginPosition, PluginFactory<'a>)>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let mut passes = factories
+            .into_iter()
+            .map(|(position, factory)| (position.slot(), factory(ctx)))
+            .collect::<Vec<_>>();
+        // Stable sort, to keep passes with the same position in the order they were registered
+        passes.sort_by_key(|(slot, _)| *slot);
+        Self { passes, ran: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Run the passes positioned before `stage` which have not run yet for the current node.
+    #[inline]
+    pub fn run_before<N>(
+        &mut self,
+        stage: TransformStage,
+        node: &mut N,
+        ctx: &mut TraverseCtx<'a>,
+        hook: impl Fn(&mut DynPass<'a, 'ctx>, &mut N, &mut TraverseCtx<'a>),
+    ) {
+        self.run_until(PluginPosition::Before(stage).slot(), node, ctx, hook);
+    }
+
+    /// Run the passes which have not run yet for the current node.
+    #[inline]
+    pub fn run_rest<N>(
+        &mut self,
+        node: &mut N,
+        ctx: &mut TraverseCtx<'a>,
+        hook: impl Fn(&mut DynPass<'a, 'ctx>, &mut N, &mut TraverseCtx<'a>),
+    ) {
+        self.run_until(u8::MAX, node, ctx, hook);
+    }
+
+    fn run_until<N>(
+        &mut self,
+        slot: u8,
+        node: &mut N,
+        ctx: &mut TraverseCtx<'a>,
+        hook: impl Fn(&mut DynPass<'a, 'ctx>, &mut N, &mut TraverseCtx<'a>),
+    ) {
+        while let Some((pass_slot, pass)) = self.passes.get_mut(self.ran) {
+            if *pass_slot > slot {
+                break;
+            }
+            hook(&mut **pass, node, ctx);
+            self.ran += 1;
+        }
+    }
+}
+
+/// Runs [`TransformerImpl`] followed by the custom passes which it did not run, for each node.
+pub struct WithPlugins<'a, 'ctx>(pub TransformerImpl<'a, 'ctx>);
+
+macro_rules! impl_traverse_for_with_plugins {
+    ($($enter:ident, $exit:ident: $ty:ty;)*) => {
+        impl<'a> Traverse<'a, TransformState<'a>> for WithPlugins<'a, '_> {
+            $(
+                fn $enter(&mut self, node: &mut $ty, ctx: &mut TraverseCtx<'a>) {
+                    self.0.plugins.ran = 0;
+                    self.0.$enter(node, ctx);
+                    self.0.plugins.run_rest(node, ctx, |pass, node, ctx| pass.$enter(node, ctx));
+                }
+
+                fn $exit(&mut self, node: &mut $ty, ctx: &mut TraverseCtx<'a>) {
+                    self.0.plugins.ran = 0;
+                    self.0.$exit(node, ctx);
+                    self.0.plugins.run_rest(node, ctx, |pass, node, ctx| pass.$exit(node, ctx));
+                }
+            )*
+        }
+    };
+}
+
+// Generated list of every method of `Traverse`, so passes are called for every type of node
+oxc_traverse::for_each_traverse_method!(impl_traverse_for_with_plugins);
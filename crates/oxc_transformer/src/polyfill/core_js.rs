@@ -0,0 +1,186 @@
+//! core-js modules, and the built-ins which require them.
+//!
+//! Definitions are based on `babel-plugin-polyfill-corejs3`. Only a subset of the stable features is included.
+//! Minimum engine versions of the modules are generated from `core-js-compat` in [`core_js_compat`].
+//!
+//! * <https://github.com/babel/babel-polyfills/blob/main/packages/babel-plugin-polyfill-corejs3/src/built-in-definitions.ts>
+
+use crate::options::EngineTargets;
+
+use super::core_js_compat;
+
+const ARRAY_NATURE_ITERATORS: &[&str] = &["es.array.iterator", "web.dom-collections.iterator"];
+
+const PROMISE: &[&str] = &["es.promise", "es.object.to-string"];
+
+const SYMBOL: &[&str] = &["es.symbol", "es.symbol.description", "es.object.to-string"];
+
+/// Modules required by a global, e.g. `Promise`.
+pub fn global(name: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match name {
+        "AggregateError" => &[
+            "es.aggregate-error",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "Map" => &[
+            "es.map",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "Promise" => PROMISE,
+        "Set" => &[
+            "es.set",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "Symbol" => SYMBOL,
+        "WeakMap" => &[
+            "es.weak-map",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "WeakSet" => &[
+            "es.weak-set",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "globalThis" => &["es.global-this"],
+        "queueMicrotask" => &["web.queue-microtask"],
+        "structuredClone" => &["web.structured-clone"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+/// Modules required by a static property of a global, e.g. `Promise.allSettled`.
+pub fn static_property(object: &str, property: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match (object, property) {
+        ("Array", "from") => &["es.array.from", "es.string.iterator"],
+        ("Array", "of") => &["es.array.of"],
+        ("Map", "groupBy") => &[
+            "es.map.group-by",
+            "es.map",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        ("Math", "sign") => &["es.math.sign"],
+        ("Math", "trunc") => &["es.math.trunc"],
+        ("Number", "isInteger") => &["es.number.is-integer"],
+        ("Number", "isNaN") => &["es.number.is-nan"],
+        ("Number", "isSafeInteger") => &["es.number.is-safe-integer"],
+        ("Object", "assign") => &["es.object.assign"],
+        ("Object", "entries") => &["es.object.entries"],
+        ("Object", "fromEntries") => &["es.object.from-entries", "es.array.iterator"],
+        ("Object", "getOwnPropertyDescriptors") => &["es.object.get-own-property-descriptors"],
+        ("Object", "groupBy") => &["es.object.group-by"],
+        ("Object", "hasOwn") => &["es.object.has-own"],
+        ("Object", "values") => &["es.object.values"],
+        ("Promise", "allSettled") => &[
+            "es.promise.all-settled",
+            "es.promise",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        ("Promise", "any") => &[
+            "es.promise.any",
+            "es.aggregate-error",
+            "es.promise",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        ("Promise", "try") => &["es.promise.try", "es.promise", "es.object.to-string"],
+        ("Promise", "withResolvers") => {
+            &["es.promise.with-resolvers", "es.promise", "es.object.to-string"]
+        }
+        ("Symbol", "asyncIterator") => &["es.symbol.async-iterator"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+/// Modules required by a property of any object, e.g. `arr.flat`.
+///
+/// The type of the object is unknown, so this includes modules for all built-ins with the property.
+pub fn instance_property(property: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match property {
+        "at" => &["es.array.at", "es.string.at-alternative"],
+        "description" => &["es.symbol", "es.symbol.description"],
+        "endsWith" => &["es.string.ends-with"],
+        "entries" | "keys" | "values" => ARRAY_NATURE_ITERATORS,
+        "fill" => &["es.array.fill"],
+        "finally" => &["es.promise.finally", "es.promise", "es.object.to-string"],
+        "find" => &["es.array.find"],
+        "findIndex" => &["es.array.find-index"],
+        "findLast" => &["es.array.find-last"],
+        "findLastIndex" => &["es.array.find-last-index"],
+        "flat" => &["es.array.flat", "es.array.unscopables.flat"],
+        "flatMap" => &["es.array.flat-map", "es.array.unscopables.flat-map"],
+        "includes" => &["es.array.includes", "es.string.includes"],
+        "isWellFormed" => &["es.string.is-well-formed"],
+        "matchAll" => &["es.string.match-all"],
+        "padEnd" => &["es.string.pad-end"],
+        "padStart" => &["es.string.pad-start"],
+        "repeat" => &["es.string.repeat"],
+        "replaceAll" => &["es.string.replace-all"],
+        "startsWith" => &["es.string.starts-with"],
+        "toReversed" => &["es.array.to-reversed"],
+        "toSorted" => &["es.array.to-sorted"],
+        "toSpliced" => &["es.array.to-spliced"],
+        "toWellFormed" => &["es.string.to-well-formed"],
+        "trimEnd" => &["es.string.trim-end"],
+        "trimStart" => &["es.string.trim-start"],
+        "with" => &["es.array.with"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+/// A set of core-js modules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleSet(u128);
+
+impl ModuleSet {
+    /// Modules which are not supported natively by any of `targets`.
+    pub fn unsupported_by(targets: &EngineTargets) -> Self {
+        let modules = core_js_compat::modules();
+        debug_assert!(modules.len() <= 128);
+        let mut set = Self::default();
+        for (index, (_, versions)) in modules.iter().enumerate() {
+            if targets.is_any_older_than(versions) {
+                set.0 |= 1 << index;
+            }
+        }
+        set
+    }
+
+    /// # Panics
+    ///
+    /// * `name` is not in [`core_js_compat::modules`].
+    pub fn contains(self, name: &str) -> bool {
+        let index = core_js_compat::modules()
+            .binary_search_by(|(module, _)| (*module).cmp(name))
+            .unwrap_or_else(|_| panic!("Unknown core-js module {name}"));
+        self.0 & (1 << index) != 0
+    }
+}
+
+/// Import source of the core-js module `name`.
+pub fn module_source(name: &str) -> String {
+    format!("core-js/modules/{name}.js")
+}
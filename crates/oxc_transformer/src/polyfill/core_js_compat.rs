@@ -0,0 +1,779 @@
+// Auto generated by `tasks/compat_data/src/lib.rs`.
+#![allow(clippy::enum_glob_use)]
+use crate::options::{Engine, EngineTargets};
+use browserslist::Version;
+use rustc_hash::FxHashMap;
+use std::sync::OnceLock;
+/// core-js modules sorted by name, and the minimum engine versions which support them natively.
+pub fn modules() -> &'static [(&'static str, EngineTargets)] {
+    use Engine::*;
+    static MODULES: OnceLock<Vec<(&'static str, EngineTargets)>> = OnceLock::new();
+    MODULES.get_or_init(|| {
+        vec![
+            (
+                "es.aggregate-error",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(85u32, 0u32, 0u32)),
+                    (Node, Version(15u32, 0u32, 0u32)),
+                    (Firefox, Version(79u32, 0u32, 0u32)),
+                    (Safari, Version(14u32, 0u32, 0u32)),
+                    (Ios, Version(14u32, 0u32, 0u32)),
+                    (Edge, Version(85u32, 0u32, 0u32)),
+                    (Es, Version(2021u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.at",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(92u32, 0u32, 0u32)),
+                    (Node, Version(16u32, 6u32, 0u32)),
+                    (Firefox, Version(90u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(92u32, 0u32, 0u32)),
+                    (Es, Version(2022u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.fill",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(45u32, 0u32, 0u32)),
+                    (Node, Version(4u32, 0u32, 0u32)),
+                    (Firefox, Version(31u32, 0u32, 0u32)),
+                    (Safari, Version(7u32, 1u32, 0u32)),
+                    (Ios, Version(7u32, 1u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.find",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(45u32, 0u32, 0u32)),
+                    (Node, Version(4u32, 0u32, 0u32)),
+                    (Firefox, Version(25u32, 0u32, 0u32)),
+                    (Safari, Version(7u32, 1u32, 0u32)),
+                    (Ios, Version(7u32, 1u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.find-index",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(45u32, 0u32, 0u32)),
+                    (Node, Version(4u32, 0u32, 0u32)),
+                    (Firefox, Version(25u32, 0u32, 0u32)),
+                    (Safari, Version(7u32, 1u32, 0u32)),
+                    (Ios, Version(7u32, 1u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.find-last",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(97u32, 0u32, 0u32)),
+                    (Node, Version(18u32, 0u32, 0u32)),
+                    (Firefox, Version(104u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(97u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.find-last-index",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(97u32, 0u32, 0u32)),
+                    (Node, Version(18u32, 0u32, 0u32)),
+                    (Firefox, Version(104u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(97u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.flat",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(69u32, 0u32, 0u32)),
+                    (Node, Version(11u32, 0u32, 0u32)),
+                    (Firefox, Version(62u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.flat-map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(69u32, 0u32, 0u32)),
+                    (Node, Version(11u32, 0u32, 0u32)),
+                    (Firefox, Version(62u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.from",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.includes",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(53u32, 0u32, 0u32)),
+                    (Node, Version(7u32, 0u32, 0u32)),
+                    (Firefox, Version(102u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(14u32, 0u32, 0u32)),
+                    (Es, Version(2016u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.iterator",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(66u32, 0u32, 0u32)),
+                    (Node, Version(10u32, 0u32, 0u32)),
+                    (Firefox, Version(60u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.of",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(45u32, 0u32, 0u32)),
+                    (Node, Version(4u32, 0u32, 0u32)),
+                    (Firefox, Version(25u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.to-reversed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(110u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(115u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 0u32, 0u32)),
+                    (Edge, Version(110u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.to-sorted",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(110u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(115u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 0u32, 0u32)),
+                    (Edge, Version(110u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.to-spliced",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(110u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(115u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 0u32, 0u32)),
+                    (Edge, Version(110u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.unscopables.flat",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(73u32, 0u32, 0u32)),
+                    (Node, Version(12u32, 0u32, 0u32)),
+                    (Firefox, Version(67u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 0u32, 0u32)),
+                    (Ios, Version(13u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.unscopables.flat-map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(73u32, 0u32, 0u32)),
+                    (Node, Version(12u32, 0u32, 0u32)),
+                    (Firefox, Version(67u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 0u32, 0u32)),
+                    (Ios, Version(13u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.array.with",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(110u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(115u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 0u32, 0u32)),
+                    (Ios, Version(16u32, 0u32, 0u32)),
+                    (Edge, Version(110u32, 0u32, 0u32)),
+                    (Es, Version(2023u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.global-this",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(71u32, 0u32, 0u32)),
+                    (Node, Version(12u32, 0u32, 0u32)),
+                    (Firefox, Version(65u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 1u32, 0u32)),
+                    (Ios, Version(12u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2020u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.map.group-by",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(117u32, 0u32, 0u32)),
+                    (Node, Version(21u32, 0u32, 0u32)),
+                    (Firefox, Version(119u32, 0u32, 0u32)),
+                    (Safari, Version(17u32, 4u32, 0u32)),
+                    (Ios, Version(17u32, 4u32, 0u32)),
+                    (Edge, Version(117u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.math.sign",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(38u32, 0u32, 0u32)),
+                    (Node, Version(0u32, 12u32, 0u32)),
+                    (Firefox, Version(25u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.math.trunc",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(38u32, 0u32, 0u32)),
+                    (Node, Version(0u32, 12u32, 0u32)),
+                    (Firefox, Version(25u32, 0u32, 0u32)),
+                    (Safari, Version(7u32, 1u32, 0u32)),
+                    (Ios, Version(7u32, 1u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.number.is-integer",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(34u32, 0u32, 0u32)),
+                    (Node, Version(0u32, 12u32, 0u32)),
+                    (Firefox, Version(16u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.number.is-nan",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(19u32, 0u32, 0u32)),
+                    (Node, Version(0u32, 8u32, 0u32)),
+                    (Firefox, Version(15u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.number.is-safe-integer",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(34u32, 0u32, 0u32)),
+                    (Node, Version(0u32, 12u32, 0u32)),
+                    (Firefox, Version(32u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(12u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.assign",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(49u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 0u32, 0u32)),
+                    (Firefox, Version(36u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.entries",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(54u32, 0u32, 0u32)),
+                    (Node, Version(7u32, 0u32, 0u32)),
+                    (Firefox, Version(47u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 1u32, 0u32)),
+                    (Ios, Version(10u32, 1u32, 0u32)),
+                    (Edge, Version(14u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.from-entries",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(73u32, 0u32, 0u32)),
+                    (Node, Version(12u32, 0u32, 0u32)),
+                    (Firefox, Version(63u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 1u32, 0u32)),
+                    (Ios, Version(12u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.get-own-property-descriptors",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(54u32, 0u32, 0u32)),
+                    (Node, Version(7u32, 0u32, 0u32)),
+                    (Firefox, Version(50u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.group-by",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(117u32, 0u32, 0u32)),
+                    (Node, Version(21u32, 0u32, 0u32)),
+                    (Firefox, Version(119u32, 0u32, 0u32)),
+                    (Safari, Version(17u32, 4u32, 0u32)),
+                    (Ios, Version(17u32, 4u32, 0u32)),
+                    (Edge, Version(117u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.has-own",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(93u32, 0u32, 0u32)),
+                    (Node, Version(16u32, 9u32, 0u32)),
+                    (Firefox, Version(92u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(93u32, 0u32, 0u32)),
+                    (Es, Version(2022u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.to-string",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(49u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 0u32, 0u32)),
+                    (Firefox, Version(51u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.object.values",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(54u32, 0u32, 0u32)),
+                    (Node, Version(7u32, 0u32, 0u32)),
+                    (Firefox, Version(47u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 1u32, 0u32)),
+                    (Ios, Version(10u32, 1u32, 0u32)),
+                    (Edge, Version(14u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(67u32, 0u32, 0u32)),
+                    (Node, Version(10u32, 4u32, 0u32)),
+                    (Firefox, Version(69u32, 0u32, 0u32)),
+                    (Safari, Version(11u32, 0u32, 0u32)),
+                    (Ios, Version(11u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.all-settled",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(76u32, 0u32, 0u32)),
+                    (Node, Version(12u32, 9u32, 0u32)),
+                    (Firefox, Version(71u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 0u32, 0u32)),
+                    (Ios, Version(13u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2020u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.any",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(85u32, 0u32, 0u32)),
+                    (Node, Version(15u32, 0u32, 0u32)),
+                    (Firefox, Version(79u32, 0u32, 0u32)),
+                    (Safari, Version(14u32, 0u32, 0u32)),
+                    (Ios, Version(14u32, 0u32, 0u32)),
+                    (Edge, Version(85u32, 0u32, 0u32)),
+                    (Es, Version(2021u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.finally",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(67u32, 0u32, 0u32)),
+                    (Node, Version(10u32, 4u32, 0u32)),
+                    (Firefox, Version(69u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 1u32, 0u32)),
+                    (Ios, Version(13u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2018u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.try",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(128u32, 0u32, 0u32)),
+                    (Node, Version(23u32, 0u32, 0u32)),
+                    (Firefox, Version(134u32, 0u32, 0u32)),
+                    (Safari, Version(18u32, 2u32, 0u32)),
+                    (Ios, Version(18u32, 2u32, 0u32)),
+                    (Edge, Version(128u32, 0u32, 0u32)),
+                    (Es, Version(2025u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.promise.with-resolvers",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(119u32, 0u32, 0u32)),
+                    (Node, Version(22u32, 0u32, 0u32)),
+                    (Firefox, Version(121u32, 0u32, 0u32)),
+                    (Safari, Version(17u32, 4u32, 0u32)),
+                    (Ios, Version(17u32, 4u32, 0u32)),
+                    (Edge, Version(119u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.set",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.at-alternative",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(92u32, 0u32, 0u32)),
+                    (Node, Version(16u32, 6u32, 0u32)),
+                    (Firefox, Version(90u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(92u32, 0u32, 0u32)),
+                    (Es, Version(2022u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.ends-with",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 0u32, 0u32)),
+                    (Firefox, Version(40u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.includes",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 0u32, 0u32)),
+                    (Firefox, Version(40u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.is-well-formed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(111u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(119u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 4u32, 0u32)),
+                    (Ios, Version(16u32, 4u32, 0u32)),
+                    (Edge, Version(111u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.iterator",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(39u32, 0u32, 0u32)),
+                    (Node, Version(0u32, 12u32, 0u32)),
+                    (Firefox, Version(36u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(13u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.match-all",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(80u32, 0u32, 0u32)),
+                    (Node, Version(14u32, 0u32, 0u32)),
+                    (Firefox, Version(73u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 1u32, 0u32)),
+                    (Ios, Version(13u32, 1u32, 0u32)),
+                    (Edge, Version(80u32, 0u32, 0u32)),
+                    (Es, Version(2020u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.pad-end",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(57u32, 0u32, 0u32)),
+                    (Node, Version(8u32, 0u32, 0u32)),
+                    (Firefox, Version(48u32, 0u32, 0u32)),
+                    (Safari, Version(11u32, 0u32, 0u32)),
+                    (Ios, Version(11u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.pad-start",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(57u32, 0u32, 0u32)),
+                    (Node, Version(8u32, 0u32, 0u32)),
+                    (Firefox, Version(48u32, 0u32, 0u32)),
+                    (Safari, Version(11u32, 0u32, 0u32)),
+                    (Ios, Version(11u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2017u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.repeat",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(41u32, 0u32, 0u32)),
+                    (Node, Version(4u32, 0u32, 0u32)),
+                    (Firefox, Version(24u32, 0u32, 0u32)),
+                    (Safari, Version(9u32, 0u32, 0u32)),
+                    (Ios, Version(9u32, 0u32, 0u32)),
+                    (Edge, Version(13u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.replace-all",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(85u32, 0u32, 0u32)),
+                    (Node, Version(15u32, 0u32, 0u32)),
+                    (Firefox, Version(77u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 1u32, 0u32)),
+                    (Ios, Version(13u32, 1u32, 0u32)),
+                    (Edge, Version(85u32, 0u32, 0u32)),
+                    (Es, Version(2021u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.starts-with",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 0u32, 0u32)),
+                    (Firefox, Version(40u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.to-well-formed",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(111u32, 0u32, 0u32)),
+                    (Node, Version(20u32, 0u32, 0u32)),
+                    (Firefox, Version(119u32, 0u32, 0u32)),
+                    (Safari, Version(16u32, 4u32, 0u32)),
+                    (Ios, Version(16u32, 4u32, 0u32)),
+                    (Edge, Version(111u32, 0u32, 0u32)),
+                    (Es, Version(2024u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.trim-end",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(66u32, 0u32, 0u32)),
+                    (Node, Version(10u32, 0u32, 0u32)),
+                    (Firefox, Version(61u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.string.trim-start",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(66u32, 0u32, 0u32)),
+                    (Node, Version(10u32, 0u32, 0u32)),
+                    (Firefox, Version(61u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 0u32, 0u32)),
+                    (Ios, Version(12u32, 0u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.symbol",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(49u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 0u32, 0u32)),
+                    (Firefox, Version(51u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.symbol.async-iterator",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(63u32, 0u32, 0u32)),
+                    (Node, Version(10u32, 0u32, 0u32)),
+                    (Firefox, Version(55u32, 0u32, 0u32)),
+                    (Safari, Version(11u32, 1u32, 0u32)),
+                    (Ios, Version(11u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2018u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.symbol.description",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(70u32, 0u32, 0u32)),
+                    (Node, Version(11u32, 0u32, 0u32)),
+                    (Firefox, Version(63u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 1u32, 0u32)),
+                    (Ios, Version(12u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                    (Es, Version(2019u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.weak-map",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "es.weak-set",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(51u32, 0u32, 0u32)),
+                    (Node, Version(6u32, 5u32, 0u32)),
+                    (Firefox, Version(53u32, 0u32, 0u32)),
+                    (Safari, Version(10u32, 0u32, 0u32)),
+                    (Ios, Version(10u32, 0u32, 0u32)),
+                    (Edge, Version(15u32, 0u32, 0u32)),
+                    (Es, Version(2015u32, 0, 0)),
+                ])),
+            ),
+            (
+                "web.dom-collections.iterator",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(66u32, 0u32, 0u32)),
+                    (Firefox, Version(60u32, 0u32, 0u32)),
+                    (Safari, Version(13u32, 1u32, 0u32)),
+                    (Ios, Version(13u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                ])),
+            ),
+            (
+                "web.queue-microtask",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(71u32, 0u32, 0u32)),
+                    (Node, Version(12u32, 0u32, 0u32)),
+                    (Firefox, Version(69u32, 0u32, 0u32)),
+                    (Safari, Version(12u32, 1u32, 0u32)),
+                    (Ios, Version(12u32, 1u32, 0u32)),
+                    (Edge, Version(79u32, 0u32, 0u32)),
+                ])),
+            ),
+            (
+                "web.structured-clone",
+                EngineTargets::new(FxHashMap::from_iter([
+                    (Chrome, Version(98u32, 0u32, 0u32)),
+                    (Node, Version(17u32, 0u32, 0u32)),
+                    (Firefox, Version(94u32, 0u32, 0u32)),
+                    (Safari, Version(15u32, 4u32, 0u32)),
+                    (Ios, Version(15u32, 4u32, 0u32)),
+                    (Edge, Version(98u32, 0u32, 0u32)),
+                ])),
+            ),
+        ]
+    })
+}
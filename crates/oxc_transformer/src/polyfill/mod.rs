@@ -0,0 +1,209 @@
+//! Inject core-js polyfills for the built-ins used in a file.
+//!
+//! Similar to Babel's `useBuiltIns: "usage"` with `corejs: 3`, which uses `babel-plugin-polyfill-corejs3`,
+//! but only for a subset of the built-ins. See the differences below.
+//!
+//! ## Example
+//!
+//! Targets: `chrome 60`
+//!
+//! Input:
+//! ```js
+//! Promise.allSettled(promises);
+//! arr.flat();
+//! ```
+//!
+//! Output:
+//! ```js
+//! import "core-js/modules/es.promise.all-settled.js";
+//! import "core-js/modules/es.promise.js";
+//! import "core-js/modules/es.array.iterator.js";
+//! import "core-js/modules/web.dom-collections.iterator.js";
+//! import "core-js/modules/es.array.flat.js";
+//! import "core-js/modules/es.array.unscopables.flat.js";
+//! Promise.allSettled(promises);
+//! arr.flat();
+//! ```
+//!
+//! ## Implementation
+//!
+//! Built-ins are detected on the original AST, before other transforms run:
+//!
+//! * Global references, e.g. `Promise`, `structuredClone`.
+//! * Static properties of global references, e.g. `Promise.allSettled`.
+//! * Properties of any other object, e.g. `arr.flat`. The type of the object is not known,
+//!   so modules for all built-ins with the property are imported, e.g. `x.includes` imports both
+//!   `es.array.includes` and `es.string.includes`.
+//!
+//! Identifiers which are bound to a local variable (e.g. `const Promise = ...`) are ignored.
+//! Modules which all targets support natively are not imported.
+//!
+//! Polyfills are imported at the very top of the program, before any other `import`s,
+//! so they are loaded before any other module is evaluated.
+//!
+//! ## Differences from Babel
+//!
+//! * Only the built-ins in [`core_js`] are polyfilled, which are a subset of the stable ES features
+//!   and a few web APIs. Proposals, `Reflect`, typed arrays, `RegExp` and `Symbol.iterator` related
+//!   modules are not included.
+//! * Syntax which requires built-ins, e.g. spread, `for...of` and destructuring, does not import
+//!   the modules it requires.
+//! * The minor version of `corejs` is ignored, all modules in [`core_js`] are assumed to be available.
+//!
+//! References:
+//! * Babel docs: <https://babeljs.io/docs/babel-preset-env#usebuiltins>
+//! * babel-plugin-polyfill-corejs3: <https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3>
+
+use indexmap::IndexSet;
+use rustc_hash::FxBuildHasher;
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{IsGlobalReference, ReferenceFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod core_js;
+mod core_js_compat;
+mod options;
+
+use core_js::ModuleSet;
+
+pub use options::PolyfillOptions;
+
+type FxIndexSet<T> = IndexSet<T, FxBuildHasher>;
+
+pub struct Polyfill<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// core-js modules which are not supported by the targets.
+    unsupported_modules: ModuleSet,
+    /// core-js modules to import, in the order they are first used.
+    modules: FxIndexSet<&'static str>,
+}
+
+impl<'a, 'ctx> Polyfill<'a, 'ctx> {
+    pub fn new(options: PolyfillOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            unsupported_modules: options.unsupported_modules,
+            modules: FxIndexSet::default(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Polyfill<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.modules.is_empty() {
+            return;
+        }
+        let is_script = self.ctx.source_type.is_script();
+        let stmts = self
+            .modules
+            .iter()
+            .map(|name| {
+                let source = ctx.ast.atom(&core_js::module_source(name));
+                if is_script {
+                    Self::create_require(source, ctx)
+                } else {
+                    Self::create_import(source, ctx)
+                }
+            })
+            .collect::<Vec<_>>();
+        program.body.splice(0..0, stmts);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if ident.is_global_reference(ctx.scoping()) {
+                    self.add_modules(core_js::global(&ident.name));
+                }
+            }
+            Expression::StaticMemberExpression(member) => {
+                self.enter_member_expression(&member.object, member.property.name, ctx);
+            }
+            Expression::ComputedMemberExpression(member) => {
+                if let Some(property) = member.static_property_name() {
+                    self.enter_member_expression(&member.object, property, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_chain_element(&mut self, element: &mut ChainElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match element {
+            ChainElement::StaticMemberExpression(member) => {
+                self.enter_member_expression(&member.object, member.property.name, ctx);
+            }
+            ChainElement::ComputedMemberExpression(member) => {
+                if let Some(property) = member.static_property_name() {
+                    self.enter_member_expression(&member.object, property, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Polyfill<'a, '_> {
+    /// `Promise.allSettled` or `arr.flat`.
+    fn enter_member_expression(
+        &mut self,
+        object: &Expression<'a>,
+        property: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if let Expression::Identifier(ident) = object {
+            if ident.is_global_reference(ctx.scoping()) {
+                // Static properties of globals are never instance methods of built-ins
+                if core_js::global(&ident.name).is_some()
+                    || matches!(ident.name.as_str(), "Array" | "Math" | "Number" | "Object")
+                {
+                    self.add_modules(core_js::static_property(&ident.name, &property));
+                    return;
+                }
+            }
+        }
+        self.add_modules(core_js::instance_property(&property));
+    }
+
+    /// `import 'source';`
+    fn create_import(source: Atom<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+        Statement::from(ctx.ast.module_declaration_import_declaration(
+            SPAN,
+            None,
+            ctx.ast.string_literal(SPAN, source, None),
+            None,
+            NONE,
+            ImportOrExportKind::Value,
+        ))
+    }
+
+    /// `require('source');`
+    fn create_require(source: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let require_symbol_id = ctx.scoping().get_root_binding("require");
+        let callee = ctx.create_ident_expr(
+            SPAN,
+            Atom::from("require"),
+            require_symbol_id,
+            ReferenceFlags::read(),
+        );
+        let arg = Argument::from(ctx.ast.expression_string_literal(SPAN, source, None));
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(arg), false);
+        ctx.ast.statement_expression(SPAN, call)
+    }
+
+    fn add_modules(&mut self, modules: Option<&'static [&'static str]>) {
+        let Some(modules) = modules else { return };
+        for &name in modules {
+            if self.unsupported_modules.contains(name) {
+                self.modules.insert(name);
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::EngineTargets;
+
+use super::core_js::ModuleSet;
+
+/// Options for injecting [core-js] polyfills.
+///
+/// [core-js]: <https://github.com/zloirock/core-js>
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PolyfillOptions {
+    /// Add `import "core-js/modules/..."` to each file for the built-ins it uses,
+    /// which are not supported by the targets.
+    ///
+    /// Similar to Babel's `useBuiltIns: "usage"` with `corejs: 3`, for a subset of the built-ins.
+    /// <https://babeljs.io/docs/babel-preset-env#usebuiltins>
+    pub usage: bool,
+
+    /// core-js modules which are not supported by the targets.
+    pub(super) unsupported_modules: ModuleSet,
+}
+
+impl From<&EngineTargets> for PolyfillOptions {
+    fn from(targets: &EngineTargets) -> Self {
+        Self { usage: false, unsupported_modules: ModuleSet::unsupported_by(targets) }
+    }
+}
@@ -0,0 +1,859 @@
+//! Lowering of RegExp pattern syntax.
+//!
+//! Rewrites a parsed [`Pattern`] so it can be used by engines which do not support some
+//! of the newer RegExp syntax, without changing the meaning of the pattern:
+//!
+//! * `.` with `s` flag -> `[\s\S]`
+//! * `\p{...}` and `\P{...}` -> Explicit code point classes
+//! * Set notation of `v` flag -> Character classes compatible with the `u` flag
+//! * `(?<name>x)` -> `(x)`, and `\k<name>` -> `\1`
+//! * Astral code points of `u` flag -> Surrogate pairs, e.g. `[😀-😂]` -> `\uD83D[\uDE00-\uDE02]`
+//!
+//! Code point sets are computed with the Unicode tables of `regex-syntax`.
+
+use std::mem;
+
+use oxc_allocator::{Allocator, Box as ArenaBox, Vec as ArenaVec};
+use oxc_regular_expression::ast::{
+    Alternative, Character, CharacterClass, CharacterClassContents, CharacterClassContentsKind,
+    CharacterClassEscape, CharacterClassEscapeKind, CharacterClassRange, CharacterKind,
+    Disjunction, IgnoreGroup, IndexedReference, LookAroundAssertionKind, Modifier, Pattern, Term,
+    UnicodePropertyEscape,
+};
+use oxc_span::{Atom, SPAN};
+use regex_syntax::hir::{Class, ClassUnicode, ClassUnicodeRange, HirKind};
+
+/// Pattern syntax which must be lowered.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PatternLoweringOptions {
+    /// Rewrite `.` to match line terminators (`s` flag is removed)
+    pub dot_all: bool,
+    /// Pattern has `s` flag
+    pub dot_all_flag: bool,
+    /// Remove names of capture groups and named backreferences
+    pub named_capture_groups: bool,
+    /// Expand `\p{...}` and `\P{...}`
+    pub unicode_property_escapes: bool,
+    /// Rewrite character classes of the `v` flag to `u` flag syntax (`v` flag is replaced with `u`)
+    pub set_notation: bool,
+    /// Match astral code points as surrogate pairs, and expand `\p{...}` (`u` flag is removed)
+    pub unicode: bool,
+    /// Lookbehind assertions are not supported, and cannot be lowered
+    pub look_behind_assertions: bool,
+    /// Pattern has `i` flag
+    pub ignore_case: bool,
+}
+
+/// Result of lowering a [`Pattern`].
+#[derive(Debug, Default)]
+pub struct LoweredPattern<'a> {
+    /// `true` if the pattern was modified
+    pub changed: bool,
+    /// Capture group indexes of named capture groups which were removed, in order of appearance.
+    /// A name has more than one index if it is used by groups in different alternatives.
+    pub groups: Vec<(Atom<'a>, Vec<u32>)>,
+}
+
+/// Lower `pattern` in place.
+///
+/// Returns `None` if the pattern contains syntax which must be lowered but cannot be.
+/// `pattern` may have been partially modified in that case, and should be discarded.
+pub fn lower_pattern<'a>(
+    pattern: &mut Pattern<'a>,
+    options: PatternLoweringOptions,
+    allocator: &'a Allocator,
+) -> Option<LoweredPattern<'a>> {
+    let mut lowering = PatternLowering { allocator, options, result: LoweredPattern::default() };
+    if options.named_capture_groups {
+        lowering.collect_groups(&pattern.body, &mut 0);
+    }
+    lowering.lower_disjunction(&mut pattern.body, options.dot_all_flag)?;
+    Some(lowering.result)
+}
+
+struct PatternLowering<'a> {
+    allocator: &'a Allocator,
+    options: PatternLoweringOptions,
+    result: LoweredPattern<'a>,
+}
+
+impl<'a> PatternLowering<'a> {
+    /// Collect indexes of named capture groups.
+    /// Groups are numbered in order of their opening parenthesis.
+    fn collect_groups(&mut self, disjunction: &Disjunction<'a>, index: &mut u32) {
+        for alternative in &disjunction.body {
+            for term in &alternative.body {
+                self.collect_groups_in_term(term, index);
+            }
+        }
+    }
+
+    fn collect_groups_in_term(&mut self, term: &Term<'a>, index: &mut u32) {
+        match term {
+            Term::CapturingGroup(group) => {
+                *index += 1;
+                if let Some(name) = group.name {
+                    match self.result.groups.iter_mut().find(|(n, _)| *n == name) {
+                        Some((_, indexes)) => indexes.push(*index),
+                        None => self.result.groups.push((name, vec![*index])),
+                    }
+                }
+                self.collect_groups(&group.body, index);
+            }
+            Term::IgnoreGroup(group) => self.collect_groups(&group.body, index),
+            Term::LookAroundAssertion(assertion) => self.collect_groups(&assertion.body, index),
+            Term::Quantifier(quantifier) => self.collect_groups_in_term(&quantifier.body, index),
+            _ => {}
+        }
+    }
+
+    /// `dot_all` is `true` if `.` matches line terminators, from `s` flag or a `(?s:...)` group.
+    fn lower_disjunction(
+        &mut self,
+        disjunction: &mut Disjunction<'a>,
+        dot_all: bool,
+    ) -> Option<()> {
+        for alternative in &mut disjunction.body {
+            self.lower_alternative(alternative, dot_all)?;
+        }
+        Some(())
+    }
+
+    fn lower_alternative(
+        &mut self,
+        alternative: &mut Alternative<'a>,
+        dot_all: bool,
+    ) -> Option<()> {
+        for index in 0..alternative.body.len() {
+            // `\k<a>0` must not become `\10`
+            let followed_by_digit = matches!(
+                alternative.body.get(index + 1),
+                Some(Term::Character(ch)) if (u32::from('0')..=u32::from('9')).contains(&ch.value)
+            );
+            self.lower_term(&mut alternative.body[index], dot_all, followed_by_digit)?;
+        }
+        if self.options.unicode
+            && alternative
+                .body
+                .iter()
+                .any(|term| matches!(term, Term::Character(ch) if ch.value > 0xFFFF))
+        {
+            let terms = mem::replace(&mut alternative.body, ArenaVec::new_in(self.allocator));
+            for term in terms {
+                match term {
+                    Term::Character(ch) if ch.value > 0xFFFF => {
+                        alternative.body.extend(self.surrogate_pair(ch.span, ch.value));
+                    }
+                    term => alternative.body.push(term),
+                }
+            }
+            self.result.changed = true;
+        }
+        Some(())
+    }
+
+    fn lower_term(
+        &mut self,
+        term: &mut Term<'a>,
+        dot_all: bool,
+        followed_by_digit: bool,
+    ) -> Option<()> {
+        match term {
+            Term::Dot(dot) if self.options.unicode => {
+                // `.` -> `(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[\0-\t\x0B\f\x0E-\u2027\u202A-\uFFFF])`
+                let mut set = ClassUnicode::new([ClassUnicodeRange::new('\0', char::MAX)]);
+                if !dot_all {
+                    set.difference(&ClassUnicode::new(
+                        ['\n', '\r', '\u{2028}', '\u{2029}']
+                            .map(|ch| ClassUnicodeRange::new(ch, ch)),
+                    ));
+                }
+                *term = self.create_set_term(dot.span, &set);
+                self.result.changed = true;
+            }
+            Term::Dot(dot) => {
+                if self.options.dot_all && dot_all {
+                    // `.` -> `[\s\S]`
+                    let span = dot.span;
+                    let body = ArenaVec::from_array_in(
+                        [
+                            self.class_escape(CharacterClassEscapeKind::S),
+                            self.class_escape(CharacterClassEscapeKind::NegativeS),
+                        ],
+                        self.allocator,
+                    );
+                    *term = Term::CharacterClass(self.boxed(CharacterClass {
+                        span,
+                        negative: false,
+                        strings: false,
+                        kind: CharacterClassContentsKind::Union,
+                        body,
+                    }));
+                    self.result.changed = true;
+                }
+            }
+            Term::UnicodePropertyEscape(escape) => {
+                if escape.strings && self.options.set_notation {
+                    return None;
+                }
+                if self.options.unicode {
+                    let mut set = property_escape_set(escape)?;
+                    self.case_fold(&mut set);
+                    *term = self.create_set_term(escape.span, &set);
+                    self.result.changed = true;
+                } else if self.options.unicode_property_escapes {
+                    // `\P{...}` -> `[^...]`
+                    let class = unicode_property_class(&escape.name, escape.value.as_ref())?;
+                    let class = self.create_class(escape.span, escape.negative, &class);
+                    *term = Term::CharacterClass(class);
+                    self.result.changed = true;
+                }
+            }
+            Term::CharacterClass(class) => {
+                if self.options.set_notation {
+                    *term = self.lower_class_set(class)?;
+                    self.result.changed = true;
+                } else if self.options.unicode {
+                    self.lower_unicode_class(term)?;
+                } else if self.options.unicode_property_escapes {
+                    self.lower_class_property_escapes(class)?;
+                }
+            }
+            Term::Character(ch) => {
+                // `/s/iu` also matches `ſ`, which `/s/i` does not
+                if self.options.unicode && self.options.ignore_case {
+                    let mut set = class_from_range(ch.value, ch.value)?;
+                    if self.case_fold(&mut set) {
+                        *term = self.create_set_term(ch.span, &set);
+                        self.result.changed = true;
+                    }
+                }
+            }
+            Term::CharacterClassEscape(escape) => {
+                // `\W`, `\S` and `\D` match astral code points, and `\w` with `i` flag matches `ſ` and `K`
+                let lower = match escape.kind {
+                    CharacterClassEscapeKind::NegativeD
+                    | CharacterClassEscapeKind::NegativeS
+                    | CharacterClassEscapeKind::NegativeW => true,
+                    CharacterClassEscapeKind::W => self.options.ignore_case,
+                    _ => false,
+                };
+                if self.options.unicode && lower {
+                    let set = class_escape_set(escape.kind, self.options.ignore_case);
+                    *term = self.create_set_term(escape.span, &set);
+                    self.result.changed = true;
+                }
+            }
+            Term::CapturingGroup(group) => {
+                if self.options.named_capture_groups && group.name.take().is_some() {
+                    self.result.changed = true;
+                }
+                self.lower_disjunction(&mut group.body, dot_all)?;
+            }
+            Term::IgnoreGroup(group) => {
+                let mut dot_all = dot_all;
+                if let Some(modifiers) = &group.modifiers {
+                    if modifiers.enabling.contains(Modifier::S) {
+                        dot_all = true;
+                    } else if modifiers.disabling.contains(Modifier::S) {
+                        dot_all = false;
+                    }
+                }
+                self.lower_disjunction(&mut group.body, dot_all)?;
+            }
+            Term::LookAroundAssertion(assertion) => {
+                if self.options.look_behind_assertions
+                    && matches!(
+                        assertion.kind,
+                        LookAroundAssertionKind::Lookbehind
+                            | LookAroundAssertionKind::NegativeLookbehind
+                    )
+                {
+                    return None;
+                }
+                self.lower_disjunction(&mut assertion.body, dot_all)?;
+            }
+            Term::Quantifier(quantifier) => {
+                self.lower_term(&mut quantifier.body, dot_all, false)?;
+                // `😀+` -> `(?:\uD83D\uDE00)+`
+                if let Term::Character(ch) = &quantifier.body {
+                    if self.options.unicode && ch.value > 0xFFFF {
+                        let span = ch.span;
+                        let pair = self.surrogate_pair(span, ch.value);
+                        let alternative = Alternative {
+                            span,
+                            body: ArenaVec::from_array_in(pair, self.allocator),
+                        };
+                        let body = Disjunction {
+                            span,
+                            body: ArenaVec::from_array_in([alternative], self.allocator),
+                        };
+                        quantifier.body = Term::IgnoreGroup(self.boxed(IgnoreGroup {
+                            span,
+                            modifiers: None,
+                            body,
+                        }));
+                        self.result.changed = true;
+                    }
+                }
+            }
+            Term::NamedReference(reference) => {
+                if self.options.named_capture_groups {
+                    *term =
+                        self.create_reference(reference.span, &reference.name, followed_by_digit)?;
+                    self.result.changed = true;
+                }
+            }
+            Term::BoundaryAssertion(_) | Term::IndexedReference(_) => {}
+        }
+        Some(())
+    }
+
+    /// `\k<name>` -> `\1`
+    ///
+    /// If there are multiple groups with the same name, only one of them can participate in a match,
+    /// and backreferences to the others match the empty string, so `\k<name>` -> `(?:\1\2)`.
+    fn create_reference(
+        &self,
+        span: oxc_span::Span,
+        name: &Atom<'a>,
+        followed_by_digit: bool,
+    ) -> Option<Term<'a>> {
+        let (_, indexes) = self.result.groups.iter().find(|(n, _)| n == name)?;
+        let mut references = indexes
+            .iter()
+            .map(|&index| Term::IndexedReference(self.boxed(IndexedReference { span, index })));
+        if indexes.len() == 1 && !followed_by_digit {
+            return references.next();
+        }
+
+        let alternative =
+            Alternative { span, body: ArenaVec::from_iter_in(references, self.allocator) };
+        let body =
+            Disjunction { span, body: ArenaVec::from_array_in([alternative], self.allocator) };
+        Some(Term::IgnoreGroup(self.boxed(IgnoreGroup { span, modifiers: None, body })))
+    }
+
+    /// Replace `\p{...}` in a class without set notation with its code points.
+    ///
+    /// `[a\p{Lu}]` -> `[aA-Z\xC0-\xD6...]`
+    fn lower_class_property_escapes(&mut self, class: &mut CharacterClass<'a>) -> Option<()> {
+        if !class
+            .body
+            .iter()
+            .any(|it| matches!(it, CharacterClassContents::UnicodePropertyEscape(_)))
+        {
+            return Some(());
+        }
+
+        let contents = mem::replace(&mut class.body, ArenaVec::new_in(self.allocator));
+        for content in contents {
+            if let CharacterClassContents::UnicodePropertyEscape(escape) = content {
+                let mut property = unicode_property_class(&escape.name, escape.value.as_ref())?;
+                if escape.negative {
+                    property.negate();
+                }
+                self.push_ranges(&mut class.body, &property);
+            } else {
+                class.body.push(content);
+            }
+        }
+        self.result.changed = true;
+        Some(())
+    }
+
+    /// Compute the code points and strings matched by a class with `v` flag syntax,
+    /// and create an equivalent term with `u` flag syntax.
+    ///
+    /// * `[\p{L}--[a-z]]` -> `[A-Z\xAA...]`
+    /// * `[\q{abc|d}e]` -> `(?:abc|[de])`
+    fn lower_class_set(&self, class: &CharacterClass<'a>) -> Option<Term<'a>> {
+        // Case folding of the `v` flag is applied to the operands of set operations and
+        // complements, before they are computed. This can't be expressed with the `u` flag.
+        if self.options.ignore_case && has_case_sensitive_operation(class, true) {
+            return None;
+        }
+
+        let (mut set, strings) = class_set(class, self.options.ignore_case)?;
+        let span = class.span;
+        if self.options.unicode {
+            self.case_fold(&mut set);
+            if class.negative {
+                set.negate();
+            }
+        }
+        let negative = class.negative && !self.options.unicode;
+        let create_term = |set: &ClassUnicode| {
+            if self.options.unicode {
+                self.create_set_term(span, set)
+            } else {
+                Term::CharacterClass(self.create_class(span, negative, set))
+            }
+        };
+        if strings.is_empty() {
+            return Some(create_term(&set));
+        }
+
+        // Longest strings first, so the longest possible string is matched
+        let mut strings = strings;
+        strings.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+        let mut alternatives = ArenaVec::with_capacity_in(strings.len() + 1, self.allocator);
+        let mut empty = false;
+        for string in strings {
+            if string.is_empty() {
+                empty = true;
+                continue;
+            }
+            let mut body = ArenaVec::with_capacity_in(string.len(), self.allocator);
+            for ch in string {
+                if self.options.unicode && u32::from(ch) > 0xFFFF {
+                    body.extend(self.surrogate_pair(span, u32::from(ch)));
+                } else {
+                    body.push(Term::Character(self.boxed(create_character(ch))));
+                }
+            }
+            alternatives.push(Alternative { span, body });
+        }
+        if !set.ranges().is_empty() {
+            let body = ArenaVec::from_array_in([create_term(&set)], self.allocator);
+            alternatives.push(Alternative { span, body });
+        }
+        if empty {
+            alternatives.push(Alternative { span, body: ArenaVec::new_in(self.allocator) });
+        }
+
+        let body = Disjunction { span, body: alternatives };
+        Some(Term::IgnoreGroup(self.boxed(IgnoreGroup { span, modifiers: None, body })))
+    }
+
+    /// Lower a class without set notation, if it can match astral code points, or more code
+    /// points without the `u` flag. Otherwise only its `\p{...}` are expanded.
+    ///
+    /// `[^a]` -> `(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[\u0000-\u0060b-\uFFFF])`
+    fn lower_unicode_class(&mut self, term: &mut Term<'a>) -> Option<()> {
+        let Term::CharacterClass(class) = term else { return Some(()) };
+        let (mut set, _) = class_set(class, self.options.ignore_case)?;
+        let folded = self.case_fold(&mut set);
+        let astral = set.ranges().last().is_some_and(|range| u32::from(range.end()) > 0xFFFF);
+        if !class.negative && !astral && !folded {
+            return self.lower_class_property_escapes(class);
+        }
+        if class.negative {
+            set.negate();
+        }
+        *term = self.create_set_term(class.span, &set);
+        self.result.changed = true;
+        Some(())
+    }
+
+    /// With `i` flag, add the code points which match `set` with the `u` flag, but not without it,
+    /// e.g. `ſ` for `s`. Returns `true` if any were added.
+    fn case_fold(&self, set: &mut ClassUnicode) -> bool {
+        if !self.options.ignore_case {
+            return false;
+        }
+        let mut folded = set.clone();
+        folded.case_fold_simple();
+        let mut extra = folded.clone();
+        extra.difference(set);
+        let missing =
+            extra.ranges().iter().flat_map(|range| range.start()..=range.end()).any(|ch| {
+                // Without `u` flag, `ch` matches if a code point of `set` has the same canonical form
+                let mut orbit = ClassUnicode::new([ClassUnicodeRange::new(ch, ch)]);
+                orbit.case_fold_simple();
+                !orbit.ranges().iter().flat_map(|range| range.start()..=range.end()).any(|other| {
+                    class_contains(set, other) && canonicalize(other) == canonicalize(ch)
+                })
+            });
+        if missing {
+            *set = folded;
+        }
+        missing
+    }
+
+    /// Create a term which matches the code points of `set` without the `u` flag, by matching
+    /// astral code points as surrogate pairs.
+    ///
+    /// `[a😀-😂]` -> `(?:\uD83D[\uDE00-\uDE02]|[a])`
+    fn create_set_term(&self, span: oxc_span::Span, set: &ClassUnicode) -> Term<'a> {
+        let mut bmp = set.clone();
+        bmp.intersect(&ClassUnicode::new([ClassUnicodeRange::new('\0', '\u{FFFF}')]));
+        let pairs = surrogate_pairs(set);
+        if pairs.is_empty() {
+            return Term::CharacterClass(self.create_class(span, false, &bmp));
+        }
+
+        // Surrogate pairs first, so they are not matched as single code units
+        let mut alternatives = ArenaVec::with_capacity_in(pairs.len() + 1, self.allocator);
+        for (lead, trails) in pairs {
+            let body = ArenaVec::from_array_in(
+                [self.code_unit_term(span, &[lead]), self.code_unit_term(span, &trails)],
+                self.allocator,
+            );
+            alternatives.push(Alternative { span, body });
+        }
+        if !bmp.ranges().is_empty() {
+            let class = Term::CharacterClass(self.create_class(span, false, &bmp));
+            alternatives
+                .push(Alternative { span, body: ArenaVec::from_array_in([class], self.allocator) });
+        }
+        let body = Disjunction { span, body: alternatives };
+        Term::IgnoreGroup(self.boxed(IgnoreGroup { span, modifiers: None, body }))
+    }
+
+    /// A code unit, or a class of the code units in `ranges`.
+    fn code_unit_term(&self, span: oxc_span::Span, ranges: &[(u32, u32)]) -> Term<'a> {
+        if let [(min, max)] = ranges {
+            if min == max {
+                return Term::Character(self.boxed(code_unit_character(*min)));
+            }
+        }
+        let mut body = ArenaVec::with_capacity_in(ranges.len(), self.allocator);
+        for &(min, max) in ranges {
+            body.push(if min == max {
+                CharacterClassContents::Character(self.boxed(code_unit_character(min)))
+            } else {
+                CharacterClassContents::CharacterClassRange(self.boxed(CharacterClassRange {
+                    span,
+                    min: code_unit_character(min),
+                    max: code_unit_character(max),
+                }))
+            });
+        }
+        Term::CharacterClass(self.boxed(CharacterClass {
+            span,
+            negative: false,
+            strings: false,
+            kind: CharacterClassContentsKind::Union,
+            body,
+        }))
+    }
+
+    /// Terms of the surrogate pair of the astral code point `value`.
+    fn surrogate_pair(&self, span: oxc_span::Span, value: u32) -> [Term<'a>; 2] {
+        let (lead, trail) = split_surrogates(value);
+        let create =
+            |unit| Term::Character(self.boxed(Character { span, ..code_unit_character(unit) }));
+        [create(lead), create(trail)]
+    }
+
+    fn create_class(
+        &self,
+        span: oxc_span::Span,
+        negative: bool,
+        set: &ClassUnicode,
+    ) -> ArenaBox<'a, CharacterClass<'a>> {
+        let mut body = ArenaVec::with_capacity_in(set.ranges().len(), self.allocator);
+        self.push_ranges(&mut body, set);
+        self.boxed(CharacterClass {
+            span,
+            negative,
+            strings: false,
+            kind: CharacterClassContentsKind::Union,
+            body,
+        })
+    }
+
+    fn push_ranges(&self, body: &mut ArenaVec<'a, CharacterClassContents<'a>>, set: &ClassUnicode) {
+        for range in set.ranges() {
+            let (start, end) = (range.start(), range.end());
+            if start == end {
+                body.push(CharacterClassContents::Character(self.boxed(create_character(start))));
+            } else {
+                body.push(CharacterClassContents::CharacterClassRange(self.boxed(
+                    CharacterClassRange {
+                        span: SPAN,
+                        min: create_character(start),
+                        max: create_character(end),
+                    },
+                )));
+            }
+        }
+    }
+
+    fn class_escape(&self, kind: CharacterClassEscapeKind) -> CharacterClassContents<'a> {
+        CharacterClassContents::CharacterClassEscape(
+            self.boxed(CharacterClassEscape { span: SPAN, kind }),
+        )
+    }
+
+    fn boxed<T>(&self, value: T) -> ArenaBox<'a, T> {
+        ArenaBox::new_in(value, self.allocator)
+    }
+}
+
+/// Printable ASCII letters and digits are printed as is, everything else is escaped,
+/// so the character is valid both inside and outside of a class.
+fn create_character(ch: char) -> Character {
+    let kind = if ch.is_ascii_alphanumeric() {
+        CharacterKind::Symbol
+    } else {
+        CharacterKind::UnicodeEscape
+    };
+    Character { span: SPAN, kind, value: u32::from(ch) }
+}
+
+/// A code unit, which may be a lone surrogate.
+fn code_unit_character(value: u32) -> Character {
+    Character { span: SPAN, kind: CharacterKind::UnicodeEscape, value }
+}
+
+/// Lead and trail surrogates of an astral code point.
+fn split_surrogates(value: u32) -> (u32, u32) {
+    let offset = value - 0x10000;
+    (0xD800 + (offset >> 10), 0xDC00 + (offset & 0x3FF))
+}
+
+/// Ranges of lead surrogates and ranges of trail surrogates, which together match the astral
+/// code points of `set`.
+fn surrogate_pairs(set: &ClassUnicode) -> SurrogatePairs {
+    // Trails of each lead, in order
+    let mut leads: SurrogatePairs = vec![];
+    let mut push = |lead: (u32, u32), trail: (u32, u32)| match leads.last_mut() {
+        Some((last_lead, trails)) if *last_lead == lead && lead.0 == lead.1 => trails.push(trail),
+        _ => leads.push((lead, vec![trail])),
+    };
+    for range in set.ranges() {
+        let start = u32::from(range.start()).max(0x10000);
+        let end = u32::from(range.end());
+        if end < start {
+            continue;
+        }
+        let (start_lead, start_trail) = split_surrogates(start);
+        let (end_lead, end_trail) = split_surrogates(end);
+        if start_lead == end_lead {
+            push((start_lead, start_lead), (start_trail, end_trail));
+            continue;
+        }
+        let mut full_start = start_lead;
+        if start_trail != 0xDC00 {
+            push((start_lead, start_lead), (start_trail, 0xDFFF));
+            full_start += 1;
+        }
+        let full_end = if end_trail == 0xDFFF { end_lead } else { end_lead - 1 };
+        if full_start <= full_end {
+            push((full_start, full_end), (0xDC00, 0xDFFF));
+        }
+        if end_trail != 0xDFFF {
+            push((end_lead, end_lead), (0xDC00, end_trail));
+        }
+    }
+
+    // Merge adjacent leads which have the same trails
+    let mut pairs: SurrogatePairs = Vec::with_capacity(leads.len());
+    for (lead, trails) in leads {
+        if let Some((last_lead, last_trails)) = pairs.last_mut() {
+            if *last_trails == trails && last_lead.1 + 1 == lead.0 {
+                last_lead.1 = lead.1;
+                continue;
+            }
+        }
+        pairs.push((lead, trails));
+    }
+    pairs
+}
+
+fn class_contains(set: &ClassUnicode, ch: char) -> bool {
+    set.ranges()
+        .binary_search_by(|range| {
+            if range.end() < ch {
+                std::cmp::Ordering::Less
+            } else if range.start() > ch {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Canonical form of a code point for case-insensitive matching without `u` flag:
+/// its uppercase, unless that is not a single code unit or maps a non-ASCII code point to ASCII.
+/// Astral code points are matched as code units, which have no case.
+fn canonicalize(ch: char) -> char {
+    if u32::from(ch) > 0xFFFF {
+        return ch;
+    }
+    let mut upper = ch.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None)
+            if u32::from(upper) <= 0xFFFF && (ch.is_ascii() || !upper.is_ascii()) =>
+        {
+            upper
+        }
+        _ => ch,
+    }
+}
+
+/// Check if a class with `v` flag syntax contains a set operation or a complement.
+fn has_case_sensitive_operation(class: &CharacterClass, top_level: bool) -> bool {
+    if class.kind != CharacterClassContentsKind::Union || (class.negative && !top_level) {
+        return true;
+    }
+    class.body.iter().any(|content| match content {
+        CharacterClassContents::CharacterClassEscape(escape) => matches!(
+            escape.kind,
+            CharacterClassEscapeKind::NegativeD
+                | CharacterClassEscapeKind::NegativeS
+                | CharacterClassEscapeKind::NegativeW
+        ),
+        CharacterClassContents::UnicodePropertyEscape(escape) => escape.negative,
+        CharacterClassContents::NestedCharacterClass(class) => {
+            has_case_sensitive_operation(class, false)
+        }
+        _ => false,
+    })
+}
+
+/// Code points and strings (with more or less than one code point) matched by a class.
+type ClassSet = (ClassUnicode, Vec<Vec<char>>);
+
+/// Ranges of lead surrogates, each with the ranges of trail surrogates which may follow them.
+type SurrogatePairs = Vec<((u32, u32), Vec<(u32, u32)>)>;
+
+fn class_set(class: &CharacterClass, ignore_case: bool) -> Option<ClassSet> {
+    let mut operands = class.body.iter().map(|content| content_set(content, ignore_case));
+    let (mut set, mut strings) = operands.next().unwrap_or_else(|| Some(empty_set()))?;
+    for operand in operands {
+        let (operand_set, operand_strings) = operand?;
+        match class.kind {
+            CharacterClassContentsKind::Union => {
+                set.union(&operand_set);
+                for string in operand_strings {
+                    if !strings.contains(&string) {
+                        strings.push(string);
+                    }
+                }
+            }
+            CharacterClassContentsKind::Intersection => {
+                set.intersect(&operand_set);
+                strings.retain(|string| operand_strings.contains(string));
+            }
+            CharacterClassContentsKind::Subtraction => {
+                set.difference(&operand_set);
+                strings.retain(|string| !operand_strings.contains(string));
+            }
+        }
+    }
+    Some((set, strings))
+}
+
+fn content_set(content: &CharacterClassContents, ignore_case: bool) -> Option<ClassSet> {
+    let set = match content {
+        CharacterClassContents::Character(ch) => class_from_range(ch.value, ch.value)?,
+        CharacterClassContents::CharacterClassRange(range) => {
+            class_from_range(range.min.value, range.max.value)?
+        }
+        CharacterClassContents::CharacterClassEscape(escape) => {
+            class_escape_set(escape.kind, ignore_case)
+        }
+        CharacterClassContents::UnicodePropertyEscape(escape) => property_escape_set(escape)?,
+        CharacterClassContents::NestedCharacterClass(class) => {
+            let (mut set, strings) = class_set(class, ignore_case)?;
+            if class.negative {
+                set.negate();
+            }
+            return Some((set, strings));
+        }
+        CharacterClassContents::ClassStringDisjunction(disjunction) => {
+            let mut set = ClassUnicode::empty();
+            let mut strings = vec![];
+            for string in &disjunction.body {
+                let chars = string
+                    .body
+                    .iter()
+                    .map(|ch| char::from_u32(ch.value))
+                    .collect::<Option<Vec<_>>>()?;
+                if let [ch] = chars.as_slice() {
+                    set.push(ClassUnicodeRange::new(*ch, *ch));
+                } else if !strings.contains(&chars) {
+                    strings.push(chars);
+                }
+            }
+            return Some((set, strings));
+        }
+    };
+    Some((set, vec![]))
+}
+
+fn empty_set() -> ClassSet {
+    (ClassUnicode::empty(), vec![])
+}
+
+/// Returns `None` for lone surrogates, which can't be represented by `char`.
+fn class_from_range(min: u32, max: u32) -> Option<ClassUnicode> {
+    let range = ClassUnicodeRange::new(char::from_u32(min)?, char::from_u32(max)?);
+    Some(ClassUnicode::new([range]))
+}
+
+fn class_escape_set(kind: CharacterClassEscapeKind, ignore_case: bool) -> ClassUnicode {
+    let ranges: &[(char, char)] = match kind {
+        CharacterClassEscapeKind::D | CharacterClassEscapeKind::NegativeD => &[('0', '9')],
+        CharacterClassEscapeKind::S | CharacterClassEscapeKind::NegativeS => &[
+            ('\t', '\r'),
+            (' ', ' '),
+            ('\u{a0}', '\u{a0}'),
+            ('\u{1680}', '\u{1680}'),
+            ('\u{2000}', '\u{200a}'),
+            ('\u{2028}', '\u{2029}'),
+            ('\u{202f}', '\u{202f}'),
+            ('\u{205f}', '\u{205f}'),
+            ('\u{3000}', '\u{3000}'),
+            ('\u{feff}', '\u{feff}'),
+        ],
+        // With `u` and `i` flags, `\w` also matches `ſ` and `K` (Kelvin sign), which fold to `s` and `k`
+        CharacterClassEscapeKind::W | CharacterClassEscapeKind::NegativeW if ignore_case => &[
+            ('0', '9'),
+            ('A', 'Z'),
+            ('_', '_'),
+            ('a', 'z'),
+            ('\u{17f}', '\u{17f}'),
+            ('\u{212a}', '\u{212a}'),
+        ],
+        CharacterClassEscapeKind::W | CharacterClassEscapeKind::NegativeW => {
+            &[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')]
+        }
+    };
+    let mut set = ClassUnicode::new(ranges.iter().map(|&(a, b)| ClassUnicodeRange::new(a, b)));
+    if matches!(
+        kind,
+        CharacterClassEscapeKind::NegativeD
+            | CharacterClassEscapeKind::NegativeS
+            | CharacterClassEscapeKind::NegativeW
+    ) {
+        set.negate();
+    }
+    set
+}
+
+fn property_escape_set(escape: &UnicodePropertyEscape) -> Option<ClassUnicode> {
+    if escape.strings {
+        return None;
+    }
+    let mut set = unicode_property_class(&escape.name, escape.value.as_ref())?;
+    if escape.negative {
+        set.negate();
+    }
+    Some(set)
+}
+
+/// Get the code points of a Unicode property, e.g. `Script=Greek` or `Alphabetic`.
+///
+/// The property has already been validated by the parser.
+/// `regex-syntax` accepts the same property names and values as ECMAScript.
+fn unicode_property_class(name: &str, value: Option<&Atom>) -> Option<ClassUnicode> {
+    let pattern = match value {
+        Some(value) => format!(r"\p{{{name}={value}}}"),
+        None => format!(r"\p{{{name}}}"),
+    };
+    let hir = regex_syntax::ParserBuilder::new().build().parse(&pattern).ok()?;
+    match hir.into_kind() {
+        HirKind::Class(Class::Unicode(class)) => Some(class),
+        HirKind::Literal(literal) => {
+            let ch = std::str::from_utf8(&literal.0).ok()?.chars().next()?;
+            Some(ClassUnicode::new([ClassUnicodeRange::new(ch, ch)]))
+        }
+        _ => None,
+    }
+}
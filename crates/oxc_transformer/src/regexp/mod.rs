@@ -1,10 +1,20 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
-//! a `new RegExp()` constructor call to avoid syntax errors.
 //!
-//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
+//! Where possible, the pattern is lowered to equivalent syntax which is supported (see [`lowering`]):
+//! * DotAll flag: `/a.b/s` -> `/a[\s\S]b/`
+//! * Unicode property escapes: `/\p{Lu}/u` -> `/[A-Z\u00C0-\u00D6...]/u`
+//! * Set notation: `/[\p{L}--[a-z]]/v` -> `/[A-Z\u00AA...]/u`
+//! * Named capture groups: `/(?<a>b)\k<a>/` -> `babelHelpers.wrapRegExp(/(b)\1/, { a: 1 })`
+//! * Unicode flag: `/[a😀-😂]/u` -> `/(?:\uD83D[\uDE00-\uDE02]|[a])/`
+//!
+//! When an unsupported feature cannot be lowered (e.g. `y` and `d` flags, lookbehind assertions,
+//! properties of strings), the RegExp literal is converted into a `new RegExp()` constructor call
+//! to avoid syntax errors.
+//!
+//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct
+//! runtime behavior of RegExps which could not be lowered.
 //!
 //! ### ES2015
 //!
@@ -44,22 +54,24 @@
 //! TODO(improve-on-babel): When flags is empty, we could output `RegExp("(?<=x)")` instead of `RegExp("(?<=x)", "")`.
 //! (actually these would be improvements on ESBuild, not Babel)
 
+use oxc_allocator::{CloneIn, TakeIn};
 use oxc_ast::{NONE, ast::*};
 use oxc_diagnostics::Result;
-use oxc_regular_expression::ast::{
-    CharacterClass, CharacterClassContents, LookAroundAssertionKind, Pattern, Term,
-};
+use oxc_regular_expression::ast::Pattern;
 use oxc_semantic::ReferenceFlags;
-use oxc_span::{Atom, SPAN};
+use oxc_span::{Atom, GetSpan, SPAN};
 use oxc_traverse::Traverse;
 
 use crate::{
+    common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
     state::TransformState,
 };
 
+mod lowering;
 mod options;
 
+use lowering::{PatternLoweringOptions, lower_pattern};
 pub use options::RegExpOptions;
 
 pub struct RegExp<'a, 'ctx> {
@@ -124,7 +136,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for RegExp<'a, '_> {
 }
 
 impl<'a> RegExp<'a, '_> {
-    /// If `RegExpLiteral` contains unsupported syntax or flags, transform to `new RegExp(...)`.
+    /// If `RegExpLiteral` contains unsupported syntax or flags, lower the pattern if possible,
+    /// otherwise transform to `new RegExp(...)`.
     fn transform_regexp(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let Expression::RegExpLiteral(regexp) = expr else {
             unreachable!();
@@ -133,17 +146,29 @@ impl<'a> RegExp<'a, '_> {
 
         let pattern_text = regexp.regex.pattern.text;
         let flags = regexp.regex.flags;
-        let has_unsupported_flags = flags.intersects(self.unsupported_flags);
-        if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
-                // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
-                // so there's nothing to do
-                return;
-            }
+        if !flags.intersects(self.unsupported_flags) && !self.some_unsupported_patterns {
+            // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
+            // so there's nothing to do
+            return;
+        }
 
-            let owned_pattern;
-            let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
-                pattern
+        // `s` flag can be lowered by rewriting `.`, `v` flag by rewriting character classes
+        // to `u` flag syntax, and `u` flag by matching astral code points as surrogate pairs
+        let mut lowered_flags = flags;
+        if self.unsupported_flags.contains(RegExpFlags::S) {
+            lowered_flags.remove(RegExpFlags::S);
+        }
+        if self.unsupported_flags.contains(RegExpFlags::V) && flags.contains(RegExpFlags::V) {
+            lowered_flags.remove(RegExpFlags::V);
+            lowered_flags.insert(RegExpFlags::U);
+        }
+        if self.unsupported_flags.contains(RegExpFlags::U) {
+            lowered_flags.remove(RegExpFlags::U);
+        }
+
+        if !lowered_flags.intersects(self.unsupported_flags) {
+            let mut pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
+                pattern.as_ref().clone_in(ctx.ast.allocator)
             } else {
                 #[expect(clippy::cast_possible_truncation)]
                 let pattern_len = pattern_text.len() as u32;
@@ -160,10 +185,7 @@ impl<'a> RegExp<'a, '_> {
                     flags_span_start,
                     ctx,
                 ) {
-                    Ok(pattern) => {
-                        owned_pattern = Some(pattern);
-                        owned_pattern.as_ref().unwrap()
-                    }
+                    Ok(pattern) => pattern,
                     Err(error) => {
                         self.ctx.error(error);
                         return;
@@ -171,7 +193,29 @@ impl<'a> RegExp<'a, '_> {
                 }
             };
 
-            if !self.has_unsupported_regular_expression_pattern(pattern) {
+            let unicode = flags.intersects(RegExpFlags::U | RegExpFlags::V)
+                && !lowered_flags.intersects(RegExpFlags::U | RegExpFlags::V);
+            let options = PatternLoweringOptions {
+                dot_all: flags.contains(RegExpFlags::S) && !lowered_flags.contains(RegExpFlags::S),
+                dot_all_flag: flags.contains(RegExpFlags::S),
+                named_capture_groups: self.named_capture_groups,
+                unicode_property_escapes: self.unicode_property_escapes || unicode,
+                set_notation: flags.contains(RegExpFlags::V)
+                    && !lowered_flags.contains(RegExpFlags::V),
+                unicode,
+                look_behind_assertions: self.look_behind_assertions,
+                ignore_case: flags.contains(RegExpFlags::I),
+            };
+            if let Some(lowered) = lower_pattern(&mut pattern, options, ctx.ast.allocator) {
+                if lowered.changed || lowered_flags != flags {
+                    regexp.regex.pattern.text = ctx.ast.atom(&pattern.to_string());
+                    regexp.regex.pattern.pattern = Some(ctx.ast.alloc(pattern));
+                    regexp.regex.flags = lowered_flags;
+                    regexp.raw = None;
+                }
+                if !lowered.groups.is_empty() {
+                    self.wrap_named_capture_groups(expr, lowered.groups, ctx);
+                }
                 return;
             }
         }
@@ -193,50 +237,49 @@ impl<'a> RegExp<'a, '_> {
         *expr = ctx.ast.expression_new(regexp.span, callee, NONE, arguments);
     }
 
-    /// Check if the regular expression contains any unsupported syntax.
+    /// Restore `.groups` of match results after names of capture groups were removed.
     ///
-    /// Based on parsed regular expression pattern.
-    fn has_unsupported_regular_expression_pattern(&self, pattern: &Pattern<'a>) -> bool {
-        pattern.body.body.iter().any(|alternative| {
-            alternative.body.iter().any(|term| self.term_contains_unsupported(term))
-        })
-    }
+    /// `/(?<year>\d{4})-(?<month>\d{2})/` -> `babelHelpers.wrapRegExp(/(\d{4})-(\d{2})/, { year: 1, month: 2 })`
+    ///
+    /// If a name is used by multiple groups, its indexes are passed as an array: `{ a: [1, 2] }`
+    fn wrap_named_capture_groups(
+        &self,
+        expr: &mut Expression<'a>,
+        groups: Vec<(Atom<'a>, Vec<u32>)>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let index_expr = |index: u32, ctx: &TraverseCtx<'a>| {
+            ctx.ast.expression_numeric_literal(SPAN, f64::from(index), None, NumberBase::Decimal)
+        };
+        let properties = ctx.ast.vec_from_iter(groups.into_iter().map(|(name, indexes)| {
+            let value = if let [index] = indexes.as_slice() {
+                index_expr(*index, ctx)
+            } else {
+                let elements = indexes
+                    .into_iter()
+                    .map(|index| ArrayExpressionElement::from(index_expr(index, ctx)));
+                ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_static_identifier(SPAN, name),
+                value,
+                false,
+                false,
+                false,
+            )
+        }));
 
-    fn term_contains_unsupported(&self, mut term: &Term) -> bool {
-        // Loop because `Term::Quantifier` contains a nested `Term`
-        loop {
-            match term {
-                Term::CapturingGroup(_) => return self.named_capture_groups,
-                Term::UnicodePropertyEscape(_) => return self.unicode_property_escapes,
-                Term::CharacterClass(character_class) => {
-                    return self.unicode_property_escapes
-                        && character_class_has_unicode_property_escape(character_class);
-                }
-                Term::LookAroundAssertion(assertion) => {
-                    return self.look_behind_assertions
-                        && matches!(
-                            assertion.kind,
-                            LookAroundAssertionKind::Lookbehind
-                                | LookAroundAssertionKind::NegativeLookbehind
-                        );
-                }
-                Term::Quantifier(quantifier) => term = &quantifier.body,
-                _ => return false,
-            }
-        }
+        let span = expr.span();
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(expr.take_in(ctx.ast)),
+            Argument::from(ctx.ast.expression_object(SPAN, properties)),
+        ]);
+        *expr = self.ctx.helper_call_expr(Helper::WrapRegExp, span, arguments, ctx);
     }
 }
 
-fn character_class_has_unicode_property_escape(character_class: &CharacterClass) -> bool {
-    character_class.body.iter().any(|element| match element {
-        CharacterClassContents::UnicodePropertyEscape(_) => true,
-        CharacterClassContents::NestedCharacterClass(character_class) => {
-            character_class_has_unicode_property_escape(character_class)
-        }
-        _ => false,
-    })
-}
-
 fn try_parse_pattern<'a>(
     raw: &'a str,
     pattern_span_offset: u32,
@@ -86,7 +86,6 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.exit_program(program, ctx);
-        self.module.exit_program(program, ctx);
         ctx.scoping.delete_typescript_bindings();
     }
 
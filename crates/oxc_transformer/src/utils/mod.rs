@@ -1 +1,55 @@
+use std::cell::Cell;
+
+use oxc_ast::ast::{Expression, Function, Super};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId};
+
 pub mod ast_builder;
+
+/// Visitor which collects IDs of scopes which are direct children of the visited node.
+#[derive(Default)]
+pub struct ChildScopeCollector {
+    pub scope_ids: Vec<ScopeId>,
+    depth: u32,
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.scope_ids.push(scope_id.get().unwrap());
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// Check if `value` is a function which references `super`, e.g. `super.foo` in `{ foo() {} }`.
+///
+/// `super` is only valid in methods, so such functions cannot be converted to function expressions.
+pub fn function_references_super(value: &Expression<'_>) -> bool {
+    let Expression::FunctionExpression(func) = value else { return false };
+    let mut finder = SuperFinder::default();
+    finder.visit_formal_parameters(&func.params);
+    if let Some(body) = &func.body {
+        finder.visit_function_body(body);
+    }
+    finder.found
+}
+
+/// Visitor which checks for `super`, but not in nested functions, which have their own `super`.
+/// Arrow functions inherit `super`, so they are visited.
+#[derive(Default)]
+struct SuperFinder {
+    found: bool,
+}
+
+impl Visit<'_> for SuperFinder {
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'_>, _flags: ScopeFlags) {}
+}
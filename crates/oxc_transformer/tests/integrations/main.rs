@@ -1,4 +1,5 @@
 mod es_target;
+mod plugins;
 mod targets;
 
 use std::path::Path;
@@ -0,0 +1,212 @@
+use std::{
+    cell::{Cell, RefCell},
+    path::Path,
+    rc::Rc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SPAN, SourceType};
+use oxc_transformer::{
+    ESTarget, PluginPosition, TransformCtx, TransformOptions, TransformStage, TransformState,
+    Transformer, TraverseCtx,
+};
+use oxc_traverse::Traverse;
+
+/// Replaces `__DEBUG__` with `false`.
+struct FeatureFlags;
+
+impl<'a> Traverse<'a, TransformState<'a>> for FeatureFlags {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::Identifier(ident) if ident.name == "__DEBUG__") {
+            *expr = ctx.ast.expression_boolean_literal(SPAN, false);
+        }
+    }
+}
+
+/// Counts arrow functions when exiting them.
+struct ArrowCounter(Rc<Cell<usize>>);
+
+impl<'a> Traverse<'a, TransformState<'a>> for ArrowCounter {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::ArrowFunctionExpression(_)) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+}
+
+/// Records TypeScript `as` expressions when entering them, and template literals when exiting them.
+struct Recorder(Rc<RefCell<Vec<&'static str>>>);
+
+impl<'a> Traverse<'a, TransformState<'a>> for Recorder {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::TSAsExpression(_)) {
+            self.0.borrow_mut().push("as");
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::TemplateLiteral(_)) {
+            self.0.borrow_mut().push("template");
+        }
+    }
+}
+
+/// Declares a `var` when exiting the program.
+struct DeclareOnExit<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for DeclareOnExit<'a, '_> {
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.ctx.var_declarations.create_uid_var("flag", ctx);
+    }
+}
+
+/// Moves string literals into `var` declarations, using the `TransformCtx` of the built-in transforms.
+struct HoistStrings<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for HoistStrings<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::StringLiteral(lit) = expr {
+            let init = ctx.ast.expression_string_literal(SPAN, lit.value, None);
+            let binding = self.ctx.var_declarations.create_uid_var_with_init("str", init, ctx);
+            *expr = binding.create_read_expression(ctx);
+        }
+    }
+}
+
+fn transform<'a>(
+    source_text: &str,
+    source_type: SourceType,
+    options: &TransformOptions,
+    with_plugins: impl FnOnce(Transformer<'a>) -> Transformer<'a>,
+    allocator: &'a Allocator,
+) -> String {
+    let source_text = allocator.alloc_str(source_text);
+    let ret = Parser::new(allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let transformer = Transformer::new(allocator, Path::new(""), options);
+    let ret = with_plugins(transformer).build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn plugin_positions() {
+    let allocator = Allocator::default();
+    let options = TransformOptions::from(ESTarget::ES5);
+    let before_count = Rc::new(Cell::new(0));
+    let after_count = Rc::new(Cell::new(0));
+
+    let code = transform(
+        "const f = () => __DEBUG__; const g = () => 1;",
+        SourceType::mjs(),
+        &options,
+        |transformer| {
+            let before_count = Rc::clone(&before_count);
+            let after_count = Rc::clone(&after_count);
+            transformer
+                .with_plugin(PluginPosition::After(TransformStage::RegExp), |_| {
+                    Box::new(ArrowCounter(after_count))
+                })
+                .with_plugin(PluginPosition::Before(TransformStage::TypeScript), |_| {
+                    Box::new(FeatureFlags)
+                })
+                .with_plugin(PluginPosition::Before(TransformStage::TypeScript), |_| {
+                    Box::new(ArrowCounter(before_count))
+                })
+        },
+        &allocator,
+    );
+
+    assert_eq!(
+        code,
+        "var f = function f() {\n\treturn false;\n};\nvar g = function g() {\n\treturn 1;\n};\n"
+    );
+    // Arrow functions are transformed by the built-in transforms when exiting them
+    assert_eq!(before_count.get(), 2);
+    assert_eq!(after_count.get(), 0);
+}
+
+#[test]
+fn plugin_shares_transform_ctx() {
+    let allocator = Allocator::default();
+    let options = TransformOptions::default();
+
+    let code = transform(
+        "function f() { return 'a'; }",
+        SourceType::mjs(),
+        &options,
+        |transformer| {
+            transformer.with_plugin(PluginPosition::Before(TransformStage::TypeScript), |ctx| {
+                Box::new(HoistStrings { ctx })
+            })
+        },
+        &allocator,
+    );
+
+    assert_eq!(code, "function f() {\n\tvar _str = 'a';\n\treturn _str;\n}\n");
+}
+
+#[test]
+fn plugin_between_stages() {
+    let allocator = Allocator::default();
+    let options = TransformOptions::from(ESTarget::ES5);
+    let before_typescript = Rc::new(RefCell::new(vec![]));
+    let after_typescript = Rc::new(RefCell::new(vec![]));
+    let before_es2015 = Rc::new(RefCell::new(vec![]));
+    let after_es2015 = Rc::new(RefCell::new(vec![]));
+
+    transform(
+        "const s = `${x as number}`;",
+        SourceType::ts(),
+        &options,
+        |transformer| {
+            let recorders = [
+                (PluginPosition::After(TransformStage::ES2015), Rc::clone(&after_es2015)),
+                (PluginPosition::Before(TransformStage::ES2015), Rc::clone(&before_es2015)),
+                (PluginPosition::After(TransformStage::TypeScript), Rc::clone(&after_typescript)),
+                (PluginPosition::Before(TransformStage::TypeScript), Rc::clone(&before_typescript)),
+            ];
+            recorders.into_iter().fold(transformer, |transformer, (position, seen)| {
+                transformer.with_plugin(position, |_| Box::new(Recorder(seen)))
+            })
+        },
+        &allocator,
+    );
+
+    assert_eq!(*before_typescript.borrow(), ["as", "template"]);
+    assert_eq!(*after_typescript.borrow(), ["template"]);
+    assert_eq!(*before_es2015.borrow(), ["template"]);
+    assert!(after_es2015.borrow().is_empty());
+}
+
+#[test]
+fn plugin_after_all_stages_declares_vars_on_exit() {
+    let allocator = Allocator::default();
+    let options = TransformOptions::default();
+
+    let code = transform(
+        "foo();",
+        SourceType::mjs(),
+        &options,
+        |transformer| {
+            transformer.with_plugin(PluginPosition::After(TransformStage::RegExp), |ctx| {
+                Box::new(DeclareOnExit { ctx })
+            })
+        },
+        &allocator,
+    );
+
+    assert_eq!(code, "var _flag;\nfoo();\n");
+}
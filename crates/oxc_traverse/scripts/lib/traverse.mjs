@@ -7,7 +7,7 @@ export default function generateTraverseTraitCode(types) {
   const typesArr = Object.values(types);
   typesArr.push({ name: 'Statements', rawName: "Vec<'a, Statement<'a>>" });
 
-  let traverseMethods = '';
+  let traverseMethods = '', macroMethods = '';
   for (const type of typesArr) {
     const snakeName = camelToSnake(type.name);
     traverseMethods += `
@@ -16,6 +16,11 @@ export default function generateTraverseTraitCode(types) {
       #[inline]
       fn exit_${snakeName}(&mut self, node: &mut ${type.rawName}, ctx: &mut TraverseCtx<'a, State>) {}
     `;
+
+    const qualifiedName = type.name === 'Statements'
+      ? "::oxc_allocator::Vec<'a, ::oxc_ast::ast::Statement<'a>>"
+      : `::oxc_ast::ast::${type.rawName}`;
+    macroMethods += `\n            enter_${snakeName}, exit_${snakeName}: ${qualifiedName};`;
   }
 
   return `
@@ -28,5 +33,18 @@ export default function generateTraverseTraitCode(types) {
     pub trait Traverse<'a, State> {
       ${traverseMethods}
     }
+
+    /// Invokes macro \`$macro\` with the enter and exit methods of [\`Traverse\`], and the type of node
+    /// they visit, as \`enter_program, exit_program: ::oxc_ast::ast::Program<'a>; ...\`.
+    ///
+    /// For implementing [\`Traverse\`] by delegating every method, which must not miss any method.
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! for_each_traverse_method {
+        ($macro:ident) => {
+            $macro! {${macroMethods}
+            }
+        };
+    }
   `;
 }
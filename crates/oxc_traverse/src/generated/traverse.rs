@@ -3087,3 +3087,244 @@ pub trait Traverse<'a, State> {
     ) {
     }
 }
+
+/// Invokes macro `$macro` with the enter and exit methods of [`Traverse`], and the type of node
+/// they visit, as `enter_program, exit_program: ::oxc_ast::ast::Program<'a>; ...`.
+///
+/// For implementing [`Traverse`] by delegating every method, which must not miss any method.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_traverse_method {
+        ($macro:ident) => {
+            $macro! {
+            enter_program, exit_program: ::oxc_ast::ast::Program<'a>;
+            enter_expression, exit_expression: ::oxc_ast::ast::Expression<'a>;
+            enter_identifier_name, exit_identifier_name: ::oxc_ast::ast::IdentifierName<'a>;
+            enter_identifier_reference, exit_identifier_reference: ::oxc_ast::ast::IdentifierReference<'a>;
+            enter_binding_identifier, exit_binding_identifier: ::oxc_ast::ast::BindingIdentifier<'a>;
+            enter_label_identifier, exit_label_identifier: ::oxc_ast::ast::LabelIdentifier<'a>;
+            enter_this_expression, exit_this_expression: ::oxc_ast::ast::ThisExpression;
+            enter_array_expression, exit_array_expression: ::oxc_ast::ast::ArrayExpression<'a>;
+            enter_array_expression_element, exit_array_expression_element: ::oxc_ast::ast::ArrayExpressionElement<'a>;
+            enter_elision, exit_elision: ::oxc_ast::ast::Elision;
+            enter_object_expression, exit_object_expression: ::oxc_ast::ast::ObjectExpression<'a>;
+            enter_object_property_kind, exit_object_property_kind: ::oxc_ast::ast::ObjectPropertyKind<'a>;
+            enter_object_property, exit_object_property: ::oxc_ast::ast::ObjectProperty<'a>;
+            enter_property_key, exit_property_key: ::oxc_ast::ast::PropertyKey<'a>;
+            enter_template_literal, exit_template_literal: ::oxc_ast::ast::TemplateLiteral<'a>;
+            enter_tagged_template_expression, exit_tagged_template_expression: ::oxc_ast::ast::TaggedTemplateExpression<'a>;
+            enter_template_element, exit_template_element: ::oxc_ast::ast::TemplateElement<'a>;
+            enter_member_expression, exit_member_expression: ::oxc_ast::ast::MemberExpression<'a>;
+            enter_computed_member_expression, exit_computed_member_expression: ::oxc_ast::ast::ComputedMemberExpression<'a>;
+            enter_static_member_expression, exit_static_member_expression: ::oxc_ast::ast::StaticMemberExpression<'a>;
+            enter_private_field_expression, exit_private_field_expression: ::oxc_ast::ast::PrivateFieldExpression<'a>;
+            enter_call_expression, exit_call_expression: ::oxc_ast::ast::CallExpression<'a>;
+            enter_new_expression, exit_new_expression: ::oxc_ast::ast::NewExpression<'a>;
+            enter_meta_property, exit_meta_property: ::oxc_ast::ast::MetaProperty<'a>;
+            enter_spread_element, exit_spread_element: ::oxc_ast::ast::SpreadElement<'a>;
+            enter_argument, exit_argument: ::oxc_ast::ast::Argument<'a>;
+            enter_update_expression, exit_update_expression: ::oxc_ast::ast::UpdateExpression<'a>;
+            enter_unary_expression, exit_unary_expression: ::oxc_ast::ast::UnaryExpression<'a>;
+            enter_binary_expression, exit_binary_expression: ::oxc_ast::ast::BinaryExpression<'a>;
+            enter_private_in_expression, exit_private_in_expression: ::oxc_ast::ast::PrivateInExpression<'a>;
+            enter_logical_expression, exit_logical_expression: ::oxc_ast::ast::LogicalExpression<'a>;
+            enter_conditional_expression, exit_conditional_expression: ::oxc_ast::ast::ConditionalExpression<'a>;
+            enter_assignment_expression, exit_assignment_expression: ::oxc_ast::ast::AssignmentExpression<'a>;
+            enter_assignment_target, exit_assignment_target: ::oxc_ast::ast::AssignmentTarget<'a>;
+            enter_simple_assignment_target, exit_simple_assignment_target: ::oxc_ast::ast::SimpleAssignmentTarget<'a>;
+            enter_assignment_target_pattern, exit_assignment_target_pattern: ::oxc_ast::ast::AssignmentTargetPattern<'a>;
+            enter_array_assignment_target, exit_array_assignment_target: ::oxc_ast::ast::ArrayAssignmentTarget<'a>;
+            enter_object_assignment_target, exit_object_assignment_target: ::oxc_ast::ast::ObjectAssignmentTarget<'a>;
+            enter_assignment_target_rest, exit_assignment_target_rest: ::oxc_ast::ast::AssignmentTargetRest<'a>;
+            enter_assignment_target_maybe_default, exit_assignment_target_maybe_default: ::oxc_ast::ast::AssignmentTargetMaybeDefault<'a>;
+            enter_assignment_target_with_default, exit_assignment_target_with_default: ::oxc_ast::ast::AssignmentTargetWithDefault<'a>;
+            enter_assignment_target_property, exit_assignment_target_property: ::oxc_ast::ast::AssignmentTargetProperty<'a>;
+            enter_assignment_target_property_identifier, exit_assignment_target_property_identifier: ::oxc_ast::ast::AssignmentTargetPropertyIdentifier<'a>;
+            enter_assignment_target_property_property, exit_assignment_target_property_property: ::oxc_ast::ast::AssignmentTargetPropertyProperty<'a>;
+            enter_sequence_expression, exit_sequence_expression: ::oxc_ast::ast::SequenceExpression<'a>;
+            enter_super, exit_super: ::oxc_ast::ast::Super;
+            enter_await_expression, exit_await_expression: ::oxc_ast::ast::AwaitExpression<'a>;
+            enter_chain_expression, exit_chain_expression: ::oxc_ast::ast::ChainExpression<'a>;
+            enter_chain_element, exit_chain_element: ::oxc_ast::ast::ChainElement<'a>;
+            enter_parenthesized_expression, exit_parenthesized_expression: ::oxc_ast::ast::ParenthesizedExpression<'a>;
+            enter_statement, exit_statement: ::oxc_ast::ast::Statement<'a>;
+            enter_directive, exit_directive: ::oxc_ast::ast::Directive<'a>;
+            enter_hashbang, exit_hashbang: ::oxc_ast::ast::Hashbang<'a>;
+            enter_block_statement, exit_block_statement: ::oxc_ast::ast::BlockStatement<'a>;
+            enter_declaration, exit_declaration: ::oxc_ast::ast::Declaration<'a>;
+            enter_variable_declaration, exit_variable_declaration: ::oxc_ast::ast::VariableDeclaration<'a>;
+            enter_variable_declarator, exit_variable_declarator: ::oxc_ast::ast::VariableDeclarator<'a>;
+            enter_empty_statement, exit_empty_statement: ::oxc_ast::ast::EmptyStatement;
+            enter_expression_statement, exit_expression_statement: ::oxc_ast::ast::ExpressionStatement<'a>;
+            enter_if_statement, exit_if_statement: ::oxc_ast::ast::IfStatement<'a>;
+            enter_do_while_statement, exit_do_while_statement: ::oxc_ast::ast::DoWhileStatement<'a>;
+            enter_while_statement, exit_while_statement: ::oxc_ast::ast::WhileStatement<'a>;
+            enter_for_statement, exit_for_statement: ::oxc_ast::ast::ForStatement<'a>;
+            enter_for_statement_init, exit_for_statement_init: ::oxc_ast::ast::ForStatementInit<'a>;
+            enter_for_in_statement, exit_for_in_statement: ::oxc_ast::ast::ForInStatement<'a>;
+            enter_for_statement_left, exit_for_statement_left: ::oxc_ast::ast::ForStatementLeft<'a>;
+            enter_for_of_statement, exit_for_of_statement: ::oxc_ast::ast::ForOfStatement<'a>;
+            enter_continue_statement, exit_continue_statement: ::oxc_ast::ast::ContinueStatement<'a>;
+            enter_break_statement, exit_break_statement: ::oxc_ast::ast::BreakStatement<'a>;
+            enter_return_statement, exit_return_statement: ::oxc_ast::ast::ReturnStatement<'a>;
+            enter_with_statement, exit_with_statement: ::oxc_ast::ast::WithStatement<'a>;
+            enter_switch_statement, exit_switch_statement: ::oxc_ast::ast::SwitchStatement<'a>;
+            enter_switch_case, exit_switch_case: ::oxc_ast::ast::SwitchCase<'a>;
+            enter_labeled_statement, exit_labeled_statement: ::oxc_ast::ast::LabeledStatement<'a>;
+            enter_throw_statement, exit_throw_statement: ::oxc_ast::ast::ThrowStatement<'a>;
+            enter_try_statement, exit_try_statement: ::oxc_ast::ast::TryStatement<'a>;
+            enter_catch_clause, exit_catch_clause: ::oxc_ast::ast::CatchClause<'a>;
+            enter_catch_parameter, exit_catch_parameter: ::oxc_ast::ast::CatchParameter<'a>;
+            enter_debugger_statement, exit_debugger_statement: ::oxc_ast::ast::DebuggerStatement;
+            enter_binding_pattern, exit_binding_pattern: ::oxc_ast::ast::BindingPattern<'a>;
+            enter_binding_pattern_kind, exit_binding_pattern_kind: ::oxc_ast::ast::BindingPatternKind<'a>;
+            enter_assignment_pattern, exit_assignment_pattern: ::oxc_ast::ast::AssignmentPattern<'a>;
+            enter_object_pattern, exit_object_pattern: ::oxc_ast::ast::ObjectPattern<'a>;
+            enter_binding_property, exit_binding_property: ::oxc_ast::ast::BindingProperty<'a>;
+            enter_array_pattern, exit_array_pattern: ::oxc_ast::ast::ArrayPattern<'a>;
+            enter_binding_rest_element, exit_binding_rest_element: ::oxc_ast::ast::BindingRestElement<'a>;
+            enter_function, exit_function: ::oxc_ast::ast::Function<'a>;
+            enter_formal_parameters, exit_formal_parameters: ::oxc_ast::ast::FormalParameters<'a>;
+            enter_formal_parameter, exit_formal_parameter: ::oxc_ast::ast::FormalParameter<'a>;
+            enter_function_body, exit_function_body: ::oxc_ast::ast::FunctionBody<'a>;
+            enter_arrow_function_expression, exit_arrow_function_expression: ::oxc_ast::ast::ArrowFunctionExpression<'a>;
+            enter_yield_expression, exit_yield_expression: ::oxc_ast::ast::YieldExpression<'a>;
+            enter_class, exit_class: ::oxc_ast::ast::Class<'a>;
+            enter_class_body, exit_class_body: ::oxc_ast::ast::ClassBody<'a>;
+            enter_class_element, exit_class_element: ::oxc_ast::ast::ClassElement<'a>;
+            enter_method_definition, exit_method_definition: ::oxc_ast::ast::MethodDefinition<'a>;
+            enter_property_definition, exit_property_definition: ::oxc_ast::ast::PropertyDefinition<'a>;
+            enter_private_identifier, exit_private_identifier: ::oxc_ast::ast::PrivateIdentifier<'a>;
+            enter_static_block, exit_static_block: ::oxc_ast::ast::StaticBlock<'a>;
+            enter_module_declaration, exit_module_declaration: ::oxc_ast::ast::ModuleDeclaration<'a>;
+            enter_accessor_property, exit_accessor_property: ::oxc_ast::ast::AccessorProperty<'a>;
+            enter_import_expression, exit_import_expression: ::oxc_ast::ast::ImportExpression<'a>;
+            enter_import_declaration, exit_import_declaration: ::oxc_ast::ast::ImportDeclaration<'a>;
+            enter_import_declaration_specifier, exit_import_declaration_specifier: ::oxc_ast::ast::ImportDeclarationSpecifier<'a>;
+            enter_import_specifier, exit_import_specifier: ::oxc_ast::ast::ImportSpecifier<'a>;
+            enter_import_default_specifier, exit_import_default_specifier: ::oxc_ast::ast::ImportDefaultSpecifier<'a>;
+            enter_import_namespace_specifier, exit_import_namespace_specifier: ::oxc_ast::ast::ImportNamespaceSpecifier<'a>;
+            enter_with_clause, exit_with_clause: ::oxc_ast::ast::WithClause<'a>;
+            enter_import_attribute, exit_import_attribute: ::oxc_ast::ast::ImportAttribute<'a>;
+            enter_import_attribute_key, exit_import_attribute_key: ::oxc_ast::ast::ImportAttributeKey<'a>;
+            enter_export_named_declaration, exit_export_named_declaration: ::oxc_ast::ast::ExportNamedDeclaration<'a>;
+            enter_export_default_declaration, exit_export_default_declaration: ::oxc_ast::ast::ExportDefaultDeclaration<'a>;
+            enter_export_all_declaration, exit_export_all_declaration: ::oxc_ast::ast::ExportAllDeclaration<'a>;
+            enter_export_specifier, exit_export_specifier: ::oxc_ast::ast::ExportSpecifier<'a>;
+            enter_export_default_declaration_kind, exit_export_default_declaration_kind: ::oxc_ast::ast::ExportDefaultDeclarationKind<'a>;
+            enter_module_export_name, exit_module_export_name: ::oxc_ast::ast::ModuleExportName<'a>;
+            enter_v8_intrinsic_expression, exit_v8_intrinsic_expression: ::oxc_ast::ast::V8IntrinsicExpression<'a>;
+            enter_jsx_element, exit_jsx_element: ::oxc_ast::ast::JSXElement<'a>;
+            enter_jsx_opening_element, exit_jsx_opening_element: ::oxc_ast::ast::JSXOpeningElement<'a>;
+            enter_jsx_closing_element, exit_jsx_closing_element: ::oxc_ast::ast::JSXClosingElement<'a>;
+            enter_jsx_fragment, exit_jsx_fragment: ::oxc_ast::ast::JSXFragment<'a>;
+            enter_jsx_opening_fragment, exit_jsx_opening_fragment: ::oxc_ast::ast::JSXOpeningFragment;
+            enter_jsx_closing_fragment, exit_jsx_closing_fragment: ::oxc_ast::ast::JSXClosingFragment;
+            enter_jsx_element_name, exit_jsx_element_name: ::oxc_ast::ast::JSXElementName<'a>;
+            enter_jsx_namespaced_name, exit_jsx_namespaced_name: ::oxc_ast::ast::JSXNamespacedName<'a>;
+            enter_jsx_member_expression, exit_jsx_member_expression: ::oxc_ast::ast::JSXMemberExpression<'a>;
+            enter_jsx_member_expression_object, exit_jsx_member_expression_object: ::oxc_ast::ast::JSXMemberExpressionObject<'a>;
+            enter_jsx_expression_container, exit_jsx_expression_container: ::oxc_ast::ast::JSXExpressionContainer<'a>;
+            enter_jsx_expression, exit_jsx_expression: ::oxc_ast::ast::JSXExpression<'a>;
+            enter_jsx_empty_expression, exit_jsx_empty_expression: ::oxc_ast::ast::JSXEmptyExpression;
+            enter_jsx_attribute_item, exit_jsx_attribute_item: ::oxc_ast::ast::JSXAttributeItem<'a>;
+            enter_jsx_attribute, exit_jsx_attribute: ::oxc_ast::ast::JSXAttribute<'a>;
+            enter_jsx_spread_attribute, exit_jsx_spread_attribute: ::oxc_ast::ast::JSXSpreadAttribute<'a>;
+            enter_jsx_attribute_name, exit_jsx_attribute_name: ::oxc_ast::ast::JSXAttributeName<'a>;
+            enter_jsx_attribute_value, exit_jsx_attribute_value: ::oxc_ast::ast::JSXAttributeValue<'a>;
+            enter_jsx_identifier, exit_jsx_identifier: ::oxc_ast::ast::JSXIdentifier<'a>;
+            enter_jsx_child, exit_jsx_child: ::oxc_ast::ast::JSXChild<'a>;
+            enter_jsx_spread_child, exit_jsx_spread_child: ::oxc_ast::ast::JSXSpreadChild<'a>;
+            enter_jsx_text, exit_jsx_text: ::oxc_ast::ast::JSXText<'a>;
+            enter_boolean_literal, exit_boolean_literal: ::oxc_ast::ast::BooleanLiteral;
+            enter_null_literal, exit_null_literal: ::oxc_ast::ast::NullLiteral;
+            enter_numeric_literal, exit_numeric_literal: ::oxc_ast::ast::NumericLiteral<'a>;
+            enter_string_literal, exit_string_literal: ::oxc_ast::ast::StringLiteral<'a>;
+            enter_big_int_literal, exit_big_int_literal: ::oxc_ast::ast::BigIntLiteral<'a>;
+            enter_reg_exp_literal, exit_reg_exp_literal: ::oxc_ast::ast::RegExpLiteral<'a>;
+            enter_ts_this_parameter, exit_ts_this_parameter: ::oxc_ast::ast::TSThisParameter<'a>;
+            enter_ts_enum_declaration, exit_ts_enum_declaration: ::oxc_ast::ast::TSEnumDeclaration<'a>;
+            enter_ts_enum_body, exit_ts_enum_body: ::oxc_ast::ast::TSEnumBody<'a>;
+            enter_ts_enum_member, exit_ts_enum_member: ::oxc_ast::ast::TSEnumMember<'a>;
+            enter_ts_enum_member_name, exit_ts_enum_member_name: ::oxc_ast::ast::TSEnumMemberName<'a>;
+            enter_ts_type_annotation, exit_ts_type_annotation: ::oxc_ast::ast::TSTypeAnnotation<'a>;
+            enter_ts_literal_type, exit_ts_literal_type: ::oxc_ast::ast::TSLiteralType<'a>;
+            enter_ts_literal, exit_ts_literal: ::oxc_ast::ast::TSLiteral<'a>;
+            enter_ts_type, exit_ts_type: ::oxc_ast::ast::TSType<'a>;
+            enter_ts_conditional_type, exit_ts_conditional_type: ::oxc_ast::ast::TSConditionalType<'a>;
+            enter_ts_union_type, exit_ts_union_type: ::oxc_ast::ast::TSUnionType<'a>;
+            enter_ts_intersection_type, exit_ts_intersection_type: ::oxc_ast::ast::TSIntersectionType<'a>;
+            enter_ts_parenthesized_type, exit_ts_parenthesized_type: ::oxc_ast::ast::TSParenthesizedType<'a>;
+            enter_ts_type_operator, exit_ts_type_operator: ::oxc_ast::ast::TSTypeOperator<'a>;
+            enter_ts_array_type, exit_ts_array_type: ::oxc_ast::ast::TSArrayType<'a>;
+            enter_ts_indexed_access_type, exit_ts_indexed_access_type: ::oxc_ast::ast::TSIndexedAccessType<'a>;
+            enter_ts_tuple_type, exit_ts_tuple_type: ::oxc_ast::ast::TSTupleType<'a>;
+            enter_ts_named_tuple_member, exit_ts_named_tuple_member: ::oxc_ast::ast::TSNamedTupleMember<'a>;
+            enter_ts_optional_type, exit_ts_optional_type: ::oxc_ast::ast::TSOptionalType<'a>;
+            enter_ts_rest_type, exit_ts_rest_type: ::oxc_ast::ast::TSRestType<'a>;
+            enter_ts_tuple_element, exit_ts_tuple_element: ::oxc_ast::ast::TSTupleElement<'a>;
+            enter_ts_any_keyword, exit_ts_any_keyword: ::oxc_ast::ast::TSAnyKeyword;
+            enter_ts_string_keyword, exit_ts_string_keyword: ::oxc_ast::ast::TSStringKeyword;
+            enter_ts_boolean_keyword, exit_ts_boolean_keyword: ::oxc_ast::ast::TSBooleanKeyword;
+            enter_ts_number_keyword, exit_ts_number_keyword: ::oxc_ast::ast::TSNumberKeyword;
+            enter_ts_never_keyword, exit_ts_never_keyword: ::oxc_ast::ast::TSNeverKeyword;
+            enter_ts_intrinsic_keyword, exit_ts_intrinsic_keyword: ::oxc_ast::ast::TSIntrinsicKeyword;
+            enter_ts_unknown_keyword, exit_ts_unknown_keyword: ::oxc_ast::ast::TSUnknownKeyword;
+            enter_ts_null_keyword, exit_ts_null_keyword: ::oxc_ast::ast::TSNullKeyword;
+            enter_ts_undefined_keyword, exit_ts_undefined_keyword: ::oxc_ast::ast::TSUndefinedKeyword;
+            enter_ts_void_keyword, exit_ts_void_keyword: ::oxc_ast::ast::TSVoidKeyword;
+            enter_ts_symbol_keyword, exit_ts_symbol_keyword: ::oxc_ast::ast::TSSymbolKeyword;
+            enter_ts_this_type, exit_ts_this_type: ::oxc_ast::ast::TSThisType;
+            enter_ts_object_keyword, exit_ts_object_keyword: ::oxc_ast::ast::TSObjectKeyword;
+            enter_ts_big_int_keyword, exit_ts_big_int_keyword: ::oxc_ast::ast::TSBigIntKeyword;
+            enter_ts_type_reference, exit_ts_type_reference: ::oxc_ast::ast::TSTypeReference<'a>;
+            enter_ts_type_name, exit_ts_type_name: ::oxc_ast::ast::TSTypeName<'a>;
+            enter_ts_qualified_name, exit_ts_qualified_name: ::oxc_ast::ast::TSQualifiedName<'a>;
+            enter_ts_type_parameter_instantiation, exit_ts_type_parameter_instantiation: ::oxc_ast::ast::TSTypeParameterInstantiation<'a>;
+            enter_ts_type_parameter, exit_ts_type_parameter: ::oxc_ast::ast::TSTypeParameter<'a>;
+            enter_ts_type_parameter_declaration, exit_ts_type_parameter_declaration: ::oxc_ast::ast::TSTypeParameterDeclaration<'a>;
+            enter_ts_type_alias_declaration, exit_ts_type_alias_declaration: ::oxc_ast::ast::TSTypeAliasDeclaration<'a>;
+            enter_ts_class_implements, exit_ts_class_implements: ::oxc_ast::ast::TSClassImplements<'a>;
+            enter_ts_interface_declaration, exit_ts_interface_declaration: ::oxc_ast::ast::TSInterfaceDeclaration<'a>;
+            enter_ts_interface_body, exit_ts_interface_body: ::oxc_ast::ast::TSInterfaceBody<'a>;
+            enter_ts_property_signature, exit_ts_property_signature: ::oxc_ast::ast::TSPropertySignature<'a>;
+            enter_ts_signature, exit_ts_signature: ::oxc_ast::ast::TSSignature<'a>;
+            enter_ts_index_signature, exit_ts_index_signature: ::oxc_ast::ast::TSIndexSignature<'a>;
+            enter_ts_call_signature_declaration, exit_ts_call_signature_declaration: ::oxc_ast::ast::TSCallSignatureDeclaration<'a>;
+            enter_ts_method_signature, exit_ts_method_signature: ::oxc_ast::ast::TSMethodSignature<'a>;
+            enter_ts_construct_signature_declaration, exit_ts_construct_signature_declaration: ::oxc_ast::ast::TSConstructSignatureDeclaration<'a>;
+            enter_ts_index_signature_name, exit_ts_index_signature_name: ::oxc_ast::ast::TSIndexSignatureName<'a>;
+            enter_ts_interface_heritage, exit_ts_interface_heritage: ::oxc_ast::ast::TSInterfaceHeritage<'a>;
+            enter_ts_type_predicate, exit_ts_type_predicate: ::oxc_ast::ast::TSTypePredicate<'a>;
+            enter_ts_type_predicate_name, exit_ts_type_predicate_name: ::oxc_ast::ast::TSTypePredicateName<'a>;
+            enter_ts_module_declaration, exit_ts_module_declaration: ::oxc_ast::ast::TSModuleDeclaration<'a>;
+            enter_ts_module_declaration_name, exit_ts_module_declaration_name: ::oxc_ast::ast::TSModuleDeclarationName<'a>;
+            enter_ts_module_declaration_body, exit_ts_module_declaration_body: ::oxc_ast::ast::TSModuleDeclarationBody<'a>;
+            enter_ts_module_block, exit_ts_module_block: ::oxc_ast::ast::TSModuleBlock<'a>;
+            enter_ts_type_literal, exit_ts_type_literal: ::oxc_ast::ast::TSTypeLiteral<'a>;
+            enter_ts_infer_type, exit_ts_infer_type: ::oxc_ast::ast::TSInferType<'a>;
+            enter_ts_type_query, exit_ts_type_query: ::oxc_ast::ast::TSTypeQuery<'a>;
+            enter_ts_type_query_expr_name, exit_ts_type_query_expr_name: ::oxc_ast::ast::TSTypeQueryExprName<'a>;
+            enter_ts_import_type, exit_ts_import_type: ::oxc_ast::ast::TSImportType<'a>;
+            enter_ts_function_type, exit_ts_function_type: ::oxc_ast::ast::TSFunctionType<'a>;
+            enter_ts_constructor_type, exit_ts_constructor_type: ::oxc_ast::ast::TSConstructorType<'a>;
+            enter_ts_mapped_type, exit_ts_mapped_type: ::oxc_ast::ast::TSMappedType<'a>;
+            enter_ts_template_literal_type, exit_ts_template_literal_type: ::oxc_ast::ast::TSTemplateLiteralType<'a>;
+            enter_ts_as_expression, exit_ts_as_expression: ::oxc_ast::ast::TSAsExpression<'a>;
+            enter_ts_satisfies_expression, exit_ts_satisfies_expression: ::oxc_ast::ast::TSSatisfiesExpression<'a>;
+            enter_ts_type_assertion, exit_ts_type_assertion: ::oxc_ast::ast::TSTypeAssertion<'a>;
+            enter_ts_import_equals_declaration, exit_ts_import_equals_declaration: ::oxc_ast::ast::TSImportEqualsDeclaration<'a>;
+            enter_ts_module_reference, exit_ts_module_reference: ::oxc_ast::ast::TSModuleReference<'a>;
+            enter_ts_external_module_reference, exit_ts_external_module_reference: ::oxc_ast::ast::TSExternalModuleReference<'a>;
+            enter_ts_non_null_expression, exit_ts_non_null_expression: ::oxc_ast::ast::TSNonNullExpression<'a>;
+            enter_decorator, exit_decorator: ::oxc_ast::ast::Decorator<'a>;
+            enter_ts_export_assignment, exit_ts_export_assignment: ::oxc_ast::ast::TSExportAssignment<'a>;
+            enter_ts_namespace_export_declaration, exit_ts_namespace_export_declaration: ::oxc_ast::ast::TSNamespaceExportDeclaration<'a>;
+            enter_ts_instantiation_expression, exit_ts_instantiation_expression: ::oxc_ast::ast::TSInstantiationExpression<'a>;
+            enter_js_doc_nullable_type, exit_js_doc_nullable_type: ::oxc_ast::ast::JSDocNullableType<'a>;
+            enter_js_doc_non_nullable_type, exit_js_doc_non_nullable_type: ::oxc_ast::ast::JSDocNonNullableType<'a>;
+            enter_js_doc_unknown_type, exit_js_doc_unknown_type: ::oxc_ast::ast::JSDocUnknownType;
+            enter_statements, exit_statements: ::oxc_allocator::Vec<'a, ::oxc_ast::ast::Statement<'a>>;
+            }
+        };
+    }
@@ -1,28 +1,44 @@
-use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
+//! Collects the source files in a set of paths, respecting ignore files and patterns.
+//!
+//! Shared by `oxlint` and `oxfmt`, so that both tools agree on which files are ignored.
 
-use ignore::{DirEntry, overrides::Override};
-use oxc_linter::LINTABLE_EXTENSIONS;
+use std::{
+    ffi::{OsStr, OsString},
+    path::PathBuf,
+    sync::{Arc, mpsc},
+};
 
-use crate::cli::IgnoreOptions;
+use ignore::{DirEntry, overrides::Override};
+use rustc_hash::FxHashSet;
+
+/// Options of [Walk::new], usually set by the `--ignore-path`, `--no-ignore` and `--symlinks`
+/// command line options.
+#[derive(Debug, Clone)]
+pub struct WalkOptions {
+    /// Name of the ignore file to respect in addition to `.gitignore`, e.g. `.eslintignore`.
+    pub ignore_path: OsString,
+    /// Do not respect the ignore file and the ignore patterns.
+    pub no_ignore: bool,
+    /// Follow symbolic links.
+    pub symlinks: bool,
+}
 
+/// The file extensions to include during the traversal.
 #[derive(Clone)]
 pub struct Extensions(pub Vec<&'static str>);
 
-impl Default for Extensions {
-    fn default() -> Self {
-        Self(LINTABLE_EXTENSIONS.to_vec())
-    }
-}
-
 pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    /// Only include these files, if set. Paths are absolute.
+    changed_files: Option<Arc<FxHashSet<PathBuf>>>,
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    changed_files: Option<Arc<FxHashSet<PathBuf>>>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
@@ -31,6 +47,7 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
             paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            changed_files: self.changed_files.clone(),
         })
     }
 }
@@ -39,6 +56,7 @@ struct WalkCollector {
     paths: Vec<Arc<OsStr>>,
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    changed_files: Option<Arc<FxHashSet<PathBuf>>>,
 }
 
 impl Drop for WalkCollector {
@@ -52,7 +70,9 @@ impl ignore::ParallelVisitor for WalkCollector {
     fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
         match entry {
             Ok(entry) => {
-                if Walk::is_wanted_entry(&entry, &self.extensions) {
+                if Walk::is_wanted_entry(&entry, &self.extensions)
+                    && Walk::is_changed_entry(&entry, self.changed_files.as_deref())
+                {
                     self.paths.push(entry.path().as_os_str().into());
                 }
                 ignore::WalkState::Continue
@@ -61,13 +81,15 @@ impl ignore::ParallelVisitor for WalkCollector {
         }
     }
 }
+
 impl Walk {
     /// Will not canonicalize paths.
     /// # Panics
     pub fn new(
         paths: &[PathBuf],
-        options: &IgnoreOptions,
+        options: &WalkOptions,
         override_builder: Option<Override>,
+        extensions: Extensions,
     ) -> Self {
         assert!(!paths.is_empty(), "At least one path must be provided to Walk::new");
 
@@ -97,23 +119,30 @@ impl Walk {
         // * it is super rare to have symlinked source code
         let inner =
             inner.ignore(false).git_global(false).follow_links(options.symlinks).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        Self { inner, extensions, changed_files: None }
     }
 
     pub fn paths(self) -> Vec<Arc<OsStr>> {
         let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let mut builder =
+            WalkBuilder { sender, extensions: self.extensions, changed_files: self.changed_files };
         self.inner.visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
     }
 
-    #[cfg_attr(not(test), expect(dead_code))]
-    pub fn with_extensions(mut self, extensions: Extensions) -> Self {
-        self.extensions = extensions;
+    /// Only include these files, e.g. the files in a diff. Paths must be absolute.
+    #[must_use]
+    pub fn with_changed_files(mut self, changed_files: FxHashSet<PathBuf>) -> Self {
+        self.changed_files = Some(Arc::new(changed_files));
         self
     }
 
+    fn is_changed_entry(dir_entry: &DirEntry, changed_files: Option<&FxHashSet<PathBuf>>) -> bool {
+        let Some(changed_files) = changed_files else { return true };
+        std::path::absolute(dir_entry.path()).is_ok_and(|path| changed_files.contains(&path))
+    }
+
     fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions) -> bool {
         let Some(file_type) = dir_entry.file_type() else { return false };
         if file_type.is_dir() {
@@ -135,32 +164,48 @@ mod test {
 
     use ignore::overrides::OverrideBuilder;
 
-    use super::{Extensions, Walk};
-    use crate::cli::IgnoreOptions;
+    use super::{Extensions, Walk, WalkOptions};
+
+    fn walk_options() -> WalkOptions {
+        WalkOptions { ignore_path: OsString::from(".gitignore"), no_ignore: false, symlinks: false }
+    }
 
     #[test]
     fn test_walk_with_extensions() {
         let fixture = env::current_dir().unwrap().join("fixtures/walk_dir");
         let fixtures = vec![fixture.clone()];
-        let ignore_options = IgnoreOptions {
-            no_ignore: false,
-            ignore_path: OsString::from(".gitignore"),
-            ignore_pattern: vec![],
-            symlinks: false,
-        };
-
         let override_builder = OverrideBuilder::new("/").build().unwrap();
 
-        let mut paths = Walk::new(&fixtures, &ignore_options, Some(override_builder))
-            .with_extensions(Extensions(["js", "vue"].to_vec()))
+        let mut paths = Walk::new(
+            &fixtures,
+            &walk_options(),
+            Some(override_builder),
+            Extensions(["js", "vue"].to_vec()),
+        )
+        .paths()
+        .into_iter()
+        .map(|path| Path::new(&path).strip_prefix(&fixture).unwrap().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+        paths.sort();
+
+        assert_eq!(paths, vec!["bar.vue", "foo.js"]);
+    }
+
+    #[test]
+    fn test_walk_with_changed_files() {
+        let fixture = env::current_dir().unwrap().join("fixtures/walk_dir");
+        let fixtures = vec![fixture.clone()];
+
+        let changed_files = [fixture.join("foo.js"), fixture.join("missing.js")];
+        let paths = Walk::new(&fixtures, &walk_options(), None, Extensions(["js"].to_vec()))
+            .with_changed_files(changed_files.into_iter().collect())
             .paths()
             .into_iter()
             .map(|path| {
                 Path::new(&path).strip_prefix(&fixture).unwrap().to_string_lossy().to_string()
             })
             .collect::<Vec<_>>();
-        paths.sort();
 
-        assert_eq!(paths, vec!["bar.vue", "foo.js"]);
+        assert_eq!(paths, vec!["foo.js"]);
     }
 }
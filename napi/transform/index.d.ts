@@ -108,6 +108,15 @@ export interface DecoratorOptions {
    * @default false
    */
   legacy?: boolean
+  /**
+   * Enables the transform of TC39 standard decorators, following the `2023-11` version of the proposal.
+   *
+   * This also lowers `accessor` class fields. It has no effect when `legacy` is true.
+   *
+   * @see https://github.com/tc39/proposal-decorators
+   * @default false
+   */
+  standard?: boolean
   /**
    * Enables emitting decorator metadata.
    *
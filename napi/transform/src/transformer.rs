@@ -369,6 +369,14 @@ pub struct DecoratorOptions {
     /// @default false
     pub legacy: Option<bool>,
 
+    /// Enables the transform of TC39 standard decorators, following the `2023-11` version of the proposal.
+    ///
+    /// This also lowers `accessor` class fields. It has no effect when `legacy` is true.
+    ///
+    /// @see https://github.com/tc39/proposal-decorators
+    /// @default false
+    pub standard: Option<bool>,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -383,6 +391,7 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     fn from(options: DecoratorOptions) -> Self {
         oxc::transformer::DecoratorOptions {
             legacy: options.legacy.unwrap_or_default(),
+            standard: options.standard.unwrap_or_default(),
             emit_decorator_metadata: options.emit_decorator_metadata.unwrap_or_default(),
         }
     }
@@ -548,7 +557,10 @@ pub struct Es2015Options {
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            ..Default::default()
+        }
     }
 }
 
@@ -8,6 +8,8 @@ const interpolateAllResults = require('./compat-table/build-utils/interpolate-al
 const compareVersions = require('./compat-table/build-utils/compare-versions');
 const { addElectronSupportFromChromium } = require('./chromium-to-electron');
 const esFeatures = require(`./es-features`);
+const coreJsCompat = require('core-js-compat/data.json');
+const coreJsModules = require('./core-js-modules');
 
 const environments = [
   'chrome',
@@ -115,3 +117,17 @@ const generateData = (environments, items) => {
 const items = generateData(environments, esFeatures);
 
 fs.writeFileSync('./data.json', JSON.stringify(items, null, 2));
+
+const generateCoreJsData = (environments, items) =>
+  items.map(item => {
+    const targets = {};
+    environments.forEach(env => {
+      const version = coreJsCompat[item.name][env];
+      if (version) targets[env] = version;
+    });
+    return Object.assign({}, item, { targets });
+  });
+
+const coreJsItems = generateCoreJsData([...environments, 'electron'], coreJsModules);
+
+fs.writeFileSync('./core-js-data.json', JSON.stringify(coreJsItems, null, 2));
@@ -0,0 +1,120 @@
+// core-js modules injected by `crates/oxc_transformer/src/polyfill`, and the ES version of their feature.
+// Minimum engine versions are taken from `core-js-compat`.
+// https://github.com/zloirock/core-js/blob/v3.43.0/packages/core-js-compat/src/data.mjs
+
+const f = (es) => (name) => ({ name, es });
+
+const es2015 = [
+  'es.array.fill',
+  'es.array.find',
+  'es.array.find-index',
+  'es.array.from',
+  'es.array.iterator',
+  'es.array.of',
+  'es.map',
+  'es.math.sign',
+  'es.math.trunc',
+  'es.number.is-integer',
+  'es.number.is-nan',
+  'es.number.is-safe-integer',
+  'es.object.assign',
+  'es.object.to-string',
+  'es.promise',
+  'es.set',
+  'es.string.ends-with',
+  'es.string.includes',
+  'es.string.iterator',
+  'es.string.repeat',
+  'es.string.starts-with',
+  'es.symbol',
+  'es.weak-map',
+  'es.weak-set',
+].map(f('ES2015'));
+
+const es2016 = [
+  'es.array.includes',
+].map(f('ES2016'));
+
+const es2017 = [
+  'es.object.entries',
+  'es.object.get-own-property-descriptors',
+  'es.object.values',
+  'es.string.pad-end',
+  'es.string.pad-start',
+].map(f('ES2017'));
+
+const es2018 = [
+  'es.promise.finally',
+  'es.symbol.async-iterator',
+].map(f('ES2018'));
+
+const es2019 = [
+  'es.array.flat',
+  'es.array.flat-map',
+  'es.array.unscopables.flat',
+  'es.array.unscopables.flat-map',
+  'es.object.from-entries',
+  'es.string.trim-end',
+  'es.string.trim-start',
+  'es.symbol.description',
+].map(f('ES2019'));
+
+const es2020 = [
+  'es.global-this',
+  'es.promise.all-settled',
+  'es.string.match-all',
+].map(f('ES2020'));
+
+const es2021 = [
+  'es.aggregate-error',
+  'es.promise.any',
+  'es.string.replace-all',
+].map(f('ES2021'));
+
+const es2022 = [
+  'es.array.at',
+  'es.object.has-own',
+  'es.string.at-alternative',
+].map(f('ES2022'));
+
+const es2023 = [
+  'es.array.find-last',
+  'es.array.find-last-index',
+  'es.array.to-reversed',
+  'es.array.to-sorted',
+  'es.array.to-spliced',
+  'es.array.with',
+].map(f('ES2023'));
+
+const es2024 = [
+  'es.map.group-by',
+  'es.object.group-by',
+  'es.promise.with-resolvers',
+  'es.string.is-well-formed',
+  'es.string.to-well-formed',
+].map(f('ES2024'));
+
+const es2025 = [
+  'es.promise.try',
+].map(f('ES2025'));
+
+const web = [
+  'web.dom-collections.iterator',
+  'web.queue-microtask',
+  'web.structured-clone',
+].map(f(null));
+
+module.exports = [
+  ...es2015,
+  ...es2016,
+  ...es2017,
+  ...es2018,
+  ...es2019,
+  ...es2020,
+  ...es2021,
+  ...es2022,
+  ...es2023,
+  ...es2024,
+  ...es2025,
+  ...web,
+];
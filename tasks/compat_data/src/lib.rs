@@ -20,8 +20,20 @@ impl Item {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct CoreJsItem {
+    name: String,
+    es: Option<String>,
+    targets: EngineTargets,
+}
+
 /// # Panics
 pub fn generate() {
+    generate_es_features();
+    generate_core_js_compat();
+}
+
+fn generate_es_features() {
     let path = project_root().join("tasks/compat_data/data.json");
     let content = fs::read_to_string(path).unwrap();
     let items = serde_json::from_str::<Vec<Item>>(&content).unwrap();
@@ -30,20 +42,9 @@ pub fn generate() {
 
     let features = items.iter().map(|item| {
         let key = item.es_name();
-        let es_version = u32::from_str(item.es.trim_start_matches("ES")).unwrap();
-        let targets = item
-            .targets
-            .iter()
-            .map(|(engine, version)| {
-                let engine = quote::format_ident!("{engine:?}");
-                let (a, b, c) = (version.0, version.1, version.2);
-                quote! {
-                    (#engine, Version(#a, #b, #c))
-                }
-            })
-            .chain([quote! { (Es, Version(#es_version, 0, 0)) }]);
+        let targets = engine_targets(&item.targets, Some(&item.es));
         quote! {
-            (#key, EngineTargets::new(FxHashMap::from_iter([#(#targets),*])))
+            (#key, #targets)
         }
     });
 
@@ -74,6 +75,61 @@ pub fn generate() {
     generate_file("crates/oxc_transformer/src/options/es_features.rs", code);
 }
 
+fn generate_core_js_compat() {
+    let path = project_root().join("tasks/compat_data/core-js-data.json");
+    let content = fs::read_to_string(path).unwrap();
+    let mut items = serde_json::from_str::<Vec<CoreJsItem>>(&content).unwrap();
+    // Sorted, for binary search by name
+    items.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+    let modules = items.iter().map(|item| {
+        let name = &item.name;
+        let targets = engine_targets(&item.targets, item.es.as_deref());
+        quote! {
+            (#name, #targets)
+        }
+    });
+
+    let code = quote! {
+        #![allow(clippy::enum_glob_use)]
+        use std::sync::OnceLock;
+
+        use browserslist::Version;
+        use rustc_hash::FxHashMap;
+
+        use crate::options::{Engine, EngineTargets};
+
+        /// core-js modules sorted by name, and the minimum engine versions which support them natively.
+        pub fn modules() -> &'static [(&'static str, EngineTargets)] {
+            use Engine::*;
+            static MODULES: OnceLock<Vec<(&'static str, EngineTargets)>> = OnceLock::new();
+            MODULES.get_or_init(|| {
+                vec![#(#modules),*]
+            })
+        }
+    };
+
+    generate_file("crates/oxc_transformer/src/polyfill/core_js_compat.rs", code);
+}
+
+/// `EngineTargets::new(..)` for `targets`, and the `ESXXXX` version `es` if any.
+fn engine_targets(targets: &EngineTargets, es: Option<&str>) -> proc_macro2::TokenStream {
+    let es_version = es.map(|es| u32::from_str(es.trim_start_matches("ES")).unwrap());
+    let targets = targets
+        .iter()
+        .map(|(engine, version)| {
+            let engine = quote::format_ident!("{engine:?}");
+            let (a, b, c) = (version.0, version.1, version.2);
+            quote! {
+                (#engine, Version(#a, #b, #c))
+            }
+        })
+        .chain(es_version.map(|es_version| quote! { (Es, Version(#es_version, 0, 0)) }));
+    quote! {
+        EngineTargets::new(FxHashMap::from_iter([#(#targets),*]))
+    }
+}
+
 fn generate_file(file: &str, token_stream: proc_macro2::TokenStream) {
     let syntax_tree = syn::parse2(token_stream).unwrap();
     let code = format!(
@@ -7,8 +7,9 @@ use oxc_ast::ast::{
 };
 use oxc_ast_visit::VisitMut;
 use oxc_formatter::{
-    ArrowParentheses, BracketSpacing, FormatOptions, IndentWidth, LineEnding, LineWidth,
-    OperatorPosition, QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
+    ArrowParentheses, BracketSpacing, EmbeddedLanguageFormatting, FormatOptions, IndentWidth,
+    LineEnding, LineWidth, OperatorPosition, QuoteProperties, QuoteStyle, Semicolons,
+    TrailingCommas,
 };
 use oxc_parser::Parser;
 use oxc_span::{GetSpan, SourceType};
@@ -81,7 +82,11 @@ impl VisitMut<'_> for SpecParser {
 
         let mut snapshot_options: SnapshotOptions = vec![];
         let mut parsers = vec![];
-        let mut options = FormatOptions::default();
+        // Prettier formats embedded languages by default.
+        let mut options = FormatOptions {
+            embedded_language_formatting: EmbeddedLanguageFormatting::Auto,
+            ..FormatOptions::default()
+        };
 
         // Get parsers
         if let Some(argument) = expr.arguments.get(1) {
@@ -171,6 +176,10 @@ impl VisitMut<'_> for SpecParser {
                                         options.arrow_parentheses =
                                             ArrowParentheses::from_str(s).unwrap_or_default();
                                     }
+                                    "embeddedLanguageFormatting" => {
+                                        options.embedded_language_formatting =
+                                            EmbeddedLanguageFormatting::from_str(s).unwrap();
+                                    }
                                     "experimentalOperatorPosition" => {
                                         // TODO: change `unwrap_or_default` to `unwrap`
                                         options.experimental_operator_position =
@@ -34,14 +34,24 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
-    // "babel-plugin-transform-function-name",
-    // "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-classes",
+    "babel-plugin-transform-computed-properties",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-for-of",
+    "babel-plugin-transform-function-name",
+    "babel-plugin-transform-literals",
+    "babel-plugin-transform-parameters",
+    "babel-plugin-transform-regenerator",
+    "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-spread",
     // "babel-plugin-transform-sticky-regex",
     // "babel-plugin-transform-unicode-regex",
-    // "babel-plugin-transform-template-literals",
-    // "babel-plugin-transform-duplicate-keys",
+    "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-typeof-symbol",
+    "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
-    // "babel-plugin-transform-new-target",
+    "babel-plugin-transform-new-target",
     // // ES3
     // "babel-plugin-transform-property-literals",
     // TypeScript
@@ -54,24 +64,25 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-self",
     "babel-plugin-transform-react-jsx-source",
     "babel-plugin-transform-react-jsx-development",
-    // // Proposal
-    // "babel-plugin-proposal-decorators",
+    // Modules
+    "babel-plugin-transform-modules-commonjs",
+    // Proposal
+    "babel-plugin-proposal-decorators",
     "babel-plugin-proposal-explicit-resource-management",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
+    // ES2015 transforms, cases not covered by the Babel suites
+    "es2015",
+    // ES modules to CommonJS
+    "modules-commonjs",
+    // 2023-11 decorators
+    "decorators",
     // Legacy decorators, tests almost ported from TypeScript
     "legacy-decorators",
 ];
 
-pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
-    "proposal-decorators",
-    "transform-classes",
-    "transform-destructuring",
-    "transform-modules-commonjs",
-    "transform-parameters",
-    "transform-property-literals",
-    "transform-react-constant-elements",
-];
+pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] =
+    &["transform-property-literals", "transform-react-constant-elements"];
 
 pub const SKIP_TESTS: &[&str] = &[
     // Shouldn't report in transformer
@@ -168,13 +168,9 @@ impl TestCase {
             }
         }
 
-        // Legacy decorators is not supported
-        if options
-            .plugins
-            .proposal_decorators
-            .as_ref()
-            .or(options.plugins.syntax_decorators.as_ref())
-            .is_some_and(|o| o.version == "legacy")
+        // Legacy decorators is not supported, and only the 2023-11 version of standard decorators is
+        if options.plugins.proposal_decorators.as_ref().is_some_and(|o| o.version != "2023-11")
+            || options.plugins.syntax_decorators.as_ref().is_some_and(|o| o.version == "legacy")
         {
             return true;
         }
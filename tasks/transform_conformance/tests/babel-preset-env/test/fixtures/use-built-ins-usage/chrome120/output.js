@@ -0,0 +1,3 @@
+Promise.withResolvers();
+const grouped = Object.groupBy([1, 2], (x) => x % 2);
+[1, 2].toSorted().at(-1);
@@ -0,0 +1,15 @@
+import { helper } from "./helper";
+
+Promise.allSettled([helper(), Promise.resolve(1)]);
+const flat = [[1], [2]].flat();
+str?.replaceAll("a", "b");
+Object["fromEntries"](new Map());
+const copy = structuredClone({ flat });
+
+function shadowed(Set, globalThis) {
+  return new Set(globalThis);
+}
+
+class Foo {
+  static from = Array.from;
+}
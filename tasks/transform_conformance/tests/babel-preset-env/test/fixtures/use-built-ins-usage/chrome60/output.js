@@ -0,0 +1,21 @@
+import "core-js/modules/es.promise.all-settled.js";
+import "core-js/modules/es.promise.js";
+import "core-js/modules/es.array.iterator.js";
+import "core-js/modules/web.dom-collections.iterator.js";
+import "core-js/modules/es.array.flat.js";
+import "core-js/modules/es.array.unscopables.flat.js";
+import "core-js/modules/es.string.replace-all.js";
+import "core-js/modules/es.object.from-entries.js";
+import "core-js/modules/web.structured-clone.js";
+import { helper } from "./helper";
+var _str;
+Promise.allSettled([helper(), Promise.resolve(1)]);
+const flat = [[1], [2]].flat();
+(_str = str) === null || _str === void 0 ? void 0 : _str.replaceAll("a", "b");
+Object["fromEntries"](new Map());
+const copy = structuredClone({ flat });
+function shadowed(Set, globalThis) {
+  return new Set(globalThis);
+}
+class Foo {}
+babelHelpers.defineProperty(Foo, "from", Array.from);
@@ -0,0 +1,2 @@
+Promise.allSettled([]);
+[[1], [2]].flat();
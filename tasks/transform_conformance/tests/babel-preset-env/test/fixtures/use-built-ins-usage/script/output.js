@@ -0,0 +1,8 @@
+require("core-js/modules/es.promise.all-settled.js");
+require("core-js/modules/es.promise.js");
+require("core-js/modules/es.array.iterator.js");
+require("core-js/modules/web.dom-collections.iterator.js");
+require("core-js/modules/es.array.flat.js");
+require("core-js/modules/es.array.unscopables.flat.js");
+Promise.allSettled([]);
+[[1], [2]].flat();
@@ -0,0 +1,7 @@
+class Foo {
+  accessor a = 1;
+  @dec accessor b;
+  @dec static accessor c = 3;
+  @dec accessor #d = 4;
+  @dec static accessor #e;
+}
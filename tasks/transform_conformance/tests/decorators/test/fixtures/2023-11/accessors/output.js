@@ -0,0 +1,72 @@
+var _init_b, _init_extra_b, _init_c, _init_extra_c, _init_d, _init_extra_d, _get_d, _set_d, _init_e, _init_extra_e, _get_e, _set_e;
+class Foo {
+  static {
+    [_init_c, _init_extra_c, _init_e, _get_e, _set_e, _init_extra_e, _init_b, _init_extra_b, _init_d, _get_d, _set_d, _init_extra_d] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        1,
+        "b"
+      ],
+      [
+        dec,
+        9,
+        "c"
+      ],
+      [
+        dec,
+        1,
+        "d",
+        (_) => _.#D,
+        (_, value) => _.#D = value
+      ],
+      [
+        dec,
+        9,
+        "e",
+        (_) => _.#E,
+        (_, value) => _.#E = value
+      ]
+    ], 0, (_) => #D in _).e;
+  }
+  constructor() {
+    _init_extra_d(this);
+  }
+  #A = 1;
+  get a() {
+    return this.#A;
+  }
+  set a(value) {
+    this.#A = value;
+  }
+  #B = _init_b(this);
+  get b() {
+    return this.#B;
+  }
+  set b(value) {
+    this.#B = value;
+  }
+  static #C = _init_c(3);
+  static get c() {
+    return this.#C;
+  }
+  static set c(value) {
+    this.#C = value;
+  }
+  #D = (_init_extra_b(this), _init_d(this, 4));
+  get #d() {
+    return _get_d(this);
+  }
+  set #d(value) {
+    _set_d(this, value);
+  }
+  static #E = (_init_extra_c(), _init_e());
+  static get #e() {
+    return _get_e();
+  }
+  static set #e(value) {
+    _set_e(value);
+  }
+  static {
+    _init_extra_e();
+  }
+}
@@ -0,0 +1,9 @@
+const Foo = @dec class Bar {
+  method() {
+    return Bar;
+  }
+};
+
+const Baz = @dec class {
+  @dec field = 1;
+};
@@ -0,0 +1,28 @@
+var _Bar, _initClass, _Class, _initClass2, _init_field, _init_extra_field;
+const Foo = (class Bar {
+  static {
+    [_Bar, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  method() {
+    return _Bar;
+  }
+  static {
+    _initClass();
+  }
+}, _Bar);
+const Baz = (class {
+  static {
+    ({e: [_init_field, _init_extra_field], c: [_Class, _initClass2]} = babelHelpers.applyDecs2311(this, [dec], [[
+      dec,
+      0,
+      "field"
+    ]]));
+  }
+  constructor() {
+    _init_extra_field(this);
+  }
+  field = _init_field(this, 1);
+  static {
+    _initClass2();
+  }
+}, _Class);
@@ -0,0 +1,9 @@
+@dec
+class Foo {
+  static self = Foo;
+  method() {
+    return Foo;
+  }
+}
+
+new Foo();
@@ -0,0 +1,15 @@
+var _Foo, _initClass;
+class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static self = _Foo;
+  method() {
+    return _Foo;
+  }
+  static {
+    _initClass();
+  }
+}
+Foo = _Foo;
+new Foo();
@@ -0,0 +1,6 @@
+class Foo {
+  [first()] = 1;
+  @dec [second()] = 2;
+  ["literal"]() {}
+  @dec accessor [third()];
+}
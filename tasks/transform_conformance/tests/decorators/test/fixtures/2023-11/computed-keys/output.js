@@ -0,0 +1,30 @@
+var _computedKey, _computedKey2, _init_computedKey, _init_extra_computedKey, _computedKey3, _init_computedKey2, _init_extra_computedKey2;
+_computedKey = babelHelpers.toPropertyKey(first());
+_computedKey2 = babelHelpers.toPropertyKey(second());
+_computedKey3 = babelHelpers.toPropertyKey(third());
+class Foo {
+  static {
+    [_init_computedKey2, _init_extra_computedKey2, _init_computedKey, _init_extra_computedKey] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      0,
+      _computedKey2
+    ], [
+      dec,
+      1,
+      _computedKey3
+    ]]).e;
+  }
+  constructor() {
+    _init_extra_computedKey2(this);
+  }
+  [_computedKey] = 1;
+  [_computedKey2] = _init_computedKey(this, 2);
+  ["literal"]() {}
+  #A = (_init_extra_computedKey(this), _init_computedKey2(this));
+  get [_computedKey3]() {
+    return this.#A;
+  }
+  set [_computedKey3](value) {
+    this.#A = value;
+  }
+}
@@ -0,0 +1,7 @@
+class Foo {
+  @dec method() {}
+
+  constructor() {
+    console.log("constructor");
+  }
+}
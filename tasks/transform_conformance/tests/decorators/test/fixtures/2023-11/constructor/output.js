@@ -0,0 +1,15 @@
+var _initProto;
+class Foo {
+  static {
+    [_initProto] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      2,
+      "method"
+    ]]).e;
+  }
+  method() {}
+  constructor() {
+    _initProto(this);
+    console.log("constructor");
+  }
+}
@@ -0,0 +1,4 @@
+class Foo {
+  @a.b @c() @(d) method() {}
+  @e field;
+}
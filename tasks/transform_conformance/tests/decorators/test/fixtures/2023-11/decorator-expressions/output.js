@@ -0,0 +1,29 @@
+var _initProto, _dec, _dec2, _dec3, _init_field, _init_extra_field;
+_dec = a.b;
+_dec2 = c();
+_dec3 = d;
+class Foo {
+  static {
+    [_init_field, _init_extra_field, _initProto] = babelHelpers.applyDecs2311(this, [], [[
+      [
+        a,
+        _dec,
+        void 0,
+        _dec2,
+        void 0,
+        _dec3
+      ],
+      18,
+      "method"
+    ], [
+      e,
+      0,
+      "field"
+    ]]).e;
+  }
+  constructor() {
+    _init_extra_field(this);
+  }
+  method() {}
+  field = (_initProto(this), _init_field(this));
+}
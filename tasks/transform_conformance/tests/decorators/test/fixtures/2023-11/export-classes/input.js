@@ -0,0 +1,3 @@
+export @dec class Foo {}
+
+export default @dec class {}
@@ -0,0 +1,19 @@
+var _Foo, _initClass, _default2, _initClass2;
+export class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass();
+  }
+}
+Foo = _Foo;
+export default class _default {
+  static {
+    [_default2, _initClass2] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass2();
+  }
+}
+_default = _default2;
@@ -0,0 +1,7 @@
+class Foo {
+  @dec a;
+  @dec b = 1;
+  c = 2;
+  @dec static d = 3;
+  static e = 4;
+}
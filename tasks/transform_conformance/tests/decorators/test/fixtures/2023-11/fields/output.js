@@ -0,0 +1,27 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b, _init_d, _init_extra_d;
+class Foo {
+  static {
+    [_init_d, _init_extra_d, _init_a, _init_extra_a, _init_b, _init_extra_b] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        0,
+        "a"
+      ],
+      [
+        dec,
+        0,
+        "b"
+      ],
+      [
+        dec,
+        8,
+        "d"
+      ]
+    ]).e;
+  }
+  a = _init_a(this);
+  b = (_init_extra_a(this), _init_b(this, 1));
+  c = (_init_extra_b(this), 2);
+  static d = _init_d(3);
+  static e = (_init_extra_d(), 4);
+}
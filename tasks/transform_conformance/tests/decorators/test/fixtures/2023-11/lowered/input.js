@@ -0,0 +1,5 @@
+@dec
+class Foo {
+  @dec accessor a = 1;
+  static accessor b;
+}
@@ -0,0 +1,29 @@
+var _Foo, _initClass, _init_a, _init_extra_a, _Foo2;
+var _A = /* @__PURE__ */ new WeakMap();
+class Foo {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _A, _init_a(this, 1));
+    _init_extra_a(this);
+  }
+  get a() {
+    return babelHelpers.classPrivateFieldGet2(_A, this);
+  }
+  set a(value) {
+    babelHelpers.classPrivateFieldSet2(_A, this, value);
+  }
+  static get b() {
+    return babelHelpers.assertClassBrand(Foo, this, _B)._;
+  }
+  static set b(value) {
+    _B._ = babelHelpers.assertClassBrand(Foo, this, value);
+  }
+}
+_Foo2 = Foo;
+({e: [_init_a, _init_extra_a], c: [_Foo, _initClass]} = babelHelpers.applyDecs2311(_Foo2, [dec], [[
+  dec,
+  1,
+  "a"
+]]));
+var _B = { _: void 0 };
+_initClass();
+Foo = _Foo;
@@ -0,0 +1,7 @@
+class Foo {
+  @dec method() {}
+  @dec get getter() { return 1; }
+  @dec set setter(v) {}
+  @dec static staticMethod() {}
+  value = 1;
+}
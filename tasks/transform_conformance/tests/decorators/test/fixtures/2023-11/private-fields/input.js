@@ -0,0 +1,4 @@
+class Foo {
+  @dec #a = 1;
+  @dec static #b;
+}
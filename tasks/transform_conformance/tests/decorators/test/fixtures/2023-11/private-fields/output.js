@@ -0,0 +1,26 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b;
+class Foo {
+  static {
+    [_init_b, _init_extra_b, _init_a, _init_extra_a] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      0,
+      "a",
+      (_) => _.#a,
+      (_, value) => _.#a = value
+    ], [
+      dec,
+      8,
+      "b",
+      (_) => _.#b,
+      (_, value) => _.#b = value
+    ]], 0, (_) => #a in _).e;
+  }
+  constructor() {
+    _init_extra_a(this);
+  }
+  #a = _init_a(this, 1);
+  static #b = _init_b();
+  static {
+    _init_extra_b();
+  }
+}
@@ -0,0 +1,5 @@
+class Foo {
+  @dec #method() { return 1; }
+  @dec get #getter() { return 2; }
+  @dec set #setter(v) {}
+}
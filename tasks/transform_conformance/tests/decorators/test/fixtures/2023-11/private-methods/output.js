@@ -0,0 +1,41 @@
+var _initProto, _call_method, _call_getter, _call_setter;
+class Foo {
+  static {
+    [_call_method, _call_getter, _call_setter, _initProto] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        2,
+        "method",
+        function() {
+          return 1;
+        }
+      ],
+      [
+        dec,
+        3,
+        "getter",
+        function() {
+          return 2;
+        }
+      ],
+      [
+        dec,
+        4,
+        "setter",
+        function(v) {}
+      ]
+    ], 0, (_) => #method in _).e;
+  }
+  constructor() {
+    _initProto(this);
+  }
+  get #method() {
+    return _call_method;
+  }
+  get #getter() {
+    return _call_getter(this);
+  }
+  set #setter(value) {
+    _call_setter(this, value);
+  }
+}
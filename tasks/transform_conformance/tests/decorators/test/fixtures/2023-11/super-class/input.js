@@ -0,0 +1,15 @@
+class Foo extends Bar {
+  @dec field = 1;
+}
+
+class Baz extends getBase() {
+  @dec field = 1;
+
+  constructor() {
+    if (cond) {
+      super();
+    } else {
+      super(1);
+    }
+  }
+}
@@ -0,0 +1,33 @@
+var _init_field, _init_extra_field, _superClass, _init_field2, _init_extra_field2;
+class Foo extends Bar {
+  static {
+    [_init_field, _init_extra_field] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      0,
+      "field"
+    ]], 0, void 0, Bar).e;
+  }
+  constructor(..._args) {
+    super(..._args);
+    _init_extra_field(this);
+  }
+  field = _init_field(this, 1);
+}
+_superClass = getBase();
+class Baz extends _superClass {
+  static {
+    [_init_field2, _init_extra_field2] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      0,
+      "field"
+    ]], 0, void 0, _superClass).e;
+  }
+  field = _init_field2(this, 1);
+  constructor() {
+    if (cond) {
+      _init_extra_field2(super());
+    } else {
+      _init_extra_field2(super(1));
+    }
+  }
+}
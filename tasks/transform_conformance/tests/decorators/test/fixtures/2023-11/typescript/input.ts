@@ -0,0 +1,6 @@
+class A {
+  @dec accessor x: number = 1;
+  @dec y!: string;
+  declare z: number;
+  @dec m(a: number): void {}
+}
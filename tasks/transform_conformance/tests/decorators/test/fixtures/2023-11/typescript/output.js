@@ -0,0 +1,34 @@
+var _initProto, _init_x, _init_extra_x, _init_y, _init_extra_y;
+class A {
+  static {
+    [_init_x, _init_extra_x, _init_y, _init_extra_y, _initProto] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        1,
+        "x"
+      ],
+      [
+        dec,
+        0,
+        "y"
+      ],
+      [
+        dec,
+        2,
+        "m"
+      ]
+    ]).e;
+  }
+  constructor() {
+    _init_extra_y(this);
+  }
+  #A = (_initProto(this), _init_x(this, 1));
+  get x() {
+    return this.#A;
+  }
+  set x(value) {
+    this.#A = value;
+  }
+  y = (_init_extra_x(this), _init_y(this));
+  m(a) {}
+}
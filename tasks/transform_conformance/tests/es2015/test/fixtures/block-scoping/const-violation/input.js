@@ -0,0 +1,18 @@
+const a = 1;
+a = 2;
+a += 3;
+a ||= 4;
+a++;
+--a;
+function f() {
+  const b = 1;
+  return () => {
+    b = 2;
+  };
+}
+{
+  const a = 5;
+  a = 6;
+}
+let c = 7;
+c = 8;
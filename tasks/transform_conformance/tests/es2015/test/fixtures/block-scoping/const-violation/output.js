@@ -0,0 +1,18 @@
+var a = 1;
+2, babelHelpers.readOnlyError("a");
+a + 3, babelHelpers.readOnlyError("a");
+a || (4, babelHelpers.readOnlyError("a"));
++a, babelHelpers.readOnlyError("a");
++a, babelHelpers.readOnlyError("a");
+function f() {
+  var b = 1;
+  return () => {
+    2, babelHelpers.readOnlyError("b");
+  };
+}
+{
+  var _a = 5;
+  6, babelHelpers.readOnlyError("a");
+}
+var c = 7;
+c = 8;
@@ -0,0 +1,15 @@
+const fns = [];
+for (let i = 0; i < 3; i++) {
+  fns.push(() => i);
+}
+for (const key in obj) {
+  const value = obj[key];
+  fns.push(function () {
+    return key + value;
+  });
+}
+for (const item of items) fns.push(() => item);
+while (fns.length < 10) {
+  let n = fns.length;
+  fns.push(() => n);
+}
@@ -0,0 +1,29 @@
+var fns = [];
+var _loop = function(i) {
+  fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+var _loop2 = function(key) {
+  var value = obj[key];
+  fns.push(function() {
+    return key + value;
+  });
+};
+for (var key in obj) {
+  _loop2(key);
+}
+var _loop3 = function(item) {
+  fns.push(() => item);
+};
+for (var item of items) {
+  _loop3(item);
+}
+var _loop4 = function() {
+  var n = fns.length;
+  fns.push(() => n);
+};
+while (fns.length < 10) {
+  _loop4();
+}
@@ -0,0 +1,7 @@
+for (let i = 0; i < 10; i++) {
+  if (i % 2) {
+    i++;
+    continue;
+  }
+  setTimeout(() => console.log(i));
+}
@@ -0,0 +1,11 @@
+var _loop = function(_i) {
+  if (_i % 2) {
+    _i++;
+    return void (i = _i);
+  }
+  setTimeout(() => console.log(_i));
+  i = _i;
+};
+for (var i = 0; i < 10; i++) {
+  _loop(i);
+}
@@ -0,0 +1,19 @@
+function f(items) {
+  outer: for (const item of items) {
+    for (let i = 0; i < item.length; i++) {
+      if (i === 1) continue;
+      if (i === 2) break;
+      if (i === 3) continue outer;
+      if (i === 4) break outer;
+      if (i === 5) return item;
+      switch (i) {
+        case 6:
+          break;
+      }
+      inner: for (;;) {
+        break inner;
+      }
+      later(() => i);
+    }
+  }
+}
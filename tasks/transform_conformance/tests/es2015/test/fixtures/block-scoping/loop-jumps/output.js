@@ -0,0 +1,25 @@
+function f(items) {
+  outer: for (var item of items) {
+    var _loop = function(i) {
+      if (i === 1) return;
+      if (i === 2) return 1;
+      if (i === 3) return 2;
+      if (i === 4) return 3;
+      if (i === 5) return { v: item };
+      switch (i) {
+        case 6: break;
+      }
+      inner: for (;;) {
+        break inner;
+      }
+      later(() => i);
+    };
+    for (var i = 0; i < item.length; i++) {
+      var _ret = _loop(i);
+      if (_ret === 1) break;
+      if (_ret === 2) continue outer;
+      if (_ret === 3) break outer;
+      if (_ret) return _ret.v;
+    }
+  }
+}
@@ -0,0 +1,5 @@
+function f() {
+  for (let i = 0; i < 3; i++) {
+    this.fns.push(() => i + arguments[0]);
+  }
+}
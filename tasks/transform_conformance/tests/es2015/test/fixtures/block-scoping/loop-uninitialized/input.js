@@ -0,0 +1,5 @@
+for (let i = 0; i < 3; i++) {
+  let x;
+  let y = i, z;
+  x = y + z;
+}
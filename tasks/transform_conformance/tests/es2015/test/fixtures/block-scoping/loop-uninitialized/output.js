@@ -0,0 +1,5 @@
+for (var i = 0; i < 3; i++) {
+  var x = void 0;
+  var y = i, z = void 0;
+  x = y + z;
+}
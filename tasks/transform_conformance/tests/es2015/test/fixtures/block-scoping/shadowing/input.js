@@ -0,0 +1,15 @@
+let a = 1;
+const b = 2;
+{
+  let a = 3;
+  const b = a;
+  console.log(a, b);
+}
+function f(a) {
+  if (a) {
+    let a = 4;
+    return a;
+  }
+  for (let b = 0; b < a; b++) {}
+  return b;
+}
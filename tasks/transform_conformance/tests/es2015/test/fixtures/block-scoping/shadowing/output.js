@@ -0,0 +1,15 @@
+var a = 1;
+var b = 2;
+{
+  var _a = 3;
+  var _b = _a;
+  console.log(_a, _b);
+}
+function f(a) {
+  if (a) {
+    var _a2 = 4;
+    return _a2;
+  }
+  for (var _b2 = 0; _b2 < a; _b2++) {}
+  return b;
+}
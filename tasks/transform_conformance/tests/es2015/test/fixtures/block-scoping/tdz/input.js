@@ -0,0 +1,11 @@
+console.log(a);
+let a = 1;
+function f() {
+  return b;
+}
+let b = 2;
+{
+  c = 3;
+  const d = c;
+  let c;
+}
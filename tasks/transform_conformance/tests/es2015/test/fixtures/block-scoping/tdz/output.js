@@ -0,0 +1,11 @@
+console.log(babelHelpers.tdz("a"));
+var a = 1;
+function f() {
+  return b;
+}
+var b = 2;
+{
+  c = 3;
+  var d = babelHelpers.tdz("c");
+  var c;
+}
@@ -0,0 +1,16 @@
+const name = "world";
+const greeting = `hello ${name}`;
+const obj = {
+  name,
+  [greeting]: 1,
+  greet() {
+    return typeof this.name;
+  },
+  handler: function () {},
+};
+function Foo(...args) {
+  if (!new.target) throw new Error(`${args.length}`);
+}
+for (const [key, value] of Object.entries(obj)) {
+  console.log(key, value, 0b101);
+}
@@ -0,0 +1,24 @@
+var _obj;
+var name = "world";
+var greeting = "hello ".concat(name);
+var obj = (_obj = { name: name }, babelHelpers.defineProperty(_obj, greeting, 1), babelHelpers.defineProperty(_obj, "greet", function greet() {
+  return babelHelpers.typeof(this.name);
+}), babelHelpers.defineProperty(_obj, "handler", function handler() {}), _obj);
+function Foo() {
+  for (var _len = arguments.length, args = new Array(_len), _key = 0; _key < _len; _key++) {
+    args[_key] = arguments[_key];
+  }
+  if (!(this instanceof Foo ? this.constructor : void 0)) throw new Error("".concat(args.length));
+}
+var _iterator = babelHelpers.createForOfIteratorHelper(Object.entries(obj)), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    var _ref = _step.value;
+    var _ref2 = babelHelpers.slicedToArray(_ref, 2), key = _ref2[0], value = _ref2[1];
+    console.log(key, value, 5);
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
@@ -0,0 +1,9 @@
+class A {
+  get x() {
+    return this._x;
+  }
+  set x(v) {
+    this._x = v;
+  }
+  [key]() {}
+}
@@ -0,0 +1,17 @@
+let A = /*#__PURE__*/function () {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A, [{
+    key: "x",
+    get: function () {
+      return this._x;
+    },
+    set: function (v) {
+      this._x = v;
+    }
+  }, {
+    key: key,
+    value: function () {}
+  }]);
+}();
@@ -0,0 +1,9 @@
+class A {
+  constructor(x) {
+    this.x = x;
+  }
+  foo() {
+    return A;
+  }
+  static bar() {}
+}
@@ -0,0 +1,15 @@
+let A = /*#__PURE__*/function () {
+  function A(x) {
+    babelHelpers.classCallCheck(this, A);
+    this.x = x;
+  }
+  return babelHelpers.createClass(A, [{
+    key: "foo",
+    value: function foo() {
+      return A;
+    }
+  }], [{
+    key: "bar",
+    value: function bar() {}
+  }]);
+}();
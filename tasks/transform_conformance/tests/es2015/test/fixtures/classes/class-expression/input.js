@@ -0,0 +1,6 @@
+const A = class B {
+  foo() {
+    return B;
+  }
+};
+const C = class {};
@@ -0,0 +1,17 @@
+const A = /*#__PURE__*/function () {
+  function B() {
+    babelHelpers.classCallCheck(this, B);
+  }
+  return babelHelpers.createClass(B, [{
+    key: "foo",
+    value: function foo() {
+      return B;
+    }
+  }]);
+}();
+const C = /*#__PURE__*/function () {
+  function _Class() {
+    babelHelpers.classCallCheck(this, _Class);
+  }
+  return babelHelpers.createClass(_Class);
+}();
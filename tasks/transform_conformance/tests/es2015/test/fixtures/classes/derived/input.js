@@ -0,0 +1,16 @@
+class A extends B {
+  constructor(x) {
+    super(x);
+    this.x = x;
+  }
+}
+class C extends B {
+  constructor() {
+    if (foo) {
+      super();
+      return;
+    }
+    return {};
+  }
+}
+class D extends B {}
@@ -0,0 +1,32 @@
+let A = /*#__PURE__*/function (_B) {
+  function A(x) {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A, [x]);
+    _this.x = x;
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+let C = /*#__PURE__*/function (_B2) {
+  function C() {
+    var _this2;
+    babelHelpers.classCallCheck(this, C);
+    if (foo) {
+      _this2 = babelHelpers.callSuper(this, C);
+      return babelHelpers.assertThisInitialized(_this2);
+    }
+    return babelHelpers.possibleConstructorReturn(_this2, {});
+  }
+  babelHelpers.inherits(C, _B2);
+  return babelHelpers.createClass(C);
+}(B);
+let D = /*#__PURE__*/function (_B3) {
+  function D() {
+    babelHelpers.classCallCheck(this, D);
+    return babelHelpers.callSuper(this, D, arguments);
+  }
+  babelHelpers.inherits(D, _B3);
+  return babelHelpers.createClass(D);
+}(B);
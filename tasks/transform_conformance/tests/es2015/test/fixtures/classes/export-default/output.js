@@ -0,0 +1,13 @@
+let A = /*#__PURE__*/function () {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A);
+}();
+export { A as default };
+export let B = /*#__PURE__*/function () {
+  function B() {
+    babelHelpers.classCallCheck(this, B);
+  }
+  return babelHelpers.createClass(B);
+}();
@@ -0,0 +1,11 @@
+class A extends B {
+  foo(a) {
+    super.foo(a);
+    super[a] = 1;
+    super.bar += 2;
+    return super.baz;
+  }
+  static qux() {
+    return super.qux();
+  }
+}
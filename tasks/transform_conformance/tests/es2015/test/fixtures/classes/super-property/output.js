@@ -0,0 +1,21 @@
+let A = /*#__PURE__*/function (_B) {
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    return babelHelpers.callSuper(this, A, arguments);
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "foo",
+    value: function foo(a) {
+      babelHelpers.superPropGet(A, "foo", this, 3)([a]);
+      babelHelpers.superPropSet(A, a, 1, this, 1, 1);
+      babelHelpers.superPropSet(A, "bar", babelHelpers.superPropGet(A, "bar", this, 1) + 2, this, 1, 1);
+      return babelHelpers.superPropGet(A, "baz", this, 1);
+    }
+  }], [{
+    key: "qux",
+    value: function qux() {
+      return babelHelpers.superPropGet(A, "qux", this, 2)([]);
+    }
+  }]);
+}(B);
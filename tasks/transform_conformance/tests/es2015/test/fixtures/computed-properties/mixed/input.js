@@ -0,0 +1,9 @@
+var a = {
+  b: 1,
+  [c]: 2,
+  d: 3,
+  get [e]() {
+    return 4;
+  },
+  set [e](v) {}
+};
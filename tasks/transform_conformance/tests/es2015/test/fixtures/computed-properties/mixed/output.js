@@ -0,0 +1,4 @@
+var _obj;
+var a = (_obj = { b: 1 }, babelHelpers.defineProperty(_obj, c, 2), babelHelpers.defineProperty(_obj, "d", 3), babelHelpers.defineAccessor("get", _obj, e, function() {
+  return 4;
+}), babelHelpers.defineAccessor("set", _obj, e, function(v) {}), _obj);
@@ -0,0 +1 @@
+var a = babelHelpers.defineProperty({}, b, babelHelpers.defineProperty({}, c, 1));
@@ -0,0 +1 @@
+var a = babelHelpers.defineProperty({}, b, 1);
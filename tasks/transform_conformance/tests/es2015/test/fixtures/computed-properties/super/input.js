@@ -0,0 +1,20 @@
+var a = {
+  [b]: 1,
+  c() {
+    return super.c();
+  }
+};
+var d = {
+  [e]: 1,
+  get f() {
+    return super.f;
+  }
+};
+var g = {
+  h() {
+    return super.h();
+  },
+  [i]() {
+    return function () {};
+  }
+};
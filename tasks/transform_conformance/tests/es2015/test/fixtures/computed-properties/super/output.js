@@ -0,0 +1,19 @@
+var a = {
+  [b]: 1,
+  c() {
+    return super.c();
+  }
+};
+var d = {
+  [e]: 1,
+  get f() {
+    return super.f;
+  }
+};
+var g = babelHelpers.defineProperty({
+  h() {
+    return super.h();
+  }
+}, i, function () {
+  return function () {};
+});
@@ -0,0 +1,6 @@
+[a, b] = [b, a];
+({ c, d: { e } } = obj);
+var f = ({ g } = obj);
+console.log([h, i] = arr);
+({ j = 1, k: l = 2 } = obj);
+[m.n, o[p]] = arr;
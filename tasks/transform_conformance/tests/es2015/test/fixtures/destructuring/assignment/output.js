@@ -0,0 +1,7 @@
+var _ref, _arr, _obj$j, _obj$k, _arr2;
+_ref = babelHelpers.slicedToArray([b, a], 2), a = _ref[0], b = _ref[1];
+c = obj.c, e = obj.d.e;
+var f = (g = obj.g, obj);
+console.log((_arr = babelHelpers.slicedToArray(arr, 2), h = _arr[0], i = _arr[1], arr));
+_obj$j = obj.j, j = _obj$j === void 0 ? 1 : _obj$j, _obj$k = obj.k, l = _obj$k === void 0 ? 2 : _obj$k;
+_arr2 = babelHelpers.slicedToArray(arr, 2), m.n = _arr2[0], o[p] = _arr2[1];
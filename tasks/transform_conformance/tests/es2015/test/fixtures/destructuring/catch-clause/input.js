@@ -0,0 +1,5 @@
+try {
+  foo();
+} catch ({ message, stack: [first] }) {
+  console.log(message, first);
+}
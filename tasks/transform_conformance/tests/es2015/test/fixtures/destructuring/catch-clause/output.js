@@ -0,0 +1,6 @@
+try {
+  foo();
+} catch (_ref) {
+  let message = _ref.message, _ref$stack = babelHelpers.slicedToArray(_ref.stack, 1), first = _ref$stack[0];
+  console.log(message, first);
+}
@@ -0,0 +1,6 @@
+var { a, b: { c = 1 } } = obj;
+var [d, , e = 2] = arr;
+let [f, ...g] = getArr();
+const { "h-i": h, [key]: j } = obj;
+var {} = empty;
+var x = 1, [y] = arr;
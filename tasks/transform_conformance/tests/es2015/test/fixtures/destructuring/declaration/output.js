@@ -0,0 +1,6 @@
+var a = obj.a, _obj$b$c = obj.b.c, c = _obj$b$c === void 0 ? 1 : _obj$b$c;
+var _arr = babelHelpers.slicedToArray(arr, 3), d = _arr[0], _arr$ = _arr[2], e = _arr$ === void 0 ? 2 : _arr$;
+let _getArr = babelHelpers.toArray(getArr()), f = _getArr[0], g = _getArr.slice(1);
+const h = obj["h-i"], j = obj[key];
+var _ref = babelHelpers.objectDestructuringEmpty(empty);
+var x = 1, _arr2 = babelHelpers.slicedToArray(arr, 1), y = _arr2[0];
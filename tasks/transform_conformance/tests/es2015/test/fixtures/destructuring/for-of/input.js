@@ -0,0 +1,6 @@
+for (const [key, value] of Object.entries(obj)) {
+  console.log(key, value);
+}
+for (var { a, b } of items) console.log(a, b);
+for ([c, d] of pairs);
+for (let { length } in obj) {}
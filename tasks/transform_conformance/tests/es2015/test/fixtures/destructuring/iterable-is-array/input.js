@@ -0,0 +1,4 @@
+var [a, b] = arr;
+var [c, ...d] = getArr();
+var [e] = arr;
+[f, g] = [g, f];
@@ -0,0 +1,5 @@
+var _ref;
+var a = arr[0], b = arr[1];
+var _getArr = getArr(), c = _getArr[0], d = _getArr.slice(1);
+var e = arr[0];
+_ref = [g, f], f = _ref[0], g = _ref[1];
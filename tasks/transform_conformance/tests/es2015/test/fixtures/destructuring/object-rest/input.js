@@ -0,0 +1,4 @@
+var { a, ...b } = obj;
+var { [key]: c, ...d } = obj;
+var { ...e } = obj;
+({ f, ...g } = obj);
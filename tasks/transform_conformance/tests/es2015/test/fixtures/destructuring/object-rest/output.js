@@ -0,0 +1,4 @@
+var a = obj.a, b = babelHelpers.objectWithoutProperties(obj, ["a"]);
+var _key = key, c = obj[_key], d = babelHelpers.objectWithoutProperties(obj, [_key].map(babelHelpers.toPropertyKey));
+var e = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(obj), obj));
+f = obj.f, g = babelHelpers.objectWithoutProperties(obj, ["f"]);
@@ -0,0 +1,15 @@
+function f({ a, b }, [c, d] = [], ...[e]) {
+  return a + b + c + d + e;
+}
+var g = function ({ h }) {
+  return h;
+};
+var i = ({ j }) => j;
+var k = ([l]) => {
+  return l;
+};
+class C {
+  method({ m }) {
+    return m;
+  }
+}
@@ -0,0 +1,24 @@
+function f(_ref, _ref2 = [], ..._ref4) {
+  var a = _ref.a, b = _ref.b;
+  var _ref3 = babelHelpers.slicedToArray(_ref2, 2), c = _ref3[0], d = _ref3[1];
+  var _ref5 = babelHelpers.slicedToArray(_ref4, 1), e = _ref5[0];
+  return a + b + c + d + e;
+}
+var g = function(_ref6) {
+  var h = _ref6.h;
+  return h;
+};
+var i = (_ref7) => {
+  var j = _ref7.j;
+  return j;
+};
+var k = (_ref8) => {
+  var _ref9 = babelHelpers.slicedToArray(_ref8, 1), l = _ref9[0];
+  return l;
+};
+class C {
+  method(_ref10) {
+    var m = _ref10.m;
+    return m;
+  }
+}
@@ -0,0 +1,11 @@
+var a = {
+  b: 1,
+  b: 2
+};
+var c = {
+  d: 1,
+  "d": 2,
+  get e() {},
+  set e(v) {},
+  get e() {}
+};
@@ -0,0 +1,4 @@
+for (const x of [1, 2, 3]) {
+  console.log(x);
+}
+for (let y of []) y();
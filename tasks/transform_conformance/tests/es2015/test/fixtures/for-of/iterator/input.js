@@ -0,0 +1,4 @@
+for (const x of items) {
+  console.log(x);
+}
+for (x of items);
@@ -0,0 +1,21 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(items), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    const x = _step.value;
+    console.log(x);
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
+var _iterator2 = babelHelpers.createForOfIteratorHelper(items), _step2;
+try {
+  for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    x = _step2.value;
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
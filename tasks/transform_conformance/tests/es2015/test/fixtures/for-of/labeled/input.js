@@ -0,0 +1,6 @@
+outer: for (const x of items) {
+  for (const y of x) {
+    if (y) continue outer;
+    break outer;
+  }
+}
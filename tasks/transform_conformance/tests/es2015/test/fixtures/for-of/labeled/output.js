@@ -0,0 +1,22 @@
+var _iterator2 = babelHelpers.createForOfIteratorHelper(items), _step2;
+try {
+  outer: for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    const x = _step2.value;
+    var _iterator = babelHelpers.createForOfIteratorHelper(x), _step;
+    try {
+      for (_iterator.s(); !(_step = _iterator.n()).done;) {
+        const y = _step.value;
+        if (y) continue outer;
+        break outer;
+      }
+    } catch (err) {
+      _iterator.e(err);
+    } finally {
+      _iterator.f();
+    }
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
@@ -0,0 +1,4 @@
+for (const x of items) {
+  let x = 1;
+  x++;
+}
@@ -0,0 +1,13 @@
+if (cond) {
+  var _iterator = babelHelpers.createForOfIteratorHelper(items), _step;
+  try {
+    for (_iterator.s(); !(_step = _iterator.n()).done;) {
+      const x = _step.value;
+      x();
+    }
+  } catch (err) {
+    _iterator.e(err);
+  } finally {
+    _iterator.f();
+  }
+}
@@ -0,0 +1,8 @@
+var foo = function () {};
+bar = function () {};
+var obj = {
+  baz: function () {},
+  "qux": function () {},
+  "foo-bar": function () {},
+  delete: function () {}
+};
@@ -0,0 +1,8 @@
+var foo = function foo() {};
+bar = function bar() {};
+var obj = {
+  baz: function baz() {},
+  "qux": function qux() {},
+  "foo-bar": function() {},
+  delete: function() {}
+};
@@ -0,0 +1,8 @@
+var foo = function () {
+  return foo;
+};
+var bar = function () {
+  var bar = 1;
+  return bar;
+};
+var baz = function named() {};
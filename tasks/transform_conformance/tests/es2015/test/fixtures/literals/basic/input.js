@@ -0,0 +1,4 @@
+var a = 0b11;
+var b = 0O17;
+var c = "\u{1F600}";
+var d = 0x1F;
@@ -0,0 +1,4 @@
+var a = 3;
+var b = 15;
+var c = "😀";
+var d = 31;
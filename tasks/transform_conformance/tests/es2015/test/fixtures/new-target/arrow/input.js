@@ -0,0 +1,3 @@
+function Foo() {
+  var f = () => new.target;
+}
@@ -0,0 +1,3 @@
+function Foo() {
+  var f = () => this instanceof Foo ? this.constructor : void 0;
+}
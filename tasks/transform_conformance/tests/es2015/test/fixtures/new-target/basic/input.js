@@ -0,0 +1,19 @@
+function Foo() {
+  console.log(new.target);
+}
+var bar = function () {
+  console.log(new.target);
+};
+class Baz {
+  constructor() {
+    console.log(new.target);
+  }
+  method() {
+    console.log(new.target);
+  }
+}
+var obj = {
+  method() {
+    return new.target;
+  }
+};
@@ -0,0 +1,17 @@
+function Foo() {
+  console.log(this instanceof Foo ? this.constructor : void 0);
+}
+var bar = function _target() {
+  console.log(this instanceof _target ? this.constructor : void 0);
+};
+class Baz {
+  constructor() {
+    console.log(this.constructor);
+  }
+  method() {
+    console.log(void 0);
+  }
+}
+var obj = { method() {
+  return void 0;
+} };
@@ -0,0 +1,5 @@
+var a = (b = 1) => b;
+var c = (d, ...e) => e;
+var f = (g, { h } = {}) => {
+  return h;
+};
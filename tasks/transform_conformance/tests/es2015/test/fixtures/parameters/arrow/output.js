@@ -0,0 +1,14 @@
+var a = (b) => {
+  if (b === void 0) b = 1;
+  return b;
+};
+var c = function(d) {
+  for (var _len = arguments.length, e = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    e[_key - 1] = arguments[_key];
+  }
+  return e;
+};
+var f = (g, _ref) => {
+  var { h } = _ref === void 0 ? {} : _ref;
+  return h;
+};
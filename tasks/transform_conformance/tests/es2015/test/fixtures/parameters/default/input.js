@@ -0,0 +1,9 @@
+function a(b, c = 1, d) {
+  return b + c + d;
+}
+function e(f = {}, { g } = f) {
+  return g;
+}
+var h = function (i = 1) {
+  return i;
+};
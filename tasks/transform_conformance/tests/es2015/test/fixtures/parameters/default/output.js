@@ -0,0 +1,14 @@
+function a(b) {
+  var c = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+  var d = arguments.length > 2 ? arguments[2] : void 0;
+  return b + c + d;
+}
+function e() {
+  var f = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : {};
+  var { g } = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : f;
+  return g;
+}
+var h = function() {
+  var i = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : 1;
+  return i;
+};
@@ -0,0 +1,4 @@
+function a({ b } = {}, [c], ...[d]) {
+  return b + c + d;
+}
+var e = ({ f } = {}) => f;
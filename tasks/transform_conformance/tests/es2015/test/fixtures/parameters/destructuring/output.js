@@ -0,0 +1,16 @@
+function a() {
+  var _ref = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : {};
+  var _ref2 = arguments.length > 1 ? arguments[1] : void 0;
+  for (var _len = arguments.length, _ref4 = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+    _ref4[_key - 2] = arguments[_key];
+  }
+  var b = _ref.b;
+  var _ref3 = babelHelpers.slicedToArray(_ref2, 1), c = _ref3[0];
+  var _ref5 = babelHelpers.slicedToArray(_ref4, 1), d = _ref5[0];
+  return b + c + d;
+}
+var e = (_ref6) => {
+  if (_ref6 === void 0) _ref6 = {};
+  var f = _ref6.f;
+  return f;
+};
@@ -0,0 +1,3 @@
+function a(b, c = 1, { d } = {}) {
+  return b + c + d;
+}
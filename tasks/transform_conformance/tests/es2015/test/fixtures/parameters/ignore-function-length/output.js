@@ -0,0 +1,5 @@
+function a(b, c, _ref) {
+  if (c === void 0) c = 1;
+  var { d } = _ref === void 0 ? {} : _ref;
+  return b + c + d;
+}
@@ -0,0 +1,12 @@
+function a(...b) {
+  return b;
+}
+function c(d, e, ...f) {
+  return f;
+}
+function g(h = 1, ...i) {
+  return i;
+}
+function j(...[k, l]) {
+  return k + l;
+}
@@ -0,0 +1,26 @@
+function a() {
+  for (var _len = arguments.length, b = new Array(_len), _key = 0; _key < _len; _key++) {
+    b[_key] = arguments[_key];
+  }
+  return b;
+}
+function c(d, e) {
+  for (var _len2 = arguments.length, f = new Array(_len2 > 2 ? _len2 - 2 : 0), _key2 = 2; _key2 < _len2; _key2++) {
+    f[_key2 - 2] = arguments[_key2];
+  }
+  return f;
+}
+function g() {
+  var h = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : 1;
+  for (var _len3 = arguments.length, i = new Array(_len3 > 1 ? _len3 - 1 : 0), _key3 = 1; _key3 < _len3; _key3++) {
+    i[_key3 - 1] = arguments[_key3];
+  }
+  return i;
+}
+function j() {
+  for (var _len4 = arguments.length, _ref = new Array(_len4), _key4 = 0; _key4 < _len4; _key4++) {
+    _ref[_key4] = arguments[_key4];
+  }
+  var [k, l] = _ref;
+  return k + l;
+}
@@ -0,0 +1,19 @@
+function* gen(x) {
+  var y = yield x;
+  yield;
+  return y + 1;
+}
+
+var expr = function* () {
+  yield 1;
+};
+
+var named = function* named() {
+  yield named;
+};
+
+function* empty() {}
+
+function* noYield(a) {
+  console.log(a);
+}
@@ -0,0 +1,57 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen), _marked2 = babelHelpers.regeneratorRuntime().mark(empty), _marked3 = babelHelpers.regeneratorRuntime().mark(noYield);
+function gen(x) {
+  var y;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return x;
+      case 2:
+        y = _context.sent;
+        _context.next = 5;
+        return;
+      case 5: return _context.abrupt("return", y + 1);
+      case 6:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+var expr = babelHelpers.regeneratorRuntime().mark(function _callee() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.next = 2;
+        return 1;
+      case 2:
+      case "end": return _context2.stop();
+    }
+  }, _callee);
+});
+var named = babelHelpers.regeneratorRuntime().mark(function named() {
+  return babelHelpers.regeneratorRuntime().wrap(function named$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.next = 2;
+        return named;
+      case 2:
+      case "end": return _context3.stop();
+    }
+  }, named);
+});
+function empty() {
+  return babelHelpers.regeneratorRuntime().wrap(function empty$(_context4) {
+    while (1) switch (_context4.prev = _context4.next) {
+      case 0:
+      case "end": return _context4.stop();
+    }
+  }, _marked2);
+}
+function noYield(a) {
+  return babelHelpers.regeneratorRuntime().wrap(function noYield$(_context5) {
+    while (1) switch (_context5.prev = _context5.next) {
+      case 0: console.log(a);
+      case 1:
+      case "end": return _context5.stop();
+    }
+  }, _marked3);
+}
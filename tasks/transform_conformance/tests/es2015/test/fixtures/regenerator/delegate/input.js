@@ -0,0 +1,5 @@
+function* gen() {
+  yield* inner();
+  var result = yield* inner();
+  return result;
+}
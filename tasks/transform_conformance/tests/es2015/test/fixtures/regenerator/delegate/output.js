@@ -0,0 +1,15 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  var result;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: return _context.delegateYield(inner(), "t0", 1);
+      case 1: return _context.delegateYield(inner(), "t1", 2);
+      case 2:
+        result = _context.t1;
+        return _context.abrupt("return", result);
+      case 4:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,15 @@
+function* gen(obj) {
+  foo(a, yield b);
+  obj.method(yield 1, 2);
+  obj[key](yield);
+  var x = (yield 1) + (yield 2);
+  var y = a && (yield b);
+  var z = a ? yield b : c;
+  x += yield;
+  obj.prop *= yield;
+  var o = { a: yield 1, [yield 2]: 3, ...(yield 4) };
+  var arr = [yield 1, , ...(yield 2)];
+  var s = `a${yield 1}b`;
+  new Foo(yield);
+  typeof (yield);
+}
@@ -0,0 +1,118 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen(obj) {
+  var x, y, z, o, arr, s;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.t0 = foo;
+        _context.t1 = a;
+        _context.next = 4;
+        return b;
+      case 4:
+        _context.t2 = _context.sent;
+        (0, _context.t0)(_context.t1, _context.t2);
+        _context.t3 = obj;
+        _context.next = 9;
+        return 1;
+      case 9:
+        _context.t4 = _context.sent;
+        _context.t3.method.call(_context.t3, _context.t4, 2);
+        _context.t5 = obj;
+        _context.t6 = key;
+        _context.next = 15;
+        return;
+      case 15:
+        _context.t7 = _context.sent;
+        _context.t5[_context.t6].call(_context.t5, _context.t7);
+        _context.next = 19;
+        return 1;
+      case 19:
+        _context.t8 = _context.sent;
+        _context.next = 22;
+        return 2;
+      case 22:
+        _context.t9 = _context.sent;
+        x = _context.t8 + _context.t9;
+        _context.t10 = a;
+        if (!_context.t10) {
+          _context.next = 29;
+          break;
+        }
+        _context.next = 28;
+        return b;
+      case 28: _context.t10 = _context.sent;
+      case 29:
+        y = _context.t10;
+        if (!a) {
+          _context.next = 36;
+          break;
+        }
+        _context.next = 33;
+        return b;
+      case 33:
+        _context.t11 = _context.sent;
+        _context.next = 37;
+        break;
+      case 36: _context.t11 = c;
+      case 37:
+        z = _context.t11;
+        _context.t12 = x;
+        _context.next = 41;
+        return;
+      case 41:
+        x = _context.t12 + _context.sent;
+        _context.t13 = obj;
+        _context.t14 = _context.t13.prop;
+        _context.next = 46;
+        return;
+      case 46:
+        _context.t13.prop = _context.t14 * _context.sent;
+        _context.next = 49;
+        return 1;
+      case 49:
+        _context.t15 = _context.sent;
+        _context.next = 52;
+        return 2;
+      case 52:
+        _context.t16 = _context.sent;
+        _context.next = 55;
+        return 4;
+      case 55:
+        _context.t17 = _context.sent;
+        o = {
+          a: _context.t15,
+          [_context.t16]: 3,
+          ..._context.t17
+        };
+        _context.next = 59;
+        return 1;
+      case 59:
+        _context.t18 = _context.sent;
+        _context.next = 62;
+        return 2;
+      case 62:
+        _context.t19 = _context.sent;
+        arr = [
+          _context.t18,
+          ,
+          ..._context.t19
+        ];
+        _context.next = 66;
+        return 1;
+      case 66:
+        _context.t20 = _context.sent;
+        s = `a${_context.t20}b`;
+        _context.t21 = Foo;
+        _context.next = 71;
+        return;
+      case 71:
+        _context.t22 = _context.sent;
+        new _context.t21(_context.t22);
+        _context.next = 75;
+        return;
+      case 75: typeof _context.sent;
+      case 76:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
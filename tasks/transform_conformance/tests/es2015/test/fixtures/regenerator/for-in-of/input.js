@@ -0,0 +1,8 @@
+function* gen(obj, arr) {
+  for (var key in obj) {
+    yield key;
+  }
+  for (const item of arr) {
+    yield item;
+  }
+}
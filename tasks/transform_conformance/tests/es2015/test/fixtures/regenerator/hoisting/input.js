@@ -0,0 +1,18 @@
+function* gen(a) {
+  var a = 1;
+  let b = 2;
+  {
+    let b = yield a;
+    const c = b;
+    console.log(b, c);
+  }
+  class C {}
+  var { d, e: [f] } = yield;
+  console.log(arguments, () => [this, arguments]);
+  function inner() {
+    return arguments;
+  }
+  {
+    function nested() {}
+  }
+}
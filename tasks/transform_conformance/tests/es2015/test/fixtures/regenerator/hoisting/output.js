@@ -0,0 +1,30 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen(a) {
+  function inner() {
+    return arguments;
+  }
+  function nested() {}
+  var b, _b, c, C, d, f, _args = arguments;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        a = 1;
+        b = 2;
+        _context.next = 4;
+        return a;
+      case 4:
+        _b = _context.sent;
+        c = _b;
+        console.log(_b, c);
+        C = class {};
+        _context.next = 10;
+        return;
+      case 10:
+        ({d: d, e: [f]} = _context.sent);
+        console.log(_args, () => [this, _args]);
+        {}
+      case 13:
+      case "end": return _context.stop();
+    }
+  }, _marked, this);
+}
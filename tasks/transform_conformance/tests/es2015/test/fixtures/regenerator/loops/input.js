@@ -0,0 +1,19 @@
+function* loops(n) {
+  while (n > 0) {
+    yield n--;
+  }
+  do {
+    yield n++;
+  } while (n < 3);
+  for (var i = 0; i < n; i++) {
+    if (i === 1) continue;
+    if (i === 2) break;
+    yield i;
+  }
+  outer: for (;;) {
+    for (;;) {
+      if (yield) break outer;
+      continue outer;
+    }
+  }
+}
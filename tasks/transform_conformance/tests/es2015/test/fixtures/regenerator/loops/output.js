@@ -0,0 +1,67 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(loops);
+function loops(n) {
+  var i;
+  return babelHelpers.regeneratorRuntime().wrap(function loops$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        if (!(n > 0)) {
+          _context.next = 5;
+          break;
+        }
+        _context.next = 3;
+        return n--;
+      case 3:
+        _context.next = 0;
+        break;
+      case 5:
+        _context.next = 7;
+        return n++;
+      case 7: if (n < 3) {
+        _context.next = 5;
+        break;
+      }
+      case 8: i = 0;
+      case 9:
+        if (!(i < n)) {
+          _context.next = 19;
+          break;
+        }
+        if (!(i === 1)) {
+          _context.next = 12;
+          break;
+        }
+        return _context.abrupt("continue", 16);
+      case 12:
+        if (!(i === 2)) {
+          _context.next = 14;
+          break;
+        }
+        return _context.abrupt("break", 19);
+      case 14:
+        _context.next = 16;
+        return i;
+      case 16:
+        i++;
+        _context.next = 9;
+        break;
+      case 19:
+        _context.next = 21;
+        return;
+      case 21:
+        if (!_context.sent) {
+          _context.next = 23;
+          break;
+        }
+        return _context.abrupt("break", 28);
+      case 23: return _context.abrupt("continue", 26);
+      case 24:
+        _context.next = 19;
+        break;
+      case 26:
+        _context.next = 19;
+        break;
+      case 28:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
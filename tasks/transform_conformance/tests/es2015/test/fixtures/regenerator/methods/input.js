@@ -0,0 +1,21 @@
+var obj = {
+  *method() {
+    yield this;
+  },
+};
+
+class Foo {
+  *method() {
+    yield 1;
+  }
+  static *[Symbol.iterator]() {
+    yield 2;
+  }
+}
+
+function* outer() {
+  function* innerGen() {
+    yield 1;
+  }
+  yield* innerGen();
+}
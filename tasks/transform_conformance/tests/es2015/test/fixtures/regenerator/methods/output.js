@@ -0,0 +1,59 @@
+var _marked2 = babelHelpers.regeneratorRuntime().mark(outer);
+var obj = { method() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return this;
+      case 2:
+      case "end": return _context.stop();
+    }
+  }, null, this);
+} };
+class Foo {
+  method() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 2;
+          return 1;
+        case 2:
+        case "end": return _context2.stop();
+      }
+    }, null);
+  }
+  static [Symbol.iterator]() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context3) {
+      while (1) switch (_context3.prev = _context3.next) {
+        case 0:
+          _context3.next = 2;
+          return 2;
+        case 2:
+        case "end": return _context3.stop();
+      }
+    }, null);
+  }
+}
+function outer() {
+  function innerGen() {
+    return babelHelpers.regeneratorRuntime().wrap(function innerGen$(_context4) {
+      while (1) switch (_context4.prev = _context4.next) {
+        case 0:
+          _context4.next = 2;
+          return 1;
+        case 2:
+        case "end": return _context4.stop();
+      }
+    }, _marked);
+  }
+  var _marked;
+  return babelHelpers.regeneratorRuntime().wrap(function outer$(_context5) {
+    while (1) switch (_context5.prev = _context5.next) {
+      case 0:
+        _marked = babelHelpers.regeneratorRuntime().mark(innerGen);
+        return _context5.delegateYield(innerGen(), "t0", 2);
+      case 2:
+      case "end": return _context5.stop();
+    }
+  }, _marked2);
+}
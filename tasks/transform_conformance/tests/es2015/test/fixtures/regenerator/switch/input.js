@@ -0,0 +1,15 @@
+function* gen(x) {
+  switch (x) {
+    case 1:
+      yield "one";
+    case 2:
+      yield "two";
+      break;
+    default:
+      yield "other";
+  }
+  switch (yield) {
+    case yield:
+      return 1;
+  }
+}
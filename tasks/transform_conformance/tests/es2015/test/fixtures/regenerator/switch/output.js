@@ -0,0 +1,45 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen(x) {
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.t0 = x;
+        _context.next = _context.t0 === 1 ? 3 : _context.t0 === 2 ? 5 : 8;
+        break;
+      case 3:
+        _context.next = 5;
+        return "one";
+      case 5:
+        _context.next = 7;
+        return "two";
+      case 7: return _context.abrupt("break", 10);
+      case 8:
+        _context.next = 10;
+        return "other";
+      case 10:
+        _context.next = 12;
+        return;
+      case 12:
+        _context.t1 = _context.sent;
+        _context.t2 = _context.t1;
+        _context.next = 16;
+        return;
+      case 16:
+        _context.t3 = _context.sent;
+        if (!(_context.t2 === _context.t3)) {
+          _context.next = 21;
+          break;
+        }
+        _context.t4 = 24;
+        _context.next = 22;
+        break;
+      case 21: _context.t4 = 25;
+      case 22:
+        _context.next = _context.t4;
+        break;
+      case 24: return _context.abrupt("return", 1);
+      case 25:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,19 @@
+function* tryCatch() {
+  try {
+    yield 1;
+  } catch (e) {
+    yield e;
+  }
+  try {
+    yield 2;
+  } finally {
+    cleanup();
+  }
+  try {
+    return yield 3;
+  } catch {
+    console.log("error");
+  } finally {
+    yield 4;
+  }
+}
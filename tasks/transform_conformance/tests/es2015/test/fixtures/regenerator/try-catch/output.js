@@ -0,0 +1,58 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(tryCatch);
+function tryCatch() {
+  var e;
+  return babelHelpers.regeneratorRuntime().wrap(function tryCatch$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.prev = 0;
+        _context.next = 3;
+        return 1;
+      case 3:
+        _context.next = 9;
+        break;
+      case 5:
+        _context.prev = 5;
+        e = _context["catch"](0);
+        _context.next = 9;
+        return e;
+      case 9:
+        _context.prev = 9;
+        _context.next = 12;
+        return 2;
+      case 12:
+        _context.prev = 12;
+        cleanup();
+        return _context.finish(12);
+      case 15:
+        _context.prev = 15;
+        _context.next = 18;
+        return 3;
+      case 18: return _context.abrupt("return", _context.sent);
+      case 21:
+        _context.prev = 21;
+        _context["catch"](15);
+        console.log("error");
+      case 24:
+        _context.prev = 24;
+        _context.next = 27;
+        return 4;
+      case 27: return _context.finish(24);
+      case 28:
+      case "end": return _context.stop();
+    }
+  }, _marked, null, [
+    [0, 5],
+    [
+      9,
+      ,
+      12,
+      15
+    ],
+    [
+      15,
+      21,
+      24,
+      28
+    ]
+  ]);
+}
@@ -0,0 +1,7 @@
+var obj = {
+  a,
+  b() {},
+  c: c,
+  async d() {},
+  *e() {}
+};
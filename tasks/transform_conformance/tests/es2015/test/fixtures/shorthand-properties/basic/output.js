@@ -0,0 +1,7 @@
+var obj = {
+  a: a,
+  b: function() {},
+  c: c,
+  d: async function() {},
+  e: function* () {}
+};
@@ -0,0 +1,3 @@
+var a = { __proto__ };
+var b = { __proto__() {} };
+var c = { __proto__: c };
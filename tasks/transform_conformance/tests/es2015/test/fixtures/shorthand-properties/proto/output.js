@@ -0,0 +1,3 @@
+var a = { ["__proto__"]: __proto__ };
+var b = { ["__proto__"]: function() {} };
+var c = { __proto__: c };
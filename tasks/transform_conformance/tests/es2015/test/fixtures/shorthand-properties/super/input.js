@@ -0,0 +1,16 @@
+var obj = {
+  a() {
+    return super.a();
+  },
+  b() {
+    return () => super.b;
+  },
+  c() {
+    return function () {
+      return { d() { return super.d; } };
+    };
+  },
+  __proto__() {
+    return super.__proto__;
+  }
+};
@@ -0,0 +1,20 @@
+var obj = {
+  a() {
+    return super.a();
+  },
+  b() {
+    return () => super.b;
+  },
+  c: function () {
+    return function () {
+      return {
+        d() {
+          return super.d;
+        }
+      };
+    };
+  },
+  __proto__() {
+    return super.__proto__;
+  }
+};
@@ -0,0 +1,4 @@
+var a = [...b];
+var c = [d, ...e, f];
+var g = [...h, ...i];
+var j = [, ...k];
@@ -0,0 +1,4 @@
+var a = babelHelpers.toConsumableArray(b);
+var c = [d].concat(babelHelpers.toConsumableArray(e), [f]);
+var g = [].concat(babelHelpers.toConsumableArray(h), babelHelpers.toConsumableArray(i));
+var j = [,].concat(babelHelpers.toConsumableArray(k));
@@ -0,0 +1,18 @@
+foo(...args);
+foo(a, ...args, b);
+obj.method(...args);
+obj[key](...args);
+a.b.c(...args);
+this.method(...args);
+getObj().method(...args);
+foo?.(...args);
+obj.method?.(...args);
+a?.b(...args);
+foo?.(...args).bar(...args);
+a?.b.c(...args);
+class A extends B {
+  constructor(...args) {
+    super(...args);
+    super.method(...args);
+  }
+}
@@ -0,0 +1,19 @@
+var _a$b, _getObj;
+foo.apply(void 0, babelHelpers.toConsumableArray(args));
+foo.apply(void 0, [a].concat(babelHelpers.toConsumableArray(args), [b]));
+obj.method.apply(obj, babelHelpers.toConsumableArray(args));
+obj[key].apply(obj, babelHelpers.toConsumableArray(args));
+(_a$b = a.b).c.apply(_a$b, babelHelpers.toConsumableArray(args));
+this.method.apply(this, babelHelpers.toConsumableArray(args));
+(_getObj = getObj()).method.apply(_getObj, babelHelpers.toConsumableArray(args));
+foo?.apply(void 0, babelHelpers.toConsumableArray(args));
+obj.method?.apply(obj, babelHelpers.toConsumableArray(args));
+a?.b.apply(a, babelHelpers.toConsumableArray(args));
+foo?.apply(void 0, babelHelpers.toConsumableArray(args)).bar(...args);
+a?.b.c(...args);
+class A extends B {
+  constructor(...args) {
+    super(...args);
+    super.method.apply(this, babelHelpers.toConsumableArray(args));
+  }
+}
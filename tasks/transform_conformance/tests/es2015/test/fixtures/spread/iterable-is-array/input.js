@@ -0,0 +1,5 @@
+var a = [...b];
+var c = [d, ...e];
+foo(...args);
+obj.method(a, ...args);
+new Foo(...args);
@@ -0,0 +1,5 @@
+var a = [].concat(b);
+var c = [d].concat(e);
+foo.apply(void 0, args);
+obj.method.apply(obj, [a].concat(args));
+babelHelpers.construct(Foo, args);
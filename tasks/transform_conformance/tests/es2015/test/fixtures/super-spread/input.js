@@ -0,0 +1,7 @@
+class A extends B {
+  constructor(...args) {
+    super(...args);
+    super.method(a, ...args);
+  }
+}
+foo?.(...args);
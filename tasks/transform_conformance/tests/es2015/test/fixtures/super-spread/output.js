@@ -0,0 +1,16 @@
+var _foo;
+var A = /* @__PURE__ */ function(_B) {
+  function A() {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    for (var _len = arguments.length, args = new Array(_len), _key = 0; _key < _len; _key++) {
+      args[_key] = arguments[_key];
+    }
+    _this = babelHelpers.callSuper(this, A, babelHelpers.toConsumableArray(args));
+    babelHelpers.superPropGet(A, "method", _this, 1).apply(_this, [a].concat(babelHelpers.toConsumableArray(args)));
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+(_foo = foo) === null || _foo === void 0 ? void 0 : _foo.apply(void 0, babelHelpers.toConsumableArray(args));
@@ -0,0 +1,5 @@
+var a = `foo`;
+var b = `foo${bar}`;
+var c = `${foo}bar${baz}`;
+var d = `${1}${2}`;
+var e = `a${b}c${d}e`;
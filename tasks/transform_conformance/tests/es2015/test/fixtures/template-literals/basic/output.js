@@ -0,0 +1,5 @@
+var a = "foo";
+var b = "foo".concat(bar);
+var c = "".concat(foo, "bar").concat(baz);
+var d = "".concat(1).concat(2);
+var e = "a".concat(b, "c").concat(d, "e");
@@ -0,0 +1,4 @@
+var a = tag`foo`;
+var b = tag`foo${bar}baz`;
+var c = tag`\u{41}${x}`;
+var d = tag`\unicode`;
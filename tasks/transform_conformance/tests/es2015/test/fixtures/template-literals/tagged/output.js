@@ -0,0 +1,5 @@
+var _templateObject, _templateObject2, _templateObject3, _templateObject4;
+var a = tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo"])));
+var b = tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["foo", "baz"])), bar);
+var c = tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral(["A", ""], ["\\u{41}", ""])), x);
+var d = tag(_templateObject4 || (_templateObject4 = babelHelpers.taggedTemplateLiteral([void 0], ["\\unicode"])));
@@ -0,0 +1,7 @@
+var a = typeof s;
+var b = typeof s === "string";
+var c = typeof s === "symbol";
+var d = typeof obj.prop;
+function f(x) {
+  return typeof x;
+}
@@ -0,0 +1,7 @@
+var a = typeof s === "undefined" ? "undefined" : babelHelpers.typeof(s);
+var b = typeof s === "string";
+var c = (typeof s === "undefined" ? "undefined" : babelHelpers.typeof(s)) === "symbol";
+var d = babelHelpers.typeof(obj.prop);
+function f(x) {
+  return babelHelpers.typeof(x);
+}
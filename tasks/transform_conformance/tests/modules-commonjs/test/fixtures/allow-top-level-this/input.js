@@ -0,0 +1,11 @@
+export const self = this;
+const arrow = () => this;
+function fn() {
+  return this;
+}
+class C {
+  x = this;
+  method() {
+    return this;
+  }
+}
@@ -0,0 +1,14 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.self = void 0;
+const self = exports.self = this;
+const arrow = () => this;
+function fn() {
+  return this;
+}
+class C {
+  x = this;
+  method() {
+    return this;
+  }
+}
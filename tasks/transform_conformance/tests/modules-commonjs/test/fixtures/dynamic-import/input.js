@@ -0,0 +1,2 @@
+import("foo").then((foo) => foo.default);
+import(`./locale/${lang}.js`);
@@ -0,0 +1,7 @@
+"use strict";
+Promise.resolve().then(function() {
+  return babelHelpers.interopRequireWildcard(require("foo"));
+}).then((foo) => foo.default);
+Promise.resolve(`./locale/${lang}.js`).then(function(s) {
+  return babelHelpers.interopRequireWildcard(require(s));
+});
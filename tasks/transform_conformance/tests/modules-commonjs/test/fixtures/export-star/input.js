@@ -0,0 +1,3 @@
+export * from "foo";
+export * from "bar";
+export const baz = 1;
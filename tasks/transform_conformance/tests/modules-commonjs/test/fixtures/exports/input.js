@@ -0,0 +1,7 @@
+export const a = 1, b = 2;
+export let { c, d: [e] } = obj;
+export function f() {}
+export class G {}
+export default function () {}
+var h = 1, i;
+export { h, h as j, i };
@@ -0,0 +1,14 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.a = exports.b = exports.c = exports.e = exports.G = exports.h = exports.j = exports.i = void 0;
+exports.f = f;
+exports.default = _default;
+const a = exports.a = 1, b = exports.b = 2;
+let { c, d: [e] } = obj;
+exports.c = c;
+exports.e = e;
+function f() {}
+class G {}
+exports.G = G;
+function _default() {}
+var h = exports.h = exports.j = 1, i;
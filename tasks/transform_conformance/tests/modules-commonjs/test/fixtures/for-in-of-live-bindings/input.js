@@ -0,0 +1,11 @@
+export let key, item, a, b;
+let local;
+for (key in obj) {}
+for (item of list) console.log(item);
+for ([a, { b }] of pairs) {
+  console.log(a, b);
+}
+for (local of list) {}
+function f() {
+  for (item of list) {}
+}
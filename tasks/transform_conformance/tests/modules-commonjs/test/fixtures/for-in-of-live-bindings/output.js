@@ -0,0 +1,24 @@
+"use strict";
+
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.key = exports.item = exports.a = exports.b = void 0;
+var _ref2;
+let key, item, a, b;
+let local;
+for (var _key in obj) {
+  exports.key = key = _key;
+}
+for (var _item of list) {
+  exports.item = item = _item;
+  console.log(item);
+}
+for (var _ref of pairs) {
+  _ref2 = [a, { b }] = _ref, exports.a = a, exports.b = b, _ref2;
+  console.log(a, b);
+}
+for (local of list) {}
+function f() {
+  for (var _item2 of list) {
+    exports.item = item = _item2;
+  }
+}
@@ -0,0 +1,8 @@
+"use strict";
+var _foo = require("foo");
+var _bar = require("bar");
+var ns = babelHelpers.interopRequireWildcard(require("ns"), true);
+_foo(_bar.bar, ns, ns.default);
+Promise.resolve().then(function() {
+  return babelHelpers.interopRequireWildcard(require("lazy"), true);
+});
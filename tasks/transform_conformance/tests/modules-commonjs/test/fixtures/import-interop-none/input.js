@@ -0,0 +1,6 @@
+import foo from "foo";
+import { bar } from "bar";
+import * as ns from "ns";
+import def from "ns";
+foo(bar, ns, def);
+import("lazy");
@@ -0,0 +1,8 @@
+"use strict";
+var _foo = require("foo");
+var _bar = require("bar");
+var ns = require("ns");
+(0, _foo.default)(_bar.bar, ns, ns.default);
+Promise.resolve().then(function() {
+  return require("lazy");
+});
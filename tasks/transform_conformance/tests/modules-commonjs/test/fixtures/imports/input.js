@@ -0,0 +1,12 @@
+import "side-effect";
+import foo from "foo";
+import { bar, baz as qux } from "bar";
+import def, { named } from "mixed";
+import * as ns from "namespace";
+
+foo();
+bar();
+qux.call(null);
+tag`${named}`;
+new def();
+console.log(ns.value, ns, { bar });
@@ -0,0 +1,12 @@
+"use strict";
+require("side-effect");
+var _foo = babelHelpers.interopRequireDefault(require("foo"));
+var _bar = require("bar");
+var _mixed = babelHelpers.interopRequireWildcard(require("mixed"));
+var ns = babelHelpers.interopRequireWildcard(require("namespace"));
+(0, _foo.default)();
+(0, _bar.bar)();
+_bar.baz.call(null);
+tag`${_mixed.named}`;
+new _mixed.default();
+console.log(ns.value, ns, { bar: _bar.bar });
@@ -0,0 +1,13 @@
+export let count = 0;
+export let a, b;
+export function increment() {
+  count++;
+  count += 1;
+  ++count;
+  return count++;
+}
+export function reset() {
+  [a, { b }] = [1, { b: 2 }];
+  count = 0;
+}
+export { count as total };
@@ -0,0 +1,18 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.count = exports.a = exports.b = exports.total = void 0;
+exports.increment = increment;
+exports.reset = reset;
+var _old, _ref;
+let count = exports.count = exports.total = 0;
+let a, b;
+function increment() {
+  exports.count = exports.total = ++count;
+  exports.count = exports.total = count += 1;
+  exports.count = exports.total = ++count;
+  return _old = count++, exports.count = exports.total = count, _old;
+}
+function reset() {
+  _ref = [a, {b}] = [1, { b: 2 }], exports.a = a, exports.b = b, _ref;
+  exports.count = exports.total = count = 0;
+}
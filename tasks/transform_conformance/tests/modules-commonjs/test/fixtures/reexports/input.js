@@ -0,0 +1,6 @@
+import { a } from "a";
+import * as ns from "ns";
+export { a, ns };
+export { b, default as c } from "b";
+export * as d from "d";
+export default a;
@@ -0,0 +1,38 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.default = void 0;
+Object.defineProperty(exports, "a", {
+  enumerable: true,
+  get: function() {
+    return _a.a;
+  }
+});
+Object.defineProperty(exports, "ns", {
+  enumerable: true,
+  get: function() {
+    return ns;
+  }
+});
+Object.defineProperty(exports, "b", {
+  enumerable: true,
+  get: function() {
+    return _b.b;
+  }
+});
+Object.defineProperty(exports, "c", {
+  enumerable: true,
+  get: function() {
+    return _b.default;
+  }
+});
+Object.defineProperty(exports, "d", {
+  enumerable: true,
+  get: function() {
+    return _d;
+  }
+});
+var _a = require("a");
+var ns = babelHelpers.interopRequireWildcard(require("ns"));
+var _b = babelHelpers.interopRequireWildcard(require("b"));
+var _d = babelHelpers.interopRequireWildcard(require("d"));
+exports.default = _a.a;
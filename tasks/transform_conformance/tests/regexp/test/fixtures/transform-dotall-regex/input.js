@@ -1 +1,3 @@
 a1 = /a.b/s
+a2 = /a.b/su
+a3 = /[.]|(?-s:.)/s
@@ -1,2 +1,4 @@
 c1 = /(?<a>b)/
 c2 = /((?<a>b)){2}/
+c3 = /(?<year>\d{4})-\k<year>/
+c4 = /(?<a>x)|(?<a>y)/
@@ -1,2 +1,4 @@
-c1 = new RegExp("(?<a>b)", "");
-c2 = new RegExp("((?<a>b)){2}", "");
+c1 = /* @__PURE__ */ babelHelpers.wrapRegExp(/(b)/, { a: 1 });
+c2 = /* @__PURE__ */ babelHelpers.wrapRegExp(/((b)){2}/, { a: 2 });
+c3 = /* @__PURE__ */ babelHelpers.wrapRegExp(/(\d{4})-\1/, { year: 1 });
+c4 = /* @__PURE__ */ babelHelpers.wrapRegExp(/(x)|(y)/, { a: [1, 2] });
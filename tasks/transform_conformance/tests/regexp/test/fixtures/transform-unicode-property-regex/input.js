@@ -1,2 +1,4 @@
 d1 = /\p{Emoji}/u
 d2 = /\p{Emoji}{2}/u
+d3 = /[\p{ASCII_Hex_Digit}_]/u
+d4 = /\P{ASCII}/u
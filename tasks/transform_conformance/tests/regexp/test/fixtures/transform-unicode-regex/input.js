@@ -1 +1,12 @@
-x2 = /./u
+x1 = /./u
+x2 = /./su
+x3 = /😀+/u
+x4 = /\u{1F600}\u{41}/u
+x5 = /[a😀-😂]/u
+x6 = /[\u{10000}-\u{10FFFF}]/u
+x7 = /[^a]/u
+x8 = /\S/u
+x9 = /[a-z]/u
+x10 = /s/iu
+x11 = /\p{Emoji_Presentation}/u
+x12 = /[\p{ASCII}]/u
@@ -1 +1,13 @@
-x2 = new RegExp(".", "u");
+
+x1 = /(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[\u0000-\u0009\u000B-\u000C\u000E-\u2027\u202A-\uFFFF])/;
+x2 = /(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[\u0000-\uFFFF])/s;
+x3 = /(?:😀)+/;
+x4 = /😀\u0041/;
+x5 = /(?:\uD83D[\uDE00-\uDE02]|[a])/;
+x6 = /(?:[\uD800-\uDBFF][\uDC00-\uDFFF])/;
+x7 = /(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[\u0000-\u0060b-\uFFFF])/;
+x8 = /(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[\u0000-\u0008\u000E-\u001F\u0021-\u009F\u00A1-\u167F\u1681-\u1FFF\u200B-\u2027\u202A-\u202E\u2030-\u205E\u2060-\u2FFF\u3001-\uFEFE\uFF00-\uFFFF])/;
+x9 = /[a-z]/;
+x10 = /[Ss\u017F]/i;
+x11 = /(?:\uD83C[\uDC04\uDCCF\uDD8E\uDD91-\uDD9A\uDDE6-\uDDFF\uDE01\uDE1A\uDE2F\uDE32-\uDE36\uDE38-\uDE3A\uDE50-\uDE51\uDF00-\uDF20\uDF2D-\uDF35\uDF37-\uDF7C\uDF7E-\uDF93\uDFA0-\uDFCA\uDFCF-\uDFD3\uDFE0-\uDFF0\uDFF4\uDFF8-\uDFFF]|\uD83D[\uDC00-\uDC3E\uDC40\uDC42-\uDCFC\uDCFF-\uDD3D\uDD4B-\uDD4E\uDD50-\uDD67\uDD7A\uDD95-\uDD96\uDDA4\uDDFB-\uDE4F\uDE80-\uDEC5\uDECC\uDED0-\uDED2\uDED5-\uDED7\uDEDC-\uDEDF\uDEEB-\uDEEC\uDEF4-\uDEFC\uDFE0-\uDFEB\uDFF0]|\uD83E[\uDD0C-\uDD3A\uDD3C-\uDD45\uDD47-\uDDFF\uDE70-\uDE7C\uDE80-\uDE89\uDE8F-\uDEC6\uDECE-\uDEDC\uDEDF-\uDEE9\uDEF0-\uDEF8]|[\u231A-\u231B\u23E9-\u23EC\u23F0\u23F3\u25FD-\u25FE\u2614-\u2615\u2648-\u2653\u267F\u2693\u26A1\u26AA-\u26AB\u26BD-\u26BE\u26C4-\u26C5\u26CE\u26D4\u26EA\u26F2-\u26F3\u26F5\u26FA\u26FD\u2705\u270A-\u270B\u2728\u274C\u274E\u2753-\u2755\u2757\u2795-\u2797\u27B0\u27BF\u2B1B-\u2B1C\u2B50\u2B55])/;
+x12 = /[\u0000-\u007F]/;
@@ -1 +1,5 @@
 g1 = /[\p{White_Space}&&\p{ASCII}]/v
+g2 = /[\q{abc|d}x]/v
+g3 = /[[a-z]--[aeiou]]/v
+g4 = /[[a-z]--[aeiou]]/vi
+g5 = /\p{RGI_Emoji}/v
@@ -1 +1,5 @@
-g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g1 = /[\u0009-\u000D\u0020]/u;
+g2 = /(?:abc|[dx])/u;
+g3 = /[b-df-hj-np-tv-z]/u;
+g4 = new RegExp("[[a-z]--[aeiou]]", "iv");
+g5 = new RegExp("\\p{RGI_Emoji}", "v");